@@ -4,6 +4,15 @@ pub enum BankStatus {
     Disabled = 2,
 }
 
+/// How a bank's account numbers are checked, on top of its optional length
+/// range and pattern.
+#[rf_db_enum(storage = "i16")]
+pub enum BankAccountNumberFormat {
+    Any = 1,
+    Digits = 2,
+    Iban = 3,
+}
+
 #[rf_model(table = "banks")]
 pub struct Bank {
     #[rf(pk(strategy = snowflake))]
@@ -12,6 +21,11 @@ pub struct Bank {
     pub name: String,
     pub code: Option<String>,
     pub status: BankStatus,
+    pub account_number_format: BankAccountNumberFormat,
+    pub account_number_min_length: Option<i32>,
+    pub account_number_max_length: Option<i32>,
+    /// Regex the whole account number must match
+    pub account_number_pattern: Option<String>,
     pub sort_order: i32,
     pub created_at: time::OffsetDateTime,
     pub updated_at: time::OffsetDateTime,
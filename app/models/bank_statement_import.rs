@@ -0,0 +1,17 @@
+/// One uploaded bank statement CSV for a company bank account.
+#[rf_model(table = "bank_statement_imports")]
+pub struct BankStatementImport {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub company_bank_account_id: i64,
+    pub file_name: Option<String>,
+    pub line_count: i32,
+    pub matched_count: i32,
+    pub uploaded_by: i64,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+    #[rf(foreign_key = "company_bank_account_id")]
+    pub company_bank_account: BelongsTo<CompanyBankAccount>,
+    #[rf(foreign_key = "uploaded_by")]
+    pub uploader: BelongsTo<Admin>,
+}
@@ -0,0 +1,34 @@
+#[rf_db_enum(storage = "i16")]
+pub enum BankStatementLineStatus {
+    /// Matches a pending deposit, waiting for an admin to approve it
+    Matched = 1,
+    Unmatched = 2,
+    /// The matched deposit was approved from this line
+    Approved = 3,
+}
+
+/// A credit line of an imported bank statement and what it was matched to.
+#[rf_model(table = "bank_statement_lines", observe = false)]
+pub struct BankStatementLine {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub import_id: i64,
+    /// 1-based line in the uploaded file
+    pub line_number: i32,
+    pub reference: String,
+    pub amount: Option<rust_decimal::Decimal>,
+    pub account_number: Option<String>,
+    pub transaction_date: Option<String>,
+    /// The CSV row keyed by column header
+    pub raw: serde_json::Value,
+    pub status: BankStatementLineStatus,
+    pub deposit_id: Option<i64>,
+    /// Why the line did not match, if it didn't
+    pub note: Option<String>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+    #[rf(foreign_key = "import_id")]
+    pub statement_import: BelongsTo<BankStatementImport>,
+    #[rf(foreign_key = "deposit_id")]
+    pub deposit: BelongsTo<Deposit>,
+}
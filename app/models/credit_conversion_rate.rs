@@ -0,0 +1,20 @@
+#[rf_db_enum(storage = "i16")]
+pub enum CreditConversionRateStatus {
+    Enabled = 1,
+    Disabled = 2,
+}
+
+#[rf_model(table = "credit_conversion_rates")]
+pub struct CreditConversionRate {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub from_credit_type: CreditType,
+    pub to_credit_type: CreditType,
+    pub rate: rust_decimal::Decimal,
+    pub fee_percentage: rust_decimal::Decimal,
+    pub fee_fixed: rust_decimal::Decimal,
+    pub min_amount: rust_decimal::Decimal,
+    pub status: CreditConversionRateStatus,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+}
@@ -0,0 +1,27 @@
+#[rf_db_enum(storage = "i16")]
+pub enum CreditLedgerMismatchStatus {
+    Open = 1,
+    Resolved = 2,
+    Corrected = 3,
+}
+
+#[rf_model(table = "credit_ledger_mismatches")]
+pub struct CreditLedgerMismatch {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub user_id: i64,
+    pub admin_id: Option<i64>,
+    pub credit_type: CreditType,
+    pub stored_balance: rust_decimal::Decimal,
+    pub ledger_balance: rust_decimal::Decimal,
+    pub difference: rust_decimal::Decimal,
+    pub status: CreditLedgerMismatchStatus,
+    pub detected_at: time::OffsetDateTime,
+    pub resolved_at: Option<time::OffsetDateTime>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+    #[rf(foreign_key = "user_id")]
+    pub user: BelongsTo<User>,
+    #[rf(foreign_key = "admin_id")]
+    pub admin: BelongsTo<Admin>,
+}
@@ -4,6 +4,16 @@ pub enum CryptoNetworkStatus {
     Disabled = 2,
 }
 
+/// How wallet addresses on a network are validated. `Unchecked` leaves
+/// them as free text.
+#[rf_db_enum(storage = "i16")]
+pub enum CryptoAddressFormat {
+    Unchecked = 1,
+    Evm = 2,
+    Tron = 3,
+    Bitcoin = 4,
+}
+
 #[rf_model(table = "crypto_networks")]
 pub struct CryptoNetwork {
     #[rf(pk(strategy = snowflake))]
@@ -11,6 +21,7 @@ pub struct CryptoNetwork {
     pub name: String,
     pub symbol: String,
     pub status: CryptoNetworkStatus,
+    pub address_format: CryptoAddressFormat,
     pub sort_order: i32,
     pub created_at: time::OffsetDateTime,
     pub updated_at: time::OffsetDateTime,
@@ -6,6 +6,7 @@ pub enum OwnerType {
 #[rf_db_enum(storage = "i16")]
 pub enum DepositMethod {
     Manual = 1,
+    Gateway = 2,
 }
 
 #[rf_db_enum(storage = "i16")]
@@ -13,6 +14,8 @@ pub enum DepositStatus {
     Pending = 1,
     Approved = 2,
     Rejected = 3,
+    Reversed = 4,
+    Expired = 5,
 }
 
 #[rf_db_enum(storage = "i16")]
@@ -42,10 +45,26 @@ pub struct Deposit {
     pub remark: Option<String>,
     pub admin_remark: Option<String>,
     pub reviewed_at: Option<time::OffsetDateTime>,
+    pub reversed_by: Option<i64>,
+    pub reversal_reason: Option<String>,
+    pub reversed_at: Option<time::OffsetDateTime>,
+    /// Short code the user puts in the transfer memo, used to match bank statements
+    pub reference_code: Option<String>,
+    /// Payment gateway and checkout session a `Gateway` deposit was paid through
+    pub gateway_provider: Option<String>,
+    pub gateway_session_id: Option<String>,
+    pub gateway_checkout_url: Option<String>,
+    /// Admin currently reviewing the record, until `claimed_until`
+    pub claimed_by: Option<i64>,
+    pub claimed_until: Option<time::OffsetDateTime>,
     pub created_at: time::OffsetDateTime,
     pub updated_at: time::OffsetDateTime,
     #[rf(foreign_key = "admin_id")]
     pub admin: BelongsTo<Admin>,
+    #[rf(foreign_key = "reversed_by")]
+    pub reverser: BelongsTo<Admin>,
+    #[rf(foreign_key = "claimed_by")]
+    pub claimer: BelongsTo<Admin>,
     #[rf(foreign_key = "company_bank_account_id")]
     pub company_bank_account: BelongsTo<CompanyBankAccount>,
     #[rf(foreign_key = "company_crypto_account_id")]
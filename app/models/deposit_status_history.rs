@@ -0,0 +1,17 @@
+/// One row per status a deposit entered, oldest first, so earlier review steps
+/// are kept after the deposit moves on.
+#[rf_model(table = "deposit_status_histories", observe = false)]
+pub struct DepositStatusHistory {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub deposit_id: i64,
+    pub status: DepositStatus,
+    /// Admin who made the transition; empty when the owner submitted it
+    pub admin_id: Option<i64>,
+    pub remark: Option<String>,
+    pub created_at: time::OffsetDateTime,
+    #[rf(foreign_key = "deposit_id")]
+    pub deposit: BelongsTo<Deposit>,
+    #[rf(foreign_key = "admin_id")]
+    pub admin: BelongsTo<Admin>,
+}
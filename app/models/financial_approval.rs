@@ -0,0 +1,37 @@
+#[rf_db_enum(storage = "i16")]
+pub enum FinancialApprovalAction {
+    DepositApprove = 1,
+    WithdrawalApprove = 2,
+    CreditAdjust = 3,
+}
+
+#[rf_db_enum(storage = "i16")]
+pub enum FinancialApprovalStatus {
+    Pending = 1,
+    Confirmed = 2,
+    Declined = 3,
+}
+
+#[rf_model(table = "financial_approvals")]
+pub struct FinancialApproval {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub action: FinancialApprovalAction,
+    /// Deposit or withdrawal id, or the user id for a credit adjustment
+    pub subject_id: i64,
+    pub credit_type: CreditType,
+    pub amount: rust_decimal::Decimal,
+    /// The proposed request, replayed when the approval is confirmed
+    pub payload: serde_json::Value,
+    pub status: FinancialApprovalStatus,
+    pub proposed_by: i64,
+    pub reviewed_by: Option<i64>,
+    pub review_remark: Option<String>,
+    pub reviewed_at: Option<time::OffsetDateTime>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+    #[rf(foreign_key = "proposed_by")]
+    pub proposer: BelongsTo<Admin>,
+    #[rf(foreign_key = "reviewed_by")]
+    pub reviewer: BelongsTo<Admin>,
+}
@@ -0,0 +1,57 @@
+#[rf_db_enum(storage = "i16")]
+pub enum KycStatus {
+    Pending = 1,
+    Approved = 2,
+    Rejected = 3,
+}
+
+#[rf_db_enum(storage = "i16")]
+pub enum KycReviewAction {
+    Approve = 1,
+    Reject = 2,
+}
+
+#[rf_db_enum(storage = "i16")]
+pub enum KycDocumentType {
+    IdCard = 1,
+    Passport = 2,
+    DriverLicense = 3,
+}
+
+/// A user's request to reach `level`. The document images are uploaded to
+/// the submission while it is pending.
+#[rf_model(table = "kyc_submissions")]
+pub struct KycSubmission {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub user_id: i64,
+    pub level: KycLevel,
+    pub status: KycStatus,
+    pub full_name: String,
+    pub document_type: KycDocumentType,
+    pub document_number: String,
+    pub reject_reason: Option<String>,
+    pub admin_id: Option<i64>,
+    pub reviewed_at: Option<time::OffsetDateTime>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+    #[rf(foreign_key = "user_id")]
+    pub user: BelongsTo<User>,
+    #[rf(foreign_key = "admin_id")]
+    pub admin: BelongsTo<Admin>,
+    #[rf(kind = "image")]
+    pub id_front: Attachment,
+    #[rf(kind = "image")]
+    pub id_back: Attachment,
+    #[rf(kind = "image")]
+    pub selfie: Attachment,
+    #[rf(kind = "image")]
+    pub proof_of_address: Attachment,
+}
+
+#[rf_record_impl]
+impl KycSubmissionRecord {
+    pub fn status_label(&self) -> String {
+        self.status.explained_label().to_string()
+    }
+}
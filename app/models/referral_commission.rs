@@ -0,0 +1,33 @@
+#[rf_db_enum(storage = "i16")]
+pub enum ReferralCommissionSource {
+    Deposit = 1,
+}
+
+#[rf_db_enum(storage = "i16")]
+pub enum ReferralCommissionStatus {
+    Paid = 1,
+    Reversed = 2,
+}
+
+#[rf_model(table = "referral_commissions")]
+pub struct ReferralCommission {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub source_type: ReferralCommissionSource,
+    pub source_id: i64,
+    pub source_user_id: i64,
+    pub beneficiary_user_id: i64,
+    pub level: i32,
+    pub credit_type: CreditType,
+    pub base_amount: rust_decimal::Decimal,
+    pub percentage: rust_decimal::Decimal,
+    pub amount: rust_decimal::Decimal,
+    pub status: ReferralCommissionStatus,
+    pub reversed_at: Option<time::OffsetDateTime>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+    #[rf(foreign_key = "source_user_id")]
+    pub source_user: BelongsTo<User>,
+    #[rf(foreign_key = "beneficiary_user_id")]
+    pub beneficiary: BelongsTo<User>,
+}
@@ -0,0 +1,11 @@
+#[rf_model(table = "referral_commission_rates")]
+pub struct ReferralCommissionRate {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub credit_type: CreditType,
+    /// 1 = the source user's direct introducer
+    pub level: i32,
+    pub percentage: rust_decimal::Decimal,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+}
@@ -4,6 +4,15 @@ pub enum UserBanStatus {
     Yes = 1,
 }
 
+/// Highest identity verification a user has passed; raised when an admin
+/// approves a `KycSubmission`.
+#[rf_db_enum(storage = "i16")]
+pub enum KycLevel {
+    Unverified = 0,
+    Basic = 1,
+    Advanced = 2,
+}
+
 #[rf_model(table = "users")]
 pub struct User {
     #[rf(pk(strategy = snowflake))]
@@ -19,6 +28,7 @@ pub struct User {
     pub contact_number: Option<String>,
     pub introducer_user_id: Option<i64>,
     pub ban: UserBanStatus,
+    pub kyc_level: KycLevel,
     pub credit_1: rust_decimal::Decimal,
     pub credit_2: rust_decimal::Decimal,
     pub created_at: time::OffsetDateTime,
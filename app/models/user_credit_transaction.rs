@@ -14,7 +14,15 @@ pub enum CreditTransactionType {
     TransferOut = 202,
     Withdraw = 301,
     WithdrawRefund = 302,
+    /// Returns the amount held when a withdrawal was submitted
+    WithdrawHoldRelease = 303,
     TopUp = 401,
+    DepositReversal = 402,
+    ConvertOut = 501,
+    ConvertIn = 502,
+    ReferralCommission = 601,
+    ReferralCommissionReversal = 602,
+    LedgerCorrection = 901,
 }
 
 #[rf_db_enum(storage = "i16")]
@@ -30,6 +38,8 @@ pub struct UserCreditTransaction {
     pub admin_id: Option<i64>,
     pub credit_type: CreditType,
     pub amount: rust_decimal::Decimal,
+    pub balance_before: rust_decimal::Decimal,
+    pub balance_after: rust_decimal::Decimal,
     pub transaction_type: CreditTransactionType,
     pub related_key: Option<String>,
     pub params: Option<serde_json::Value>,
@@ -0,0 +1,30 @@
+#[rf_db_enum(storage = "i16")]
+pub enum UserLimitKind {
+    Deposit = 1,
+    Withdrawal = 2,
+}
+
+/// A user's override of the `[limits]` config. Unset fields inherit the
+/// country or global value; 0 lifts the limit.
+#[rf_model(table = "user_limits")]
+pub struct UserLimit {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub user_id: i64,
+    pub kind: UserLimitKind,
+    pub min_amount: Option<rust_decimal::Decimal>,
+    pub max_amount: Option<rust_decimal::Decimal>,
+    pub daily_count: Option<i32>,
+    pub daily_amount: Option<rust_decimal::Decimal>,
+    pub weekly_count: Option<i32>,
+    pub weekly_amount: Option<rust_decimal::Decimal>,
+    pub monthly_count: Option<i32>,
+    pub monthly_amount: Option<rust_decimal::Decimal>,
+    pub updated_by: Option<i64>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+    #[rf(foreign_key = "user_id")]
+    pub user: BelongsTo<User>,
+    #[rf(foreign_key = "updated_by")]
+    pub updater: BelongsTo<Admin>,
+}
@@ -0,0 +1,49 @@
+#[rf_db_enum(storage = "i16")]
+pub enum WithdrawalDestinationKind {
+    Bank = 1,
+    Crypto = 2,
+}
+
+#[rf_db_enum(storage = "i16")]
+pub enum WithdrawalDestinationStatus {
+    Active = 1,
+    Locked = 2,
+}
+
+/// A bank account or crypto wallet a user saved to withdraw to. Only the
+/// fields of its `kind` are set.
+#[rf_model(table = "user_withdrawal_destinations")]
+pub struct UserWithdrawalDestination {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub user_id: i64,
+    pub kind: WithdrawalDestinationKind,
+    pub bank_id: Option<i64>,
+    pub bank_account_name: Option<String>,
+    pub bank_account_number: Option<String>,
+    pub crypto_network_id: Option<i64>,
+    pub crypto_wallet_address: Option<String>,
+    pub status: WithdrawalDestinationStatus,
+    /// End of the cooling-off period; withdrawals cannot use it before then
+    pub available_at: time::OffsetDateTime,
+    pub lock_reason: Option<String>,
+    pub locked_by: Option<i64>,
+    pub locked_at: Option<time::OffsetDateTime>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+    #[rf(foreign_key = "user_id")]
+    pub user: BelongsTo<User>,
+    #[rf(foreign_key = "bank_id")]
+    pub bank: BelongsTo<Bank>,
+    #[rf(foreign_key = "crypto_network_id")]
+    pub crypto_network: BelongsTo<CryptoNetwork>,
+    #[rf(foreign_key = "locked_by")]
+    pub locker: BelongsTo<Admin>,
+}
+
+#[rf_record_impl]
+impl UserWithdrawalDestinationRecord {
+    pub fn status_label(&self) -> String {
+        self.status.explained_label().to_string()
+    }
+}
@@ -1,6 +1,7 @@
 #[rf_db_enum(storage = "i16")]
 pub enum WithdrawalMethod {
     Manual = 1,
+    Payout = 2,
 }
 
 #[rf_db_enum(storage = "i16")]
@@ -9,6 +10,8 @@ pub enum WithdrawalStatus {
     Processing = 2,
     Approved = 3,
     Rejected = 4,
+    Refunded = 5,
+    Expired = 6,
 }
 
 #[rf_db_enum(storage = "i16")]
@@ -16,6 +19,7 @@ pub enum WithdrawalReviewAction {
     Process = 1,
     Approve = 2,
     Reject = 3,
+    Refund = 4,
 }
 
 #[rf_model(table = "withdrawals")]
@@ -32,6 +36,8 @@ pub struct Withdrawal {
     pub bank_account_number: Option<String>,
     pub crypto_network_id: Option<i64>,
     pub crypto_wallet_address: Option<String>,
+    /// Saved destination the details above were copied from
+    pub destination_id: Option<i64>,
     pub conversion_rate: Option<rust_decimal::Decimal>,
     pub status: WithdrawalStatus,
     pub amount: rust_decimal::Decimal,
@@ -42,10 +48,29 @@ pub struct Withdrawal {
     pub remark: Option<String>,
     pub admin_remark: Option<String>,
     pub reviewed_at: Option<time::OffsetDateTime>,
+    pub refunded_by: Option<i64>,
+    pub refund_reason: Option<String>,
+    pub refunded_at: Option<time::OffsetDateTime>,
+    /// Set once the withdrawal has been processing longer than the SLA
+    pub sla_breached_at: Option<time::OffsetDateTime>,
+    /// Payout provider the withdrawal was sent to on Process, and its id there
+    pub payout_provider: Option<String>,
+    pub payout_reference: Option<String>,
+    pub payout_submitted_at: Option<time::OffsetDateTime>,
+    /// Last time the provider answered about the payout, including a failed
+    /// submission
+    pub payout_checked_at: Option<time::OffsetDateTime>,
+    /// Admin currently reviewing the record, until `claimed_until`
+    pub claimed_by: Option<i64>,
+    pub claimed_until: Option<time::OffsetDateTime>,
     pub created_at: time::OffsetDateTime,
     pub updated_at: time::OffsetDateTime,
     #[rf(foreign_key = "admin_id")]
     pub admin: BelongsTo<Admin>,
+    #[rf(foreign_key = "refunded_by")]
+    pub refunder: BelongsTo<Admin>,
+    #[rf(foreign_key = "claimed_by")]
+    pub claimer: BelongsTo<Admin>,
     #[rf(foreign_key = "bank_id")]
     pub bank: BelongsTo<Bank>,
     #[rf(foreign_key = "crypto_network_id")]
@@ -0,0 +1,17 @@
+/// One row per status a withdrawal entered, oldest first, so earlier review steps
+/// are kept after the withdrawal moves on.
+#[rf_model(table = "withdrawal_status_histories", observe = false)]
+pub struct WithdrawalStatusHistory {
+    #[rf(pk(strategy = snowflake))]
+    pub id: i64,
+    pub withdrawal_id: i64,
+    pub status: WithdrawalStatus,
+    /// Admin who made the transition; empty when the owner submitted it
+    pub admin_id: Option<i64>,
+    pub remark: Option<String>,
+    pub created_at: time::OffsetDateTime,
+    #[rf(foreign_key = "withdrawal_id")]
+    pub withdrawal: BelongsTo<Withdrawal>,
+    #[rf(foreign_key = "admin_id")]
+    pub admin: BelongsTo<Admin>,
+}
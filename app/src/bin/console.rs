@@ -1,3 +1,4 @@
+use app::internal::workflows::ledger_reconcile;
 use bootstrap::boot::BootContext;
 use clap::Subcommand;
 use core_realtime::RealtimePublisher;
@@ -30,6 +31,20 @@ pub enum ProjectCommands {
         #[arg(long)]
         room: Option<String>,
     },
+
+    /// Compare user balances against the credit ledger and record any drift.
+    ///
+    /// Open mismatches are listed in the admin portal, where they can be
+    /// corrected through an audited ledger adjustment.
+    ///
+    /// Examples:
+    ///   ./console ledger-reconcile
+    ///   ./console ledger-reconcile --dry-run
+    LedgerReconcile {
+        /// Only print the drift, without recording it
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 #[async_trait::async_trait]
@@ -65,6 +80,28 @@ impl bootstrap::console::ProjectCommand for ProjectCommands {
                 let target = room.as_deref().unwrap_or("(broadcast)");
                 println!("Published to channel={channel} event={event} room={target}");
             }
+            ProjectCommands::LedgerReconcile { dry_run } => {
+                let drifts = if dry_run {
+                    ledger_reconcile::find_drift(&ctx.db).await?
+                } else {
+                    let summary = ledger_reconcile::scan(&ctx.db).await?;
+                    println!("Resolved {} previously open mismatch(es)", summary.resolved);
+                    summary.drifts
+                };
+
+                for drift in &drifts {
+                    println!(
+                        "user={} ({}) credit_type={} stored={} ledger={} difference={}",
+                        drift.user_id,
+                        drift.username,
+                        drift.credit_type.as_str(),
+                        drift.stored_balance.normalize(),
+                        drift.ledger_balance.normalize(),
+                        drift.difference().normalize(),
+                    );
+                }
+                println!("{} balance(s) out of step with the ledger", drifts.len());
+            }
         }
         Ok(())
     }
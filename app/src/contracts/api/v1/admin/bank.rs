@@ -1,11 +1,11 @@
 use core_web::ids::SnowflakeId;
-use generated::models::BankStatus;
+use generated::models::{BankAccountNumberFormat, BankStatus};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use validator::Validate;
 
-#[derive(Debug, Clone, Deserialize, Validate, JsonSchema, TS)]
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
 #[ts(export, export_to = "admin/types/")]
 pub struct AdminBankInput {
     pub country_iso2: String,
@@ -13,10 +13,62 @@ pub struct AdminBankInput {
     #[serde(default)]
     pub code: Option<String>,
     pub status: BankStatus,
+    /// Defaults to `Any`
+    #[serde(default)]
+    pub account_number_format: Option<BankAccountNumberFormat>,
+    #[serde(default)]
+    pub account_number_min_length: Option<i32>,
+    #[serde(default)]
+    pub account_number_max_length: Option<i32>,
+    /// Regex the whole account number must match
+    #[serde(default)]
+    pub account_number_pattern: Option<String>,
     #[serde(default)]
     pub sort_order: Option<i32>,
 }
 
+impl Validate for AdminBankInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        let mut add = |field: &'static str, code: &'static str, message: &'static str| {
+            errors.add(
+                field,
+                validator::ValidationError::new(code)
+                    .with_message(std::borrow::Cow::Borrowed(message)),
+            );
+        };
+        if self.account_number_min_length.is_some_and(|min| min < 1) {
+            add("account_number_min_length", "min", "Length must be at least 1.");
+        }
+        if self.account_number_max_length.is_some_and(|max| max < 1) {
+            add("account_number_max_length", "min", "Length must be at least 1.");
+        }
+        if let (Some(min), Some(max)) =
+            (self.account_number_min_length, self.account_number_max_length)
+        {
+            if max < min {
+                add(
+                    "account_number_max_length",
+                    "range",
+                    "Maximum length cannot be less than the minimum length.",
+                );
+            }
+        }
+        if let Some(pattern) = self.account_number_pattern.as_deref() {
+            if let Err(error) =
+                crate::validation::bank_account::validate_account_number_pattern(pattern)
+            {
+                errors.add("account_number_pattern", error);
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, JsonSchema, TS)]
 #[ts(export, export_to = "admin/types/")]
 pub struct BankOutput {
@@ -26,6 +78,10 @@ pub struct BankOutput {
     pub code: Option<String>,
     pub logo_url: Option<String>,
     pub status: BankStatus,
+    pub account_number_format: BankAccountNumberFormat,
+    pub account_number_min_length: Option<i32>,
+    pub account_number_max_length: Option<i32>,
+    pub account_number_pattern: Option<String>,
     pub sort_order: i32,
     #[schemars(with = "String")]
     #[ts(type = "string")]
@@ -0,0 +1,54 @@
+use core_web::ids::SnowflakeId;
+use generated::models::BankStatementLineStatus;
+use schemars::JsonSchema;
+use serde::Serialize;
+use ts_rs::TS;
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct BankStatementOutput {
+    pub id: SnowflakeId,
+    pub company_bank_account_id: SnowflakeId,
+    pub file_name: Option<String>,
+    pub line_count: i32,
+    pub matched_count: i32,
+    pub uploaded_by: SnowflakeId,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct BankStatementLineOutput {
+    pub id: SnowflakeId,
+    pub line_number: i32,
+    pub reference: String,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub amount: Option<rust_decimal::Decimal>,
+    pub account_number: Option<String>,
+    pub transaction_date: Option<String>,
+    #[ts(type = "Record<string, string>")]
+    pub raw: serde_json::Value,
+    pub status: BankStatementLineStatus,
+    /// The pending deposit the line was matched to
+    pub deposit_id: Option<SnowflakeId>,
+    /// Why the line did not match
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct BankStatementDetailOutput {
+    pub statement: BankStatementOutput,
+    #[ts(inline)]
+    pub lines: Vec<BankStatementLineOutput>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct BankStatementsOutput {
+    #[ts(inline)]
+    pub statements: Vec<BankStatementOutput>,
+}
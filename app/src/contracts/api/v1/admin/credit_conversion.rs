@@ -0,0 +1,125 @@
+use generated::models::{CreditConversionRateStatus, CreditType};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct AdminCreditConversionRateInput {
+    pub from_credit_type: CreditType,
+    pub to_credit_type: CreditType,
+    /// Target units received per source unit after fees
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub rate: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee_percentage: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee_fixed: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub min_amount: rust_decimal::Decimal,
+    pub status: CreditConversionRateStatus,
+}
+
+impl Validate for AdminCreditConversionRateInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        let mut add = |field: &'static str, code: &'static str, message: &'static str| {
+            errors.add(
+                field,
+                validator::ValidationError::new(code)
+                    .with_message(std::borrow::Cow::Borrowed(message)),
+            );
+        };
+        if self.from_credit_type == self.to_credit_type {
+            add("to_credit_type", "different", "Source and target credit types must differ.");
+        }
+        if self.rate <= rust_decimal::Decimal::ZERO {
+            add("rate", "positive", "Rate must be greater than zero.");
+        }
+        if self.fee_percentage < rust_decimal::Decimal::ZERO
+            || self.fee_percentage > rust_decimal::Decimal::ONE
+        {
+            add("fee_percentage", "range", "Fee percentage must be between 0 and 1.");
+        }
+        if self.fee_fixed < rust_decimal::Decimal::ZERO {
+            add("fee_fixed", "non_negative", "Fixed fee must not be negative.");
+        }
+        if self.min_amount < self.fee_fixed {
+            add("min_amount", "min_amount", "Minimum amount must cover the fixed fee.");
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct CreditConversionRateOutput {
+    pub from_credit_type: CreditType,
+    pub to_credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub rate: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee_percentage: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee_fixed: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub min_amount: rust_decimal::Decimal,
+    pub status: CreditConversionRateStatus,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub updated_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct CreditConversionRatesOutput {
+    #[ts(inline)]
+    pub rates: Vec<CreditConversionRateOutput>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct AdminCreditConvertInput {
+    pub username: String,
+    pub from_credit_type: CreditType,
+    pub to_credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct CreditConversionOutput {
+    pub related_key: String,
+    pub username: String,
+    pub from_credit_type: CreditType,
+    pub to_credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub rate: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub converted_amount: rust_decimal::Decimal,
+}
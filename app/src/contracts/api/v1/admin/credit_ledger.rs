@@ -0,0 +1,47 @@
+use core_web::ids::SnowflakeId;
+use generated::models::{CreditLedgerMismatchStatus, CreditType};
+use schemars::JsonSchema;
+use serde::Serialize;
+use ts_rs::TS;
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct CreditLedgerMismatchOutput {
+    pub id: SnowflakeId,
+    pub user_id: SnowflakeId,
+    pub admin_id: Option<SnowflakeId>,
+    pub credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub stored_balance: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub ledger_balance: rust_decimal::Decimal,
+    /// Stored balance minus ledger balance
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub difference: rust_decimal::Decimal,
+    pub status: CreditLedgerMismatchStatus,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub detected_at: time::OffsetDateTime,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub resolved_at: Option<time::OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct CreditLedgerMismatchesOutput {
+    #[ts(inline)]
+    pub mismatches: Vec<CreditLedgerMismatchOutput>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct CreditLedgerScanOutput {
+    #[ts(type = "number")]
+    pub open: i64,
+    #[ts(type = "number")]
+    pub resolved: i64,
+}
@@ -1,5 +1,5 @@
 use core_web::ids::SnowflakeId;
-use generated::models::CryptoNetworkStatus;
+use generated::models::{CryptoAddressFormat, CryptoNetworkStatus};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -11,6 +11,9 @@ pub struct AdminCryptoNetworkInput {
     pub name: String,
     pub symbol: String,
     pub status: CryptoNetworkStatus,
+    /// Defaults to `Unchecked`
+    #[serde(default)]
+    pub address_format: Option<CryptoAddressFormat>,
     #[serde(default)]
     pub sort_order: Option<i32>,
 }
@@ -23,6 +26,7 @@ pub struct CryptoNetworkOutput {
     pub symbol: String,
     pub logo_url: Option<String>,
     pub status: CryptoNetworkStatus,
+    pub address_format: CryptoAddressFormat,
     pub sort_order: i32,
     #[schemars(with = "String")]
     #[ts(type = "string")]
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use validator::Validate;
 
+use crate::contracts::api::v1::admin::user_credit::UserCreditTransactionOutput;
+
 #[derive(Debug, Clone, Deserialize, Validate, JsonSchema, TS)]
 #[ts(export, export_to = "admin/types/")]
 pub struct AdminDepositReviewInput {
@@ -38,13 +40,76 @@ pub struct DepositOutput {
     #[schemars(with = "String")]
     #[ts(type = "string")]
     pub net_amount: rust_decimal::Decimal,
+    pub reference_code: Option<String>,
+    pub gateway_provider: Option<String>,
+    pub gateway_session_id: Option<String>,
     pub related_key: Option<String>,
     pub remark: Option<String>,
     pub admin_remark: Option<String>,
     #[schemars(with = "Option<String>")]
     #[ts(type = "string | null")]
     pub reviewed_at: Option<time::OffsetDateTime>,
+    pub reversed_by: Option<SnowflakeId>,
+    pub reversal_reason: Option<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub reversed_at: Option<time::OffsetDateTime>,
     #[schemars(with = "String")]
     #[ts(type = "string")]
     pub created_at: time::OffsetDateTime,
 }
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct AdminDepositReverseInput {
+    pub reason: String,
+}
+
+impl Validate for AdminDepositReverseInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        if self.reason.trim().is_empty() {
+            errors.add(
+                "reason",
+                validator::ValidationError::new("required")
+                    .with_message(std::borrow::Cow::Borrowed("Reason is required.")),
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct DepositReversalOutput {
+    pub deposit: DepositOutput,
+    #[ts(type = "number")]
+    pub commissions_reversed: i64,
+    /// Ledger entries linked to the deposit that still need manual reversal
+    #[ts(inline)]
+    pub needs_reversal: Vec<UserCreditTransactionOutput>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct DepositStatusHistoryOutput {
+    pub status: DepositStatus,
+    pub admin_id: Option<SnowflakeId>,
+    pub remark: Option<String>,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct DepositDetailOutput {
+    pub deposit: DepositOutput,
+    /// Every status the deposit entered, oldest first
+    #[ts(inline)]
+    pub history: Vec<DepositStatusHistoryOutput>,
+}
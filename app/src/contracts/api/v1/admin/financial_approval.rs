@@ -0,0 +1,45 @@
+use core_web::ids::SnowflakeId;
+use generated::models::{CreditType, FinancialApprovalAction, FinancialApprovalStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, Validate, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct AdminFinancialApprovalDecisionInput {
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct FinancialApprovalOutput {
+    pub id: SnowflakeId,
+    pub action: FinancialApprovalAction,
+    /// Deposit or withdrawal id, or the user id for a credit adjustment
+    pub subject_id: SnowflakeId,
+    pub credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[ts(type = "Record<string, unknown>")]
+    pub payload: serde_json::Value,
+    pub status: FinancialApprovalStatus,
+    pub proposed_by: SnowflakeId,
+    pub reviewed_by: Option<SnowflakeId>,
+    pub review_remark: Option<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub reviewed_at: Option<time::OffsetDateTime>,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct FinancialApprovalsOutput {
+    #[ts(inline)]
+    pub approvals: Vec<FinancialApprovalOutput>,
+}
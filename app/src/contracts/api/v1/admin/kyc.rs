@@ -0,0 +1,63 @@
+use core_web::ids::SnowflakeId;
+use generated::models::{KycDocumentType, KycLevel, KycReviewAction, KycStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct AdminKycReviewInput {
+    pub action: KycReviewAction,
+    #[serde(default)]
+    pub reject_reason: Option<String>,
+}
+
+impl Validate for AdminKycReviewInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        let reason = self.reject_reason.as_deref().map(str::trim).unwrap_or("");
+        if self.action == KycReviewAction::Reject && reason.is_empty() {
+            errors.add(
+                "reject_reason",
+                validator::ValidationError::new("required")
+                    .with_message(std::borrow::Cow::Borrowed("Reason is required.")),
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct KycSubmissionOutput {
+    pub id: SnowflakeId,
+    pub user_id: SnowflakeId,
+    pub username: Option<String>,
+    /// The user's level now, before this submission is reviewed
+    pub user_kyc_level: Option<KycLevel>,
+    pub level: KycLevel,
+    pub status: KycStatus,
+    pub full_name: String,
+    pub document_type: KycDocumentType,
+    pub document_number: String,
+    pub reject_reason: Option<String>,
+    pub admin_id: Option<SnowflakeId>,
+    pub admin_username: Option<String>,
+    pub id_front_url: Option<String>,
+    pub id_back_url: Option<String>,
+    pub selfie_url: Option<String>,
+    pub proof_of_address_url: Option<String>,
+    /// Documents the user has yet to upload; approval needs none missing
+    pub missing_documents: Vec<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub reviewed_at: Option<time::OffsetDateTime>,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
@@ -1,17 +1,25 @@
 pub mod account;
 pub mod auth;
 pub mod bank;
+pub mod bank_statement;
 pub mod company_bank_account;
 pub mod company_crypto_account;
 pub mod content_page;
 pub mod country;
+pub mod credit_conversion;
+pub mod credit_ledger;
 pub mod crypto_network;
 pub mod deposit;
+pub mod financial_approval;
 pub mod developer_logs;
 pub mod notification;
+pub mod referral_commission;
+pub mod review_claim;
 pub mod hierarchy;
 pub mod introducer_change;
+pub mod kyc;
 pub mod tiptap_upload;
 pub mod user;
 pub mod user_credit;
 pub mod withdrawal;
+pub mod withdrawal_destination;
@@ -7,4 +7,5 @@ use ts_rs::TS;
 pub struct AdminNotificationCountsOutput {
     pub deposit: i64,
     pub withdrawal: i64,
+    pub kyc: i64,
 }
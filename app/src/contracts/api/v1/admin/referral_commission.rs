@@ -0,0 +1,103 @@
+use core_web::ids::SnowflakeId;
+use generated::models::{CreditType, ReferralCommissionSource, ReferralCommissionStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+use crate::internal::workflows::referral_commission::MAX_LEVELS;
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct AdminReferralCommissionRatesInput {
+    pub credit_type: CreditType,
+    /// Commission per upline level, starting with the direct introducer
+    #[schemars(with = "Vec<String>")]
+    #[ts(type = "string[]")]
+    pub percentages: Vec<rust_decimal::Decimal>,
+}
+
+impl Validate for AdminReferralCommissionRatesInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        let mut add = |code: &'static str, message: &'static str| {
+            errors.add(
+                "percentages",
+                validator::ValidationError::new(code)
+                    .with_message(std::borrow::Cow::Borrowed(message)),
+            );
+        };
+        if self.percentages.len() > MAX_LEVELS {
+            add("max_levels", "Too many commission levels.");
+        }
+        if self
+            .percentages
+            .iter()
+            .any(|p| *p < rust_decimal::Decimal::ZERO || *p > rust_decimal::Decimal::ONE)
+        {
+            add("range", "Commission percentages must be between 0 and 1.");
+        }
+        if self.percentages.iter().sum::<rust_decimal::Decimal>() > rust_decimal::Decimal::ONE {
+            add("total", "Commission percentages must not add up to more than 1.");
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct ReferralCommissionRateOutput {
+    pub credit_type: CreditType,
+    pub level: i32,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub percentage: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub updated_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct ReferralCommissionRatesOutput {
+    #[ts(inline)]
+    pub rates: Vec<ReferralCommissionRateOutput>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct ReferralCommissionOutput {
+    pub id: SnowflakeId,
+    pub source_type: ReferralCommissionSource,
+    pub source_id: SnowflakeId,
+    pub beneficiary_user_id: SnowflakeId,
+    pub level: i32,
+    pub credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub base_amount: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub percentage: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    pub status: ReferralCommissionStatus,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub reversed_at: Option<time::OffsetDateTime>,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct ReferralCommissionsOutput {
+    #[ts(inline)]
+    pub commissions: Vec<ReferralCommissionOutput>,
+}
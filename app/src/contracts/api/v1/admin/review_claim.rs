@@ -0,0 +1,17 @@
+use core_web::ids::SnowflakeId;
+use schemars::JsonSchema;
+use serde::Serialize;
+use ts_rs::TS;
+
+/// Who is reviewing a pending deposit or withdrawal. Empty when nobody holds
+/// a live claim.
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct ReviewClaimOutput {
+    pub id: SnowflakeId,
+    pub claimed_by: Option<SnowflakeId>,
+    pub claimer_username: Option<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub claimed_until: Option<time::OffsetDateTime>,
+}
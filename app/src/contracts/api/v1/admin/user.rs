@@ -2,7 +2,7 @@ use crate::contracts::types::username::UsernameString;
 use core_web::contracts::rustforge_contract;
 use core_web::ids::SnowflakeId;
 use core_web::Patch;
-use generated::models::UserBanStatus;
+use generated::models::{UserBanStatus, UserLimitKind};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -140,3 +140,140 @@ pub struct BatchResolveEntry {
     pub username: String,
     pub name: Option<String>,
 }
+
+/// One set of deposit or withdrawal limits. `null` is not set at this layer;
+/// 0 is unlimited.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct LimitValues {
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub min_amount: Option<rust_decimal::Decimal>,
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub max_amount: Option<rust_decimal::Decimal>,
+    #[serde(default)]
+    pub daily_count: Option<u32>,
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub daily_amount: Option<rust_decimal::Decimal>,
+    #[serde(default)]
+    pub weekly_count: Option<u32>,
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub weekly_amount: Option<rust_decimal::Decimal>,
+    #[serde(default)]
+    pub monthly_count: Option<u32>,
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub monthly_amount: Option<rust_decimal::Decimal>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct LimitPeriodUsage {
+    pub count: i64,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct UserLimitOutput {
+    pub kind: UserLimitKind,
+    /// Global limits with the user's country and KYC level applied
+    pub inherited: LimitValues,
+    /// The user's own override
+    pub overrides: LimitValues,
+    /// What is enforced
+    pub effective: LimitValues,
+    pub daily: LimitPeriodUsage,
+    pub weekly: LimitPeriodUsage,
+    pub monthly: LimitPeriodUsage,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct UserLimitsOutput {
+    pub deposit: UserLimitOutput,
+    pub withdrawal: UserLimitOutput,
+}
+
+/// Replaces the user's overrides; leave every field empty to drop one.
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct AdminUserLimitsInput {
+    #[serde(default)]
+    pub deposit: LimitValues,
+    #[serde(default)]
+    pub withdrawal: LimitValues,
+}
+
+impl Validate for AdminUserLimitsInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        let mut add = |field: &'static str, code: &'static str, message: &'static str| {
+            errors.add(
+                field,
+                validator::ValidationError::new(code)
+                    .with_message(std::borrow::Cow::Borrowed(message)),
+            );
+        };
+        let sets = [
+            (
+                &self.deposit,
+                [
+                    "deposit.min_amount",
+                    "deposit.max_amount",
+                    "deposit.daily_amount",
+                    "deposit.weekly_amount",
+                    "deposit.monthly_amount",
+                ],
+            ),
+            (
+                &self.withdrawal,
+                [
+                    "withdrawal.min_amount",
+                    "withdrawal.max_amount",
+                    "withdrawal.daily_amount",
+                    "withdrawal.weekly_amount",
+                    "withdrawal.monthly_amount",
+                ],
+            ),
+        ];
+        for (values, fields) in sets {
+            let amounts = [
+                values.min_amount,
+                values.max_amount,
+                values.daily_amount,
+                values.weekly_amount,
+                values.monthly_amount,
+            ];
+            for (field, amount) in fields.into_iter().zip(amounts) {
+                if amount.is_some_and(|amount| amount < rust_decimal::Decimal::ZERO) {
+                    add(field, "min", "Limits must not be negative.");
+                }
+            }
+            if let (Some(min), Some(max)) = (values.min_amount, values.max_amount) {
+                if !max.is_zero() && max < min {
+                    add(
+                        fields[1],
+                        "gte_min",
+                        "Maximum amount must not be lower than the minimum.",
+                    );
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use validator::Validate;
 
+use super::financial_approval::FinancialApprovalOutput;
+
 #[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
 #[ts(export, export_to = "admin/types/")]
 pub struct AdminCreditAdjustInput {
@@ -44,6 +46,15 @@ impl Validate for AdminCreditAdjustInput {
     }
 }
 
+/// Either the posted transaction, or the proposal when the adjustment is
+/// above the approval threshold and waits for a second admin.
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct AdminCreditAdjustOutput {
+    pub transaction: Option<UserCreditTransactionOutput>,
+    pub approval: Option<FinancialApprovalOutput>,
+}
+
 #[derive(Debug, Clone, Serialize, JsonSchema, TS)]
 #[ts(export, export_to = "admin/types/")]
 pub struct UserCreditTransactionOutput {
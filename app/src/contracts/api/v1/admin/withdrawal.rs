@@ -28,6 +28,7 @@ pub struct WithdrawalOutput {
     pub bank_account_number: Option<String>,
     pub crypto_network_id: Option<SnowflakeId>,
     pub crypto_wallet_address: Option<String>,
+    pub destination_id: Option<SnowflakeId>,
     #[schemars(with = "Option<String>")]
     #[ts(type = "string | null")]
     pub conversion_rate: Option<rust_decimal::Decimal>,
@@ -47,7 +48,37 @@ pub struct WithdrawalOutput {
     #[schemars(with = "Option<String>")]
     #[ts(type = "string | null")]
     pub reviewed_at: Option<time::OffsetDateTime>,
+    pub refunded_by: Option<SnowflakeId>,
+    pub refund_reason: Option<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub refunded_at: Option<time::OffsetDateTime>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub sla_breached_at: Option<time::OffsetDateTime>,
+    pub payout_provider: Option<String>,
+    pub payout_reference: Option<String>,
     #[schemars(with = "String")]
     #[ts(type = "string")]
     pub created_at: time::OffsetDateTime,
 }
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct WithdrawalStatusHistoryOutput {
+    pub status: WithdrawalStatus,
+    pub admin_id: Option<SnowflakeId>,
+    pub remark: Option<String>,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct WithdrawalDetailOutput {
+    pub withdrawal: WithdrawalOutput,
+    /// Every status the withdrawal entered, oldest first
+    #[ts(inline)]
+    pub history: Vec<WithdrawalStatusHistoryOutput>,
+}
@@ -0,0 +1,59 @@
+use core_web::ids::SnowflakeId;
+use generated::models::{WithdrawalDestinationKind, WithdrawalDestinationStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct AdminWithdrawalDestinationLockInput {
+    pub reason: String,
+}
+
+impl Validate for AdminWithdrawalDestinationLockInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        if self.reason.trim().is_empty() {
+            errors.add(
+                "reason",
+                validator::ValidationError::new("required")
+                    .with_message(std::borrow::Cow::Borrowed("Reason is required.")),
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct WithdrawalDestinationOutput {
+    pub id: SnowflakeId,
+    pub user_id: SnowflakeId,
+    pub username: Option<String>,
+    pub kind: WithdrawalDestinationKind,
+    pub bank_id: Option<SnowflakeId>,
+    pub bank_name: Option<String>,
+    pub bank_account_name: Option<String>,
+    pub bank_account_number: Option<String>,
+    pub crypto_network_id: Option<SnowflakeId>,
+    pub crypto_network_name: Option<String>,
+    pub crypto_wallet_address: Option<String>,
+    pub status: WithdrawalDestinationStatus,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub available_at: time::OffsetDateTime,
+    pub lock_reason: Option<String>,
+    pub locked_by: Option<SnowflakeId>,
+    pub locked_by_username: Option<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub locked_at: Option<time::OffsetDateTime>,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
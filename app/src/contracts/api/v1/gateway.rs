@@ -0,0 +1,19 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct GatewayCallbackOutput {
+    /// `approved`, `rejected`, `duplicate` or `ignored: <reason>`
+    pub outcome: String,
+}
+
+/// What the user did on the fake provider's checkout page.
+#[derive(Debug, Clone, Deserialize, Validate, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct FakeCheckoutInput {
+    /// `false` simulates a declined payment
+    pub paid: bool,
+}
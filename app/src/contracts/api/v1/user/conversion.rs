@@ -0,0 +1,61 @@
+use generated::models::CreditType;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserConversionRate {
+    pub from_credit_type: CreditType,
+    pub to_credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub rate: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee_percentage: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee_fixed: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub min_amount: rust_decimal::Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserConversionRatesOutput {
+    #[ts(inline)]
+    pub rates: Vec<UserConversionRate>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserConversionInput {
+    pub from_credit_type: CreditType,
+    pub to_credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserConversionOutput {
+    pub related_key: String,
+    pub from_credit_type: CreditType,
+    pub to_credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub rate: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub converted_amount: rust_decimal::Decimal,
+}
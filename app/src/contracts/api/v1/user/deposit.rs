@@ -0,0 +1,175 @@
+use core_web::ids::SnowflakeId;
+use generated::models::{CreditType, DepositMethod, DepositStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserDepositBankTarget {
+    pub id: SnowflakeId,
+    pub bank_id: SnowflakeId,
+    pub bank_name: Option<String>,
+    pub bank_logo_url: Option<String>,
+    pub account_name: String,
+    pub account_number: String,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserDepositCryptoTarget {
+    pub id: SnowflakeId,
+    pub crypto_network_id: SnowflakeId,
+    pub crypto_network_name: Option<String>,
+    pub crypto_network_symbol: Option<String>,
+    pub crypto_network_logo_url: Option<String>,
+    pub wallet_address: String,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub conversion_rate: rust_decimal::Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserDepositTargetsOutput {
+    #[ts(inline)]
+    pub bank_accounts: Vec<UserDepositBankTarget>,
+    #[ts(inline)]
+    pub crypto_accounts: Vec<UserDepositCryptoTarget>,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserDepositCreateInput {
+    pub credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[serde(default)]
+    pub company_bank_account_id: Option<SnowflakeId>,
+    #[serde(default)]
+    pub company_crypto_account_id: Option<SnowflakeId>,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+impl Validate for UserDepositCreateInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        match (&self.company_bank_account_id, &self.company_crypto_account_id) {
+            (Some(_), Some(_)) | (None, None) => errors.add(
+                "company_bank_account_id",
+                validator::ValidationError::new("deposit_target").with_message(
+                    std::borrow::Cow::Borrowed("Select either a bank account or a crypto account."),
+                ),
+            ),
+            _ => {}
+        }
+        if self.amount <= rust_decimal::Decimal::ZERO {
+            errors.add(
+                "amount",
+                validator::ValidationError::new("positive")
+                    .with_message(std::borrow::Cow::Borrowed("Amount must be greater than zero.")),
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserGatewayDepositInput {
+    pub credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+impl Validate for UserGatewayDepositInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        if self.amount <= rust_decimal::Decimal::ZERO {
+            errors.add(
+                "amount",
+                validator::ValidationError::new("positive")
+                    .with_message(std::borrow::Cow::Borrowed("Amount must be greater than zero.")),
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserDepositListQuery {
+    #[serde(default)]
+    pub page: Option<i64>,
+    #[serde(default)]
+    pub per_page: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserDepositOutput {
+    pub id: SnowflakeId,
+    pub credit_type: CreditType,
+    pub deposit_method: DepositMethod,
+    pub company_bank_account_id: Option<SnowflakeId>,
+    pub company_crypto_account_id: Option<SnowflakeId>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub conversion_rate: Option<rust_decimal::Decimal>,
+    pub status: DepositStatus,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub net_amount: rust_decimal::Decimal,
+    /// Put this in the transfer memo so the payment can be matched
+    pub reference_code: Option<String>,
+    /// Payment page of a gateway deposit that is still pending
+    pub checkout_url: Option<String>,
+    pub receipt_url: Option<String>,
+    pub remark: Option<String>,
+    pub admin_remark: Option<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub reviewed_at: Option<time::OffsetDateTime>,
+    pub reversal_reason: Option<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub reversed_at: Option<time::OffsetDateTime>,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserDepositListOutput {
+    #[ts(inline)]
+    pub data: Vec<UserDepositOutput>,
+    #[ts(type = "number")]
+    pub total: i64,
+    #[ts(type = "number")]
+    pub per_page: i64,
+    #[ts(type = "number")]
+    pub current_page: i64,
+    #[ts(type = "number")]
+    pub last_page: i64,
+}
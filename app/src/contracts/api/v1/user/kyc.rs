@@ -0,0 +1,82 @@
+use core_web::ids::SnowflakeId;
+use generated::models::{KycDocumentType, KycLevel, KycStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserKycSubmitInput {
+    pub level: KycLevel,
+    pub full_name: String,
+    pub document_type: KycDocumentType,
+    pub document_number: String,
+}
+
+impl Validate for UserKycSubmitInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        let mut add = |field: &'static str, code: &'static str, message: &'static str| {
+            errors.add(
+                field,
+                validator::ValidationError::new(code)
+                    .with_message(std::borrow::Cow::Borrowed(message)),
+            );
+        };
+
+        if self.level == KycLevel::Unverified {
+            add("level", "kyc_level", "Select the verification level to apply for.");
+        }
+        let full_name = self.full_name.trim();
+        if full_name.is_empty() {
+            add("full_name", "required", "Full name is required.");
+        } else if full_name.chars().count() > 255 {
+            add("full_name", "length", "Full name must be at most 255 characters.");
+        }
+        let document_number = self.document_number.trim();
+        if document_number.is_empty() {
+            add("document_number", "required", "Document number is required.");
+        } else if document_number.chars().count() > 64 {
+            add("document_number", "length", "Document number must be at most 64 characters.");
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserKycSubmissionOutput {
+    pub id: SnowflakeId,
+    pub level: KycLevel,
+    pub status: KycStatus,
+    pub full_name: String,
+    pub document_type: KycDocumentType,
+    pub document_number: String,
+    pub reject_reason: Option<String>,
+    pub id_front_url: Option<String>,
+    pub id_back_url: Option<String>,
+    pub selfie_url: Option<String>,
+    pub proof_of_address_url: Option<String>,
+    /// Documents still to upload before the submission can be approved
+    pub missing_documents: Vec<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub reviewed_at: Option<time::OffsetDateTime>,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserKycOutput {
+    pub kyc_level: KycLevel,
+    /// The latest submission, if any
+    pub submission: Option<UserKycSubmissionOutput>,
+}
@@ -1,2 +1,8 @@
 pub mod auth;
+pub mod conversion;
+pub mod deposit;
+pub mod kyc;
 pub mod team;
+pub mod transfer;
+pub mod withdrawal;
+pub mod withdrawal_destination;
@@ -0,0 +1,59 @@
+use generated::models::CreditType;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserTransferInput {
+    pub to_username: String,
+    pub credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+impl Validate for UserTransferInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        if self.to_username.trim().is_empty() {
+            errors.add(
+                "to_username",
+                validator::ValidationError::new("required")
+                    .with_message(std::borrow::Cow::Borrowed("Recipient username is required.")),
+            );
+        }
+        if self.amount <= rust_decimal::Decimal::ZERO {
+            errors.add(
+                "amount",
+                validator::ValidationError::new("positive")
+                    .with_message(std::borrow::Cow::Borrowed("Amount must be greater than zero.")),
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserTransferOutput {
+    pub related_key: String,
+    pub to_username: String,
+    pub credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub balance: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
@@ -0,0 +1,98 @@
+use core_web::ids::SnowflakeId;
+use generated::models::{CreditType, WithdrawalMethod, WithdrawalStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserWithdrawalCreateInput {
+    pub credit_type: CreditType,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    /// One of the user's saved withdrawal destinations
+    pub destination_id: SnowflakeId,
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+impl Validate for UserWithdrawalCreateInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        if self.amount <= rust_decimal::Decimal::ZERO {
+            errors.add(
+                "amount",
+                validator::ValidationError::new("positive")
+                    .with_message(std::borrow::Cow::Borrowed("Amount must be greater than zero.")),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserWithdrawalListQuery {
+    #[serde(default)]
+    pub page: Option<i64>,
+    #[serde(default)]
+    pub per_page: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserWithdrawalOutput {
+    pub id: SnowflakeId,
+    pub credit_type: CreditType,
+    pub withdrawal_method: WithdrawalMethod,
+    pub bank_id: Option<SnowflakeId>,
+    pub bank_account_name: Option<String>,
+    pub bank_account_number: Option<String>,
+    pub crypto_network_id: Option<SnowflakeId>,
+    pub crypto_wallet_address: Option<String>,
+    pub destination_id: Option<SnowflakeId>,
+    pub status: WithdrawalStatus,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub amount: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub fee: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub net_amount: rust_decimal::Decimal,
+    pub remark: Option<String>,
+    pub admin_remark: Option<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub reviewed_at: Option<time::OffsetDateTime>,
+    pub refund_reason: Option<String>,
+    #[schemars(with = "Option<String>")]
+    #[ts(type = "string | null")]
+    pub refunded_at: Option<time::OffsetDateTime>,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserWithdrawalListOutput {
+    #[ts(inline)]
+    pub data: Vec<UserWithdrawalOutput>,
+    #[ts(type = "number")]
+    pub total: i64,
+    #[ts(type = "number")]
+    pub per_page: i64,
+    #[ts(type = "number")]
+    pub current_page: i64,
+    #[ts(type = "number")]
+    pub last_page: i64,
+}
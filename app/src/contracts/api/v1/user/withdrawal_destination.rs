@@ -0,0 +1,102 @@
+use core_web::ids::SnowflakeId;
+use generated::models::{WithdrawalDestinationKind, WithdrawalDestinationStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+/// A bank account (`bank_id` with the account fields) or a crypto wallet
+/// (`crypto_network_id` with the address), never both.
+#[derive(Debug, Clone, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserWithdrawalDestinationInput {
+    #[serde(default)]
+    pub bank_id: Option<SnowflakeId>,
+    #[serde(default)]
+    pub bank_account_name: Option<String>,
+    #[serde(default)]
+    pub bank_account_number: Option<String>,
+    #[serde(default)]
+    pub crypto_network_id: Option<SnowflakeId>,
+    #[serde(default)]
+    pub crypto_wallet_address: Option<String>,
+}
+
+impl Validate for UserWithdrawalDestinationInput {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+        let blank = |v: &Option<String>| v.as_deref().map(str::trim).unwrap_or("").is_empty();
+
+        match (&self.bank_id, &self.crypto_network_id) {
+            (Some(_), None) => {
+                if blank(&self.bank_account_name) {
+                    errors.add(
+                        "bank_account_name",
+                        validator::ValidationError::new("required").with_message(
+                            std::borrow::Cow::Borrowed("Bank account name is required."),
+                        ),
+                    );
+                }
+                if blank(&self.bank_account_number) {
+                    errors.add(
+                        "bank_account_number",
+                        validator::ValidationError::new("required").with_message(
+                            std::borrow::Cow::Borrowed("Bank account number is required."),
+                        ),
+                    );
+                }
+            }
+            (None, Some(_)) => {
+                if blank(&self.crypto_wallet_address) {
+                    errors.add(
+                        "crypto_wallet_address",
+                        validator::ValidationError::new("required").with_message(
+                            std::borrow::Cow::Borrowed("Wallet address is required."),
+                        ),
+                    );
+                }
+            }
+            _ => errors.add(
+                "bank_id",
+                validator::ValidationError::new("withdrawal_destination").with_message(
+                    std::borrow::Cow::Borrowed("Select either a bank or a crypto network."),
+                ),
+            ),
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserWithdrawalDestinationOutput {
+    pub id: SnowflakeId,
+    pub kind: WithdrawalDestinationKind,
+    pub bank_id: Option<SnowflakeId>,
+    pub bank_name: Option<String>,
+    pub bank_account_name: Option<String>,
+    pub bank_account_number: Option<String>,
+    pub crypto_network_id: Option<SnowflakeId>,
+    pub crypto_network_name: Option<String>,
+    pub crypto_wallet_address: Option<String>,
+    pub status: WithdrawalDestinationStatus,
+    /// Withdrawals can use the destination from this time
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub available_at: time::OffsetDateTime,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub created_at: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema, TS)]
+#[ts(export, export_to = "user/types/")]
+pub struct UserWithdrawalDestinationListOutput {
+    #[ts(inline)]
+    pub data: Vec<UserWithdrawalDestinationOutput>,
+}
@@ -3,7 +3,7 @@ use core_web::datatable::{
     DataTableGenericQueryRequest, DataTableScopedContract,
 };
 use core_web::ids::SnowflakeId;
-use generated::models::BankStatus;
+use generated::models::{BankAccountNumberFormat, BankStatus};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -20,6 +20,10 @@ pub struct BankDatatableRow {
     pub code: Option<String>,
     pub status: BankStatus,
     pub status_label: String,
+    pub account_number_format: BankAccountNumberFormat,
+    pub account_number_min_length: Option<i32>,
+    pub account_number_max_length: Option<i32>,
+    pub account_number_pattern: Option<String>,
     pub sort_order: i32,
     pub updated_at: String,
 }
@@ -3,7 +3,7 @@ use core_web::datatable::{
     DataTableGenericQueryRequest, DataTableScopedContract,
 };
 use core_web::ids::SnowflakeId;
-use generated::models::CryptoNetworkStatus;
+use generated::models::{CryptoAddressFormat, CryptoNetworkStatus};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -19,6 +19,7 @@ pub struct CryptoNetworkDatatableRow {
     pub symbol: String,
     pub status: CryptoNetworkStatus,
     pub status_label: String,
+    pub address_format: CryptoAddressFormat,
     pub sort_order: i32,
     pub updated_at: String,
 }
@@ -38,12 +38,20 @@ pub struct DepositDatatableRow {
     #[schemars(with = "String")]
     #[ts(type = "string")]
     pub net_amount: rust_decimal::Decimal,
+    pub reference_code: Option<String>,
     pub related_key: Option<String>,
     pub remark: Option<String>,
     pub admin_remark: Option<String>,
     pub owner_name: Option<String>,
     pub admin_username: Option<String>,
     pub reviewed_at: Option<String>,
+    pub reversed_by: Option<SnowflakeId>,
+    pub reverser_username: Option<String>,
+    pub reversal_reason: Option<String>,
+    pub reversed_at: Option<String>,
+    pub claimed_by: Option<SnowflakeId>,
+    pub claimer_username: Option<String>,
+    pub claimed_until: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
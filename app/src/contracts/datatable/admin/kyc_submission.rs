@@ -0,0 +1,112 @@
+use core_web::datatable::{
+    DataTableFilterFieldDto, DataTableFilterFieldType, DataTableGenericEmailExportRequest,
+    DataTableGenericQueryRequest, DataTableScopedContract,
+};
+use core_web::ids::SnowflakeId;
+use generated::models::{KycDocumentType, KycLevel, KycStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+pub const SCOPED_KEY: &str = "admin.kyc_submission";
+pub const ROUTE_PREFIX: &str = "/datatable/kyc_submission";
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct KycSubmissionDatatableRow {
+    pub id: SnowflakeId,
+    pub user_id: SnowflakeId,
+    pub user_username: Option<String>,
+    pub level: KycLevel,
+    pub status: KycStatus,
+    pub status_label: String,
+    pub full_name: String,
+    pub document_type: KycDocumentType,
+    pub document_number: String,
+    pub reject_reason: Option<String>,
+    pub admin_id: Option<SnowflakeId>,
+    pub admin_username: Option<String>,
+    pub reviewed_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AdminKycSubmissionDataTableContract;
+
+impl DataTableScopedContract for AdminKycSubmissionDataTableContract {
+    type QueryRequest = DataTableGenericQueryRequest;
+    type EmailRequest = DataTableGenericEmailExportRequest;
+    type Row = KycSubmissionDatatableRow;
+
+    fn scoped_key(&self) -> &'static str {
+        SCOPED_KEY
+    }
+
+    fn openapi_tag(&self) -> &'static str {
+        "Admin KYC Submission DataTable"
+    }
+
+    fn filter_rows(&self) -> Vec<Vec<DataTableFilterFieldDto>> {
+        vec![
+            vec![
+                DataTableFilterFieldDto {
+                    field: "q".to_string(),
+                    filter_key: "q".to_string(),
+                    field_type: DataTableFilterFieldType::Text,
+                    label: "Keyword".to_string(),
+                    placeholder: Some("Search username, name or document number".to_string()),
+                    description: None,
+                    options: None,
+                },
+                DataTableFilterFieldDto {
+                    field: "status".to_string(),
+                    filter_key: "f-status".to_string(),
+                    field_type: DataTableFilterFieldType::Select,
+                    label: "Status".to_string(),
+                    placeholder: Some("All".to_string()),
+                    description: None,
+                    options: Some(KycStatus::datatable_filter_options()),
+                },
+                DataTableFilterFieldDto {
+                    field: "level".to_string(),
+                    filter_key: "f-level".to_string(),
+                    field_type: DataTableFilterFieldType::Select,
+                    label: "Level".to_string(),
+                    placeholder: Some("All".to_string()),
+                    description: None,
+                    options: Some(KycLevel::datatable_filter_options()),
+                },
+            ],
+            vec![
+                DataTableFilterFieldDto {
+                    field: "document_type".to_string(),
+                    filter_key: "f-document_type".to_string(),
+                    field_type: DataTableFilterFieldType::Select,
+                    label: "Document Type".to_string(),
+                    placeholder: Some("All".to_string()),
+                    description: None,
+                    options: Some(KycDocumentType::datatable_filter_options()),
+                },
+                DataTableFilterFieldDto {
+                    field: "created_at_from".to_string(),
+                    filter_key: "f-date-from-created_at".to_string(),
+                    field_type: DataTableFilterFieldType::Date,
+                    label: "Created At From".to_string(),
+                    placeholder: Some("Start datetime".to_string()),
+                    description: None,
+                    options: None,
+                },
+                DataTableFilterFieldDto {
+                    field: "created_at_to".to_string(),
+                    filter_key: "f-date-to-created_at".to_string(),
+                    field_type: DataTableFilterFieldType::Date,
+                    label: "Created At To".to_string(),
+                    placeholder: Some("End datetime".to_string()),
+                    description: None,
+                    options: None,
+                },
+            ],
+        ]
+    }
+}
@@ -9,9 +9,11 @@ pub mod crypto_network;
 pub mod deposit;
 pub mod http_client_log;
 pub mod introducer_change;
+pub mod kyc_submission;
 pub mod sql_profiler_query;
 pub mod sql_profiler_request;
 pub mod user;
 pub mod user_credit_transaction;
 pub mod webhook_log;
 pub mod withdrawal;
+pub mod withdrawal_destination;
@@ -3,7 +3,7 @@ use core_web::datatable::{
     DataTableGenericQueryRequest, DataTableScopedContract,
 };
 use core_web::ids::SnowflakeId;
-use generated::models::UserBanStatus;
+use generated::models::{KycLevel, UserBanStatus};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -23,6 +23,7 @@ pub struct UserDatatableRow {
     pub country_iso2: Option<String>,
     pub contact_number: Option<String>,
     pub ban: UserBanStatus,
+    pub kyc_level: KycLevel,
     #[schemars(with = "String")]
     #[ts(type = "string")]
     pub credit_1: rust_decimal::Decimal,
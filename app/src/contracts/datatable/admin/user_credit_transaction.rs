@@ -21,6 +21,12 @@ pub struct UserCreditTransactionDatatableRow {
     #[schemars(with = "String")]
     #[ts(type = "string")]
     pub amount: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub balance_before: rust_decimal::Decimal,
+    #[schemars(with = "String")]
+    #[ts(type = "string")]
+    pub balance_after: rust_decimal::Decimal,
     pub transaction_type: CreditTransactionType,
     /// Backend-computed human-readable label for the transaction type,
     /// respecting current locale and interpolating dynamic `params`.
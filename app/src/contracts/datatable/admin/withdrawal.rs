@@ -44,6 +44,14 @@ pub struct WithdrawalDatatableRow {
     pub owner_name: Option<String>,
     pub admin_username: Option<String>,
     pub reviewed_at: Option<String>,
+    pub refunded_by: Option<SnowflakeId>,
+    pub refunder_username: Option<String>,
+    pub refund_reason: Option<String>,
+    pub refunded_at: Option<String>,
+    pub sla_breached_at: Option<String>,
+    pub claimed_by: Option<SnowflakeId>,
+    pub claimer_username: Option<String>,
+    pub claimed_until: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
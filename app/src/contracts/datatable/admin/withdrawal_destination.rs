@@ -0,0 +1,110 @@
+use core_web::datatable::{
+    DataTableFilterFieldDto, DataTableFilterFieldType, DataTableGenericEmailExportRequest,
+    DataTableGenericQueryRequest, DataTableScopedContract,
+};
+use core_web::ids::SnowflakeId;
+use generated::models::{WithdrawalDestinationKind, WithdrawalDestinationStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+pub const SCOPED_KEY: &str = "admin.withdrawal_destination";
+pub const ROUTE_PREFIX: &str = "/datatable/withdrawal_destination";
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
+#[ts(export, export_to = "admin/types/")]
+pub struct WithdrawalDestinationDatatableRow {
+    pub id: SnowflakeId,
+    pub user_id: SnowflakeId,
+    pub user_username: Option<String>,
+    pub kind: WithdrawalDestinationKind,
+    pub bank_id: Option<SnowflakeId>,
+    pub bank_name: Option<String>,
+    pub bank_account_name: Option<String>,
+    pub bank_account_number: Option<String>,
+    pub crypto_network_id: Option<SnowflakeId>,
+    pub crypto_network_name: Option<String>,
+    pub crypto_wallet_address: Option<String>,
+    pub status: WithdrawalDestinationStatus,
+    pub status_label: String,
+    pub available_at: String,
+    pub lock_reason: Option<String>,
+    pub locked_by: Option<SnowflakeId>,
+    pub locked_by_username: Option<String>,
+    pub locked_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AdminWithdrawalDestinationDataTableContract;
+
+impl DataTableScopedContract for AdminWithdrawalDestinationDataTableContract {
+    type QueryRequest = DataTableGenericQueryRequest;
+    type EmailRequest = DataTableGenericEmailExportRequest;
+    type Row = WithdrawalDestinationDatatableRow;
+
+    fn scoped_key(&self) -> &'static str {
+        SCOPED_KEY
+    }
+
+    fn openapi_tag(&self) -> &'static str {
+        "Admin Withdrawal Destination DataTable"
+    }
+
+    fn filter_rows(&self) -> Vec<Vec<DataTableFilterFieldDto>> {
+        vec![
+            vec![
+                DataTableFilterFieldDto {
+                    field: "q".to_string(),
+                    filter_key: "q".to_string(),
+                    field_type: DataTableFilterFieldType::Text,
+                    label: "Keyword".to_string(),
+                    placeholder: Some(
+                        "Search username, account number or wallet address".to_string(),
+                    ),
+                    description: None,
+                    options: None,
+                },
+                DataTableFilterFieldDto {
+                    field: "kind".to_string(),
+                    filter_key: "f-kind".to_string(),
+                    field_type: DataTableFilterFieldType::Select,
+                    label: "Type".to_string(),
+                    placeholder: Some("All".to_string()),
+                    description: None,
+                    options: Some(WithdrawalDestinationKind::datatable_filter_options()),
+                },
+                DataTableFilterFieldDto {
+                    field: "status".to_string(),
+                    filter_key: "f-status".to_string(),
+                    field_type: DataTableFilterFieldType::Select,
+                    label: "Status".to_string(),
+                    placeholder: Some("All".to_string()),
+                    description: None,
+                    options: Some(WithdrawalDestinationStatus::datatable_filter_options()),
+                },
+            ],
+            vec![
+                DataTableFilterFieldDto {
+                    field: "created_at_from".to_string(),
+                    filter_key: "f-date-from-created_at".to_string(),
+                    field_type: DataTableFilterFieldType::Date,
+                    label: "Created At From".to_string(),
+                    placeholder: Some("Start datetime".to_string()),
+                    description: None,
+                    options: None,
+                },
+                DataTableFilterFieldDto {
+                    field: "created_at_to".to_string(),
+                    filter_key: "f-date-to-created_at".to_string(),
+                    field_type: DataTableFilterFieldType::Date,
+                    label: "Created At To".to_string(),
+                    placeholder: Some("End datetime".to_string()),
+                    description: None,
+                    options: None,
+                },
+            ],
+        ]
+    }
+}
@@ -7,6 +7,18 @@ use core_db::infra::storage::Storage;
 use core_realtime::RealtimePublisher;
 use core_web::datatable::DataTableEmailExportManager;
 
+use crate::internal::config::{
+    self, approval::ApprovalConfig, balance::BalanceConfig, bank_statement::BankStatementConfig,
+    deposit::DepositFeeConfig, idempotency::IdempotencyConfig, limits::LimitsConfig,
+    payment_gateway::PaymentGatewayConfig, payout::PayoutConfig,
+    review_claim::ReviewClaimConfig, transfer::TransferConfig, withdrawal::WithdrawalFeeConfig,
+};
+use crate::internal::{
+    payment_gateway::{self, PaymentGateway},
+    payout::{self, PayoutProvider},
+    workflows::period,
+};
+
 #[derive(Clone)]
 pub struct AppApiState {
     pub db: sqlx::PgPool,
@@ -24,6 +36,21 @@ pub struct AppApiState {
     pub i18n_default_locale: String,
     pub i18n_supported_locales: Vec<String>,
     pub realtime: RealtimePublisher,
+    pub deposit_config: Arc<DepositFeeConfig>,
+    pub withdrawal_config: Arc<WithdrawalFeeConfig>,
+    pub transfer_config: Arc<TransferConfig>,
+    pub idempotency_config: Arc<IdempotencyConfig>,
+    pub balance_config: Arc<BalanceConfig>,
+    pub approval_config: Arc<ApprovalConfig>,
+    pub review_claim_config: Arc<ReviewClaimConfig>,
+    pub bank_statement_config: Arc<BankStatementConfig>,
+    pub payment_gateway_config: Arc<PaymentGatewayConfig>,
+    pub limits_config: Arc<LimitsConfig>,
+    /// Provider for gateway deposits; `None` when they are turned off
+    pub payment_gateway: Option<Arc<dyn PaymentGateway>>,
+    /// Provider withdrawals are paid out through; `None` when admins pay out
+    /// by hand
+    pub payout: Option<Arc<dyn PayoutProvider>>,
 }
 
 impl AppApiState {
@@ -34,6 +61,36 @@ impl AppApiState {
             &ctx.db,
         );
 
+        let deposit_config: DepositFeeConfig = config::load_section("deposit")?;
+        deposit_config.validate()?;
+        let withdrawal_config: WithdrawalFeeConfig = config::load_section("withdrawal")?;
+        withdrawal_config.validate()?;
+        let transfer_config: TransferConfig = config::load_section("transfer")?;
+        transfer_config.validate()?;
+        let idempotency_config: IdempotencyConfig = config::load_section("idempotency")?;
+        idempotency_config.validate()?;
+        let balance_config: BalanceConfig = config::load_section("balance")?;
+        balance_config.validate()?;
+        let approval_config: ApprovalConfig = config::load_section("approval")?;
+        approval_config.validate()?;
+        let review_claim_config: ReviewClaimConfig = config::load_section("review_claim")?;
+        review_claim_config.validate()?;
+        let bank_statement_config: BankStatementConfig = config::load_section("bank_statement")?;
+        bank_statement_config.validate()?;
+        let payment_gateway_config: PaymentGatewayConfig =
+            config::load_section("payment_gateway")?;
+        payment_gateway_config.validate()?;
+        let payout_config: PayoutConfig = config::load_section("payout")?;
+        payout_config.validate()?;
+        let limits_config: LimitsConfig = config::load_section("limits")?;
+        limits_config.validate()?;
+        // Limit windows and the daily transfer cap start at midnight here
+        let app_timezone = ctx.settings.i18n.default_timezone_str.clone();
+        anyhow::ensure!(
+            period::parse_offset(&app_timezone).is_some(),
+            "APP_TIMEZONE must be a fixed offset such as +08:00 or UTC, got `{app_timezone}`"
+        );
+
         let datatable_registry = Arc::new(datatable_registry);
         let datatable_async_exports =
             Arc::new(DataTableAsyncExportManager::new(datatable_registry.clone()));
@@ -52,7 +109,7 @@ impl AppApiState {
                 ctx.settings.app.datatable_unknown_filter_mode,
             ),
             datatable_export_link_ttl_secs: ctx.settings.app.datatable_export_link_ttl_secs,
-            app_timezone: ctx.settings.i18n.default_timezone_str.clone(),
+            app_timezone,
             i18n_default_locale: ctx.settings.i18n.default_locale.to_string(),
             i18n_supported_locales: ctx
                 .settings
@@ -65,6 +122,18 @@ impl AppApiState {
                 &ctx.settings.redis.url,
                 &ctx.settings.realtime,
             )?,
+            deposit_config: Arc::new(deposit_config),
+            withdrawal_config: Arc::new(withdrawal_config),
+            transfer_config: Arc::new(transfer_config),
+            idempotency_config: Arc::new(idempotency_config),
+            balance_config: Arc::new(balance_config),
+            approval_config: Arc::new(approval_config),
+            review_claim_config: Arc::new(review_claim_config),
+            bank_statement_config: Arc::new(bank_statement_config),
+            payment_gateway: payment_gateway::from_config(&payment_gateway_config),
+            payment_gateway_config: Arc::new(payment_gateway_config),
+            payout: payout::from_config(&payout_config),
+            limits_config: Arc::new(limits_config),
         })
     }
 }
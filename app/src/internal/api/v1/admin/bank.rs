@@ -12,7 +12,11 @@ use core_web::{
     },
     response::ApiResponse,
 };
-use generated::{guards::AdminGuard, models::BankStatus, permissions::Permission};
+use generated::{
+    guards::AdminGuard,
+    models::{BankAccountNumberFormat, BankStatus},
+    permissions::Permission,
+};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -21,7 +25,7 @@ use crate::{
     internal::{api::state::AppApiState, workflows::bank as workflow},
 };
 
-use super::receipt_upload::validate_attachment_allowed;
+use crate::internal::api::v1::receipt_upload::validate_attachment_allowed;
 
 pub fn router(state: AppApiState) -> ApiRouter {
     ApiRouter::new()
@@ -81,6 +85,10 @@ async fn parse_bank_multipart(
     let mut name: Option<String> = None;
     let mut code: Option<String> = None;
     let mut status: Option<String> = None;
+    let mut account_number_format: Option<String> = None;
+    let mut account_number_min_length: Option<String> = None;
+    let mut account_number_max_length: Option<String> = None;
+    let mut account_number_pattern: Option<String> = None;
     let mut sort_order: Option<String> = None;
     let mut logo: Option<AttachmentInput> = None;
 
@@ -95,6 +103,18 @@ async fn parse_bank_multipart(
             "name" => name = Some(field.text().await.map_err(AppError::from)?),
             "code" => code = Some(field.text().await.map_err(AppError::from)?),
             "status" => status = Some(field.text().await.map_err(AppError::from)?),
+            "account_number_format" => {
+                account_number_format = Some(field.text().await.map_err(AppError::from)?)
+            }
+            "account_number_min_length" => {
+                account_number_min_length = Some(field.text().await.map_err(AppError::from)?)
+            }
+            "account_number_max_length" => {
+                account_number_max_length = Some(field.text().await.map_err(AppError::from)?)
+            }
+            "account_number_pattern" => {
+                account_number_pattern = Some(field.text().await.map_err(AppError::from)?)
+            }
             "sort_order" => sort_order = Some(field.text().await.map_err(AppError::from)?),
             "logo" => {
                 if field.file_name().is_some() {
@@ -143,9 +163,34 @@ async fn parse_bank_multipart(
     let status: BankStatus = serde_json::from_str(&format!("\"{status_str}\""))
         .or_else(|_| serde_json::from_str(&status_str))
         .map_err(|_| AppError::BadRequest(t("Invalid status value")))?;
+    let account_number_format = match account_number_format.map(|v| v.trim().to_string()) {
+        Some(v) if !v.is_empty() => Some(
+            serde_json::from_str::<BankAccountNumberFormat>(&format!("\"{v}\""))
+                .or_else(|_| serde_json::from_str(&v))
+                .map_err(|_| AppError::BadRequest(t("Invalid account number format value")))?,
+        ),
+        _ => None,
+    };
+    let account_number_min_length =
+        account_number_min_length.and_then(|v| v.trim().parse::<i32>().ok());
+    let account_number_max_length =
+        account_number_max_length.and_then(|v| v.trim().parse::<i32>().ok());
+    let account_number_pattern = account_number_pattern
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
     let sort_order = sort_order.and_then(|v| v.trim().parse::<i32>().ok());
 
-    let input = AdminBankInput { country_iso2, name, code, status, sort_order };
+    let input = AdminBankInput {
+        country_iso2,
+        name,
+        code,
+        status,
+        account_number_format,
+        account_number_min_length,
+        account_number_max_length,
+        account_number_pattern,
+        sort_order,
+    };
     if let Err(e) = input.validate() {
         return Err(AppError::Validation {
             message: t("Validation failed"),
@@ -164,6 +209,10 @@ fn bank_output(bank: &generated::models::BankRecord) -> BankOutput {
         code: bank.code.clone(),
         logo_url: bank.logo_url.clone(),
         status: bank.status,
+        account_number_format: bank.account_number_format,
+        account_number_min_length: bank.account_number_min_length,
+        account_number_max_length: bank.account_number_max_length,
+        account_number_pattern: bank.account_number_pattern.clone(),
         sort_order: bank.sort_order,
         created_at: bank.created_at,
         updated_at: bank.updated_at,
@@ -0,0 +1,208 @@
+use axum::{
+    extract::{Multipart, Path, State},
+    middleware::from_fn_with_state,
+};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    authz::PermissionMode,
+    error::AppError,
+    openapi::{with_permission_check_get_with, with_permission_check_post_with, ApiRouter},
+    response::ApiResponse,
+};
+use generated::{
+    guards::AdminGuard,
+    models::{BankStatementImportRecord, BankStatementLineRecord},
+    permissions::Permission,
+};
+
+use crate::{
+    contracts::api::v1::admin::bank_statement::{
+        BankStatementDetailOutput, BankStatementLineOutput, BankStatementOutput,
+        BankStatementsOutput,
+    },
+    internal::{
+        api::state::AppApiState,
+        middleware::idempotency,
+        workflows::{
+            bank_statement::{self as workflow, BankStatementDetail},
+            financial_approval::ApprovalOutcome,
+        },
+    },
+};
+
+const MAX_STATEMENT_BYTES: usize = 5 * 1024 * 1024;
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/",
+            with_permission_check_get_with(
+                list,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::DepositRead.as_str(),
+                    Permission::DepositManage.as_str(),
+                ],
+                |op| op.summary("Recent bank statement uploads").tag("Admin Bank Statements"),
+            ),
+        )
+        .api_route(
+            "/",
+            with_permission_check_post_with(
+                upload,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::DepositManage.as_str()],
+                |op| {
+                    op.summary("Upload a bank statement CSV and match it to pending deposits")
+                        .tag("Admin Bank Statements")
+                },
+            ),
+        )
+        .api_route(
+            "/{id}",
+            with_permission_check_get_with(
+                detail,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::DepositRead.as_str(),
+                    Permission::DepositManage.as_str(),
+                ],
+                |op| op.summary("Bank statement with its lines").tag("Admin Bank Statements"),
+            ),
+        )
+        .api_route(
+            "/lines/{id}/approve",
+            with_permission_check_post_with(
+                approve_line,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::DepositManage.as_str()],
+                |op| {
+                    op.summary("Approve the deposit a statement line matched")
+                        .tag("Admin Bank Statements")
+                },
+            ),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::admin_idempotency,
+        ))
+        .with_state(state)
+}
+
+fn to_output(statement: BankStatementImportRecord) -> BankStatementOutput {
+    BankStatementOutput {
+        id: statement.id.into(),
+        company_bank_account_id: statement.company_bank_account_id.into(),
+        file_name: statement.file_name,
+        line_count: statement.line_count,
+        matched_count: statement.matched_count,
+        uploaded_by: statement.uploaded_by.into(),
+        created_at: statement.created_at,
+    }
+}
+
+fn to_line_output(line: BankStatementLineRecord) -> BankStatementLineOutput {
+    BankStatementLineOutput {
+        id: line.id.into(),
+        line_number: line.line_number,
+        reference: line.reference,
+        amount: line.amount,
+        account_number: line.account_number,
+        transaction_date: line.transaction_date,
+        raw: line.raw,
+        status: line.status,
+        deposit_id: line.deposit_id.map(Into::into),
+        note: line.note,
+    }
+}
+
+fn to_detail_output(detail: BankStatementDetail) -> BankStatementDetailOutput {
+    BankStatementDetailOutput {
+        statement: to_output(detail.statement),
+        lines: detail.lines.into_iter().map(to_line_output).collect(),
+    }
+}
+
+async fn list(
+    State(state): State<AppApiState>,
+) -> Result<ApiResponse<BankStatementsOutput>, AppError> {
+    let statements = workflow::list(&state).await?;
+
+    Ok(ApiResponse::success(
+        BankStatementsOutput {
+            statements: statements.into_iter().map(to_output).collect(),
+        },
+        &t("Bank statements loaded"),
+    ))
+}
+
+async fn detail(
+    State(state): State<AppApiState>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<BankStatementDetailOutput>, AppError> {
+    let detail = workflow::detail(&state, id).await?;
+
+    Ok(ApiResponse::success(to_detail_output(detail), &t("Bank statement loaded")))
+}
+
+/// Multipart fields: `company_bank_account_id` and the CSV as `file`.
+async fn upload(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    mut multipart: Multipart,
+) -> Result<ApiResponse<BankStatementDetailOutput>, AppError> {
+    let mut account_id: Option<i64> = None;
+    let mut file: Option<(Option<String>, Vec<u8>)> = None;
+
+    while let Some(field) = multipart.next_field().await.map_err(AppError::from)? {
+        match field.name() {
+            Some("company_bank_account_id") => {
+                let value = field.text().await.map_err(AppError::from)?;
+                account_id = Some(value.trim().parse().map_err(|_| {
+                    AppError::BadRequest(t("Invalid company bank account"))
+                })?);
+            }
+            Some("file") => {
+                let file_name = field.file_name().map(ToString::to_string);
+                let data = field.bytes().await.map_err(AppError::from)?;
+                if data.is_empty() {
+                    return Err(AppError::BadRequest(t("Uploaded file is empty")));
+                }
+                if data.len() > MAX_STATEMENT_BYTES {
+                    return Err(AppError::BadRequest(t("File exceeds maximum allowed size")));
+                }
+                file = Some((file_name, data.to_vec()));
+            }
+            _ => {}
+        }
+    }
+
+    let account_id = account_id.ok_or_else(|| {
+        AppError::BadRequest(t("Missing required field: company_bank_account_id"))
+    })?;
+    let (file_name, content) =
+        file.ok_or_else(|| AppError::BadRequest(t("Missing required field: file")))?;
+
+    let detail = workflow::import(&state, auth.user.id, account_id, file_name, &content).await?;
+
+    Ok(ApiResponse::success(to_detail_output(detail), &t("Bank statement imported")))
+}
+
+async fn approve_line(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<BankStatementLineOutput>, AppError> {
+    let (line, outcome) = workflow::approve_line(&state, auth.user.id, id).await?;
+    let message = match outcome {
+        ApprovalOutcome::Applied(_) => t("Deposit reviewed"),
+        ApprovalOutcome::Proposed(_) => t("Submitted for confirmation by a second admin"),
+    };
+
+    Ok(ApiResponse::success(to_line_output(line), &message))
+}
@@ -0,0 +1,83 @@
+use axum::extract::State;
+use core_i18n::t;
+use core_web::{
+    authz::PermissionMode,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{with_permission_check_get_with, with_permission_check_put_with, ApiRouter},
+    response::ApiResponse,
+};
+use generated::{guards::AdminGuard, models::CreditConversionRateRecord, permissions::Permission};
+
+use crate::{
+    contracts::api::v1::admin::credit_conversion::{
+        AdminCreditConversionRateInput, CreditConversionRateOutput, CreditConversionRatesOutput,
+    },
+    internal::{api::state::AppApiState, workflows::credit_conversion as workflow},
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/",
+            with_permission_check_get_with(
+                list,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::UserCreditRead.as_str(),
+                    Permission::UserCreditManage.as_str(),
+                ],
+                |op| op.summary("List credit conversion rates").tag("Admin User Credit"),
+            ),
+        )
+        .api_route(
+            "/",
+            with_permission_check_put_with(
+                upsert,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::UserCreditManage.as_str()],
+                |op| op.summary("Set credit conversion rate").tag("Admin User Credit"),
+            ),
+        )
+        .with_state(state)
+}
+
+fn to_output(rate: CreditConversionRateRecord) -> CreditConversionRateOutput {
+    CreditConversionRateOutput {
+        from_credit_type: rate.from_credit_type,
+        to_credit_type: rate.to_credit_type,
+        rate: rate.rate,
+        fee_percentage: rate.fee_percentage,
+        fee_fixed: rate.fee_fixed,
+        min_amount: rate.min_amount,
+        status: rate.status,
+        updated_at: rate.updated_at,
+    }
+}
+
+async fn list(
+    State(state): State<AppApiState>,
+) -> Result<ApiResponse<CreditConversionRatesOutput>, AppError> {
+    let rates = workflow::list_rates(&state, false).await?;
+
+    Ok(ApiResponse::success(
+        CreditConversionRatesOutput {
+            rates: rates.into_iter().map(to_output).collect(),
+        },
+        &t("Conversion rates loaded"),
+    ))
+}
+
+async fn upsert(
+    State(state): State<AppApiState>,
+    ContractJson(req): ContractJson<AdminCreditConversionRateInput>,
+) -> Result<ApiResponse<CreditConversionRateOutput>, AppError> {
+    let rate = workflow::upsert_rate(&state, req).await?;
+
+    Ok(ApiResponse::success(
+        to_output(rate),
+        &t("Conversion rate saved"),
+    ))
+}
@@ -0,0 +1,120 @@
+use axum::{
+    extract::{Path, State},
+    middleware::from_fn_with_state,
+};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    authz::PermissionMode,
+    error::AppError,
+    openapi::{with_permission_check_get_with, with_permission_check_post_with, ApiRouter},
+    response::ApiResponse,
+};
+use generated::{guards::AdminGuard, models::CreditLedgerMismatchRecord, permissions::Permission};
+
+use crate::{
+    contracts::api::v1::admin::credit_ledger::{
+        CreditLedgerMismatchOutput, CreditLedgerMismatchesOutput, CreditLedgerScanOutput,
+    },
+    internal::{
+        api::state::AppApiState, middleware::idempotency,
+        workflows::ledger_reconcile as workflow,
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/",
+            with_permission_check_get_with(
+                list,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::UserCreditRead.as_str(),
+                    Permission::UserCreditManage.as_str(),
+                ],
+                |op| op.summary("List open ledger mismatches").tag("Admin User Credit"),
+            ),
+        )
+        .api_route(
+            "/scan",
+            with_permission_check_post_with(
+                scan,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::UserCreditManage.as_str()],
+                |op| op.summary("Scan balances against the ledger").tag("Admin User Credit"),
+            ),
+        )
+        .api_route(
+            "/{id}/correct",
+            with_permission_check_post_with(
+                correct,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::UserCreditManage.as_str()],
+                |op| op.summary("Correct ledger mismatch").tag("Admin User Credit"),
+            ),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::admin_idempotency,
+        ))
+        .with_state(state)
+}
+
+fn to_output(mismatch: CreditLedgerMismatchRecord) -> CreditLedgerMismatchOutput {
+    CreditLedgerMismatchOutput {
+        id: mismatch.id.into(),
+        user_id: mismatch.user_id.into(),
+        admin_id: mismatch.admin_id.map(Into::into),
+        credit_type: mismatch.credit_type,
+        stored_balance: mismatch.stored_balance,
+        ledger_balance: mismatch.ledger_balance,
+        difference: mismatch.difference,
+        status: mismatch.status,
+        detected_at: mismatch.detected_at,
+        resolved_at: mismatch.resolved_at,
+    }
+}
+
+async fn list(
+    State(state): State<AppApiState>,
+) -> Result<ApiResponse<CreditLedgerMismatchesOutput>, AppError> {
+    let mismatches = workflow::list_open(&state).await?;
+
+    Ok(ApiResponse::success(
+        CreditLedgerMismatchesOutput {
+            mismatches: mismatches.into_iter().map(to_output).collect(),
+        },
+        &t("Ledger mismatches loaded"),
+    ))
+}
+
+async fn scan(
+    State(state): State<AppApiState>,
+) -> Result<ApiResponse<CreditLedgerScanOutput>, AppError> {
+    let summary = workflow::scan(&state.db).await.map_err(AppError::from)?;
+
+    Ok(ApiResponse::success(
+        CreditLedgerScanOutput {
+            open: summary.drifts.len() as i64,
+            resolved: summary.resolved as i64,
+        },
+        &t("Ledger scan completed"),
+    ))
+}
+
+async fn correct(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<CreditLedgerMismatchOutput>, AppError> {
+    let mismatch = workflow::correct(&state, auth.user.id, id).await?;
+
+    Ok(ApiResponse::success(
+        to_output(mismatch),
+        &t("Ledger mismatch corrected"),
+    ))
+}
@@ -12,7 +12,11 @@ use core_web::{
     },
     response::ApiResponse,
 };
-use generated::{guards::AdminGuard, models::CryptoNetworkStatus, permissions::Permission};
+use generated::{
+    guards::AdminGuard,
+    models::{CryptoAddressFormat, CryptoNetworkStatus},
+    permissions::Permission,
+};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -21,7 +25,7 @@ use crate::{
     internal::{api::state::AppApiState, workflows::crypto_network as workflow},
 };
 
-use super::receipt_upload::validate_attachment_allowed;
+use crate::internal::api::v1::receipt_upload::validate_attachment_allowed;
 
 pub fn router(state: AppApiState) -> ApiRouter {
     ApiRouter::new()
@@ -83,6 +87,7 @@ async fn parse_crypto_network_multipart(
     let mut name: Option<String> = None;
     let mut symbol: Option<String> = None;
     let mut status: Option<String> = None;
+    let mut address_format: Option<String> = None;
     let mut sort_order: Option<String> = None;
     let mut logo: Option<AttachmentInput> = None;
 
@@ -96,6 +101,9 @@ async fn parse_crypto_network_multipart(
             "name" => name = Some(field.text().await.map_err(AppError::from)?),
             "symbol" => symbol = Some(field.text().await.map_err(AppError::from)?),
             "status" => status = Some(field.text().await.map_err(AppError::from)?),
+            "address_format" => {
+                address_format = Some(field.text().await.map_err(AppError::from)?)
+            }
             "sort_order" => sort_order = Some(field.text().await.map_err(AppError::from)?),
             "logo" => {
                 if field.file_name().is_some() {
@@ -143,9 +151,23 @@ async fn parse_crypto_network_multipart(
     let status: CryptoNetworkStatus = serde_json::from_str(&format!("\"{status_str}\""))
         .or_else(|_| serde_json::from_str(&status_str))
         .map_err(|_| AppError::BadRequest(t("Invalid status value")))?;
+    let address_format = match address_format.map(|v| v.trim().to_string()) {
+        Some(v) if !v.is_empty() => Some(
+            serde_json::from_str::<CryptoAddressFormat>(&format!("\"{v}\""))
+                .or_else(|_| serde_json::from_str(&v))
+                .map_err(|_| AppError::BadRequest(t("Invalid address format value")))?,
+        ),
+        _ => None,
+    };
     let sort_order = sort_order.and_then(|v| v.trim().parse::<i32>().ok());
 
-    let input = AdminCryptoNetworkInput { name, symbol, status, sort_order };
+    let input = AdminCryptoNetworkInput {
+        name,
+        symbol,
+        status,
+        address_format,
+        sort_order,
+    };
     if let Err(e) = input.validate() {
         return Err(AppError::Validation {
             message: t("Validation failed"),
@@ -163,6 +185,7 @@ fn network_output(network: &generated::models::CryptoNetworkRecord) -> CryptoNet
         symbol: network.symbol.clone(),
         logo_url: network.logo_url.clone(),
         status: network.status,
+        address_format: network.address_format,
         sort_order: network.sort_order,
         created_at: network.created_at,
         updated_at: network.updated_at,
@@ -1,25 +1,59 @@
-use axum::extract::{Multipart, Path, State};
+use axum::{
+    extract::{Multipart, Path, State},
+    middleware::from_fn_with_state,
+};
 use core_i18n::t;
 use core_web::{
     auth::AuthUser,
     authz::PermissionMode,
     contracts::ContractJson,
     error::AppError,
-    openapi::{with_permission_check_post_with, ApiRouter},
+    openapi::{with_permission_check_get_with, with_permission_check_post_with, ApiRouter},
     response::ApiResponse,
 };
-use generated::{guards::AdminGuard, models::DepositStatus, permissions::Permission};
-use uuid::Uuid;
+use generated::{
+    guards::AdminGuard,
+    models::{DepositRecord, DepositStatus, DepositStatusHistoryRecord},
+    permissions::Permission,
+};
 
 use crate::{
-    contracts::api::v1::admin::deposit::{AdminDepositReviewInput, DepositOutput},
-    internal::{api::state::AppApiState, workflows::deposit as workflow},
+    contracts::api::v1::admin::{
+        deposit::{
+            AdminDepositReverseInput, AdminDepositReviewInput, DepositDetailOutput,
+            DepositOutput, DepositReversalOutput, DepositStatusHistoryOutput,
+        },
+        review_claim::ReviewClaimOutput,
+        user_credit::UserCreditTransactionOutput,
+    },
+    internal::{
+        api::{state::AppApiState, v1::admin::review_claim::to_output as review_claim_output},
+        middleware::idempotency,
+        workflows::{
+            deposit as workflow,
+            financial_approval::ApprovalOutcome,
+            review_claim::{self, ClaimSubject},
+        },
+    },
 };
 
-use super::receipt_upload::{build_attachment_url, parse_receipt_multipart, validate_attachment_allowed};
+use crate::internal::api::v1::receipt_upload::store_deposit_receipt;
 
 pub fn router(state: AppApiState) -> ApiRouter {
     ApiRouter::new()
+        .api_route(
+            "/{id}",
+            with_permission_check_get_with(
+                deposit_detail,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::DepositRead.as_str(),
+                    Permission::DepositManage.as_str(),
+                ],
+                |op| op.summary("Deposit detail with status history").tag("Admin Deposits"),
+            ),
+        )
         .api_route(
             "/{id}/review",
             with_permission_check_post_with(
@@ -30,6 +64,36 @@ pub fn router(state: AppApiState) -> ApiRouter {
                 |op| op.summary("Review deposit").tag("Admin Deposits"),
             ),
         )
+        .api_route(
+            "/{id}/reverse",
+            with_permission_check_post_with(
+                reverse_deposit,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::DepositManage.as_str()],
+                |op| op.summary("Reverse approved deposit").tag("Admin Deposits"),
+            ),
+        )
+        .api_route(
+            "/{id}/claim",
+            with_permission_check_post_with(
+                claim_deposit,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::DepositManage.as_str()],
+                |op| op.summary("Claim deposit for review").tag("Admin Deposits"),
+            ),
+        )
+        .api_route(
+            "/{id}/release",
+            with_permission_check_post_with(
+                release_deposit,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::DepositManage.as_str()],
+                |op| op.summary("Release deposit review claim").tag("Admin Deposits"),
+            ),
+        )
         .api_route(
             "/{id}/upload-receipt",
             with_permission_check_post_with(
@@ -40,38 +104,137 @@ pub fn router(state: AppApiState) -> ApiRouter {
                 |op| op.summary("Upload deposit receipt").tag("Admin Deposits"),
             ),
         )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::admin_idempotency,
+        ))
         .with_state(state)
 }
 
+fn to_output(deposit: &DepositRecord) -> DepositOutput {
+    DepositOutput {
+        id: deposit.id.into(),
+        owner_type: deposit.owner_type,
+        owner_id: deposit.owner_id.into(),
+        credit_type: deposit.credit_type,
+        deposit_method: deposit.deposit_method,
+        company_bank_account_id: deposit.company_bank_account_id.map(Into::into),
+        company_crypto_account_id: deposit.company_crypto_account_id.map(Into::into),
+        conversion_rate: deposit.conversion_rate,
+        status: deposit.status,
+        amount: deposit.amount,
+        fee: deposit.fee,
+        net_amount: deposit.net_amount,
+        reference_code: deposit.reference_code.clone(),
+        gateway_provider: deposit.gateway_provider.clone(),
+        gateway_session_id: deposit.gateway_session_id.clone(),
+        related_key: deposit.related_key.clone(),
+        remark: deposit.remark.clone(),
+        admin_remark: deposit.admin_remark.clone(),
+        reviewed_at: deposit.reviewed_at,
+        reversed_by: deposit.reversed_by.map(Into::into),
+        reversal_reason: deposit.reversal_reason.clone(),
+        reversed_at: deposit.reversed_at,
+        created_at: deposit.created_at,
+    }
+}
+
+fn to_history_output(entry: DepositStatusHistoryRecord) -> DepositStatusHistoryOutput {
+    DepositStatusHistoryOutput {
+        status: entry.status,
+        admin_id: entry.admin_id.map(Into::into),
+        remark: entry.remark,
+        created_at: entry.created_at,
+    }
+}
+
+async fn deposit_detail(
+    State(state): State<AppApiState>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<DepositDetailOutput>, AppError> {
+    let detail = workflow::detail(&state, id).await?;
+
+    Ok(ApiResponse::success(
+        DepositDetailOutput {
+            deposit: to_output(&detail.deposit),
+            history: detail.history.into_iter().map(to_history_output).collect(),
+        },
+        &t("Deposit loaded"),
+    ))
+}
+
 async fn review_deposit(
     State(state): State<AppApiState>,
     auth: AuthUser<AdminGuard>,
     Path(id): Path<i64>,
     ContractJson(req): ContractJson<AdminDepositReviewInput>,
 ) -> Result<ApiResponse<DepositOutput>, AppError> {
-    let deposit = workflow::review_deposit(&state, auth.user.id, id, req).await?;
+    let (deposit, message) = match workflow::review_deposit(&state, auth.user.id, id, req).await? {
+        ApprovalOutcome::Applied(deposit) => (deposit, t("Deposit reviewed")),
+        ApprovalOutcome::Proposed(_) => (
+            workflow::find(&state, id).await?,
+            t("Submitted for confirmation by a second admin"),
+        ),
+    };
+
+    Ok(ApiResponse::success(to_output(&deposit), &message))
+}
+
+async fn reverse_deposit(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+    ContractJson(req): ContractJson<AdminDepositReverseInput>,
+) -> Result<ApiResponse<DepositReversalOutput>, AppError> {
+    let reversal =
+        workflow::reverse_deposit(&state, auth.user.id, id, req.reason.trim().to_string()).await?;
 
     Ok(ApiResponse::success(
-        DepositOutput {
-            id: deposit.id.into(),
-            owner_type: deposit.owner_type,
-            owner_id: deposit.owner_id.into(),
-            credit_type: deposit.credit_type,
-            deposit_method: deposit.deposit_method,
-            company_bank_account_id: deposit.company_bank_account_id.map(Into::into),
-            company_crypto_account_id: deposit.company_crypto_account_id.map(Into::into),
-            conversion_rate: deposit.conversion_rate,
-            status: deposit.status,
-            amount: deposit.amount,
-            fee: deposit.fee,
-            net_amount: deposit.net_amount,
-            related_key: deposit.related_key.clone(),
-            remark: deposit.remark.clone(),
-            admin_remark: deposit.admin_remark.clone(),
-            reviewed_at: deposit.reviewed_at,
-            created_at: deposit.created_at,
+        DepositReversalOutput {
+            deposit: to_output(&reversal.deposit),
+            commissions_reversed: reversal.commissions_reversed as i64,
+            needs_reversal: reversal
+                .needs_reversal
+                .into_iter()
+                .map(|txn| UserCreditTransactionOutput {
+                    id: txn.id.into(),
+                    user_id: txn.user_id.into(),
+                    credit_type: txn.credit_type,
+                    amount: txn.amount,
+                    transaction_type: txn.transaction_type,
+                    related_key: txn.related_key,
+                    remark: txn.remark,
+                    created_at: txn.created_at,
+                })
+                .collect(),
         },
-        &t("Deposit reviewed"),
+        &t("Deposit reversed"),
+    ))
+}
+
+async fn claim_deposit(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<ReviewClaimOutput>, AppError> {
+    let claim = review_claim::claim(&state, auth.user.id, ClaimSubject::Deposit, id).await?;
+
+    Ok(ApiResponse::success(
+        review_claim_output(claim),
+        &t("Deposit claimed for review"),
+    ))
+}
+
+async fn release_deposit(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<ReviewClaimOutput>, AppError> {
+    let claim = review_claim::release(&state, auth.user.id, ClaimSubject::Deposit, id).await?;
+
+    Ok(ApiResponse::success(
+        review_claim_output(claim),
+        &t("Review claim released"),
     ))
 }
 
@@ -81,54 +244,12 @@ async fn upload_receipt(
     Path(id): Path<i64>,
     multipart: Multipart,
 ) -> Result<ApiResponse<serde_json::Value>, AppError> {
-    let deposit = workflow::detail(&state, id).await?;
+    let deposit = workflow::find(&state, id).await?;
     if deposit.status != DepositStatus::Pending {
         return Err(AppError::BadRequest(t("Deposit is not pending")));
     }
 
-    let (file_name, content_type, bytes) = parse_receipt_multipart(multipart).await?;
-
-    let rules = generated::get_attachment_rules("image")
-        .ok_or_else(|| AppError::BadRequest(t("Unknown attachment type: image")))?;
-    validate_attachment_allowed(&rules.allowed, file_name.as_deref(), &content_type)?;
-
-    let ext = file_name
-        .as_deref()
-        .and_then(|name| {
-            std::path::Path::new(name)
-                .extension()
-                .and_then(|e| e.to_str())
-        })
-        .unwrap_or_else(|| content_type.split('/').nth(1).unwrap_or("bin"));
-
-    let now = time::OffsetDateTime::now_utc();
-    let object_key = format!(
-        "deposits/{:04}/{:02}/{:02}/receipt/{}.{}",
-        now.year(),
-        now.month() as u8,
-        now.day(),
-        Uuid::new_v4(),
-        ext
-    );
-
-    state
-        .storage
-        .put(&object_key, bytes.clone(), &content_type)
-        .await
-        .map_err(AppError::from)?;
-
-    let base_url = std::env::var("S3_URL").ok();
-    let receipt_url = build_attachment_url(&object_key, base_url.as_deref());
-
-    let params = serde_json::json!({ "receipt_url": receipt_url, "receipt_path": object_key });
-    generated::models::DepositModel::query(core_db::common::sql::DbConn::pool(&state.db))
-        .where_col(generated::models::DepositCol::ID, core_db::common::sql::Op::Eq, id)
-        .patch()
-        .assign(generated::models::DepositCol::PARAMS, Some(params.clone()))
-        .map_err(AppError::from)?
-        .save()
-        .await
-        .map_err(AppError::from)?;
+    let params = store_deposit_receipt(&state, id, multipart).await?;
 
     Ok(ApiResponse::success(params, &t("Receipt uploaded")))
 }
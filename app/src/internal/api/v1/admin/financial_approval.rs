@@ -0,0 +1,130 @@
+use axum::{
+    extract::{Path, State},
+    middleware::from_fn_with_state,
+};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    authz::PermissionMode,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{with_permission_check_get_with, with_permission_check_post_with, ApiRouter},
+    response::ApiResponse,
+};
+use generated::{guards::AdminGuard, models::FinancialApprovalRecord, permissions::Permission};
+
+use crate::{
+    contracts::api::v1::admin::financial_approval::{
+        AdminFinancialApprovalDecisionInput, FinancialApprovalOutput, FinancialApprovalsOutput,
+    },
+    internal::{
+        api::state::AppApiState, middleware::idempotency,
+        workflows::financial_approval as workflow,
+    },
+};
+
+/// Each approval is additionally checked against the permission its own
+/// action needs.
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/",
+            with_permission_check_get_with(
+                list_pending,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::DepositManage.as_str(),
+                    Permission::WithdrawalManage.as_str(),
+                    Permission::UserCreditManage.as_str(),
+                ],
+                |op| op.summary("List approvals awaiting confirmation").tag("Admin Approvals"),
+            ),
+        )
+        .api_route(
+            "/{id}/confirm",
+            with_permission_check_post_with(
+                confirm,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::DepositManage.as_str(),
+                    Permission::WithdrawalManage.as_str(),
+                    Permission::UserCreditManage.as_str(),
+                ],
+                |op| op.summary("Confirm a proposed financial action").tag("Admin Approvals"),
+            ),
+        )
+        .api_route(
+            "/{id}/decline",
+            with_permission_check_post_with(
+                decline,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::DepositManage.as_str(),
+                    Permission::WithdrawalManage.as_str(),
+                    Permission::UserCreditManage.as_str(),
+                ],
+                |op| op.summary("Decline a proposed financial action").tag("Admin Approvals"),
+            ),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::admin_idempotency,
+        ))
+        .with_state(state)
+}
+
+pub fn to_output(approval: FinancialApprovalRecord) -> FinancialApprovalOutput {
+    FinancialApprovalOutput {
+        id: approval.id.into(),
+        action: approval.action,
+        subject_id: approval.subject_id.into(),
+        credit_type: approval.credit_type,
+        amount: approval.amount,
+        payload: approval.payload,
+        status: approval.status,
+        proposed_by: approval.proposed_by.into(),
+        reviewed_by: approval.reviewed_by.map(Into::into),
+        review_remark: approval.review_remark,
+        reviewed_at: approval.reviewed_at,
+        created_at: approval.created_at,
+    }
+}
+
+async fn list_pending(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+) -> Result<ApiResponse<FinancialApprovalsOutput>, AppError> {
+    let approvals = workflow::list_pending(&state, &auth).await?;
+
+    Ok(ApiResponse::success(
+        FinancialApprovalsOutput {
+            approvals: approvals.into_iter().map(to_output).collect(),
+        },
+        &t("Approvals loaded"),
+    ))
+}
+
+async fn confirm(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+    ContractJson(req): ContractJson<AdminFinancialApprovalDecisionInput>,
+) -> Result<ApiResponse<FinancialApprovalOutput>, AppError> {
+    let approval = workflow::confirm(&state, &auth, id, req.remark).await?;
+
+    Ok(ApiResponse::success(to_output(approval), &t("Approval confirmed")))
+}
+
+async fn decline(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+    ContractJson(req): ContractJson<AdminFinancialApprovalDecisionInput>,
+) -> Result<ApiResponse<FinancialApprovalOutput>, AppError> {
+    let approval = workflow::decline(&state, &auth, id, req.remark).await?;
+
+    Ok(ApiResponse::success(to_output(approval), &t("Approval declined")))
+}
@@ -0,0 +1,90 @@
+use axum::extract::{Path, State};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    authz::PermissionMode,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{with_permission_check_get_with, with_permission_check_post_with, ApiRouter},
+    response::ApiResponse,
+};
+use generated::{guards::AdminGuard, permissions::Permission};
+
+use crate::{
+    contracts::api::v1::admin::kyc::{AdminKycReviewInput, KycSubmissionOutput},
+    internal::{
+        api::state::AppApiState,
+        workflows::kyc::{self as workflow, KycSubmissionDetail},
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/{id}",
+            with_permission_check_get_with(
+                detail,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::KycRead.as_str(), Permission::KycManage.as_str()],
+                |op| op.summary("KYC submission with its documents").tag("Admin KYC"),
+            ),
+        )
+        .api_route(
+            "/{id}/review",
+            with_permission_check_post_with(
+                review,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::KycManage.as_str()],
+                |op| op.summary("Approve or reject a KYC submission").tag("Admin KYC"),
+            ),
+        )
+        .with_state(state)
+}
+
+fn to_output(detail: KycSubmissionDetail) -> KycSubmissionOutput {
+    let missing_documents = workflow::missing_documents(&detail.submission);
+    let submission = detail.submission;
+    KycSubmissionOutput {
+        id: submission.id.into(),
+        user_id: submission.user_id.into(),
+        username: detail.user.as_ref().map(|u| u.username.clone()),
+        user_kyc_level: detail.user.as_ref().map(|u| u.kyc_level),
+        level: submission.level,
+        status: submission.status,
+        full_name: submission.full_name,
+        document_type: submission.document_type,
+        document_number: submission.document_number,
+        reject_reason: submission.reject_reason,
+        admin_id: submission.admin_id.map(Into::into),
+        admin_username: detail.admin.map(|a| a.username),
+        id_front_url: submission.id_front_url,
+        id_back_url: submission.id_back_url,
+        selfie_url: submission.selfie_url,
+        proof_of_address_url: submission.proof_of_address_url,
+        missing_documents,
+        reviewed_at: submission.reviewed_at,
+        created_at: submission.created_at,
+    }
+}
+
+async fn detail(
+    State(state): State<AppApiState>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<KycSubmissionOutput>, AppError> {
+    let detail = workflow::detail(&state, id).await?;
+
+    Ok(ApiResponse::success(to_output(detail), &t("KYC submission loaded")))
+}
+
+async fn review(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+    ContractJson(req): ContractJson<AdminKycReviewInput>,
+) -> Result<ApiResponse<KycSubmissionOutput>, AppError> {
+    let detail = workflow::review(&state, auth.user.id, id, req).await?;
+
+    Ok(ApiResponse::success(to_output(detail), &t("KYC submission reviewed")))
+}
@@ -6,22 +6,29 @@ use crate::internal::api::{datatable, state::AppApiState};
 mod account;
 mod auth;
 mod bank;
+mod bank_statement;
 mod company_bank_account;
 mod company_crypto_account;
 mod content_page;
 mod content_page_multipart;
 mod country;
+mod credit_conversion;
+mod credit_ledger;
 mod crypto_network;
 mod deposit;
 mod developer_logs;
+mod financial_approval;
 mod notification;
-mod receipt_upload;
+mod referral_commission;
+mod review_claim;
 mod hierarchy;
 mod introducer_change;
+mod kyc;
 mod tiptap_upload;
 mod user;
 mod user_credit;
 mod withdrawal;
+mod withdrawal_destination;
 
 pub fn router(state: AppApiState) -> ApiRouter {
     ApiRouter::new()
@@ -41,7 +48,20 @@ fn guarded_router(state: AppApiState) -> ApiRouter {
         .nest("/users", user::router(state.clone()))
         .nest("/users/hierarchy", hierarchy::router(state.clone()))
         .nest("/users/credits", user_credit::router(state.clone()))
+        .nest(
+            "/credit_conversion_rates",
+            credit_conversion::router(state.clone()),
+        )
+        .nest(
+            "/credit_ledger_mismatches",
+            credit_ledger::router(state.clone()),
+        )
+        .nest(
+            "/referral_commissions",
+            referral_commission::router(state.clone()),
+        )
         .nest("/introducer_changes", introducer_change::router(state.clone()))
+        .nest("/kyc_submissions", kyc::router(state.clone()))
         .nest("/countries", country::router(state.clone()))
         .nest("/content_page", content_page::router(state.clone()))
         .nest("/uploads", tiptap_upload::router(state.clone()))
@@ -52,6 +72,15 @@ fn guarded_router(state: AppApiState) -> ApiRouter {
         .nest("/notifications", notification::router(state.clone()))
         .nest("/deposits", deposit::router(state.clone()))
         .nest("/withdrawals", withdrawal::router(state.clone()))
+        .nest(
+            "/withdrawal_destinations",
+            withdrawal_destination::router(state.clone()),
+        )
+        .nest("/bank_statements", bank_statement::router(state.clone()))
+        .nest(
+            "/financial_approvals",
+            financial_approval::router(state.clone()),
+        )
         .nest("/developer/logs", developer_logs::router(state.clone()))
         .merge(datatable::router(state.clone()))
         .layer(from_fn_with_state(
@@ -37,6 +37,7 @@ async fn counts(
         AdminNotificationCountsOutput {
             deposit: pending.deposit,
             withdrawal: pending.withdrawal,
+            kyc: pending.kyc,
         },
         &t("Notification counts"),
     ))
@@ -0,0 +1,173 @@
+use axum::{
+    extract::{Path, State},
+    middleware::from_fn_with_state,
+};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    authz::PermissionMode,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{
+        with_permission_check_get_with, with_permission_check_post_with,
+        with_permission_check_put_with, ApiRouter,
+    },
+    response::ApiResponse,
+};
+use generated::{
+    guards::AdminGuard,
+    models::{ReferralCommissionRateRecord, ReferralCommissionRecord, ReferralCommissionSource},
+    permissions::Permission,
+};
+
+use crate::{
+    contracts::api::v1::admin::referral_commission::{
+        AdminReferralCommissionRatesInput, ReferralCommissionOutput, ReferralCommissionRateOutput,
+        ReferralCommissionRatesOutput, ReferralCommissionsOutput,
+    },
+    internal::{
+        api::state::AppApiState, middleware::idempotency,
+        workflows::referral_commission as workflow,
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/rates",
+            with_permission_check_get_with(
+                list_rates,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::UserCreditRead.as_str(),
+                    Permission::UserCreditManage.as_str(),
+                ],
+                |op| op.summary("List referral commission rates").tag("Admin User Credit"),
+            ),
+        )
+        .api_route(
+            "/rates",
+            with_permission_check_put_with(
+                replace_rates,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::UserCreditManage.as_str()],
+                |op| op.summary("Set referral commission rates").tag("Admin User Credit"),
+            ),
+        )
+        .api_route(
+            "/deposits/{id}",
+            with_permission_check_get_with(
+                deposit_commissions,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::UserCreditRead.as_str(),
+                    Permission::UserCreditManage.as_str(),
+                ],
+                |op| op.summary("List commissions paid for a deposit").tag("Admin User Credit"),
+            ),
+        )
+        .api_route(
+            "/deposits/{id}/run",
+            with_permission_check_post_with(
+                rerun_deposit,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::UserCreditManage.as_str()],
+                |op| {
+                    op.summary("Pay outstanding commissions for a deposit")
+                        .tag("Admin User Credit")
+                },
+            ),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::admin_idempotency,
+        ))
+        .with_state(state)
+}
+
+fn to_rate_output(rate: ReferralCommissionRateRecord) -> ReferralCommissionRateOutput {
+    ReferralCommissionRateOutput {
+        credit_type: rate.credit_type,
+        level: rate.level,
+        percentage: rate.percentage,
+        updated_at: rate.updated_at,
+    }
+}
+
+fn to_output(commission: ReferralCommissionRecord) -> ReferralCommissionOutput {
+    ReferralCommissionOutput {
+        id: commission.id.into(),
+        source_type: commission.source_type,
+        source_id: commission.source_id.into(),
+        beneficiary_user_id: commission.beneficiary_user_id.into(),
+        level: commission.level,
+        credit_type: commission.credit_type,
+        base_amount: commission.base_amount,
+        percentage: commission.percentage,
+        amount: commission.amount,
+        status: commission.status,
+        reversed_at: commission.reversed_at,
+        created_at: commission.created_at,
+    }
+}
+
+async fn list_rates(
+    State(state): State<AppApiState>,
+) -> Result<ApiResponse<ReferralCommissionRatesOutput>, AppError> {
+    let rates = workflow::list_rates(&state).await?;
+
+    Ok(ApiResponse::success(
+        ReferralCommissionRatesOutput {
+            rates: rates.into_iter().map(to_rate_output).collect(),
+        },
+        &t("Commission rates loaded"),
+    ))
+}
+
+async fn replace_rates(
+    State(state): State<AppApiState>,
+    ContractJson(req): ContractJson<AdminReferralCommissionRatesInput>,
+) -> Result<ApiResponse<ReferralCommissionRatesOutput>, AppError> {
+    let rates = workflow::replace_rates(&state, req.credit_type, req.percentages).await?;
+
+    Ok(ApiResponse::success(
+        ReferralCommissionRatesOutput {
+            rates: rates.into_iter().map(to_rate_output).collect(),
+        },
+        &t("Commission rates saved"),
+    ))
+}
+
+async fn deposit_commissions(
+    State(state): State<AppApiState>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<ReferralCommissionsOutput>, AppError> {
+    let commissions =
+        workflow::list_for_source(&state, ReferralCommissionSource::Deposit, id).await?;
+
+    Ok(ApiResponse::success(
+        ReferralCommissionsOutput {
+            commissions: commissions.into_iter().map(to_output).collect(),
+        },
+        &t("Commissions loaded"),
+    ))
+}
+
+async fn rerun_deposit(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<ReferralCommissionsOutput>, AppError> {
+    let commissions = workflow::rerun_for_deposit(&state, auth.user.id, id).await?;
+
+    Ok(ApiResponse::success(
+        ReferralCommissionsOutput {
+            commissions: commissions.into_iter().map(to_output).collect(),
+        },
+        &t("Commissions paid"),
+    ))
+}
@@ -0,0 +1,13 @@
+use crate::{
+    contracts::api::v1::admin::review_claim::ReviewClaimOutput,
+    internal::workflows::review_claim::ReviewClaim,
+};
+
+pub fn to_output(claim: ReviewClaim) -> ReviewClaimOutput {
+    ReviewClaimOutput {
+        id: claim.id.into(),
+        claimed_by: claim.claimed_by.map(Into::into),
+        claimer_username: claim.claimer_username,
+        claimed_until: claim.claimed_until,
+    }
+}
@@ -3,23 +3,36 @@ use core_i18n::t;
 use core_web::{
     auth::AuthUser,
     authz::PermissionMode,
+    contracts::ContractJson,
     error::AppError,
     extract::{validation::transform_validation_errors, AsyncValidate, CleanJson},
     openapi::{
         with_permission_check_get_with, with_permission_check_patch_with,
-        with_permission_check_post_with, ApiRouter,
+        with_permission_check_post_with, with_permission_check_put_with, ApiRouter,
     },
     response::ApiResponse,
 };
-use generated::{guards::AdminGuard, models::UserBanStatus, permissions::Permission};
+use generated::{
+    guards::AdminGuard,
+    models::{UserBanStatus, UserLimitKind},
+    permissions::Permission,
+};
 use validator::Validate;
 
 use crate::{
     contracts::api::v1::admin::user::{
-        BatchResolveEntry, BatchResolveInput, BatchResolveOutput, CreateUserInput, UpdateUserInput,
-        UserBanInput, UserBanOutput, UserManageOutput,
+        AdminUserLimitsInput, BatchResolveEntry, BatchResolveInput, BatchResolveOutput,
+        CreateUserInput, LimitPeriodUsage, LimitValues, UpdateUserInput, UserBanInput,
+        UserBanOutput, UserLimitOutput, UserLimitsOutput, UserManageOutput,
+    },
+    internal::{
+        api::state::AppApiState,
+        config::limits::TransactionLimits,
+        workflows::{
+            limits::{self, LimitStatus, PeriodUsage},
+            user_manage as workflow,
+        },
     },
-    internal::{api::state::AppApiState, workflows::user_manage as workflow},
 };
 
 pub fn router(state: AppApiState) -> ApiRouter {
@@ -70,6 +83,29 @@ pub fn router(state: AppApiState) -> ApiRouter {
                 |op| op.summary("Update user").tag("Admin User Management"),
             )),
         )
+        .api_route(
+            "/{id}/limits",
+            with_permission_check_get_with(
+                limit_status,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::UserRead.as_str(),
+                    Permission::UserManage.as_str(),
+                ],
+                |op| {
+                    op.summary("User deposit and withdrawal limits with current usage")
+                        .tag("Admin User Management")
+                },
+            )
+            .merge(with_permission_check_put_with(
+                set_limits,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::UserManage.as_str()],
+                |op| op.summary("Override user limits").tag("Admin User Management"),
+            )),
+        )
         .api_route(
             "/{id}/ban",
             with_permission_check_patch_with(
@@ -141,6 +177,83 @@ async fn set_ban(
     Ok(ApiResponse::success(UserBanOutput { banned }, &message))
 }
 
+fn to_limit_values(limits: TransactionLimits) -> LimitValues {
+    LimitValues {
+        min_amount: limits.min_amount,
+        max_amount: limits.max_amount,
+        daily_count: limits.daily_count,
+        daily_amount: limits.daily_amount,
+        weekly_count: limits.weekly_count,
+        weekly_amount: limits.weekly_amount,
+        monthly_count: limits.monthly_count,
+        monthly_amount: limits.monthly_amount,
+    }
+}
+
+fn from_limit_values(values: LimitValues) -> TransactionLimits {
+    TransactionLimits {
+        min_amount: values.min_amount,
+        max_amount: values.max_amount,
+        daily_count: values.daily_count,
+        daily_amount: values.daily_amount,
+        weekly_count: values.weekly_count,
+        weekly_amount: values.weekly_amount,
+        monthly_count: values.monthly_count,
+        monthly_amount: values.monthly_amount,
+    }
+}
+
+fn to_period_usage(usage: PeriodUsage) -> LimitPeriodUsage {
+    LimitPeriodUsage {
+        count: usage.count,
+        amount: usage.amount,
+    }
+}
+
+fn to_limit_output(status: LimitStatus) -> UserLimitOutput {
+    UserLimitOutput {
+        kind: status.kind,
+        inherited: to_limit_values(status.inherited),
+        overrides: to_limit_values(status.overrides),
+        effective: to_limit_values(status.effective),
+        daily: to_period_usage(status.usage.daily),
+        weekly: to_period_usage(status.usage.weekly),
+        monthly: to_period_usage(status.usage.monthly),
+    }
+}
+
+async fn load_limits(state: &AppApiState, id: i64) -> Result<UserLimitsOutput, AppError> {
+    Ok(UserLimitsOutput {
+        deposit: to_limit_output(limits::status(state, id, UserLimitKind::Deposit).await?),
+        withdrawal: to_limit_output(limits::status(state, id, UserLimitKind::Withdrawal).await?),
+    })
+}
+
+async fn limit_status(
+    State(state): State<AppApiState>,
+    _auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<UserLimitsOutput>, AppError> {
+    Ok(ApiResponse::success(load_limits(&state, id).await?, &t("User limits loaded")))
+}
+
+async fn set_limits(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+    ContractJson(req): ContractJson<AdminUserLimitsInput>,
+) -> Result<ApiResponse<UserLimitsOutput>, AppError> {
+    let sets = [
+        (UserLimitKind::Deposit, req.deposit),
+        (UserLimitKind::Withdrawal, req.withdrawal),
+    ];
+    for (kind, values) in sets {
+        limits::set_overrides(&state, auth.user.id, id, kind, from_limit_values(values)).await?;
+    }
+
+    Ok(ApiResponse::success(load_limits(&state, id).await?, &t("User limits saved")))
+}
+
 async fn batch_resolve(
     State(state): State<AppApiState>,
     _auth: AuthUser<AdminGuard>,
@@ -1,4 +1,4 @@
-use axum::extract::State;
+use axum::{extract::State, middleware::from_fn_with_state};
 use core_i18n::t;
 use core_web::{
     auth::AuthUser,
@@ -10,9 +10,22 @@ use core_web::{
 };
 use generated::{guards::AdminGuard, permissions::Permission};
 
+use super::financial_approval;
 use crate::{
-    contracts::api::v1::admin::user_credit::{AdminCreditAdjustInput, UserCreditTransactionOutput},
-    internal::{api::state::AppApiState, workflows::user_credit as workflow},
+    contracts::api::v1::admin::{
+        credit_conversion::{AdminCreditConvertInput, CreditConversionOutput},
+        user_credit::{
+            AdminCreditAdjustInput, AdminCreditAdjustOutput, UserCreditTransactionOutput,
+        },
+    },
+    internal::{
+        api::state::AppApiState,
+        middleware::idempotency,
+        workflows::{
+            credit_conversion, financial_approval::ApprovalOutcome,
+            introducer_change::resolve_user_by_username, user_credit as workflow,
+        },
+    },
 };
 
 pub fn router(state: AppApiState) -> ApiRouter {
@@ -27,6 +40,20 @@ pub fn router(state: AppApiState) -> ApiRouter {
                 |op| op.summary("Adjust user credit").tag("Admin User Credit"),
             ),
         )
+        .api_route(
+            "/convert",
+            with_permission_check_post_with(
+                convert_credit,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::UserCreditManage.as_str()],
+                |op| op.summary("Convert user credit").tag("Admin User Credit"),
+            ),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::admin_idempotency,
+        ))
         .with_state(state)
 }
 
@@ -34,20 +61,62 @@ async fn adjust_credit(
     State(state): State<AppApiState>,
     auth: AuthUser<AdminGuard>,
     ContractJson(req): ContractJson<AdminCreditAdjustInput>,
-) -> Result<ApiResponse<UserCreditTransactionOutput>, AppError> {
-    let txn = workflow::adjust_credit(&state, auth.user.id, req).await?;
+) -> Result<ApiResponse<AdminCreditAdjustOutput>, AppError> {
+    match workflow::adjust_credit(&state, auth.user.id, req).await? {
+        ApprovalOutcome::Applied(txn) => Ok(ApiResponse::success(
+            AdminCreditAdjustOutput {
+                transaction: Some(UserCreditTransactionOutput {
+                    id: txn.id.into(),
+                    user_id: txn.user_id.into(),
+                    credit_type: txn.credit_type,
+                    amount: txn.amount,
+                    transaction_type: txn.transaction_type,
+                    related_key: txn.related_key,
+                    remark: txn.remark,
+                    created_at: txn.created_at,
+                }),
+                approval: None,
+            },
+            &t("Credit adjusted"),
+        )),
+        ApprovalOutcome::Proposed(approval) => Ok(ApiResponse::success(
+            AdminCreditAdjustOutput {
+                transaction: None,
+                approval: Some(financial_approval::to_output(approval)),
+            },
+            &t("Submitted for confirmation by a second admin"),
+        )),
+    }
+}
+
+async fn convert_credit(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    ContractJson(req): ContractJson<AdminCreditConvertInput>,
+) -> Result<ApiResponse<CreditConversionOutput>, AppError> {
+    let user = resolve_user_by_username(&state, &req.username).await?;
+    let result = credit_conversion::convert(
+        &state,
+        user.id,
+        Some(auth.user.id),
+        req.from_credit_type,
+        req.to_credit_type,
+        req.amount,
+        req.remark,
+    )
+    .await?;
 
     Ok(ApiResponse::success(
-        UserCreditTransactionOutput {
-            id: txn.id.into(),
-            user_id: txn.user_id.into(),
-            credit_type: txn.credit_type,
-            amount: txn.amount,
-            transaction_type: txn.transaction_type,
-            related_key: txn.related_key,
-            remark: txn.remark,
-            created_at: txn.created_at,
+        CreditConversionOutput {
+            related_key: result.related_key,
+            username: user.username,
+            from_credit_type: result.from_credit_type,
+            to_credit_type: result.to_credit_type,
+            amount: result.amount,
+            fee: result.fee,
+            rate: result.rate,
+            converted_amount: result.converted_amount,
         },
-        &t("Credit adjusted"),
+        &t("Credit converted"),
     ))
 }
@@ -1,25 +1,62 @@
-use axum::extract::{Multipart, Path, State};
+use axum::{
+    extract::{Multipart, Path, State},
+    middleware::from_fn_with_state,
+};
 use core_i18n::t;
 use core_web::{
     auth::AuthUser,
     authz::PermissionMode,
     contracts::ContractJson,
     error::AppError,
-    openapi::{with_permission_check_post_with, ApiRouter},
+    openapi::{with_permission_check_get_with, with_permission_check_post_with, ApiRouter},
     response::ApiResponse,
 };
-use generated::{guards::AdminGuard, models::WithdrawalStatus, permissions::Permission};
+use generated::{
+    guards::AdminGuard,
+    models::{WithdrawalRecord, WithdrawalStatus, WithdrawalStatusHistoryRecord},
+    permissions::Permission,
+};
 use uuid::Uuid;
 
 use crate::{
-    contracts::api::v1::admin::withdrawal::{AdminWithdrawalReviewInput, WithdrawalOutput},
-    internal::{api::state::AppApiState, workflows::withdrawal as workflow},
+    contracts::api::v1::admin::{
+        review_claim::ReviewClaimOutput,
+        withdrawal::{
+            AdminWithdrawalReviewInput, WithdrawalDetailOutput, WithdrawalOutput,
+            WithdrawalStatusHistoryOutput,
+        },
+    },
+    internal::{
+        api::{state::AppApiState, v1::admin::review_claim::to_output as review_claim_output},
+        middleware::idempotency,
+        workflows::{
+            financial_approval::ApprovalOutcome,
+            review_claim::{self, ClaimSubject},
+            withdrawal as workflow,
+        },
+    },
 };
 
-use super::receipt_upload::{build_attachment_url, parse_receipt_multipart, validate_attachment_allowed};
+use crate::internal::api::v1::receipt_upload::{build_attachment_url, parse_receipt_multipart, validate_attachment_allowed};
 
 pub fn router(state: AppApiState) -> ApiRouter {
     ApiRouter::new()
+        .api_route(
+            "/{id}",
+            with_permission_check_get_with(
+                withdrawal_detail,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::WithdrawalRead.as_str(),
+                    Permission::WithdrawalManage.as_str(),
+                ],
+                |op| {
+                    op.summary("Withdrawal detail with status history")
+                        .tag("Admin Withdrawals")
+                },
+            ),
+        )
         .api_route(
             "/{id}/review",
             with_permission_check_post_with(
@@ -30,6 +67,26 @@ pub fn router(state: AppApiState) -> ApiRouter {
                 |op| op.summary("Review withdrawal").tag("Admin Withdrawals"),
             ),
         )
+        .api_route(
+            "/{id}/claim",
+            with_permission_check_post_with(
+                claim_withdrawal,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::WithdrawalManage.as_str()],
+                |op| op.summary("Claim withdrawal for review").tag("Admin Withdrawals"),
+            ),
+        )
+        .api_route(
+            "/{id}/release",
+            with_permission_check_post_with(
+                release_withdrawal,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::WithdrawalManage.as_str()],
+                |op| op.summary("Release withdrawal review claim").tag("Admin Withdrawals"),
+            ),
+        )
         .api_route(
             "/{id}/upload-receipt",
             with_permission_check_post_with(
@@ -40,41 +97,110 @@ pub fn router(state: AppApiState) -> ApiRouter {
                 |op| op.summary("Upload withdrawal receipt").tag("Admin Withdrawals"),
             ),
         )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::admin_idempotency,
+        ))
         .with_state(state)
 }
 
+fn to_output(withdrawal: &WithdrawalRecord) -> WithdrawalOutput {
+    WithdrawalOutput {
+        id: withdrawal.id.into(),
+        owner_type: withdrawal.owner_type,
+        owner_id: withdrawal.owner_id.into(),
+        credit_type: withdrawal.credit_type,
+        withdrawal_method: withdrawal.withdrawal_method,
+        bank_id: withdrawal.bank_id.map(Into::into),
+        bank_account_name: withdrawal.bank_account_name.clone(),
+        bank_account_number: withdrawal.bank_account_number.clone(),
+        crypto_network_id: withdrawal.crypto_network_id.map(Into::into),
+        crypto_wallet_address: withdrawal.crypto_wallet_address.clone(),
+        destination_id: withdrawal.destination_id.map(Into::into),
+        conversion_rate: withdrawal.conversion_rate,
+        status: withdrawal.status,
+        amount: withdrawal.amount,
+        fee: withdrawal.fee,
+        net_amount: withdrawal.net_amount,
+        related_key: withdrawal.related_key.clone(),
+        remark: withdrawal.remark.clone(),
+        admin_remark: withdrawal.admin_remark.clone(),
+        reviewed_at: withdrawal.reviewed_at,
+        refunded_by: withdrawal.refunded_by.map(Into::into),
+        refund_reason: withdrawal.refund_reason.clone(),
+        refunded_at: withdrawal.refunded_at,
+        sla_breached_at: withdrawal.sla_breached_at,
+        payout_provider: withdrawal.payout_provider.clone(),
+        payout_reference: withdrawal.payout_reference.clone(),
+        created_at: withdrawal.created_at,
+    }
+}
+
+fn to_history_output(entry: WithdrawalStatusHistoryRecord) -> WithdrawalStatusHistoryOutput {
+    WithdrawalStatusHistoryOutput {
+        status: entry.status,
+        admin_id: entry.admin_id.map(Into::into),
+        remark: entry.remark,
+        created_at: entry.created_at,
+    }
+}
+
+async fn withdrawal_detail(
+    State(state): State<AppApiState>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<WithdrawalDetailOutput>, AppError> {
+    let detail = workflow::detail(&state, id).await?;
+
+    Ok(ApiResponse::success(
+        WithdrawalDetailOutput {
+            withdrawal: to_output(&detail.withdrawal),
+            history: detail.history.into_iter().map(to_history_output).collect(),
+        },
+        &t("Withdrawal loaded"),
+    ))
+}
+
 async fn review_withdrawal(
     State(state): State<AppApiState>,
     auth: AuthUser<AdminGuard>,
     Path(id): Path<i64>,
     ContractJson(req): ContractJson<AdminWithdrawalReviewInput>,
 ) -> Result<ApiResponse<WithdrawalOutput>, AppError> {
-    let withdrawal = workflow::review_withdrawal(&state, auth.user.id, id, req).await?;
+    let (withdrawal, message) =
+        match workflow::review_withdrawal(&state, auth.user.id, id, req).await? {
+            ApprovalOutcome::Applied(withdrawal) => (withdrawal, t("Withdrawal reviewed")),
+            ApprovalOutcome::Proposed(_) => (
+                workflow::find(&state, id).await?,
+                t("Submitted for confirmation by a second admin"),
+            ),
+        };
+
+    Ok(ApiResponse::success(to_output(&withdrawal), &message))
+}
+
+async fn claim_withdrawal(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<ReviewClaimOutput>, AppError> {
+    let claim = review_claim::claim(&state, auth.user.id, ClaimSubject::Withdrawal, id).await?;
 
     Ok(ApiResponse::success(
-        WithdrawalOutput {
-            id: withdrawal.id.into(),
-            owner_type: withdrawal.owner_type,
-            owner_id: withdrawal.owner_id.into(),
-            credit_type: withdrawal.credit_type,
-            withdrawal_method: withdrawal.withdrawal_method,
-            bank_id: withdrawal.bank_id.map(Into::into),
-            bank_account_name: withdrawal.bank_account_name.clone(),
-            bank_account_number: withdrawal.bank_account_number.clone(),
-            crypto_network_id: withdrawal.crypto_network_id.map(Into::into),
-            crypto_wallet_address: withdrawal.crypto_wallet_address.clone(),
-            conversion_rate: withdrawal.conversion_rate,
-            status: withdrawal.status,
-            amount: withdrawal.amount,
-            fee: withdrawal.fee,
-            net_amount: withdrawal.net_amount,
-            related_key: withdrawal.related_key.clone(),
-            remark: withdrawal.remark.clone(),
-            admin_remark: withdrawal.admin_remark.clone(),
-            reviewed_at: withdrawal.reviewed_at,
-            created_at: withdrawal.created_at,
-        },
-        &t("Withdrawal reviewed"),
+        review_claim_output(claim),
+        &t("Withdrawal claimed for review"),
+    ))
+}
+
+async fn release_withdrawal(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<ReviewClaimOutput>, AppError> {
+    let claim = review_claim::release(&state, auth.user.id, ClaimSubject::Withdrawal, id).await?;
+
+    Ok(ApiResponse::success(
+        review_claim_output(claim),
+        &t("Review claim released"),
     ))
 }
 
@@ -84,7 +210,7 @@ async fn upload_receipt(
     Path(id): Path<i64>,
     multipart: Multipart,
 ) -> Result<ApiResponse<serde_json::Value>, AppError> {
-    let withdrawal = workflow::detail(&state, id).await?;
+    let withdrawal = workflow::find(&state, id).await?;
     if withdrawal.status != WithdrawalStatus::Pending
         && withdrawal.status != WithdrawalStatus::Processing
     {
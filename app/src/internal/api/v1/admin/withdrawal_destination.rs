@@ -0,0 +1,129 @@
+use axum::extract::{Path, State};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    authz::PermissionMode,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{with_permission_check_get_with, with_permission_check_post_with, ApiRouter},
+    response::ApiResponse,
+};
+use generated::{
+    guards::AdminGuard, models::UserWithdrawalDestinationRecord, permissions::Permission,
+};
+
+use crate::{
+    contracts::api::v1::admin::withdrawal_destination::{
+        AdminWithdrawalDestinationLockInput, WithdrawalDestinationOutput,
+    },
+    internal::{api::state::AppApiState, workflows::withdrawal_destination as workflow},
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/{id}",
+            with_permission_check_get_with(
+                detail,
+                AdminGuard,
+                PermissionMode::Any,
+                [
+                    Permission::WithdrawalDestinationRead.as_str(),
+                    Permission::WithdrawalDestinationManage.as_str(),
+                ],
+                |op| {
+                    op.summary("Get a user's withdrawal destination")
+                        .tag("Admin Withdrawal Destinations")
+                },
+            ),
+        )
+        .api_route(
+            "/{id}/lock",
+            with_permission_check_post_with(
+                lock,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::WithdrawalDestinationManage.as_str()],
+                |op| {
+                    op.summary("Lock a withdrawal destination")
+                        .tag("Admin Withdrawal Destinations")
+                },
+            ),
+        )
+        .api_route(
+            "/{id}/unlock",
+            with_permission_check_post_with(
+                unlock,
+                AdminGuard,
+                PermissionMode::Any,
+                [Permission::WithdrawalDestinationManage.as_str()],
+                |op| {
+                    op.summary("Unlock a withdrawal destination")
+                        .tag("Admin Withdrawal Destinations")
+                },
+            ),
+        )
+        .with_state(state)
+}
+
+fn to_output(destination: UserWithdrawalDestinationRecord) -> WithdrawalDestinationOutput {
+    WithdrawalDestinationOutput {
+        id: destination.id.into(),
+        user_id: destination.user_id.into(),
+        username: destination.user.as_ref().map(|u| u.username.clone()),
+        kind: destination.kind,
+        bank_id: destination.bank_id.map(Into::into),
+        bank_name: destination.bank.as_ref().map(|b| b.name.clone()),
+        bank_account_name: destination.bank_account_name,
+        bank_account_number: destination.bank_account_number,
+        crypto_network_id: destination.crypto_network_id.map(Into::into),
+        crypto_network_name: destination.crypto_network.as_ref().map(|n| n.name.clone()),
+        crypto_wallet_address: destination.crypto_wallet_address,
+        status: destination.status,
+        available_at: destination.available_at,
+        lock_reason: destination.lock_reason,
+        locked_by: destination.locked_by.map(Into::into),
+        locked_by_username: destination.locker.as_ref().map(|a| a.username.clone()),
+        locked_at: destination.locked_at,
+        created_at: destination.created_at,
+    }
+}
+
+async fn detail(
+    State(state): State<AppApiState>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<WithdrawalDestinationOutput>, AppError> {
+    let destination = workflow::detail(&state, id).await?;
+
+    Ok(ApiResponse::success(
+        to_output(destination),
+        &t("Withdrawal destination loaded"),
+    ))
+}
+
+async fn lock(
+    State(state): State<AppApiState>,
+    auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+    ContractJson(req): ContractJson<AdminWithdrawalDestinationLockInput>,
+) -> Result<ApiResponse<WithdrawalDestinationOutput>, AppError> {
+    let destination = workflow::lock(&state, auth.user.id, id, req.reason).await?;
+
+    Ok(ApiResponse::success(
+        to_output(destination),
+        &t("Withdrawal destination locked"),
+    ))
+}
+
+async fn unlock(
+    State(state): State<AppApiState>,
+    _auth: AuthUser<AdminGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<WithdrawalDestinationOutput>, AppError> {
+    let destination = workflow::unlock(&state, id).await?;
+
+    Ok(ApiResponse::success(
+        to_output(destination),
+        &t("Withdrawal destination unlocked"),
+    ))
+}
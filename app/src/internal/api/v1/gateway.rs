@@ -0,0 +1,97 @@
+//! Public endpoints payment gateways call. They carry no user session; each
+//! callback is authenticated by the provider's signature instead.
+//!
+//! Callbacks write their own `webhook_log` entries, so these paths stay
+//! outside `HTTP_LOG_WEBHOOK_PATHS` to avoid logging them twice.
+
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::HeaderMap,
+};
+use core_i18n::t;
+use core_web::{
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{aide::axum::routing::post_with, ApiRouter},
+    response::ApiResponse,
+};
+
+use crate::{
+    contracts::api::v1::gateway::{FakeCheckoutInput, GatewayCallbackOutput},
+    internal::{
+        api::state::AppApiState,
+        payment_gateway::{fake, GatewayPaymentStatus},
+        workflows::gateway_callback::{self as workflow, CallbackOutcome, CallbackRequest},
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    let mut router = ApiRouter::new().api_route(
+        "/callbacks/{provider}",
+        post_with(callback, |op| {
+            op.summary("Payment gateway callback").tag("Payment Gateway")
+        }),
+    );
+
+    if state.payment_gateway_config.provider == fake::CODE {
+        router = router.api_route(
+            "/fake/checkout/{session_id}",
+            post_with(fake_checkout, |op| {
+                op.summary("Complete a checkout with the fake payment gateway")
+                    .tag("Payment Gateway")
+            }),
+        );
+    }
+
+    router.with_state(state)
+}
+
+fn callback_url(provider: &str) -> String {
+    format!("/api/v1/gateway/callbacks/{provider}")
+}
+
+fn output(outcome: CallbackOutcome) -> ApiResponse<GatewayCallbackOutput> {
+    ApiResponse::success(
+        GatewayCallbackOutput {
+            outcome: outcome.describe(),
+        },
+        &t("Callback received"),
+    )
+}
+
+async fn callback(
+    State(state): State<AppApiState>,
+    Path(provider): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<ApiResponse<GatewayCallbackOutput>, AppError> {
+    let outcome = workflow::handle(
+        &state,
+        &provider,
+        CallbackRequest {
+            url: callback_url(&provider),
+            headers: &headers,
+            body: &body,
+        },
+    )
+    .await?;
+
+    Ok(output(outcome))
+}
+
+async fn fake_checkout(
+    State(state): State<AppApiState>,
+    Path(session_id): Path<String>,
+    ContractJson(req): ContractJson<FakeCheckoutInput>,
+) -> Result<ApiResponse<GatewayCallbackOutput>, AppError> {
+    let status = if req.paid {
+        GatewayPaymentStatus::Paid
+    } else {
+        GatewayPaymentStatus::Failed
+    };
+    let outcome =
+        workflow::fake_checkout(&state, callback_url(fake::CODE), &session_id, status).await?;
+
+    Ok(output(outcome))
+}
@@ -3,10 +3,13 @@ use core_web::openapi::ApiRouter;
 use crate::internal::api::state::AppApiState;
 
 mod admin;
+mod gateway;
+mod receipt_upload;
 mod user;
 
 pub fn router(state: AppApiState) -> ApiRouter {
     ApiRouter::new()
         .nest("/user", user::router(state.clone()))
-        .nest("/admin", admin::router(state))
+        .nest("/admin", admin::router(state.clone()))
+        .nest("/gateway", gateway::router(state))
 }
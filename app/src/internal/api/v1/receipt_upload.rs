@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use axum::{body::Bytes, extract::Multipart};
+use core_db::platform::attachments::types::AttachmentInput;
+use core_i18n::t;
+use core_web::error::AppError;
+use generated::models::DepositStatus;
+use uuid::Uuid;
+
+use crate::internal::api::state::AppApiState;
+
+pub const MAX_RECEIPT_BYTES: usize = 5 * 1024 * 1024;
+
+/// A file read from a multipart body: its name, content type and bytes.
+pub type ReceiptFile = (Option<String>, String, Bytes);
+
+/// Reads the receipt file, if one was sent, and the listed text fields.
+/// Other fields are ignored.
+pub async fn parse_receipt_form(
+    mut multipart: Multipart,
+    text_fields: &[&str],
+) -> Result<(HashMap<String, String>, Option<ReceiptFile>), AppError> {
+    let mut fields = HashMap::new();
+    let mut receipt: Option<ReceiptFile> = None;
+
+    while let Some(field) = multipart.next_field().await.map_err(AppError::from)? {
+        let field_name = field
+            .name()
+            .map(str::to_string)
+            .ok_or_else(|| AppError::BadRequest(t("Invalid multipart field")))?;
+
+        match field_name.as_str() {
+            "file" | "fileUpload" | "receipt" => {
+                if receipt.is_some() {
+                    return Err(AppError::BadRequest(t("Invalid multipart field")));
+                }
+                if field.file_name().is_none() {
+                    return Err(AppError::BadRequest(t("Invalid upload file field")));
+                }
+                let file_name = field.file_name().map(ToString::to_string);
+                let content_type = field
+                    .content_type()
+                    .map(ToString::to_string)
+                    .unwrap_or_else(|| "application/octet-stream".to_string());
+                let data = field.bytes().await.map_err(AppError::from)?;
+                if data.is_empty() {
+                    return Err(AppError::BadRequest(t("Uploaded file is empty")));
+                }
+                if data.len() > MAX_RECEIPT_BYTES {
+                    return Err(AppError::BadRequest(t("File exceeds maximum allowed size")));
+                }
+                receipt = Some((file_name, content_type, data));
+            }
+            name if text_fields.contains(&name) => {
+                fields.insert(field_name, field.text().await.map_err(AppError::from)?);
+            }
+            _ => {}
+        }
+    }
+
+    Ok((fields, receipt))
+}
+
+pub async fn parse_receipt_multipart(multipart: Multipart) -> Result<ReceiptFile, AppError> {
+    parse_receipt_form(multipart, &[])
+        .await?
+        .1
+        .ok_or_else(|| AppError::BadRequest(t("Missing required field: file")))
+}
+
+pub fn validate_attachment_allowed(
+    allowed: &[String],
+    filename: Option<&str>,
+    content_type: &str,
+) -> Result<(), AppError> {
+    if allowed.is_empty() {
+        return Ok(());
+    }
+    let ct = content_type.to_ascii_lowercase();
+    for rule in allowed {
+        let rule = rule.to_ascii_lowercase();
+        if rule == "*" || rule == "*/*" || ct == rule {
+            return Ok(());
+        }
+        if rule.starts_with('.') {
+            if let Some(name) = filename.map(|n| n.to_ascii_lowercase()) {
+                if name.ends_with(&rule) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+    Err(AppError::BadRequest(t("Attachment type not allowed")))
+}
+
+pub fn build_attachment_url(path: &str, base: Option<&str>) -> String {
+    let raw = path.trim();
+    if raw.is_empty() {
+        return String::new();
+    }
+    if raw.starts_with("//")
+        || raw.starts_with("http://")
+        || raw.starts_with("https://")
+        || raw.starts_with("data:")
+        || raw.starts_with("blob:")
+    {
+        return raw.to_string();
+    }
+    let Some(base) = base.map(str::trim).filter(|v| !v.is_empty()) else {
+        return raw.to_string();
+    };
+    format!(
+        "{}/{}",
+        base.trim_end_matches('/'),
+        raw.trim_start_matches('/')
+    )
+}
+
+/// Parses an image from the multipart body and stores it under
+/// `{owner}/YYYY/MM/DD/{field}/`. The caller records where it went.
+pub async fn store_image_upload(
+    state: &AppApiState,
+    multipart: Multipart,
+    owner: &str,
+    field: &str,
+) -> Result<AttachmentInput, AppError> {
+    let file = parse_receipt_multipart(multipart).await?;
+    store_image(state, file, owner, field).await
+}
+
+/// Stores an already parsed image the same way as `store_image_upload`.
+pub async fn store_image(
+    state: &AppApiState,
+    (file_name, content_type, bytes): ReceiptFile,
+    owner: &str,
+    field: &str,
+) -> Result<AttachmentInput, AppError> {
+    let rules = generated::get_attachment_rules("image")
+        .ok_or_else(|| AppError::BadRequest(t("Unknown attachment type: image")))?;
+    validate_attachment_allowed(&rules.allowed, file_name.as_deref(), &content_type)?;
+
+    let ext = file_name
+        .as_deref()
+        .and_then(|name| {
+            std::path::Path::new(name)
+                .extension()
+                .and_then(|e| e.to_str())
+        })
+        .unwrap_or_else(|| content_type.split('/').nth(1).unwrap_or("bin"));
+
+    let now = time::OffsetDateTime::now_utc();
+    let object_key = format!(
+        "{}/{:04}/{:02}/{:02}/{}/{}.{}",
+        owner,
+        now.year(),
+        now.month() as u8,
+        now.day(),
+        field,
+        Uuid::new_v4(),
+        ext
+    );
+
+    state
+        .storage
+        .put(&object_key, bytes.clone(), &content_type)
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(AttachmentInput::new(object_key, content_type, bytes.len() as i64, None, None))
+}
+
+/// Parses a receipt image from the multipart body, stores it and records the
+/// location on the deposit's `params`, keeping the other keys there. Callers
+/// check ownership; the update only matches while the deposit is pending.
+pub async fn store_deposit_receipt(
+    state: &AppApiState,
+    deposit_id: i64,
+    multipart: Multipart,
+) -> Result<serde_json::Value, AppError> {
+    let upload = store_image_upload(state, multipart, "deposits", "receipt").await?;
+    let params = deposit_receipt_params(&upload);
+    let updated = sqlx::query(
+        "UPDATE deposits SET params = COALESCE(params, '{}'::jsonb) || $1, updated_at = $2
+         WHERE id = $3 AND status = $4",
+    )
+    .bind(&params)
+    .bind(time::OffsetDateTime::now_utc())
+    .bind(deposit_id)
+    .bind(DepositStatus::Pending)
+    .execute(&state.db)
+    .await
+    .map_err(AppError::from)?
+    .rows_affected();
+    if updated == 0 {
+        return Err(AppError::Conflict(t("Deposit is not pending")));
+    }
+
+    Ok(params)
+}
+
+/// The `params` keys a deposit receipt is recorded under.
+pub fn deposit_receipt_params(upload: &AttachmentInput) -> serde_json::Value {
+    let base_url = std::env::var("S3_URL").ok();
+    let receipt_url = build_attachment_url(&upload.path, base_url.as_deref());
+    serde_json::json!({ "receipt_url": receipt_url, "receipt_path": upload.path })
+}
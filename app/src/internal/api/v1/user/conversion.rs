@@ -0,0 +1,98 @@
+use axum::{extract::State, middleware::from_fn_with_state};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{
+        aide::axum::routing::{get_with, post_with},
+        ApiRouter,
+    },
+    response::ApiResponse,
+};
+use generated::guards::UserGuard;
+
+use crate::{
+    contracts::api::v1::user::conversion::{
+        UserConversionInput, UserConversionOutput, UserConversionRate, UserConversionRatesOutput,
+    },
+    internal::{
+        api::state::AppApiState, middleware::idempotency,
+        workflows::credit_conversion as workflow,
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/rates",
+            get_with(rates, |op| {
+                op.summary("List available conversion rates").tag("User Conversions")
+            }),
+        )
+        .api_route(
+            "/",
+            post_with(convert, |op| {
+                op.summary("Convert credit between types").tag("User Conversions")
+            }),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::user_idempotency,
+        ))
+        .with_state(state)
+}
+
+async fn rates(
+    State(state): State<AppApiState>,
+    _auth: AuthUser<UserGuard>,
+) -> Result<ApiResponse<UserConversionRatesOutput>, AppError> {
+    let rates = workflow::list_rates(&state, true).await?;
+
+    Ok(ApiResponse::success(
+        UserConversionRatesOutput {
+            rates: rates
+                .into_iter()
+                .map(|rate| UserConversionRate {
+                    from_credit_type: rate.from_credit_type,
+                    to_credit_type: rate.to_credit_type,
+                    rate: rate.rate,
+                    fee_percentage: rate.fee_percentage,
+                    fee_fixed: rate.fee_fixed,
+                    min_amount: rate.min_amount,
+                })
+                .collect(),
+        },
+        &t("Conversion rates loaded"),
+    ))
+}
+
+async fn convert(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    ContractJson(req): ContractJson<UserConversionInput>,
+) -> Result<ApiResponse<UserConversionOutput>, AppError> {
+    let result = workflow::convert(
+        &state,
+        auth.user.id,
+        None,
+        req.from_credit_type,
+        req.to_credit_type,
+        req.amount,
+        None,
+    )
+    .await?;
+
+    Ok(ApiResponse::success(
+        UserConversionOutput {
+            related_key: result.related_key,
+            from_credit_type: result.from_credit_type,
+            to_credit_type: result.to_credit_type,
+            amount: result.amount,
+            fee: result.fee,
+            rate: result.rate,
+            converted_amount: result.converted_amount,
+        },
+        &t("Credit converted"),
+    ))
+}
@@ -0,0 +1,299 @@
+use axum::{
+    extract::{Multipart, Path, Query, State},
+    middleware::from_fn_with_state,
+};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    contracts::ContractJson,
+    error::AppError,
+    extract::validation::transform_validation_errors,
+    openapi::{
+        aide::axum::routing::{get_with, post_with},
+        ApiRouter,
+    },
+    response::ApiResponse,
+};
+use generated::{
+    guards::UserGuard,
+    models::{CreditType, DepositRecord, DepositStatus},
+};
+use validator::Validate;
+
+use crate::{
+    contracts::api::v1::user::deposit::{
+        UserDepositBankTarget, UserDepositCreateInput, UserDepositCryptoTarget,
+        UserDepositListOutput, UserDepositListQuery, UserDepositOutput, UserDepositTargetsOutput,
+        UserGatewayDepositInput,
+    },
+    internal::{
+        api::{
+            state::AppApiState,
+            v1::receipt_upload::{
+                deposit_receipt_params, parse_receipt_form, store_deposit_receipt, store_image,
+                ReceiptFile,
+            },
+        },
+        middleware::idempotency,
+        workflows::user_deposit as workflow,
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/targets",
+            get_with(targets, |op| {
+                op.summary("List deposit targets").tag("User Deposits")
+            }),
+        )
+        .api_route(
+            "/",
+            get_with(list, |op| op.summary("List own deposits").tag("User Deposits")),
+        )
+        .api_route(
+            "/",
+            post_with(create, |op| {
+                op.summary("Submit deposit, optionally with its receipt").tag("User Deposits")
+            }),
+        )
+        .api_route(
+            "/gateway",
+            post_with(create_gateway, |op| {
+                op.summary("Start a payment gateway deposit").tag("User Deposits")
+            }),
+        )
+        .api_route(
+            "/{id}",
+            get_with(detail, |op| op.summary("Get own deposit").tag("User Deposits")),
+        )
+        .api_route(
+            "/{id}/upload-receipt",
+            post_with(upload_receipt, |op| {
+                op.summary("Upload deposit receipt").tag("User Deposits")
+            }),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::user_idempotency,
+        ))
+        .with_state(state)
+}
+
+fn deposit_output(deposit: &DepositRecord) -> UserDepositOutput {
+    UserDepositOutput {
+        id: deposit.id.into(),
+        credit_type: deposit.credit_type,
+        deposit_method: deposit.deposit_method,
+        company_bank_account_id: deposit.company_bank_account_id.map(Into::into),
+        company_crypto_account_id: deposit.company_crypto_account_id.map(Into::into),
+        conversion_rate: deposit.conversion_rate,
+        status: deposit.status,
+        amount: deposit.amount,
+        fee: deposit.fee,
+        net_amount: deposit.net_amount,
+        reference_code: deposit.reference_code.clone(),
+        checkout_url: deposit
+            .gateway_checkout_url
+            .clone()
+            .filter(|_| deposit.status == DepositStatus::Pending),
+        receipt_url: deposit
+            .params
+            .as_ref()
+            .and_then(|p| p.get("receipt_url"))
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string),
+        remark: deposit.remark.clone(),
+        admin_remark: deposit.admin_remark.clone(),
+        reviewed_at: deposit.reviewed_at,
+        reversal_reason: deposit.reversal_reason.clone(),
+        reversed_at: deposit.reversed_at,
+        created_at: deposit.created_at,
+    }
+}
+
+async fn targets(
+    State(state): State<AppApiState>,
+    _auth: AuthUser<UserGuard>,
+) -> Result<ApiResponse<UserDepositTargetsOutput>, AppError> {
+    let targets = workflow::list_targets(&state).await?;
+
+    let bank_accounts = targets
+        .bank_accounts
+        .into_iter()
+        .map(|a| UserDepositBankTarget {
+            id: a.id.into(),
+            bank_id: a.bank_id.into(),
+            bank_name: a.bank.as_ref().map(|b| b.name.clone()),
+            bank_logo_url: a.bank.as_ref().and_then(|b| b.logo_url.clone()),
+            account_name: a.account_name,
+            account_number: a.account_number,
+        })
+        .collect();
+
+    let crypto_accounts = targets
+        .crypto_accounts
+        .into_iter()
+        .map(|a| UserDepositCryptoTarget {
+            id: a.id.into(),
+            crypto_network_id: a.crypto_network_id.into(),
+            crypto_network_name: a.crypto_network.as_ref().map(|n| n.name.clone()),
+            crypto_network_symbol: a.crypto_network.as_ref().map(|n| n.symbol.clone()),
+            crypto_network_logo_url: a.crypto_network.as_ref().and_then(|n| n.logo_url.clone()),
+            wallet_address: a.wallet_address,
+            conversion_rate: a.conversion_rate,
+        })
+        .collect();
+
+    Ok(ApiResponse::success(
+        UserDepositTargetsOutput {
+            bank_accounts,
+            crypto_accounts,
+        },
+        &t("Deposit targets loaded"),
+    ))
+}
+
+/// Multipart fields: `credit_type`, `amount`, one of `company_bank_account_id`
+/// or `company_crypto_account_id`, `remark`, and optionally the `receipt`.
+async fn create(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    multipart: Multipart,
+) -> Result<ApiResponse<UserDepositOutput>, AppError> {
+    let (req, receipt) = parse_create_multipart(multipart).await?;
+    let receipt_params = match receipt {
+        Some(file) => {
+            let upload = store_image(&state, file, "deposits", "receipt").await?;
+            Some(deposit_receipt_params(&upload))
+        }
+        None => None,
+    };
+    let deposit = workflow::create(&state, auth.user.id, req, receipt_params).await?;
+
+    Ok(ApiResponse::success(
+        deposit_output(&deposit),
+        &t("Deposit submitted"),
+    ))
+}
+
+async fn parse_create_multipart(
+    multipart: Multipart,
+) -> Result<(UserDepositCreateInput, Option<ReceiptFile>), AppError> {
+    let (mut fields, receipt) = parse_receipt_form(
+        multipart,
+        &[
+            "credit_type",
+            "amount",
+            "company_bank_account_id",
+            "company_crypto_account_id",
+            "remark",
+        ],
+    )
+    .await?;
+    let mut take = |name: &str| {
+        fields
+            .remove(name)
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    let credit_type = take("credit_type")
+        .ok_or_else(|| AppError::BadRequest(t("Missing required field: credit_type")))?;
+    let credit_type = CreditType::from_storage(&credit_type)
+        .ok_or_else(|| AppError::BadRequest(t("Invalid credit type")))?;
+    let amount = take("amount")
+        .ok_or_else(|| AppError::BadRequest(t("Missing required field: amount")))?
+        .parse::<rust_decimal::Decimal>()
+        .map_err(|_| AppError::BadRequest(t("Invalid amount")))?;
+    let company_bank_account_id = match take("company_bank_account_id") {
+        Some(v) => Some(
+            v.parse::<i64>()
+                .map_err(|_| AppError::BadRequest(t("Invalid company bank account")))?
+                .into(),
+        ),
+        None => None,
+    };
+    let company_crypto_account_id = match take("company_crypto_account_id") {
+        Some(v) => Some(
+            v.parse::<i64>()
+                .map_err(|_| AppError::BadRequest(t("Invalid company crypto account")))?
+                .into(),
+        ),
+        None => None,
+    };
+    let remark = take("remark");
+
+    let input = UserDepositCreateInput {
+        credit_type,
+        amount,
+        company_bank_account_id,
+        company_crypto_account_id,
+        remark,
+    };
+    if let Err(e) = input.validate() {
+        return Err(AppError::Validation {
+            message: t("Validation failed"),
+            errors: transform_validation_errors(e),
+        });
+    }
+
+    Ok((input, receipt))
+}
+
+async fn create_gateway(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    ContractJson(req): ContractJson<UserGatewayDepositInput>,
+) -> Result<ApiResponse<UserDepositOutput>, AppError> {
+    let deposit = workflow::create_gateway(&state, auth.user.id, req).await?;
+
+    Ok(ApiResponse::success(
+        deposit_output(&deposit),
+        &t("Continue to the payment page to complete your deposit"),
+    ))
+}
+
+async fn list(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    Query(query): Query<UserDepositListQuery>,
+) -> Result<ApiResponse<UserDepositListOutput>, AppError> {
+    let page = workflow::list(&state, auth.user.id, query.page, query.per_page).await?;
+
+    Ok(ApiResponse::success(
+        UserDepositListOutput {
+            data: page.data.iter().map(deposit_output).collect(),
+            total: page.total,
+            per_page: page.per_page,
+            current_page: page.current_page,
+            last_page: page.last_page,
+        },
+        &t("Deposits loaded"),
+    ))
+}
+
+async fn detail(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<UserDepositOutput>, AppError> {
+    let deposit = workflow::detail(&state, auth.user.id, id).await?;
+
+    Ok(ApiResponse::success(
+        deposit_output(&deposit),
+        &t("Deposit detail"),
+    ))
+}
+
+async fn upload_receipt(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    Path(id): Path<i64>,
+    multipart: Multipart,
+) -> Result<ApiResponse<serde_json::Value>, AppError> {
+    workflow::detail(&state, auth.user.id, id).await?;
+    let params = store_deposit_receipt(&state, id, multipart).await?;
+
+    Ok(ApiResponse::success(params, &t("Receipt uploaded")))
+}
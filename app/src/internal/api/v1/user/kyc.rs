@@ -0,0 +1,123 @@
+use axum::{
+    extract::{Multipart, Path, State},
+    middleware::from_fn_with_state,
+};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{
+        aide::axum::routing::{get_with, post_with},
+        ApiRouter,
+    },
+    response::ApiResponse,
+};
+use generated::{
+    guards::UserGuard,
+    models::{KycStatus, KycSubmissionRecord},
+};
+
+use crate::{
+    contracts::api::v1::user::kyc::{UserKycOutput, UserKycSubmissionOutput, UserKycSubmitInput},
+    internal::{
+        api::{state::AppApiState, v1::receipt_upload::store_image_upload},
+        middleware::idempotency,
+        workflows::kyc as workflow,
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/",
+            get_with(status, |op| {
+                op.summary("Own KYC level and latest submission").tag("User KYC")
+            }),
+        )
+        .api_route(
+            "/",
+            post_with(submit, |op| op.summary("Apply for a KYC level").tag("User KYC")),
+        )
+        .api_route(
+            "/{id}/documents/{field}",
+            post_with(upload_document, |op| {
+                op.summary("Upload a KYC document image").tag("User KYC")
+            }),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::user_idempotency,
+        ))
+        .with_state(state)
+}
+
+fn submission_output(submission: &KycSubmissionRecord) -> UserKycSubmissionOutput {
+    UserKycSubmissionOutput {
+        id: submission.id.into(),
+        level: submission.level,
+        status: submission.status,
+        full_name: submission.full_name.clone(),
+        document_type: submission.document_type,
+        document_number: submission.document_number.clone(),
+        reject_reason: submission.reject_reason.clone(),
+        id_front_url: submission.id_front_url.clone(),
+        id_back_url: submission.id_back_url.clone(),
+        selfie_url: submission.selfie_url.clone(),
+        proof_of_address_url: submission.proof_of_address_url.clone(),
+        missing_documents: workflow::missing_documents(submission),
+        reviewed_at: submission.reviewed_at,
+        created_at: submission.created_at,
+    }
+}
+
+async fn status(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+) -> Result<ApiResponse<UserKycOutput>, AppError> {
+    let (kyc_level, submission) = workflow::status(&state, auth.user.id).await?;
+
+    Ok(ApiResponse::success(
+        UserKycOutput {
+            kyc_level,
+            submission: submission.as_ref().map(submission_output),
+        },
+        &t("KYC status loaded"),
+    ))
+}
+
+async fn submit(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    ContractJson(req): ContractJson<UserKycSubmitInput>,
+) -> Result<ApiResponse<UserKycSubmissionOutput>, AppError> {
+    let submission = workflow::submit(&state, auth.user.id, req).await?;
+
+    Ok(ApiResponse::success(
+        submission_output(&submission),
+        &t("KYC submission created. Upload your documents to complete it"),
+    ))
+}
+
+/// `field` is one of `id_front`, `id_back`, `selfie` or `proof_of_address`;
+/// the image goes in the `file` multipart field.
+async fn upload_document(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    Path((id, field)): Path<(i64, String)>,
+    multipart: Multipart,
+) -> Result<ApiResponse<UserKycSubmissionOutput>, AppError> {
+    let field = workflow::document_field(&field)?;
+    let submission = workflow::find_own(&state, auth.user.id, id).await?;
+    if submission.status != KycStatus::Pending {
+        return Err(AppError::BadRequest(t("KYC submission is not pending")));
+    }
+
+    let upload = store_image_upload(&state, multipart, "kyc", field).await?;
+    let submission = workflow::attach_document(&state, &submission, field, upload).await?;
+
+    Ok(ApiResponse::success(
+        submission_output(&submission),
+        &t("Document uploaded"),
+    ))
+}
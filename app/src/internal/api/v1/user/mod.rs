@@ -4,7 +4,13 @@ use core_web::openapi::{aide::axum::routing::get_with, ApiRouter};
 use crate::internal::api::state::AppApiState;
 
 mod auth;
+mod conversion;
+mod deposit;
+mod kyc;
 mod team;
+mod transfer;
+mod withdrawal;
+mod withdrawal_destination;
 
 pub fn router(state: AppApiState) -> ApiRouter {
     ApiRouter::new()
@@ -21,6 +27,12 @@ fn guarded_router(state: AppApiState) -> ApiRouter {
             }),
         )
         .nest("/team", team::router(state.clone()))
+        .nest("/kyc", kyc::router(state.clone()))
+        .nest("/deposits", deposit::router(state.clone()))
+        .nest("/withdrawals", withdrawal::router(state.clone()))
+        .nest("/withdrawal_destinations", withdrawal_destination::router(state.clone()))
+        .nest("/transfers", transfer::router(state.clone()))
+        .nest("/conversions", conversion::router(state.clone()))
         .layer(from_fn_with_state(
             state,
             crate::internal::middleware::auth::require_user,
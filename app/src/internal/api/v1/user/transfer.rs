@@ -0,0 +1,53 @@
+use axum::{extract::State, middleware::from_fn_with_state};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{aide::axum::routing::post_with, ApiRouter},
+    response::ApiResponse,
+};
+use generated::guards::UserGuard;
+
+use crate::{
+    contracts::api::v1::user::transfer::{UserTransferInput, UserTransferOutput},
+    internal::{
+        api::state::AppApiState, middleware::idempotency,
+        workflows::user_transfer as workflow,
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/",
+            post_with(transfer, |op| {
+                op.summary("Transfer credit to another user").tag("User Transfers")
+            }),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::user_idempotency,
+        ))
+        .with_state(state)
+}
+
+async fn transfer(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    ContractJson(req): ContractJson<UserTransferInput>,
+) -> Result<ApiResponse<UserTransferOutput>, AppError> {
+    let result = workflow::transfer(&state, auth.user.id, req).await?;
+
+    Ok(ApiResponse::success(
+        UserTransferOutput {
+            related_key: result.related_key,
+            to_username: result.to_username,
+            credit_type: result.credit_type,
+            amount: result.amount,
+            balance: result.balance,
+            created_at: result.created_at,
+        },
+        &t("Transfer completed"),
+    ))
+}
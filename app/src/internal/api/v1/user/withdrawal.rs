@@ -0,0 +1,123 @@
+use axum::{
+    extract::{Path, Query, State},
+    middleware::from_fn_with_state,
+};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{
+        aide::axum::routing::{get_with, post_with},
+        ApiRouter,
+    },
+    response::ApiResponse,
+};
+use generated::{guards::UserGuard, models::WithdrawalRecord};
+
+use crate::{
+    contracts::api::v1::user::withdrawal::{
+        UserWithdrawalCreateInput, UserWithdrawalListOutput, UserWithdrawalListQuery,
+        UserWithdrawalOutput,
+    },
+    internal::{
+        api::state::AppApiState, middleware::idempotency,
+        workflows::user_withdrawal as workflow,
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/",
+            get_with(list, |op| {
+                op.summary("List own withdrawals").tag("User Withdrawals")
+            }),
+        )
+        .api_route(
+            "/",
+            post_with(create, |op| {
+                op.summary("Request withdrawal").tag("User Withdrawals")
+            }),
+        )
+        .api_route(
+            "/{id}",
+            get_with(detail, |op| {
+                op.summary("Get own withdrawal").tag("User Withdrawals")
+            }),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::user_idempotency,
+        ))
+        .with_state(state)
+}
+
+fn withdrawal_output(withdrawal: &WithdrawalRecord) -> UserWithdrawalOutput {
+    UserWithdrawalOutput {
+        id: withdrawal.id.into(),
+        credit_type: withdrawal.credit_type,
+        withdrawal_method: withdrawal.withdrawal_method,
+        bank_id: withdrawal.bank_id.map(Into::into),
+        bank_account_name: withdrawal.bank_account_name.clone(),
+        bank_account_number: withdrawal.bank_account_number.clone(),
+        crypto_network_id: withdrawal.crypto_network_id.map(Into::into),
+        crypto_wallet_address: withdrawal.crypto_wallet_address.clone(),
+        destination_id: withdrawal.destination_id.map(Into::into),
+        status: withdrawal.status,
+        amount: withdrawal.amount,
+        fee: withdrawal.fee,
+        net_amount: withdrawal.net_amount,
+        remark: withdrawal.remark.clone(),
+        admin_remark: withdrawal.admin_remark.clone(),
+        reviewed_at: withdrawal.reviewed_at,
+        refund_reason: withdrawal.refund_reason.clone(),
+        refunded_at: withdrawal.refunded_at,
+        created_at: withdrawal.created_at,
+    }
+}
+
+async fn create(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    ContractJson(req): ContractJson<UserWithdrawalCreateInput>,
+) -> Result<ApiResponse<UserWithdrawalOutput>, AppError> {
+    let withdrawal = workflow::create(&state, auth.user.id, req).await?;
+
+    Ok(ApiResponse::success(
+        withdrawal_output(&withdrawal),
+        &t("Withdrawal submitted"),
+    ))
+}
+
+async fn list(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    Query(query): Query<UserWithdrawalListQuery>,
+) -> Result<ApiResponse<UserWithdrawalListOutput>, AppError> {
+    let page = workflow::list(&state, auth.user.id, query.page, query.per_page).await?;
+
+    Ok(ApiResponse::success(
+        UserWithdrawalListOutput {
+            data: page.data.iter().map(withdrawal_output).collect(),
+            total: page.total,
+            per_page: page.per_page,
+            current_page: page.current_page,
+            last_page: page.last_page,
+        },
+        &t("Withdrawals loaded"),
+    ))
+}
+
+async fn detail(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<UserWithdrawalOutput>, AppError> {
+    let withdrawal = workflow::detail(&state, auth.user.id, id).await?;
+
+    Ok(ApiResponse::success(
+        withdrawal_output(&withdrawal),
+        &t("Withdrawal detail"),
+    ))
+}
@@ -0,0 +1,116 @@
+use axum::{
+    extract::{Path, State},
+    middleware::from_fn_with_state,
+};
+use core_i18n::t;
+use core_web::{
+    auth::AuthUser,
+    contracts::ContractJson,
+    error::AppError,
+    openapi::{
+        aide::axum::routing::{delete_with, get_with, post_with},
+        ApiRouter,
+    },
+    response::ApiResponse,
+};
+use generated::{guards::UserGuard, models::UserWithdrawalDestinationRecord};
+
+use crate::{
+    contracts::api::v1::user::withdrawal_destination::{
+        UserWithdrawalDestinationInput, UserWithdrawalDestinationListOutput,
+        UserWithdrawalDestinationOutput,
+    },
+    internal::{
+        api::state::AppApiState, middleware::idempotency,
+        workflows::withdrawal_destination as workflow,
+    },
+};
+
+pub fn router(state: AppApiState) -> ApiRouter {
+    ApiRouter::new()
+        .api_route(
+            "/",
+            get_with(list, |op| {
+                op.summary("List saved withdrawal destinations")
+                    .tag("User Withdrawal Destinations")
+            }),
+        )
+        .api_route(
+            "/",
+            post_with(create, |op| {
+                op.summary("Save a bank account or crypto wallet")
+                    .tag("User Withdrawal Destinations")
+            }),
+        )
+        .api_route(
+            "/{id}",
+            delete_with(delete, |op| {
+                op.summary("Remove a saved withdrawal destination")
+                    .tag("User Withdrawal Destinations")
+            }),
+        )
+        .route_layer(from_fn_with_state(
+            state.clone(),
+            idempotency::user_idempotency,
+        ))
+        .with_state(state)
+}
+
+fn destination_output(
+    destination: &UserWithdrawalDestinationRecord,
+) -> UserWithdrawalDestinationOutput {
+    UserWithdrawalDestinationOutput {
+        id: destination.id.into(),
+        kind: destination.kind,
+        bank_id: destination.bank_id.map(Into::into),
+        bank_name: destination.bank.as_ref().map(|b| b.name.clone()),
+        bank_account_name: destination.bank_account_name.clone(),
+        bank_account_number: destination.bank_account_number.clone(),
+        crypto_network_id: destination.crypto_network_id.map(Into::into),
+        crypto_network_name: destination.crypto_network.as_ref().map(|n| n.name.clone()),
+        crypto_wallet_address: destination.crypto_wallet_address.clone(),
+        status: destination.status,
+        available_at: destination.available_at,
+        created_at: destination.created_at,
+    }
+}
+
+async fn list(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+) -> Result<ApiResponse<UserWithdrawalDestinationListOutput>, AppError> {
+    let destinations = workflow::list_own(&state, auth.user.id).await?;
+
+    Ok(ApiResponse::success(
+        UserWithdrawalDestinationListOutput {
+            data: destinations.iter().map(destination_output).collect(),
+        },
+        &t("Withdrawal destinations loaded"),
+    ))
+}
+
+async fn create(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    ContractJson(req): ContractJson<UserWithdrawalDestinationInput>,
+) -> Result<ApiResponse<UserWithdrawalDestinationOutput>, AppError> {
+    let destination = workflow::create(&state, auth.user.id, req).await?;
+
+    Ok(ApiResponse::success(
+        destination_output(&destination),
+        &t("Withdrawal destination saved"),
+    ))
+}
+
+async fn delete(
+    State(state): State<AppApiState>,
+    auth: AuthUser<UserGuard>,
+    Path(id): Path<i64>,
+) -> Result<ApiResponse<serde_json::Value>, AppError> {
+    workflow::delete_own(&state, auth.user.id, id).await?;
+
+    Ok(ApiResponse::success(
+        serde_json::json!({ "deleted": true }),
+        &t("Withdrawal destination removed"),
+    ))
+}
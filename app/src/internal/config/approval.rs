@@ -0,0 +1,104 @@
+use generated::models::{CreditType, FinancialApprovalAction};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// `[approval]` section of `configs.toml`: amounts above which a financial
+/// action needs a second admin to confirm it (maker-checker).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ApprovalConfig {
+    pub deposit: ApprovalThresholds,
+    pub withdrawal: ApprovalThresholds,
+    pub credit_adjust: ApprovalThresholds,
+}
+
+/// Per credit type threshold; a missing value never requires a second admin.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ApprovalThresholds {
+    pub credit_1: Option<Decimal>,
+    pub credit_2: Option<Decimal>,
+}
+
+impl ApprovalConfig {
+    pub fn thresholds(&self, action: FinancialApprovalAction) -> &ApprovalThresholds {
+        match action {
+            FinancialApprovalAction::DepositApprove => &self.deposit,
+            FinancialApprovalAction::WithdrawalApprove => &self.withdrawal,
+            FinancialApprovalAction::CreditAdjust => &self.credit_adjust,
+        }
+    }
+
+    /// Whether `amount` (a deduction counts by its size) is above the
+    /// configured threshold for the action and credit type.
+    pub fn requires_second_admin(
+        &self,
+        action: FinancialApprovalAction,
+        credit_type: CreditType,
+        amount: Decimal,
+    ) -> bool {
+        self.thresholds(action)
+            .get(credit_type)
+            .is_some_and(|threshold| amount.abs() > threshold)
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for (name, thresholds) in [
+            ("deposit", &self.deposit),
+            ("withdrawal", &self.withdrawal),
+            ("credit_adjust", &self.credit_adjust),
+        ] {
+            for (credit, threshold) in [
+                ("credit_1", thresholds.credit_1),
+                ("credit_2", thresholds.credit_2),
+            ] {
+                anyhow::ensure!(
+                    threshold.map_or(true, |value| value >= Decimal::ZERO),
+                    "[approval.{name}] {credit} must not be negative"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ApprovalThresholds {
+    pub fn get(&self, credit_type: CreditType) -> Option<Decimal> {
+        match credit_type {
+            CreditType::Credit1 => self.credit_1,
+            CreditType::Credit2 => self.credit_2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApprovalConfig, ApprovalThresholds};
+    use generated::models::{CreditType, FinancialApprovalAction};
+    use rust_decimal::Decimal;
+
+    fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn only_amounts_above_the_threshold_need_a_second_admin() {
+        let config = ApprovalConfig {
+            credit_adjust: ApprovalThresholds {
+                credit_1: Some(dec("1000")),
+                credit_2: None,
+            },
+            ..Default::default()
+        };
+        let action = FinancialApprovalAction::CreditAdjust;
+        assert!(!config.requires_second_admin(action, CreditType::Credit1, dec("1000")));
+        assert!(config.requires_second_admin(action, CreditType::Credit1, dec("1000.01")));
+        assert!(config.requires_second_admin(action, CreditType::Credit1, dec("-5000")));
+        assert!(!config.requires_second_admin(action, CreditType::Credit2, dec("5000")));
+        assert!(!config.requires_second_admin(
+            FinancialApprovalAction::DepositApprove,
+            CreditType::Credit1,
+            dec("5000"),
+        ));
+    }
+}
@@ -0,0 +1,47 @@
+use generated::models::CreditType;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// `[balance]` section of `configs.toml`: how far each credit type may be
+/// deducted. Every ledger debit is checked against it.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BalanceConfig {
+    pub credit_1: BalancePolicy,
+    pub credit_2: BalancePolicy,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BalancePolicy {
+    /// How far below zero a deduction may take the balance (0 = never negative)
+    pub overdraft_limit: Decimal,
+}
+
+impl BalanceConfig {
+    pub fn policy(&self, credit_type: CreditType) -> &BalancePolicy {
+        match credit_type {
+            CreditType::Credit1 => &self.credit_1,
+            CreditType::Credit2 => &self.credit_2,
+        }
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.credit_1.overdraft_limit >= Decimal::ZERO,
+            "[balance.credit_1] overdraft_limit must not be negative"
+        );
+        anyhow::ensure!(
+            self.credit_2.overdraft_limit >= Decimal::ZERO,
+            "[balance.credit_2] overdraft_limit must not be negative"
+        );
+        Ok(())
+    }
+}
+
+impl BalancePolicy {
+    /// Lowest balance a deduction may leave behind.
+    pub fn floor(&self) -> Decimal {
+        -self.overdraft_limit
+    }
+}
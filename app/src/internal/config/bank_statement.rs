@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// `[bank_statement]` section of `configs.toml`: how to read each bank's CSV
+/// export. `banks` is keyed by `Bank.code`; banks without their own entry use
+/// `default`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BankStatementConfig {
+    pub default: Option<StatementFormat>,
+    pub banks: HashMap<String, StatementFormat>,
+}
+
+/// Column mapping for one bank's statement. Columns are named by their header.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatementFormat {
+    #[serde(default = "default_delimiter")]
+    pub delimiter: char,
+    /// Lines before the header row, e.g. an account summary
+    #[serde(default)]
+    pub skip_lines: usize,
+    /// Free-text column the transfer memo ends up in
+    pub reference_column: String,
+    /// Credited amount; lines without one (debits) are skipped
+    pub amount_column: String,
+    /// Receiving account number, checked against the company account if set
+    #[serde(default)]
+    pub account_column: Option<String>,
+    #[serde(default)]
+    pub date_column: Option<String>,
+}
+
+fn default_delimiter() -> char {
+    ','
+}
+
+impl BankStatementConfig {
+    pub fn format_for(&self, bank_code: Option<&str>) -> Option<&StatementFormat> {
+        bank_code
+            .and_then(|code| self.banks.get(code))
+            .or(self.default.as_ref())
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let formats = self
+            .default
+            .iter()
+            .map(|format| ("default", format))
+            .chain(self.banks.iter().map(|(code, format)| (code.as_str(), format)));
+        for (name, format) in formats {
+            anyhow::ensure!(
+                !format.reference_column.trim().is_empty()
+                    && !format.amount_column.trim().is_empty(),
+                "[bank_statement.{name}] reference_column and amount_column are required"
+            );
+            let delimiter = format.delimiter;
+            anyhow::ensure!(
+                delimiter == '\t' || (delimiter != '"' && !delimiter.is_whitespace()),
+                "[bank_statement.{name}] delimiter must be a visible character or a tab"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BankStatementConfig;
+
+    #[test]
+    fn format_for_falls_back_to_default() {
+        let config: BankStatementConfig = toml::from_str(
+            r#"
+            [default]
+            reference_column = "Description"
+            amount_column = "Credit"
+
+            [banks.MBB]
+            delimiter = ";"
+            reference_column = "Remarks"
+            amount_column = "Deposit"
+            "#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+
+        assert_eq!(config.format_for(Some("MBB")).unwrap().reference_column, "Remarks");
+        assert_eq!(config.format_for(Some("MBB")).unwrap().delimiter, ';');
+        assert_eq!(config.format_for(Some("CIMB")).unwrap().reference_column, "Description");
+        assert_eq!(config.format_for(None).unwrap().delimiter, ',');
+    }
+}
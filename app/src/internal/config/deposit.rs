@@ -1,28 +1,26 @@
 use rust_decimal::Decimal;
+use serde::Deserialize;
 
-#[derive(Debug, Clone)]
-pub struct DepositFeeConfig {
-    /// Fee percentage (0.0 = no fee, 0.02 = 2%)
-    pub fee_percentage: Decimal,
-    /// Fixed fee amount added on top of percentage
-    pub fee_fixed: Decimal,
-}
+use super::fee::FeeSchedule;
 
-impl Default for DepositFeeConfig {
-    fn default() -> Self {
-        Self {
-            fee_percentage: Decimal::ZERO,
-            fee_fixed: Decimal::ZERO,
-        }
-    }
+/// `[deposit]` section of `configs.toml`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DepositFeeConfig {
+    #[serde(flatten)]
+    pub fee: FeeSchedule,
+    /// Minimum deposit amount
+    pub min_amount: Decimal,
 }
 
 impl DepositFeeConfig {
     /// Calculate the fee and net amount for a given deposit amount.
-    /// Returns (fee, net_amount).
-    pub fn calculate_fee(&self, amount: Decimal) -> (Decimal, Decimal) {
-        let fee = (amount * self.fee_percentage) + self.fee_fixed;
-        let net_amount = amount - fee;
-        (fee, net_amount)
+    /// Returns (fee, net_amount), or `None` when the fee exceeds the amount.
+    pub fn calculate_fee(&self, amount: Decimal) -> Option<(Decimal, Decimal)> {
+        self.fee.calculate(amount)
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.fee.validate("deposit", self.min_amount)
     }
 }
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+/// `[expiry]` section of `configs.toml`, read by the expiry job. An age of
+/// 0 turns that check off.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ExpiryConfig {
+    /// Pending deposits older than this become `Expired`
+    pub deposit_pending_hours: u64,
+    /// Pending withdrawals older than this become `Expired` and release their hold
+    pub withdrawal_pending_hours: u64,
+    /// Withdrawals processing longer than this are flagged as past the SLA
+    pub withdrawal_processing_sla_hours: u64,
+}
+
+/// Ten years; anything longer is almost certainly a typo.
+const MAX_HOURS: u64 = 24 * 365 * 10;
+
+impl ExpiryConfig {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for (key, hours) in [
+            ("deposit_pending_hours", self.deposit_pending_hours),
+            ("withdrawal_pending_hours", self.withdrawal_pending_hours),
+            ("withdrawal_processing_sla_hours", self.withdrawal_processing_sla_hours),
+        ] {
+            anyhow::ensure!(hours <= MAX_HOURS, "[expiry] {key} must not exceed {MAX_HOURS}");
+        }
+        Ok(())
+    }
+}
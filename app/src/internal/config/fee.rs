@@ -0,0 +1,170 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// Percentage bracket applied when the amount is at least `from_amount`.
+/// The bracket with the highest matching `from_amount` wins; it is a flat
+/// rate for the whole amount, not a marginal rate.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeeTier {
+    pub from_amount: Decimal,
+    pub fee_percentage: Decimal,
+}
+
+/// Shared fee schedule behind `DepositFeeConfig` and `WithdrawalFeeConfig`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct FeeSchedule {
+    /// Fee percentage (0.0 = no fee, 0.02 = 2%), used when no tier matches
+    pub fee_percentage: Decimal,
+    /// Fixed fee amount added on top of percentage
+    pub fee_fixed: Decimal,
+    /// Optional percentage brackets by amount
+    pub tiers: Vec<FeeTier>,
+    /// Lower bound applied to the computed fee
+    pub min_fee: Option<Decimal>,
+    /// Upper bound applied to the computed fee
+    pub max_fee: Option<Decimal>,
+}
+
+impl FeeSchedule {
+    pub fn percentage_for(&self, amount: Decimal) -> Decimal {
+        self.tiers
+            .iter()
+            .filter(|tier| amount >= tier.from_amount)
+            .max_by(|a, b| a.from_amount.cmp(&b.from_amount))
+            .map(|tier| tier.fee_percentage)
+            .unwrap_or(self.fee_percentage)
+    }
+
+    /// Returns `(fee, net_amount)`, or `None` when the fee would exceed the amount.
+    pub fn calculate(&self, amount: Decimal) -> Option<(Decimal, Decimal)> {
+        let mut fee = (amount * self.percentage_for(amount)) + self.fee_fixed;
+        if let Some(min_fee) = self.min_fee {
+            fee = fee.max(min_fee);
+        }
+        if let Some(max_fee) = self.max_fee {
+            fee = fee.min(max_fee);
+        }
+        let net_amount = amount - fee;
+        if net_amount < Decimal::ZERO {
+            return None;
+        }
+        Some((fee, net_amount))
+    }
+
+    /// Checks the schedule against the smallest amount it may be applied to.
+    pub fn validate(&self, section: &str, min_amount: Decimal) -> anyhow::Result<()> {
+        let non_negative = [
+            ("fee_percentage", Some(self.fee_percentage)),
+            ("fee_fixed", Some(self.fee_fixed)),
+            ("min_fee", self.min_fee),
+            ("max_fee", self.max_fee),
+            ("min_amount", Some(min_amount)),
+        ];
+        for (key, value) in non_negative {
+            if let Some(value) = value {
+                anyhow::ensure!(
+                    value >= Decimal::ZERO,
+                    "[{section}] {key} must not be negative"
+                );
+            }
+        }
+        anyhow::ensure!(
+            self.fee_percentage <= Decimal::ONE,
+            "[{section}] fee_percentage must not exceed 1"
+        );
+        for tier in &self.tiers {
+            anyhow::ensure!(
+                tier.from_amount >= Decimal::ZERO && tier.fee_percentage >= Decimal::ZERO,
+                "[{section}] tiers must not contain negative values"
+            );
+            anyhow::ensure!(
+                tier.fee_percentage <= Decimal::ONE,
+                "[{section}] tier fee_percentage must not exceed 1"
+            );
+        }
+        if let (Some(min_fee), Some(max_fee)) = (self.min_fee, self.max_fee) {
+            anyhow::ensure!(
+                min_fee <= max_fee,
+                "[{section}] min_fee must not exceed max_fee"
+            );
+        }
+        anyhow::ensure!(
+            self.fee_fixed <= min_amount,
+            "[{section}] fee_fixed must not exceed min_amount"
+        );
+        if let Some(min_fee) = self.min_fee {
+            anyhow::ensure!(
+                min_fee <= min_amount,
+                "[{section}] min_fee must not exceed min_amount"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FeeSchedule, FeeTier};
+    use rust_decimal::Decimal;
+
+    fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn calculate_uses_highest_matching_tier() {
+        let schedule = FeeSchedule {
+            fee_percentage: dec("0.03"),
+            tiers: vec![
+                FeeTier { from_amount: dec("1000"), fee_percentage: dec("0.01") },
+                FeeTier { from_amount: dec("100"), fee_percentage: dec("0.02") },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(schedule.calculate(dec("50")), Some((dec("1.50"), dec("48.50"))));
+        assert_eq!(schedule.calculate(dec("500")), Some((dec("10.00"), dec("490.00"))));
+        assert_eq!(schedule.calculate(dec("2000")), Some((dec("20.00"), dec("1980.00"))));
+    }
+
+    #[test]
+    fn calculate_applies_fee_caps() {
+        let schedule = FeeSchedule {
+            fee_percentage: dec("0.1"),
+            min_fee: Some(dec("2")),
+            max_fee: Some(dec("5")),
+            ..Default::default()
+        };
+        assert_eq!(schedule.calculate(dec("10")).map(|r| r.0), Some(dec("2")));
+        assert_eq!(schedule.calculate(dec("1000")).map(|r| r.0), Some(dec("5")));
+    }
+
+    #[test]
+    fn calculate_refuses_negative_net_amount() {
+        let schedule = FeeSchedule {
+            fee_fixed: dec("5"),
+            ..Default::default()
+        };
+        assert_eq!(schedule.calculate(dec("4")), None);
+        assert_eq!(schedule.calculate(dec("5")), Some((dec("5"), dec("0"))));
+    }
+
+    #[test]
+    fn validate_rejects_fixed_fee_above_minimum() {
+        let schedule = FeeSchedule {
+            fee_fixed: dec("5"),
+            ..Default::default()
+        };
+        assert!(schedule.validate("withdrawal", dec("1")).is_err());
+        assert!(schedule.validate("withdrawal", dec("10")).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_negative_values() {
+        let schedule = FeeSchedule {
+            fee_percentage: dec("-0.01"),
+            ..Default::default()
+        };
+        assert!(schedule.validate("deposit", Decimal::ZERO).is_err());
+    }
+}
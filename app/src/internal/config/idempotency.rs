@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+/// `[idempotency]` section of `configs.toml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IdempotencyConfig {
+    /// How long a completed response is replayed for the same key
+    pub ttl_secs: u64,
+    /// How long an unfinished request's key outlives its last renewal; a running
+    /// request renews it every half `lock_secs`
+    pub lock_secs: u64,
+}
+
+impl Default for IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: 86_400,
+            lock_secs: 60,
+        }
+    }
+}
+
+impl IdempotencyConfig {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(self.ttl_secs > 0, "[idempotency] ttl_secs must be positive");
+        anyhow::ensure!(self.lock_secs > 0, "[idempotency] lock_secs must be positive");
+        anyhow::ensure!(
+            self.lock_secs <= self.ttl_secs,
+            "[idempotency] lock_secs must not exceed ttl_secs"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use generated::models::{KycLevel, UserLimitKind};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// `[limits]` section of `configs.toml`: caps on user deposits and
+/// withdrawals. `countries` is keyed by the user's `country_iso2` and `kyc`
+/// by their KYC level; each only overrides the fields it sets, in that order.
+/// Admins can override the result per user.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LimitsConfig {
+    pub deposit: TransactionLimits,
+    pub withdrawal: TransactionLimits,
+    pub countries: HashMap<String, LayerLimits>,
+    pub kyc: KycLimits,
+}
+
+/// Deposit and withdrawal overrides of one layer.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LayerLimits {
+    pub deposit: TransactionLimits,
+    pub withdrawal: TransactionLimits,
+}
+
+impl LayerLimits {
+    fn get(&self, kind: UserLimitKind) -> &TransactionLimits {
+        match kind {
+            UserLimitKind::Deposit => &self.deposit,
+            UserLimitKind::Withdrawal => &self.withdrawal,
+        }
+    }
+
+    fn validate(&self, section: &str) -> anyhow::Result<()> {
+        self.deposit.validate(&format!("{section}.deposit"))?;
+        self.withdrawal.validate(&format!("{section}.withdrawal"))
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct KycLimits {
+    pub unverified: LayerLimits,
+    pub basic: LayerLimits,
+    pub advanced: LayerLimits,
+}
+
+impl KycLimits {
+    fn get(&self, level: KycLevel) -> &LayerLimits {
+        match level {
+            KycLevel::Unverified => &self.unverified,
+            KycLevel::Basic => &self.basic,
+            KycLevel::Advanced => &self.advanced,
+        }
+    }
+}
+
+/// One set of caps. An unset field inherits from the layer below; 0 means
+/// unlimited. Counts and amounts are summed over the calendar day, week
+/// (from Monday) and month in the app timezone, across credit types.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct TransactionLimits {
+    /// Per transaction
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub daily_count: Option<u32>,
+    pub daily_amount: Option<Decimal>,
+    pub weekly_count: Option<u32>,
+    pub weekly_amount: Option<Decimal>,
+    pub monthly_count: Option<u32>,
+    pub monthly_amount: Option<Decimal>,
+}
+
+impl TransactionLimits {
+    /// `self` with every field `other` sets taken from `other`.
+    pub fn overlay(self, other: &Self) -> Self {
+        Self {
+            min_amount: other.min_amount.or(self.min_amount),
+            max_amount: other.max_amount.or(self.max_amount),
+            daily_count: other.daily_count.or(self.daily_count),
+            daily_amount: other.daily_amount.or(self.daily_amount),
+            weekly_count: other.weekly_count.or(self.weekly_count),
+            weekly_amount: other.weekly_amount.or(self.weekly_amount),
+            monthly_count: other.monthly_count.or(self.monthly_count),
+            monthly_amount: other.monthly_amount.or(self.monthly_amount),
+        }
+    }
+
+    fn validate(&self, section: &str) -> anyhow::Result<()> {
+        let amounts = [
+            self.min_amount,
+            self.max_amount,
+            self.daily_amount,
+            self.weekly_amount,
+            self.monthly_amount,
+        ];
+        anyhow::ensure!(
+            amounts.iter().flatten().all(|amount| *amount >= Decimal::ZERO),
+            "[{section}] amounts must not be negative"
+        );
+        if let (Some(min), Some(max)) = (self.min_amount, self.max_amount) {
+            anyhow::ensure!(
+                max.is_zero() || max >= min,
+                "[{section}] max_amount must not be lower than min_amount"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl LimitsConfig {
+    /// Global caps with the country's overrides applied.
+    pub fn for_country(
+        &self,
+        kind: UserLimitKind,
+        country_iso2: Option<&str>,
+    ) -> TransactionLimits {
+        let base = match kind {
+            UserLimitKind::Deposit => self.deposit,
+            UserLimitKind::Withdrawal => self.withdrawal,
+        };
+        country_iso2
+            .and_then(|iso2| self.countries.get(&iso2.trim().to_ascii_uppercase()))
+            .map_or(base, |country| base.overlay(country.get(kind)))
+    }
+
+    /// Global caps with the country's and then the KYC level's overrides
+    /// applied.
+    pub fn for_user(
+        &self,
+        kind: UserLimitKind,
+        country_iso2: Option<&str>,
+        kyc_level: KycLevel,
+    ) -> TransactionLimits {
+        self.for_country(kind, country_iso2)
+            .overlay(self.kyc.get(kyc_level).get(kind))
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.deposit.validate("limits.deposit")?;
+        self.withdrawal.validate("limits.withdrawal")?;
+        for (iso2, country) in &self.countries {
+            anyhow::ensure!(
+                iso2.len() == 2 && iso2.chars().all(|c| c.is_ascii_uppercase()),
+                "[limits.countries.{iso2}] must be keyed by an uppercase ISO2 code"
+            );
+            country.validate(&format!("limits.countries.{iso2}"))?;
+        }
+        self.kyc.unverified.validate("limits.kyc.unverified")?;
+        self.kyc.basic.validate("limits.kyc.basic")?;
+        self.kyc.advanced.validate("limits.kyc.advanced")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use generated::models::{KycLevel, UserLimitKind};
+    use rust_decimal::Decimal;
+
+    use super::LimitsConfig;
+
+    #[test]
+    fn country_overrides_only_the_fields_it_sets() {
+        let config: LimitsConfig = toml::from_str(
+            r#"
+            [withdrawal]
+            max_amount = 5000.0
+            daily_count = 3
+
+            [countries.MY.withdrawal]
+            daily_count = 0
+            "#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+
+        let my = config.for_country(UserLimitKind::Withdrawal, Some("my"));
+        assert_eq!(my.max_amount, Some(Decimal::from(5000)));
+        assert_eq!(my.daily_count, Some(0));
+
+        let other = config.for_country(UserLimitKind::Withdrawal, Some("SG"));
+        assert_eq!(other.daily_count, Some(3));
+        assert_eq!(config.for_country(UserLimitKind::Deposit, None).max_amount, None);
+    }
+
+    #[test]
+    fn kyc_level_overrides_the_country() {
+        let config: LimitsConfig = toml::from_str(
+            r#"
+            [withdrawal]
+            daily_amount = 1000.0
+
+            [countries.MY.withdrawal]
+            daily_amount = 500.0
+            max_amount = 200.0
+
+            [kyc.advanced.withdrawal]
+            daily_amount = 20000.0
+            "#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+
+        let basic = config.for_user(UserLimitKind::Withdrawal, Some("MY"), KycLevel::Basic);
+        assert_eq!(basic.daily_amount, Some(Decimal::from(500)));
+
+        let advanced = config.for_user(UserLimitKind::Withdrawal, Some("MY"), KycLevel::Advanced);
+        assert_eq!(advanced.daily_amount, Some(Decimal::from(20000)));
+        assert_eq!(advanced.max_amount, Some(Decimal::from(200)));
+    }
+}
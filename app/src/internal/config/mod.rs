@@ -1,2 +1,43 @@
+pub mod approval;
+pub mod balance;
+pub mod bank_statement;
 pub mod deposit;
+pub mod expiry;
+pub mod fee;
+pub mod idempotency;
+pub mod limits;
+pub mod payment_gateway;
+pub mod payout;
+pub mod review_claim;
+pub mod transfer;
 pub mod withdrawal;
+
+use serde::de::DeserializeOwned;
+
+/// Reads an app-owned section of `configs.toml` (the file pointed to by
+/// `APP_CONFIGS_PATH`). A missing section yields the type's default.
+pub fn load_section<T: DeserializeOwned + Default>(section: &str) -> anyhow::Result<T> {
+    let path =
+        std::env::var("APP_CONFIGS_PATH").unwrap_or_else(|_| "app/configs.toml".to_string());
+    let raw = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("failed to read {path}: {e}"))?;
+    let mut doc: toml::Table = toml::from_str(&raw)
+        .map_err(|e| anyhow::anyhow!("failed to parse {path}: {e}"))?;
+
+    match doc.remove(section) {
+        Some(value) => value
+            .try_into()
+            .map_err(|e| anyhow::anyhow!("invalid [{section}] in {path}: {e}")),
+        None => Ok(T::default()),
+    }
+}
+
+/// Whether `APP_ENV` names a production deployment. Unset or unknown values
+/// count as production, so test-only providers stay off unless asked for.
+pub fn is_production_env() -> bool {
+    let app_env = std::env::var("APP_ENV").unwrap_or_else(|_| "production".to_string());
+    !matches!(
+        app_env.trim().to_ascii_lowercase().as_str(),
+        "local" | "development" | "dev" | "test" | "testing"
+    )
+}
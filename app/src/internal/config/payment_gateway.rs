@@ -0,0 +1,61 @@
+use serde::Deserialize;
+
+/// `[payment_gateway]` section of `configs.toml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PaymentGatewayConfig {
+    /// Provider gateway deposits go through; empty turns gateway deposits off
+    pub provider: String,
+    /// Shared secret the provider signs callbacks with
+    pub webhook_secret: String,
+    /// Callbacks signed longer ago than this are refused
+    pub signature_tolerance_secs: u64,
+    /// Public base URL of this API, used to build checkout links
+    pub public_base_url: String,
+}
+
+impl Default for PaymentGatewayConfig {
+    fn default() -> Self {
+        Self {
+            provider: String::new(),
+            webhook_secret: String::new(),
+            signature_tolerance_secs: 300,
+            public_base_url: "http://localhost:3000".to_string(),
+        }
+    }
+}
+
+impl PaymentGatewayConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.provider.is_empty()
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        anyhow::ensure!(
+            self.provider == "fake",
+            "[payment_gateway] unknown provider `{}`",
+            self.provider
+        );
+        // The fake provider self-signs `paid` from a public checkout page
+        anyhow::ensure!(
+            !super::is_production_env(),
+            "[payment_gateway] provider `fake` is not allowed when APP_ENV is production"
+        );
+        anyhow::ensure!(
+            self.webhook_secret.len() >= 16,
+            "[payment_gateway] webhook_secret must be at least 16 characters"
+        );
+        anyhow::ensure!(
+            self.signature_tolerance_secs > 0,
+            "[payment_gateway] signature_tolerance_secs must be positive"
+        );
+        anyhow::ensure!(
+            !self.public_base_url.trim().is_empty(),
+            "[payment_gateway] public_base_url is required"
+        );
+        Ok(())
+    }
+}
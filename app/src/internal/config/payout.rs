@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+use crate::internal::payout::mock::MockOutcome;
+
+/// `[payout]` section of `configs.toml`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PayoutConfig {
+    /// Provider withdrawals are paid out through on Process; empty means
+    /// admins pay out by hand
+    pub provider: String,
+    /// How the `mock` provider settles every payout
+    pub mock_outcome: MockOutcome,
+}
+
+impl PayoutConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.provider.is_empty()
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !self.is_enabled() || self.provider == "mock",
+            "[payout] unknown provider `{}`",
+            self.provider
+        );
+        // The mock provider settles payouts without moving any money
+        anyhow::ensure!(
+            !self.is_enabled() || !super::is_production_env(),
+            "[payout] provider `mock` is not allowed when APP_ENV is production"
+        );
+        Ok(())
+    }
+}
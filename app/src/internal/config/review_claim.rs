@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+/// `[review_claim]` section of `configs.toml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ReviewClaimConfig {
+    /// How long a claim keeps other admins from reviewing the record
+    pub ttl_secs: u64,
+}
+
+impl Default for ReviewClaimConfig {
+    fn default() -> Self {
+        Self { ttl_secs: 300 }
+    }
+}
+
+impl ReviewClaimConfig {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(self.ttl_secs > 0, "[review_claim] ttl_secs must be positive");
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use generated::models::CreditType;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// `[transfer]` section of `configs.toml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct TransferConfig {
+    /// Credit types users may transfer ("1" = Credit1, "2" = Credit2)
+    pub allowed_credit_types: Vec<CreditType>,
+    /// Minimum amount per transfer
+    pub min_amount: Decimal,
+    /// Maximum total sent per credit type per day (0 = unlimited)
+    pub daily_cap: Decimal,
+    /// Only allow transfers to the sender's own upline or downline tree
+    pub restrict_to_network: bool,
+}
+
+impl Default for TransferConfig {
+    fn default() -> Self {
+        Self {
+            allowed_credit_types: vec![CreditType::Credit1],
+            min_amount: Decimal::ZERO,
+            daily_cap: Decimal::ZERO,
+            restrict_to_network: false,
+        }
+    }
+}
+
+impl TransferConfig {
+    pub fn allows(&self, credit_type: CreditType) -> bool {
+        self.allowed_credit_types.contains(&credit_type)
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.min_amount >= Decimal::ZERO,
+            "[transfer] min_amount must not be negative"
+        );
+        anyhow::ensure!(
+            self.daily_cap >= Decimal::ZERO,
+            "[transfer] daily_cap must not be negative"
+        );
+        anyhow::ensure!(
+            self.daily_cap.is_zero() || self.daily_cap >= self.min_amount,
+            "[transfer] daily_cap must not be lower than min_amount"
+        );
+        Ok(())
+    }
+}
@@ -1,31 +1,31 @@
+use generated::models::KycLevel;
 use rust_decimal::Decimal;
+use serde::Deserialize;
 
-#[derive(Debug, Clone)]
+use super::fee::FeeSchedule;
+
+/// `[withdrawal]` section of `configs.toml`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
 pub struct WithdrawalFeeConfig {
-    /// Fee percentage (0.0 = no fee, 0.02 = 2%)
-    pub fee_percentage: Decimal,
-    /// Fixed fee amount added on top of percentage
-    pub fee_fixed: Decimal,
+    #[serde(flatten)]
+    pub fee: FeeSchedule,
     /// Minimum withdrawal amount
     pub min_amount: Decimal,
-}
-
-impl Default for WithdrawalFeeConfig {
-    fn default() -> Self {
-        Self {
-            fee_percentage: Decimal::ZERO,
-            fee_fixed: Decimal::ZERO,
-            min_amount: Decimal::ZERO,
-        }
-    }
+    /// KYC level a user needs before withdrawing ("1" = Basic, "2" = Advanced)
+    pub min_kyc_level: Option<KycLevel>,
+    /// Hours a newly saved withdrawal destination waits before it can be used
+    pub destination_cooling_off_hours: u32,
 }
 
 impl WithdrawalFeeConfig {
     /// Calculate the fee and net amount for a given withdrawal amount.
-    /// Returns (fee, net_amount).
-    pub fn calculate_fee(&self, amount: Decimal) -> (Decimal, Decimal) {
-        let fee = (amount * self.fee_percentage) + self.fee_fixed;
-        let net_amount = amount - fee;
-        (fee, net_amount)
+    /// Returns (fee, net_amount), or `None` when the fee exceeds the amount.
+    pub fn calculate_fee(&self, amount: Decimal) -> Option<(Decimal, Decimal)> {
+        self.fee.calculate(amount)
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.fee.validate("withdrawal", self.min_amount)
     }
 }
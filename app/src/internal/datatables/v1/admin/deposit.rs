@@ -11,6 +11,7 @@ use crate::contracts::datatable::admin::deposit::{
     AdminDepositDataTableContract, ROUTE_PREFIX, SCOPED_KEY,
 };
 use crate::internal::datatables::v1::admin::authorize_with_optional_export;
+use crate::internal::workflows::deposit;
 
 #[derive(Default, Clone)]
 pub struct DepositDataTableAppHooks;
@@ -106,6 +107,20 @@ impl DepositDataTableHooks for DepositDataTableAppHooks {
                 .map(|a| serde_json::Value::String(a.username.clone()))
                 .unwrap_or(serde_json::Value::Null),
         );
+        record.insert(
+            "reverser_username".into(),
+            row.reverser
+                .as_ref()
+                .map(|a| serde_json::Value::String(a.username.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
+        record.insert(
+            "claimer_username".into(),
+            row.claimer
+                .as_ref()
+                .map(|a| serde_json::Value::String(a.username.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
         record.insert("owner_name".into(), serde_json::Value::Null);
         record.insert(
             "company_bank_account_name".into(),
@@ -130,6 +145,13 @@ fn apply_keyword_filter<'db>(query: Query<'db, DepositModel>, value: &str) -> Qu
     if trimmed.is_empty() {
         return query;
     }
+    if deposit::is_reference_code(trimmed) {
+        return query.where_col(
+            DepositCol::REFERENCE_CODE,
+            Op::Eq,
+            Some(trimmed.to_ascii_uppercase()),
+        );
+    }
     // Try to parse as ID first
     if let Ok(id) = trimmed.parse::<i64>() {
         return query.where_col(DepositCol::ID, Op::Eq, id);
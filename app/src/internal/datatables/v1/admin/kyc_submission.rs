@@ -0,0 +1,151 @@
+use core_datatable::{DataTableContext, DataTableInput, DataTableRegistry};
+use core_db::common::{model_api::Query, sql::Op};
+use core_web::authz::{has_required_permissions, PermissionMode};
+use core_web::datatable::{
+    routes_for_scoped_contract_with_options, DataTableRouteOptions, DataTableRouteState,
+};
+use core_web::openapi::ApiRouter;
+use generated::{
+    models::{
+        KycDocumentType, KycLevel, KycStatus, KycSubmissionCol, KycSubmissionDataTable,
+        KycSubmissionDataTableHooks, KycSubmissionModel, KycSubmissionRecord, KycSubmissionRel,
+        UserCol,
+    },
+    permissions::Permission,
+};
+
+use crate::contracts::datatable::admin::kyc_submission::{
+    AdminKycSubmissionDataTableContract, ROUTE_PREFIX, SCOPED_KEY,
+};
+use crate::internal::datatables::v1::admin::authorize_with_optional_export;
+
+#[derive(Default, Clone)]
+pub struct KycSubmissionDataTableAppHooks;
+
+impl KycSubmissionDataTableHooks for KycSubmissionDataTableAppHooks {
+    fn scope<'db>(
+        &'db self,
+        query: Query<'db, KycSubmissionModel>,
+        _input: &DataTableInput,
+        _ctx: &DataTableContext,
+    ) -> Query<'db, KycSubmissionModel> {
+        query
+    }
+
+    fn authorize(&self, input: &DataTableInput, ctx: &DataTableContext) -> anyhow::Result<bool> {
+        let Some(actor) = ctx.actor.as_ref() else {
+            return Ok(false);
+        };
+        let base_authorized = has_required_permissions(
+            &actor.permissions,
+            &[Permission::KycRead.as_str(), Permission::KycManage.as_str()],
+            PermissionMode::Any,
+        );
+        Ok(authorize_with_optional_export(base_authorized, input, ctx))
+    }
+
+    fn filter_query<'db>(
+        &'db self,
+        query: Query<'db, KycSubmissionModel>,
+        filter_key: &str,
+        value: &str,
+        _input: &DataTableInput,
+        _ctx: &DataTableContext,
+    ) -> anyhow::Result<Option<Query<'db, KycSubmissionModel>>> {
+        match filter_key {
+            "q" => Ok(Some(apply_keyword_filter(query, value))),
+            "f-status" => {
+                if let Some(s) = KycStatus::from_storage(value) {
+                    Ok(Some(query.where_col(KycSubmissionCol::STATUS, Op::Eq, s)))
+                } else {
+                    Ok(Some(query))
+                }
+            }
+            "f-level" => {
+                if let Some(level) = KycLevel::from_storage(value) {
+                    Ok(Some(query.where_col(KycSubmissionCol::LEVEL, Op::Eq, level)))
+                } else {
+                    Ok(Some(query))
+                }
+            }
+            "f-document_type" => {
+                if let Some(dt) = KycDocumentType::from_storage(value) {
+                    Ok(Some(query.where_col(KycSubmissionCol::DOCUMENT_TYPE, Op::Eq, dt)))
+                } else {
+                    Ok(Some(query))
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn row_to_record(
+        &self,
+        row: KycSubmissionRecord,
+        _input: &DataTableInput,
+        _ctx: &DataTableContext,
+    ) -> anyhow::Result<serde_json::Map<String, serde_json::Value>> {
+        let mut record = self.default_row_to_record(row.clone())?;
+        record.insert(
+            "status_label".into(),
+            serde_json::Value::String(row.status_label()),
+        );
+        record.insert(
+            "user_username".into(),
+            row.user
+                .as_ref()
+                .map(|u| serde_json::Value::String(u.username.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
+        record.insert(
+            "admin_username".into(),
+            row.admin
+                .as_ref()
+                .map(|a| serde_json::Value::String(a.username.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
+        Ok(record)
+    }
+}
+
+fn apply_keyword_filter<'db>(
+    query: Query<'db, KycSubmissionModel>,
+    value: &str,
+) -> Query<'db, KycSubmissionModel> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return query;
+    }
+    let pattern = format!("%{trimmed}%");
+    query.where_group(|q| {
+        q.where_has(KycSubmissionRel::USER, |rq| {
+            rq.where_col(UserCol::USERNAME, Op::Like, pattern.clone())
+        })
+        .or_where_col(KycSubmissionCol::FULL_NAME, Op::Like, pattern.clone())
+        .or_where_col(KycSubmissionCol::DOCUMENT_NUMBER, Op::Like, pattern)
+    })
+}
+
+pub type AppKycSubmissionDataTable = KycSubmissionDataTable<KycSubmissionDataTableAppHooks>;
+
+pub fn app_kyc_submission_datatable(db: sqlx::PgPool) -> AppKycSubmissionDataTable {
+    KycSubmissionDataTable::new(db).with_hooks(KycSubmissionDataTableAppHooks::default())
+}
+
+pub fn register_scoped(registry: &mut DataTableRegistry, db: sqlx::PgPool) {
+    registry.register_as(SCOPED_KEY, app_kyc_submission_datatable(db));
+}
+
+pub fn routes<S>(state: S) -> ApiRouter
+where
+    S: DataTableRouteState,
+{
+    routes_for_scoped_contract_with_options(
+        ROUTE_PREFIX,
+        state,
+        AdminKycSubmissionDataTableContract,
+        DataTableRouteOptions {
+            require_bearer_auth: true,
+        },
+    )
+}
@@ -9,12 +9,14 @@ pub mod crypto_network;
 pub mod deposit;
 pub mod http_client_log;
 pub mod introducer_change;
+pub mod kyc_submission;
 pub mod sql_profiler_query;
 pub mod sql_profiler_request;
 pub mod user;
 pub mod user_credit_transaction;
 pub mod webhook_log;
 pub mod withdrawal;
+pub mod withdrawal_destination;
 
 use std::collections::HashSet;
 
@@ -50,6 +52,9 @@ use crate::contracts::datatable::admin::{
         ROUTE_PREFIX as INTRODUCER_CHANGE_ROUTE_PREFIX,
         SCOPED_KEY as INTRODUCER_CHANGE_SCOPED_KEY,
     },
+    kyc_submission::{
+        ROUTE_PREFIX as KYC_SUBMISSION_ROUTE_PREFIX, SCOPED_KEY as KYC_SUBMISSION_SCOPED_KEY,
+    },
     sql_profiler_query::{
         ROUTE_PREFIX as SQL_PROFILER_QUERY_ROUTE_PREFIX,
         SCOPED_KEY as SQL_PROFILER_QUERY_SCOPED_KEY,
@@ -65,6 +70,10 @@ use crate::contracts::datatable::admin::{
     },
     webhook_log::{ROUTE_PREFIX as WEBHOOK_LOG_ROUTE_PREFIX, SCOPED_KEY as WEBHOOK_LOG_SCOPED_KEY},
     withdrawal::{ROUTE_PREFIX as WITHDRAWAL_ROUTE_PREFIX, SCOPED_KEY as WITHDRAWAL_SCOPED_KEY},
+    withdrawal_destination::{
+        ROUTE_PREFIX as WITHDRAWAL_DESTINATION_ROUTE_PREFIX,
+        SCOPED_KEY as WITHDRAWAL_DESTINATION_SCOPED_KEY,
+    },
 };
 use crate::internal::api::state::AppApiState;
 
@@ -81,10 +90,12 @@ pub use http_client_log::HttpClientLogDataTableAppHooks;
 pub use user::{build_user_summary_output, UserDataTableAppHooks};
 pub use user_credit_transaction::UserCreditTransactionDataTableAppHooks;
 pub use introducer_change::IntroducerChangeDataTableAppHooks;
+pub use kyc_submission::KycSubmissionDataTableAppHooks;
 pub use sql_profiler_query::SqlProfilerQueryDataTableAppHooks;
 pub use sql_profiler_request::SqlProfilerRequestDataTableAppHooks;
 pub use webhook_log::WebhookLogDataTableAppHooks;
 pub use withdrawal::WithdrawalDataTableAppHooks;
+pub use withdrawal_destination::WithdrawalDestinationDataTableAppHooks;
 
 pub fn authorize_with_optional_export(
     base_authorized: bool,
@@ -165,6 +176,10 @@ fn introducer_change_routes(state: AppApiState) -> ApiRouter {
     introducer_change::routes(state)
 }
 
+fn kyc_submission_routes(state: AppApiState) -> ApiRouter {
+    kyc_submission::routes(state)
+}
+
 fn sql_profiler_query_routes(state: AppApiState) -> ApiRouter {
     sql_profiler_query::routes(state)
 }
@@ -185,6 +200,10 @@ fn withdrawal_routes(state: AppApiState) -> ApiRouter {
     withdrawal::routes(state)
 }
 
+fn withdrawal_destination_routes(state: AppApiState) -> ApiRouter {
+    withdrawal_destination::routes(state)
+}
+
 pub static ADMIN_SCOPED_DATATABLES: &[ScopedDatatableSpec] = &[
     ScopedDatatableSpec {
         scoped_key: AUDIT_LOG_SCOPED_KEY,
@@ -240,6 +259,12 @@ pub static ADMIN_SCOPED_DATATABLES: &[ScopedDatatableSpec] = &[
         register: introducer_change::register_scoped,
         mount_routes: introducer_change_routes,
     },
+    ScopedDatatableSpec {
+        scoped_key: KYC_SUBMISSION_SCOPED_KEY,
+        route_prefix: KYC_SUBMISSION_ROUTE_PREFIX,
+        register: kyc_submission::register_scoped,
+        mount_routes: kyc_submission_routes,
+    },
     ScopedDatatableSpec {
         scoped_key: SQL_PROFILER_REQUEST_SCOPED_KEY,
         route_prefix: SQL_PROFILER_REQUEST_ROUTE_PREFIX,
@@ -288,6 +313,12 @@ pub static ADMIN_SCOPED_DATATABLES: &[ScopedDatatableSpec] = &[
         register: withdrawal::register_scoped,
         mount_routes: withdrawal_routes,
     },
+    ScopedDatatableSpec {
+        scoped_key: WITHDRAWAL_DESTINATION_SCOPED_KEY,
+        route_prefix: WITHDRAWAL_DESTINATION_ROUTE_PREFIX,
+        register: withdrawal_destination::register_scoped,
+        mount_routes: withdrawal_destination_routes,
+    },
 ];
 
 fn assert_catalog_valid() {
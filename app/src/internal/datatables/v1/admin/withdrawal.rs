@@ -106,6 +106,20 @@ impl WithdrawalDataTableHooks for WithdrawalDataTableAppHooks {
                 .map(|a| serde_json::Value::String(a.username.clone()))
                 .unwrap_or(serde_json::Value::Null),
         );
+        record.insert(
+            "refunder_username".into(),
+            row.refunder
+                .as_ref()
+                .map(|a| serde_json::Value::String(a.username.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
+        record.insert(
+            "claimer_username".into(),
+            row.claimer
+                .as_ref()
+                .map(|a| serde_json::Value::String(a.username.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
         record.insert("owner_name".into(), serde_json::Value::Null);
         record.insert(
             "bank_name".into(),
@@ -0,0 +1,168 @@
+use core_datatable::{DataTableContext, DataTableInput, DataTableRegistry};
+use core_db::common::{model_api::Query, sql::Op};
+use core_web::authz::{has_required_permissions, PermissionMode};
+use core_web::datatable::{
+    routes_for_scoped_contract_with_options, DataTableRouteOptions, DataTableRouteState,
+};
+use core_web::openapi::ApiRouter;
+use generated::{
+    models::{
+        UserCol, UserWithdrawalDestinationCol, UserWithdrawalDestinationDataTable,
+        UserWithdrawalDestinationDataTableHooks, UserWithdrawalDestinationModel,
+        UserWithdrawalDestinationRecord, UserWithdrawalDestinationRel, WithdrawalDestinationKind,
+        WithdrawalDestinationStatus,
+    },
+    permissions::Permission,
+};
+
+use crate::contracts::datatable::admin::withdrawal_destination::{
+    AdminWithdrawalDestinationDataTableContract, ROUTE_PREFIX, SCOPED_KEY,
+};
+use crate::internal::datatables::v1::admin::authorize_with_optional_export;
+
+#[derive(Default, Clone)]
+pub struct WithdrawalDestinationDataTableAppHooks;
+
+impl UserWithdrawalDestinationDataTableHooks for WithdrawalDestinationDataTableAppHooks {
+    fn scope<'db>(
+        &'db self,
+        query: Query<'db, UserWithdrawalDestinationModel>,
+        _input: &DataTableInput,
+        _ctx: &DataTableContext,
+    ) -> Query<'db, UserWithdrawalDestinationModel> {
+        query
+    }
+
+    fn authorize(&self, input: &DataTableInput, ctx: &DataTableContext) -> anyhow::Result<bool> {
+        let Some(actor) = ctx.actor.as_ref() else {
+            return Ok(false);
+        };
+        let base_authorized = has_required_permissions(
+            &actor.permissions,
+            &[
+                Permission::WithdrawalDestinationRead.as_str(),
+                Permission::WithdrawalDestinationManage.as_str(),
+            ],
+            PermissionMode::Any,
+        );
+        Ok(authorize_with_optional_export(base_authorized, input, ctx))
+    }
+
+    fn filter_query<'db>(
+        &'db self,
+        query: Query<'db, UserWithdrawalDestinationModel>,
+        filter_key: &str,
+        value: &str,
+        _input: &DataTableInput,
+        _ctx: &DataTableContext,
+    ) -> anyhow::Result<Option<Query<'db, UserWithdrawalDestinationModel>>> {
+        match filter_key {
+            "q" => Ok(Some(apply_keyword_filter(query, value))),
+            "f-kind" => {
+                if let Some(kind) = WithdrawalDestinationKind::from_storage(value) {
+                    Ok(Some(query.where_col(UserWithdrawalDestinationCol::KIND, Op::Eq, kind)))
+                } else {
+                    Ok(Some(query))
+                }
+            }
+            "f-status" => {
+                if let Some(s) = WithdrawalDestinationStatus::from_storage(value) {
+                    Ok(Some(query.where_col(UserWithdrawalDestinationCol::STATUS, Op::Eq, s)))
+                } else {
+                    Ok(Some(query))
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn row_to_record(
+        &self,
+        row: UserWithdrawalDestinationRecord,
+        _input: &DataTableInput,
+        _ctx: &DataTableContext,
+    ) -> anyhow::Result<serde_json::Map<String, serde_json::Value>> {
+        let mut record = self.default_row_to_record(row.clone())?;
+        record.insert(
+            "status_label".into(),
+            serde_json::Value::String(row.status_label()),
+        );
+        record.insert(
+            "user_username".into(),
+            row.user
+                .as_ref()
+                .map(|u| serde_json::Value::String(u.username.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
+        record.insert(
+            "bank_name".into(),
+            row.bank
+                .as_ref()
+                .map(|b| serde_json::Value::String(b.name.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
+        record.insert(
+            "crypto_network_name".into(),
+            row.crypto_network
+                .as_ref()
+                .map(|n| serde_json::Value::String(n.name.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
+        record.insert(
+            "locked_by_username".into(),
+            row.locker
+                .as_ref()
+                .map(|a| serde_json::Value::String(a.username.clone()))
+                .unwrap_or(serde_json::Value::Null),
+        );
+        Ok(record)
+    }
+}
+
+fn apply_keyword_filter<'db>(
+    query: Query<'db, UserWithdrawalDestinationModel>,
+    value: &str,
+) -> Query<'db, UserWithdrawalDestinationModel> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return query;
+    }
+    let pattern = format!("%{trimmed}%");
+    query.where_group(|q| {
+        q.where_has(UserWithdrawalDestinationRel::USER, |rq| {
+            rq.where_col(UserCol::USERNAME, Op::Like, pattern.clone())
+        })
+        .or_where_col(
+            UserWithdrawalDestinationCol::BANK_ACCOUNT_NUMBER,
+            Op::Like,
+            pattern.clone(),
+        )
+        .or_where_col(UserWithdrawalDestinationCol::CRYPTO_WALLET_ADDRESS, Op::Like, pattern)
+    })
+}
+
+pub type AppWithdrawalDestinationDataTable =
+    UserWithdrawalDestinationDataTable<WithdrawalDestinationDataTableAppHooks>;
+
+pub fn app_withdrawal_destination_datatable(db: sqlx::PgPool) -> AppWithdrawalDestinationDataTable {
+    UserWithdrawalDestinationDataTable::new(db)
+        .with_hooks(WithdrawalDestinationDataTableAppHooks::default())
+}
+
+pub fn register_scoped(registry: &mut DataTableRegistry, db: sqlx::PgPool) {
+    registry.register_as(SCOPED_KEY, app_withdrawal_destination_datatable(db));
+}
+
+pub fn routes<S>(state: S) -> ApiRouter
+where
+    S: DataTableRouteState,
+{
+    routes_for_scoped_contract_with_options(
+        ROUTE_PREFIX,
+        state,
+        AdminWithdrawalDestinationDataTableContract,
+        DataTableRouteOptions {
+            require_bearer_auth: true,
+        },
+    )
+}
@@ -0,0 +1,53 @@
+use core_jobs::{Job, JobContext};
+use core_realtime::RealtimePublisher;
+use serde::{Deserialize, Serialize};
+
+use crate::internal::{
+    config::{self, balance::BalanceConfig, expiry::ExpiryConfig},
+    workflows::{
+        expiry, notification,
+        review_claim::{self, ClaimSubject},
+    },
+};
+
+/// Hourly sweep of stale deposits and withdrawals, driven by `[expiry]`.
+/// Admin pending counts and the expired records' released claims are sent
+/// over realtime when anything changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExpiryJob;
+
+#[async_trait::async_trait]
+impl Job for ExpiryJob {
+    const NAME: &'static str = "expiry";
+
+    async fn handle(&self, ctx: &JobContext) -> anyhow::Result<()> {
+        let expiry_config: ExpiryConfig = config::load_section("expiry")?;
+        expiry_config.validate()?;
+        let balance_config: BalanceConfig = config::load_section("balance")?;
+        balance_config.validate()?;
+
+        let summary = expiry::run(&ctx.db, &balance_config, &expiry_config)
+            .await
+            .map_err(|err| anyhow::anyhow!("expiry run failed: {err:?}"))?;
+        if summary.is_empty() {
+            return Ok(());
+        }
+
+        let realtime = RealtimePublisher::from_realtime_settings(
+            &ctx.settings.redis.url,
+            &ctx.settings.realtime,
+        )?;
+        notification::publish_pending_counts(&ctx.db, &realtime).await;
+        let expired = summary
+            .deposits_expired
+            .iter()
+            .map(|id| (ClaimSubject::Deposit, *id))
+            .chain(summary.withdrawals_expired.iter().map(|id| (ClaimSubject::Withdrawal, *id)));
+        for (subject, id) in expired {
+            review_claim::publish_with(&ctx.db, &realtime, subject, id)
+                .await
+                .map_err(|err| anyhow::anyhow!("claim publish failed: {err:?}"))?;
+        }
+        Ok(())
+    }
+}
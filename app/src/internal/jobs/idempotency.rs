@@ -0,0 +1,18 @@
+use core_jobs::{Job, JobContext};
+use serde::{Deserialize, Serialize};
+
+use crate::internal::middleware::idempotency;
+
+/// Clears expired `Idempotency-Key` records, off the request path.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IdempotencyCleanupJob;
+
+#[async_trait::async_trait]
+impl Job for IdempotencyCleanupJob {
+    const NAME: &'static str = "idempotency_cleanup";
+
+    async fn handle(&self, ctx: &JobContext) -> anyhow::Result<()> {
+        idempotency::purge_expired(&ctx.db).await?;
+        Ok(())
+    }
+}
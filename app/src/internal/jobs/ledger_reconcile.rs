@@ -0,0 +1,19 @@
+use core_jobs::{Job, JobContext};
+use serde::{Deserialize, Serialize};
+
+use crate::internal::workflows::ledger_reconcile;
+
+/// Nightly comparison of stored user balances against the credit ledger.
+/// Drift is recorded in `credit_ledger_mismatches` for admins to review.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LedgerReconcileJob;
+
+#[async_trait::async_trait]
+impl Job for LedgerReconcileJob {
+    const NAME: &'static str = "ledger_reconcile";
+
+    async fn handle(&self, ctx: &JobContext) -> anyhow::Result<()> {
+        ledger_reconcile::scan(&ctx.db).await?;
+        Ok(())
+    }
+}
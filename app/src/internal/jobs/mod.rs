@@ -1,7 +1,29 @@
 use core_jobs::worker::Worker;
 
-#[allow(unused_variables)]
-pub fn register_jobs(worker: &mut Worker) {}
+mod expiry;
+mod idempotency;
+mod ledger_reconcile;
+mod payout;
 
-#[allow(unused_variables)]
-pub fn register_schedules(scheduler: &mut core_jobs::cron::Scheduler) {}
+pub use expiry::ExpiryJob;
+pub use idempotency::IdempotencyCleanupJob;
+pub use ledger_reconcile::LedgerReconcileJob;
+pub use payout::PayoutPollJob;
+
+pub fn register_jobs(worker: &mut Worker) {
+    worker.register::<ExpiryJob>();
+    worker.register::<IdempotencyCleanupJob>();
+    worker.register::<LedgerReconcileJob>();
+    worker.register::<PayoutPollJob>();
+}
+
+pub fn register_schedules(scheduler: &mut core_jobs::cron::Scheduler) {
+    // Daily at 03:30, after the day's deposit/withdrawal reviews settle.
+    scheduler.cron::<LedgerReconcileJob>("0 30 3 * * *");
+    // Hourly; each check is off until its age is set in `[expiry]`.
+    scheduler.cron::<ExpiryJob>("0 0 * * * *");
+    // Every 15 minutes, so lapsed keys do not pile up between requests.
+    scheduler.cron::<IdempotencyCleanupJob>("0 */15 * * * *");
+    // Every minute; returns straight away unless `[payout]` names a provider.
+    scheduler.cron::<PayoutPollJob>("0 * * * * *");
+}
@@ -0,0 +1,43 @@
+use core_jobs::{Job, JobContext};
+use core_realtime::RealtimePublisher;
+use serde::{Deserialize, Serialize};
+
+use crate::internal::{
+    config::{self, balance::BalanceConfig, payout::PayoutConfig},
+    payout,
+    workflows::{notification, payout as payout_workflow},
+};
+
+/// Polls the `[payout]` provider for payouts still processing and settles
+/// the finished ones. Does nothing while payouts are made by hand.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PayoutPollJob;
+
+#[async_trait::async_trait]
+impl Job for PayoutPollJob {
+    const NAME: &'static str = "payout_poll";
+
+    async fn handle(&self, ctx: &JobContext) -> anyhow::Result<()> {
+        let payout_config: PayoutConfig = config::load_section("payout")?;
+        payout_config.validate()?;
+        let Some(provider) = payout::from_config(&payout_config) else {
+            return Ok(());
+        };
+        let balance_config: BalanceConfig = config::load_section("balance")?;
+        balance_config.validate()?;
+
+        let summary = payout_workflow::poll(&ctx.db, &balance_config, provider.as_ref())
+            .await
+            .map_err(|err| anyhow::anyhow!("payout poll failed: {err:?}"))?;
+        if summary.settled() == 0 {
+            return Ok(());
+        }
+
+        let realtime = RealtimePublisher::from_realtime_settings(
+            &ctx.settings.redis.url,
+            &ctx.settings.realtime,
+        )?;
+        notification::publish_pending_counts(&ctx.db, &realtime).await;
+        Ok(())
+    }
+}
@@ -0,0 +1,377 @@
+use axum::{
+    body::{to_bytes, Body, Bytes},
+    extract::{Request, State},
+    http::{header, HeaderName, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use core_db::common::sql::generate_snowflake_i64;
+use core_i18n::t;
+use core_web::{auth::AuthUser, error::AppError};
+use generated::guards::{AdminGuard, UserGuard};
+use sha2::{Digest, Sha256};
+use time::{Duration, OffsetDateTime};
+
+use crate::internal::api::state::AppApiState;
+
+pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+pub const IDEMPOTENT_REPLAYED: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+const MAX_KEY_LEN: usize = 255;
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+/// Room for a 5 MB upload plus the multipart framing around it
+const MAX_MULTIPART_BYTES: usize = 6 * 1024 * 1024;
+const STATUS_IN_PROGRESS: i16 = 1;
+const STATUS_COMPLETED: i16 = 2;
+
+#[derive(sqlx::FromRow)]
+struct StoredKey {
+    fingerprint: String,
+    status: i16,
+    response_status: Option<i16>,
+    response_content_type: Option<String>,
+    response_body: Option<Vec<u8>>,
+}
+
+/// Idempotency for admin routes. Must sit inside the admin auth layer.
+pub async fn admin_idempotency(
+    State(state): State<AppApiState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let admin_id = match request.extensions().get::<AuthUser<AdminGuard>>() {
+        Some(auth) => auth.user.id,
+        None => {
+            let token = core_web::auth::extract_bearer_token(request.headers())
+                .ok_or_else(|| AppError::Unauthorized("Missing access token".to_string()))?;
+            core_web::auth::authenticate_token::<AdminGuard>(&state.db, &token)
+                .await?
+                .user
+                .id
+        }
+    };
+
+    handle(&state, format!("admin:{admin_id}"), request, next).await
+}
+
+/// Idempotency for user routes. Must sit inside the user auth layer.
+pub async fn user_idempotency(
+    State(state): State<AppApiState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let user_id = match request.extensions().get::<AuthUser<UserGuard>>() {
+        Some(auth) => auth.user.id,
+        None => {
+            let token = core_web::auth::extract_bearer_token(request.headers())
+                .ok_or_else(|| AppError::Unauthorized("Missing access token".to_string()))?;
+            core_web::auth::authenticate_token::<UserGuard>(&state.db, &token)
+                .await?
+                .user
+                .id
+        }
+    };
+
+    handle(&state, format!("user:{user_id}"), request, next).await
+}
+
+/// Runs a mutating request at most once per `Idempotency-Key` and actor.
+///
+/// The first request claims the key and its final response is stored for
+/// `ttl_secs`; a retry with the same key and body gets that response back
+/// with `Idempotent-Replayed: true` instead of running the handler again.
+/// While the handler runs the claim is renewed every half `lock_secs`, so a
+/// slow request keeps its key and only one whose process died lets it go.
+/// Server errors release the key so the client can retry for real. Requests
+/// without the header and safe methods pass straight through.
+async fn handle(
+    state: &AppApiState,
+    scope: String,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let Some(key) = idempotency_key(&request)? else {
+        return Ok(next.run(request).await);
+    };
+
+    let (parts, body) = request.into_parts();
+    let boundary = parts
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(multipart_boundary)
+        .map(str::to_string);
+    let limit = if boundary.is_some() {
+        MAX_MULTIPART_BYTES
+    } else {
+        MAX_BODY_BYTES
+    };
+    let body = to_bytes(body, limit)
+        .await
+        .map_err(|_| AppError::BadRequest(t("Request body is too large")))?;
+    let fingerprint = fingerprint(&parts.method, parts.uri.path(), &body, boundary.as_deref());
+
+    // An expired key is taken over in place; the cleanup job deletes the rest
+    let now = OffsetDateTime::now_utc();
+    let claimed = sqlx::query(
+        "INSERT INTO idempotency_keys
+             (id, scope, idempotency_key, request_method, request_path, fingerprint,
+              status, created_at, expires_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+         ON CONFLICT (scope, idempotency_key) DO UPDATE
+         SET request_method = EXCLUDED.request_method, request_path = EXCLUDED.request_path,
+             fingerprint = EXCLUDED.fingerprint, status = EXCLUDED.status,
+             response_status = NULL, response_content_type = NULL, response_body = NULL,
+             created_at = EXCLUDED.created_at, expires_at = EXCLUDED.expires_at
+         WHERE idempotency_keys.expires_at < EXCLUDED.created_at",
+    )
+    .bind(generate_snowflake_i64())
+    .bind(&scope)
+    .bind(&key)
+    .bind(parts.method.as_str())
+    .bind(parts.uri.path())
+    .bind(&fingerprint)
+    .bind(STATUS_IN_PROGRESS)
+    .bind(now)
+    .bind(now + Duration::seconds(state.idempotency_config.lock_secs as i64))
+    .execute(&state.db)
+    .await
+    .map_err(AppError::from)?
+    .rows_affected()
+        == 1;
+
+    if !claimed {
+        return replay(state, &scope, &key, &fingerprint).await;
+    }
+
+    let heartbeat = Heartbeat::start(state, &scope, &key);
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    drop(heartbeat);
+    if response.status().is_server_error() {
+        release(state, &scope, &key).await?;
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(_) => {
+            release(state, &scope, &key).await?;
+            return Err(AppError::Internal(anyhow::anyhow!("Failed to read response body")));
+        }
+    };
+    let content_type = parts
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok());
+
+    sqlx::query(
+        "UPDATE idempotency_keys
+         SET status = $1, response_status = $2, response_content_type = $3,
+             response_body = $4, expires_at = $5
+         WHERE scope = $6 AND idempotency_key = $7",
+    )
+    .bind(STATUS_COMPLETED)
+    .bind(parts.status.as_u16() as i16)
+    .bind(content_type)
+    .bind(body.as_ref())
+    .bind(OffsetDateTime::now_utc() + Duration::seconds(state.idempotency_config.ttl_secs as i64))
+    .bind(&scope)
+    .bind(&key)
+    .execute(&state.db)
+    .await
+    .map_err(AppError::from)?;
+
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+fn idempotency_key(request: &Request) -> Result<Option<String>, AppError> {
+    if matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS) {
+        return Ok(None);
+    }
+
+    let Some(value) = request.headers().get(IDEMPOTENCY_KEY) else {
+        return Ok(None);
+    };
+    let key = value
+        .to_str()
+        .map(str::trim)
+        .map_err(|_| AppError::BadRequest(t("Invalid Idempotency-Key header")))?;
+    if key.is_empty() || key.len() > MAX_KEY_LEN {
+        return Err(AppError::BadRequest(t("Invalid Idempotency-Key header")));
+    }
+    Ok(Some(key.to_string()))
+}
+
+/// The `boundary` parameter of a `multipart/*` content type.
+fn multipart_boundary(content_type: &str) -> Option<&str> {
+    let mut params = content_type.split(';').map(str::trim);
+    if !params.next()?.to_ascii_lowercase().starts_with("multipart/") {
+        return None;
+    }
+    params
+        .find_map(|param| param.strip_prefix("boundary="))
+        .map(|boundary| boundary.trim_matches('"'))
+        .filter(|boundary| !boundary.is_empty())
+}
+
+/// Hashes the request. Clients pick a fresh multipart boundary on every
+/// send, so it is left out and a retried upload matches the original.
+fn fingerprint(method: &Method, path: &str, body: &Bytes, boundary: Option<&str>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_str().as_bytes());
+    hasher.update(b"\n");
+    hasher.update(path.as_bytes());
+    hasher.update(b"\n");
+    match boundary.map(str::as_bytes) {
+        Some(boundary) => {
+            let mut rest: &[u8] = body;
+            while let Some(at) = rest.windows(boundary.len()).position(|w| w == boundary) {
+                hasher.update(&rest[..at]);
+                hasher.update(b"\0boundary\0");
+                rest = &rest[at + boundary.len()..];
+            }
+            hasher.update(rest);
+        }
+        None => hasher.update(body),
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+async fn replay(
+    state: &AppApiState,
+    scope: &str,
+    key: &str,
+    fingerprint: &str,
+) -> Result<Response, AppError> {
+    let stored = sqlx::query_as::<_, StoredKey>(
+        "SELECT fingerprint, status, response_status, response_content_type, response_body
+         FROM idempotency_keys
+         WHERE scope = $1 AND idempotency_key = $2",
+    )
+    .bind(scope)
+    .bind(key)
+    .fetch_all(&state.db)
+    .await
+    .map_err(AppError::from)?
+    .into_iter()
+    .next()
+    .ok_or_else(|| {
+        AppError::Conflict(t("A request with this Idempotency-Key is still being processed"))
+    })?;
+
+    if stored.fingerprint != fingerprint {
+        return Err(AppError::BadRequest(t(
+            "Idempotency-Key was already used for a different request",
+        )));
+    }
+    if stored.status != STATUS_COMPLETED {
+        return Err(AppError::Conflict(t(
+            "A request with this Idempotency-Key is still being processed",
+        )));
+    }
+
+    let status = stored
+        .response_status
+        .and_then(|status| StatusCode::from_u16(status as u16).ok())
+        .unwrap_or(StatusCode::OK);
+    let mut response = Response::new(Body::from(stored.response_body.unwrap_or_default()));
+    *response.status_mut() = status;
+    if let Some(content_type) = stored
+        .response_content_type
+        .and_then(|value| HeaderValue::from_str(&value).ok())
+    {
+        response.headers_mut().insert(header::CONTENT_TYPE, content_type);
+    }
+    response
+        .headers_mut()
+        .insert(IDEMPOTENT_REPLAYED, HeaderValue::from_static("true"));
+    Ok(response)
+}
+
+/// Pushes an in-progress key's expiry forward until dropped. Dropping it
+/// with the request, including when the client goes away, lets the key lapse
+/// `lock_secs` later.
+struct Heartbeat(tokio::task::JoinHandle<()>);
+
+impl Heartbeat {
+    fn start(state: &AppApiState, scope: &str, key: &str) -> Self {
+        let db = state.db.clone();
+        let (scope, key) = (scope.to_string(), key.to_string());
+        let lock_secs = state.idempotency_config.lock_secs;
+        let period = std::time::Duration::from_millis(lock_secs * 500);
+        Self(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(period).await;
+                // A failed renewal is tried again on the next beat
+                let _ = sqlx::query(
+                    "UPDATE idempotency_keys SET expires_at = $1
+                     WHERE scope = $2 AND idempotency_key = $3 AND status = $4",
+                )
+                .bind(OffsetDateTime::now_utc() + Duration::seconds(lock_secs as i64))
+                .bind(&scope)
+                .bind(&key)
+                .bind(STATUS_IN_PROGRESS)
+                .execute(&db)
+                .await;
+            }
+        }))
+    }
+}
+
+impl Drop for Heartbeat {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Deletes keys past their expiry: replays older than `ttl_secs` and claims
+/// whose request stopped renewing them. Run from the scheduled cleanup job.
+pub async fn purge_expired(db: &sqlx::PgPool) -> Result<u64, sqlx::Error> {
+    let deleted = sqlx::query("DELETE FROM idempotency_keys WHERE expires_at < $1")
+        .bind(OffsetDateTime::now_utc())
+        .execute(db)
+        .await?
+        .rows_affected();
+    Ok(deleted)
+}
+
+async fn release(state: &AppApiState, scope: &str, key: &str) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM idempotency_keys WHERE scope = $1 AND idempotency_key = $2")
+        .bind(scope)
+        .bind(key)
+        .execute(&state.db)
+        .await
+        .map_err(AppError::from)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Bytes, http::Method};
+
+    use super::{fingerprint, multipart_boundary};
+
+    fn upload(boundary: &str, file: &str) -> Bytes {
+        Bytes::from(format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; \
+             filename=\"r.png\"\r\nContent-Type: image/png\r\n\r\n{file}\r\n--{boundary}--\r\n"
+        ))
+    }
+
+    #[test]
+    fn multipart_fingerprint_ignores_the_boundary() {
+        let post = |boundary: &str, file: &str| {
+            let body = upload(boundary, file);
+            fingerprint(&Method::POST, "/deposits/1/upload-receipt", &body, Some(boundary))
+        };
+
+        assert_eq!(post("aaa111", "png-bytes"), post("bbb222", "png-bytes"));
+        assert_ne!(post("aaa111", "png-bytes"), post("aaa111", "other-bytes"));
+        assert_eq!(
+            multipart_boundary("multipart/form-data; boundary=\"aaa111\""),
+            Some("aaa111")
+        );
+        assert_eq!(multipart_boundary("application/json"), None);
+    }
+}
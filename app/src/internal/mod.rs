@@ -4,5 +4,7 @@ pub mod datatables;
 pub mod jobs;
 pub mod middleware;
 pub mod observers;
+pub mod payment_gateway;
+pub mod payout;
 pub mod realtime;
 pub mod workflows;
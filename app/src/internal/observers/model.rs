@@ -2,17 +2,28 @@ use anyhow::Context;
 use core_db::common::model_observer::{ModelEvent, ModelObserver};
 use generated::models::{
     AdminModel, AdminCreate, AdminRecord, AdminChanges, BankModel, BankCreate, BankRecord,
-    BankChanges, CompanyBankAccountModel, CompanyBankAccountCreate, CompanyBankAccountRecord,
-    CompanyBankAccountChanges, CompanyCryptoAccountModel, CompanyCryptoAccountCreate,
-    CompanyCryptoAccountRecord, CompanyCryptoAccountChanges, ContentPageModel,
-    ContentPageCreate, ContentPageRecord, ContentPageChanges, CountryModel,
-    CountryCreate, CountryRecord, CountryChanges, CryptoNetworkModel,
-    CryptoNetworkCreate, CryptoNetworkRecord, CryptoNetworkChanges, DepositModel,
-    DepositCreate, DepositRecord, DepositChanges, IntroducerChangeModel,
-    IntroducerChangeCreate, IntroducerChangeRecord, IntroducerChangeChanges, UserModel,
-    UserCreate, UserCreditTransactionModel, UserCreditTransactionCreate,
-    UserCreditTransactionRecord, UserCreditTransactionChanges, UserRecord, UserChanges,
-    WithdrawalModel, WithdrawalCreate, WithdrawalRecord, WithdrawalChanges,
+    BankChanges, BankStatementImportModel, BankStatementImportCreate, BankStatementImportRecord,
+    BankStatementImportChanges, CompanyBankAccountModel, CompanyBankAccountCreate,
+    CompanyBankAccountRecord, CompanyBankAccountChanges, CompanyCryptoAccountModel,
+    CompanyCryptoAccountCreate, CompanyCryptoAccountRecord, CompanyCryptoAccountChanges,
+    ContentPageModel, ContentPageCreate, ContentPageRecord, ContentPageChanges, CountryModel,
+    CountryCreate, CountryRecord, CountryChanges, CreditConversionRateModel,
+    CreditConversionRateCreate, CreditConversionRateRecord, CreditConversionRateChanges,
+    CreditLedgerMismatchModel, CreditLedgerMismatchCreate, CreditLedgerMismatchRecord,
+    CreditLedgerMismatchChanges, CryptoNetworkModel, CryptoNetworkCreate, CryptoNetworkRecord,
+    CryptoNetworkChanges, DepositModel, DepositCreate, DepositRecord, DepositChanges,
+    FinancialApprovalModel, FinancialApprovalCreate, FinancialApprovalRecord,
+    FinancialApprovalChanges, IntroducerChangeModel, IntroducerChangeCreate, IntroducerChangeRecord,
+    IntroducerChangeChanges, KycSubmissionModel, KycSubmissionCreate, KycSubmissionRecord,
+    KycSubmissionChanges, ReferralCommissionModel, ReferralCommissionCreate,
+    ReferralCommissionRecord, ReferralCommissionChanges, ReferralCommissionRateModel,
+    ReferralCommissionRateCreate, ReferralCommissionRateRecord, ReferralCommissionRateChanges,
+    UserModel, UserCreate, UserRecord, UserChanges, UserCreditTransactionModel,
+    UserCreditTransactionCreate, UserCreditTransactionRecord, UserCreditTransactionChanges,
+    UserLimitModel, UserLimitCreate, UserLimitRecord, UserLimitChanges,
+    UserWithdrawalDestinationModel, UserWithdrawalDestinationCreate,
+    UserWithdrawalDestinationRecord, UserWithdrawalDestinationChanges, WithdrawalModel,
+    WithdrawalCreate, WithdrawalRecord, WithdrawalChanges,
 };
 use serde::de::DeserializeOwned;
 
@@ -104,6 +115,11 @@ impl ModelObserver for AppModelObserver {
             new_data,
             (AdminModel, AdminCreate, models::admin::creating),
             (BankModel, BankCreate, models::bank::creating),
+            (
+                BankStatementImportModel,
+                BankStatementImportCreate,
+                models::bank_statement_import::creating
+            ),
             (
                 CompanyBankAccountModel,
                 CompanyBankAccountCreate,
@@ -120,23 +136,51 @@ impl ModelObserver for AppModelObserver {
                 models::content_page::creating
             ),
             (CountryModel, CountryCreate, models::country::creating),
+            (
+                CreditConversionRateModel,
+                CreditConversionRateCreate,
+                models::credit_conversion_rate::creating
+            ),
+            (
+                CreditLedgerMismatchModel,
+                CreditLedgerMismatchCreate,
+                models::credit_ledger_mismatch::creating
+            ),
             (
                 CryptoNetworkModel,
                 CryptoNetworkCreate,
                 models::crypto_network::creating
             ),
             (DepositModel, DepositCreate, models::deposit::creating),
+            (FinancialApprovalModel, FinancialApprovalCreate, models::financial_approval::creating),
             (
                 IntroducerChangeModel,
                 IntroducerChangeCreate,
                 models::introducer_change::creating
             ),
+            (KycSubmissionModel, KycSubmissionCreate, models::kyc_submission::creating),
+            (
+                ReferralCommissionModel,
+                ReferralCommissionCreate,
+                models::referral_commission::creating
+            ),
+            (
+                ReferralCommissionRateModel,
+                ReferralCommissionRateCreate,
+                models::referral_commission_rate::creating
+            ),
             (UserModel, UserCreate, models::user::creating),
             (
                 UserCreditTransactionModel,
                 UserCreditTransactionCreate,
                 models::user_credit_transaction::creating
             ),
+            (UserLimitModel, UserLimitCreate, models::user_limit::creating),
+            (
+                UserWithdrawalDestinationModel,
+                UserWithdrawalDestinationCreate,
+                models::user_withdrawal_destination::creating
+            ),
             (
                 WithdrawalModel,
                 WithdrawalCreate,
@@ -155,6 +199,11 @@ impl ModelObserver for AppModelObserver {
             new_data,
             (AdminModel, AdminRecord, models::admin::created),
             (BankModel, BankRecord, models::bank::created),
+            (
+                BankStatementImportModel,
+                BankStatementImportRecord,
+                models::bank_statement_import::created
+            ),
             (
                 CompanyBankAccountModel,
                 CompanyBankAccountRecord,
@@ -167,23 +216,51 @@ impl ModelObserver for AppModelObserver {
             ),
             (ContentPageModel, ContentPageRecord, models::content_page::created),
             (CountryModel, CountryRecord, models::country::created),
+            (
+                CreditConversionRateModel,
+                CreditConversionRateRecord,
+                models::credit_conversion_rate::created
+            ),
+            (
+                CreditLedgerMismatchModel,
+                CreditLedgerMismatchRecord,
+                models::credit_ledger_mismatch::created
+            ),
             (
                 CryptoNetworkModel,
                 CryptoNetworkRecord,
                 models::crypto_network::created
             ),
             (DepositModel, DepositRecord, models::deposit::created),
+            (FinancialApprovalModel, FinancialApprovalRecord, models::financial_approval::created),
             (
                 IntroducerChangeModel,
                 IntroducerChangeRecord,
                 models::introducer_change::created
             ),
+            (KycSubmissionModel, KycSubmissionRecord, models::kyc_submission::created),
+            (
+                ReferralCommissionModel,
+                ReferralCommissionRecord,
+                models::referral_commission::created
+            ),
+            (
+                ReferralCommissionRateModel,
+                ReferralCommissionRateRecord,
+                models::referral_commission_rate::created
+            ),
             (UserModel, UserRecord, models::user::created),
             (
                 UserCreditTransactionModel,
                 UserCreditTransactionRecord,
                 models::user_credit_transaction::created
             ),
+            (UserLimitModel, UserLimitRecord, models::user_limit::created),
+            (
+                UserWithdrawalDestinationModel,
+                UserWithdrawalDestinationRecord,
+                models::user_withdrawal_destination::created
+            ),
             (WithdrawalModel, WithdrawalRecord, models::withdrawal::created),
         );
         let audit_result = audit::created(&self.db, self.admin_id, event, new_data).await;
@@ -203,6 +280,12 @@ impl ModelObserver for AppModelObserver {
             changes,
             (AdminModel, AdminRecord, AdminChanges, models::admin::updating),
             (BankModel, BankRecord, BankChanges, models::bank::updating),
+            (
+                BankStatementImportModel,
+                BankStatementImportRecord,
+                BankStatementImportChanges,
+                models::bank_statement_import::updating
+            ),
             (
                 CompanyBankAccountModel,
                 CompanyBankAccountRecord,
@@ -227,6 +310,18 @@ impl ModelObserver for AppModelObserver {
                 CountryChanges,
                 models::country::updating
             ),
+            (
+                CreditConversionRateModel,
+                CreditConversionRateRecord,
+                CreditConversionRateChanges,
+                models::credit_conversion_rate::updating
+            ),
+            (
+                CreditLedgerMismatchModel,
+                CreditLedgerMismatchRecord,
+                CreditLedgerMismatchChanges,
+                models::credit_ledger_mismatch::updating
+            ),
             (
                 CryptoNetworkModel,
                 CryptoNetworkRecord,
@@ -239,12 +334,36 @@ impl ModelObserver for AppModelObserver {
                 DepositChanges,
                 models::deposit::updating
             ),
+            (
+                FinancialApprovalModel,
+                FinancialApprovalRecord,
+                FinancialApprovalChanges,
+                models::financial_approval::updating
+            ),
             (
                 IntroducerChangeModel,
                 IntroducerChangeRecord,
                 IntroducerChangeChanges,
                 models::introducer_change::updating
             ),
+            (
+                KycSubmissionModel,
+                KycSubmissionRecord,
+                KycSubmissionChanges,
+                models::kyc_submission::updating
+            ),
+            (
+                ReferralCommissionModel,
+                ReferralCommissionRecord,
+                ReferralCommissionChanges,
+                models::referral_commission::updating
+            ),
+            (
+                ReferralCommissionRateModel,
+                ReferralCommissionRateRecord,
+                ReferralCommissionRateChanges,
+                models::referral_commission_rate::updating
+            ),
             (UserModel, UserRecord, UserChanges, models::user::updating),
             (
                 UserCreditTransactionModel,
@@ -252,6 +371,13 @@ impl ModelObserver for AppModelObserver {
                 UserCreditTransactionChanges,
                 models::user_credit_transaction::updating
             ),
+            (UserLimitModel, UserLimitRecord, UserLimitChanges, models::user_limit::updating),
+            (
+                UserWithdrawalDestinationModel,
+                UserWithdrawalDestinationRecord,
+                UserWithdrawalDestinationChanges,
+                models::user_withdrawal_destination::updating
+            ),
             (
                 WithdrawalModel,
                 WithdrawalRecord,
@@ -273,6 +399,11 @@ impl ModelObserver for AppModelObserver {
             new_data,
             (AdminModel, AdminRecord, models::admin::updated),
             (BankModel, BankRecord, models::bank::updated),
+            (
+                BankStatementImportModel,
+                BankStatementImportRecord,
+                models::bank_statement_import::updated
+            ),
             (
                 CompanyBankAccountModel,
                 CompanyBankAccountRecord,
@@ -285,23 +416,51 @@ impl ModelObserver for AppModelObserver {
             ),
             (ContentPageModel, ContentPageRecord, models::content_page::updated),
             (CountryModel, CountryRecord, models::country::updated),
+            (
+                CreditConversionRateModel,
+                CreditConversionRateRecord,
+                models::credit_conversion_rate::updated
+            ),
+            (
+                CreditLedgerMismatchModel,
+                CreditLedgerMismatchRecord,
+                models::credit_ledger_mismatch::updated
+            ),
             (
                 CryptoNetworkModel,
                 CryptoNetworkRecord,
                 models::crypto_network::updated
             ),
             (DepositModel, DepositRecord, models::deposit::updated),
+            (FinancialApprovalModel, FinancialApprovalRecord, models::financial_approval::updated),
             (
                 IntroducerChangeModel,
                 IntroducerChangeRecord,
                 models::introducer_change::updated
             ),
+            (KycSubmissionModel, KycSubmissionRecord, models::kyc_submission::updated),
+            (
+                ReferralCommissionModel,
+                ReferralCommissionRecord,
+                models::referral_commission::updated
+            ),
+            (
+                ReferralCommissionRateModel,
+                ReferralCommissionRateRecord,
+                models::referral_commission_rate::updated
+            ),
             (UserModel, UserRecord, models::user::updated),
             (
                 UserCreditTransactionModel,
                 UserCreditTransactionRecord,
                 models::user_credit_transaction::updated
             ),
+            (UserLimitModel, UserLimitRecord, models::user_limit::updated),
+            (
+                UserWithdrawalDestinationModel,
+                UserWithdrawalDestinationRecord,
+                models::user_withdrawal_destination::updated
+            ),
             (WithdrawalModel, WithdrawalRecord, models::withdrawal::updated),
         );
         let audit_result = audit::updated(&self.db, self.admin_id, event, old_data, new_data).await;
@@ -319,6 +478,11 @@ impl ModelObserver for AppModelObserver {
             old_data,
             (AdminModel, AdminRecord, models::admin::deleting),
             (BankModel, BankRecord, models::bank::deleting),
+            (
+                BankStatementImportModel,
+                BankStatementImportRecord,
+                models::bank_statement_import::deleting
+            ),
             (
                 CompanyBankAccountModel,
                 CompanyBankAccountRecord,
@@ -331,23 +495,51 @@ impl ModelObserver for AppModelObserver {
             ),
             (ContentPageModel, ContentPageRecord, models::content_page::deleting),
             (CountryModel, CountryRecord, models::country::deleting),
+            (
+                CreditConversionRateModel,
+                CreditConversionRateRecord,
+                models::credit_conversion_rate::deleting
+            ),
+            (
+                CreditLedgerMismatchModel,
+                CreditLedgerMismatchRecord,
+                models::credit_ledger_mismatch::deleting
+            ),
             (
                 CryptoNetworkModel,
                 CryptoNetworkRecord,
                 models::crypto_network::deleting
             ),
             (DepositModel, DepositRecord, models::deposit::deleting),
+            (FinancialApprovalModel, FinancialApprovalRecord, models::financial_approval::deleting),
             (
                 IntroducerChangeModel,
                 IntroducerChangeRecord,
                 models::introducer_change::deleting
             ),
+            (KycSubmissionModel, KycSubmissionRecord, models::kyc_submission::deleting),
+            (
+                ReferralCommissionModel,
+                ReferralCommissionRecord,
+                models::referral_commission::deleting
+            ),
+            (
+                ReferralCommissionRateModel,
+                ReferralCommissionRateRecord,
+                models::referral_commission_rate::deleting
+            ),
             (UserModel, UserRecord, models::user::deleting),
             (
                 UserCreditTransactionModel,
                 UserCreditTransactionRecord,
                 models::user_credit_transaction::deleting
             ),
+            (UserLimitModel, UserLimitRecord, models::user_limit::deleting),
+            (
+                UserWithdrawalDestinationModel,
+                UserWithdrawalDestinationRecord,
+                models::user_withdrawal_destination::deleting
+            ),
             (WithdrawalModel, WithdrawalRecord, models::withdrawal::deleting),
         )
     }
@@ -362,6 +554,11 @@ impl ModelObserver for AppModelObserver {
             old_data,
             (AdminModel, AdminRecord, models::admin::deleted),
             (BankModel, BankRecord, models::bank::deleted),
+            (
+                BankStatementImportModel,
+                BankStatementImportRecord,
+                models::bank_statement_import::deleted
+            ),
             (
                 CompanyBankAccountModel,
                 CompanyBankAccountRecord,
@@ -374,23 +571,51 @@ impl ModelObserver for AppModelObserver {
             ),
             (ContentPageModel, ContentPageRecord, models::content_page::deleted),
             (CountryModel, CountryRecord, models::country::deleted),
+            (
+                CreditConversionRateModel,
+                CreditConversionRateRecord,
+                models::credit_conversion_rate::deleted
+            ),
+            (
+                CreditLedgerMismatchModel,
+                CreditLedgerMismatchRecord,
+                models::credit_ledger_mismatch::deleted
+            ),
             (
                 CryptoNetworkModel,
                 CryptoNetworkRecord,
                 models::crypto_network::deleted
             ),
             (DepositModel, DepositRecord, models::deposit::deleted),
+            (FinancialApprovalModel, FinancialApprovalRecord, models::financial_approval::deleted),
             (
                 IntroducerChangeModel,
                 IntroducerChangeRecord,
                 models::introducer_change::deleted
             ),
+            (KycSubmissionModel, KycSubmissionRecord, models::kyc_submission::deleted),
+            (
+                ReferralCommissionModel,
+                ReferralCommissionRecord,
+                models::referral_commission::deleted
+            ),
+            (
+                ReferralCommissionRateModel,
+                ReferralCommissionRateRecord,
+                models::referral_commission_rate::deleted
+            ),
             (UserModel, UserRecord, models::user::deleted),
             (
                 UserCreditTransactionModel,
                 UserCreditTransactionRecord,
                 models::user_credit_transaction::deleted
             ),
+            (UserLimitModel, UserLimitRecord, models::user_limit::deleted),
+            (
+                UserWithdrawalDestinationModel,
+                UserWithdrawalDestinationRecord,
+                models::user_withdrawal_destination::deleted
+            ),
             (WithdrawalModel, WithdrawalRecord, models::withdrawal::deleted),
         );
         let audit_result = audit::deleted(&self.db, self.admin_id, event, old_data).await;
@@ -0,0 +1,37 @@
+use core_db::common::model_observer::ModelEvent;
+use generated::models::{BankStatementImportCreate, BankStatementImportRecord, BankStatementImportChanges};
+
+pub async fn creating(
+    _event: &ModelEvent,
+    _new_data: &BankStatementImportCreate,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn created(_event: &ModelEvent, _row: &BankStatementImportRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updating(
+    _event: &ModelEvent,
+    _old_row: &BankStatementImportRecord,
+    _changes: &BankStatementImportChanges,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updated(
+    _event: &ModelEvent,
+    _old_row: &BankStatementImportRecord,
+    _new_row: &BankStatementImportRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleting(_event: &ModelEvent, _row: &BankStatementImportRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleted(_event: &ModelEvent, _row: &BankStatementImportRecord) -> anyhow::Result<()> {
+    Ok(())
+}
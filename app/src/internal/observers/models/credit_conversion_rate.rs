@@ -0,0 +1,37 @@
+use core_db::common::model_observer::ModelEvent;
+use generated::models::{CreditConversionRateCreate, CreditConversionRateRecord, CreditConversionRateChanges};
+
+pub async fn creating(
+    _event: &ModelEvent,
+    _new_data: &CreditConversionRateCreate,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn created(_event: &ModelEvent, _row: &CreditConversionRateRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updating(
+    _event: &ModelEvent,
+    _old_row: &CreditConversionRateRecord,
+    _changes: &CreditConversionRateChanges,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updated(
+    _event: &ModelEvent,
+    _old_row: &CreditConversionRateRecord,
+    _new_row: &CreditConversionRateRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleting(_event: &ModelEvent, _row: &CreditConversionRateRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleted(_event: &ModelEvent, _row: &CreditConversionRateRecord) -> anyhow::Result<()> {
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use core_db::common::model_observer::ModelEvent;
+use generated::models::{CreditLedgerMismatchCreate, CreditLedgerMismatchRecord, CreditLedgerMismatchChanges};
+
+pub async fn creating(
+    _event: &ModelEvent,
+    _new_data: &CreditLedgerMismatchCreate,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn created(_event: &ModelEvent, _row: &CreditLedgerMismatchRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updating(
+    _event: &ModelEvent,
+    _old_row: &CreditLedgerMismatchRecord,
+    _changes: &CreditLedgerMismatchChanges,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updated(
+    _event: &ModelEvent,
+    _old_row: &CreditLedgerMismatchRecord,
+    _new_row: &CreditLedgerMismatchRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleting(_event: &ModelEvent, _row: &CreditLedgerMismatchRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleted(_event: &ModelEvent, _row: &CreditLedgerMismatchRecord) -> anyhow::Result<()> {
+    Ok(())
+}
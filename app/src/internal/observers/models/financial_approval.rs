@@ -0,0 +1,37 @@
+use core_db::common::model_observer::ModelEvent;
+use generated::models::{FinancialApprovalCreate, FinancialApprovalRecord, FinancialApprovalChanges};
+
+pub async fn creating(
+    _event: &ModelEvent,
+    _new_data: &FinancialApprovalCreate,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn created(_event: &ModelEvent, _row: &FinancialApprovalRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updating(
+    _event: &ModelEvent,
+    _old_row: &FinancialApprovalRecord,
+    _changes: &FinancialApprovalChanges,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updated(
+    _event: &ModelEvent,
+    _old_row: &FinancialApprovalRecord,
+    _new_row: &FinancialApprovalRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleting(_event: &ModelEvent, _row: &FinancialApprovalRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleted(_event: &ModelEvent, _row: &FinancialApprovalRecord) -> anyhow::Result<()> {
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use core_db::common::model_observer::ModelEvent;
+use generated::models::{KycSubmissionCreate, KycSubmissionRecord, KycSubmissionChanges};
+
+pub async fn creating(
+    _event: &ModelEvent,
+    _new_data: &KycSubmissionCreate,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn created(_event: &ModelEvent, _row: &KycSubmissionRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updating(
+    _event: &ModelEvent,
+    _old_row: &KycSubmissionRecord,
+    _changes: &KycSubmissionChanges,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updated(
+    _event: &ModelEvent,
+    _old_row: &KycSubmissionRecord,
+    _new_row: &KycSubmissionRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleting(_event: &ModelEvent, _row: &KycSubmissionRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleted(_event: &ModelEvent, _row: &KycSubmissionRecord) -> anyhow::Result<()> {
+    Ok(())
+}
@@ -1,12 +1,21 @@
 pub mod admin;
 pub mod bank;
+pub mod bank_statement_import;
 pub mod company_bank_account;
 pub mod company_crypto_account;
 pub mod content_page;
 pub mod country;
+pub mod credit_conversion_rate;
+pub mod credit_ledger_mismatch;
 pub mod crypto_network;
 pub mod deposit;
+pub mod financial_approval;
 pub mod introducer_change;
+pub mod kyc_submission;
+pub mod referral_commission;
+pub mod referral_commission_rate;
 pub mod user;
 pub mod user_credit_transaction;
+pub mod user_limit;
+pub mod user_withdrawal_destination;
 pub mod withdrawal;
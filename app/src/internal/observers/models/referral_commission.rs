@@ -0,0 +1,37 @@
+use core_db::common::model_observer::ModelEvent;
+use generated::models::{ReferralCommissionCreate, ReferralCommissionRecord, ReferralCommissionChanges};
+
+pub async fn creating(
+    _event: &ModelEvent,
+    _new_data: &ReferralCommissionCreate,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn created(_event: &ModelEvent, _row: &ReferralCommissionRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updating(
+    _event: &ModelEvent,
+    _old_row: &ReferralCommissionRecord,
+    _changes: &ReferralCommissionChanges,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updated(
+    _event: &ModelEvent,
+    _old_row: &ReferralCommissionRecord,
+    _new_row: &ReferralCommissionRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleting(_event: &ModelEvent, _row: &ReferralCommissionRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleted(_event: &ModelEvent, _row: &ReferralCommissionRecord) -> anyhow::Result<()> {
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use core_db::common::model_observer::ModelEvent;
+use generated::models::{ReferralCommissionRateCreate, ReferralCommissionRateRecord, ReferralCommissionRateChanges};
+
+pub async fn creating(
+    _event: &ModelEvent,
+    _new_data: &ReferralCommissionRateCreate,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn created(_event: &ModelEvent, _row: &ReferralCommissionRateRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updating(
+    _event: &ModelEvent,
+    _old_row: &ReferralCommissionRateRecord,
+    _changes: &ReferralCommissionRateChanges,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updated(
+    _event: &ModelEvent,
+    _old_row: &ReferralCommissionRateRecord,
+    _new_row: &ReferralCommissionRateRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleting(_event: &ModelEvent, _row: &ReferralCommissionRateRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleted(_event: &ModelEvent, _row: &ReferralCommissionRateRecord) -> anyhow::Result<()> {
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use core_db::common::model_observer::ModelEvent;
+use generated::models::{UserLimitCreate, UserLimitRecord, UserLimitChanges};
+
+pub async fn creating(
+    _event: &ModelEvent,
+    _new_data: &UserLimitCreate,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn created(_event: &ModelEvent, _row: &UserLimitRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updating(
+    _event: &ModelEvent,
+    _old_row: &UserLimitRecord,
+    _changes: &UserLimitChanges,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updated(
+    _event: &ModelEvent,
+    _old_row: &UserLimitRecord,
+    _new_row: &UserLimitRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleting(_event: &ModelEvent, _row: &UserLimitRecord) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleted(_event: &ModelEvent, _row: &UserLimitRecord) -> anyhow::Result<()> {
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use core_db::common::model_observer::ModelEvent;
+use generated::models::{
+    UserWithdrawalDestinationCreate, UserWithdrawalDestinationRecord,
+    UserWithdrawalDestinationChanges,
+};
+
+pub async fn creating(
+    _event: &ModelEvent,
+    _new_data: &UserWithdrawalDestinationCreate,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn created(
+    _event: &ModelEvent,
+    _row: &UserWithdrawalDestinationRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updating(
+    _event: &ModelEvent,
+    _old_row: &UserWithdrawalDestinationRecord,
+    _changes: &UserWithdrawalDestinationChanges,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn updated(
+    _event: &ModelEvent,
+    _old_row: &UserWithdrawalDestinationRecord,
+    _new_row: &UserWithdrawalDestinationRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleting(
+    _event: &ModelEvent,
+    _row: &UserWithdrawalDestinationRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
+
+pub async fn deleted(
+    _event: &ModelEvent,
+    _row: &UserWithdrawalDestinationRecord,
+) -> anyhow::Result<()> {
+    Ok(())
+}
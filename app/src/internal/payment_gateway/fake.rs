@@ -0,0 +1,84 @@
+//! A provider that runs inside the app, for development and tests. Its
+//! checkout URL is a local endpoint that, when posted to, signs a callback
+//! with the configured secret and feeds it through the normal callback
+//! handling, so the whole flow works offline.
+
+use axum::http::{HeaderMap, HeaderName};
+use core_i18n::t;
+use core_web::error::AppError;
+use time::OffsetDateTime;
+
+use super::{CheckoutRequest, CheckoutSession, GatewayCallback, PaymentGateway};
+use crate::internal::config::payment_gateway::PaymentGatewayConfig;
+
+pub const CODE: &str = "fake";
+pub const SIGNATURE_HEADER: HeaderName = HeaderName::from_static("x-fake-gateway-signature");
+
+pub struct FakeGateway {
+    secret: String,
+    tolerance_secs: u64,
+    public_base_url: String,
+}
+
+impl FakeGateway {
+    pub fn new(config: &PaymentGatewayConfig) -> Self {
+        Self {
+            secret: config.webhook_secret.clone(),
+            tolerance_secs: config.signature_tolerance_secs,
+            public_base_url: config.public_base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Signature header value and body of the callback a real provider would
+    /// send for `callback`.
+    pub fn signed_callback(
+        &self,
+        callback: &GatewayCallback,
+    ) -> Result<(String, Vec<u8>), AppError> {
+        let body = serde_json::to_vec(callback)
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("Failed to encode callback: {e}")))?;
+        let timestamp = OffsetDateTime::now_utc().unix_timestamp();
+        let signature = super::sign(&self.secret, timestamp, &body);
+        Ok((signature, body))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentGateway for FakeGateway {
+    fn code(&self) -> &'static str {
+        CODE
+    }
+
+    async fn create_checkout(
+        &self,
+        request: &CheckoutRequest,
+    ) -> Result<CheckoutSession, AppError> {
+        let session_id = format!("fake_{}_{}", request.deposit_id, nanoid::nanoid!(12));
+        Ok(CheckoutSession {
+            checkout_url: format!(
+                "{}/api/v1/gateway/fake/checkout/{session_id}",
+                self.public_base_url
+            ),
+            session_id,
+        })
+    }
+
+    fn verify_signature(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), AppError> {
+        let header = headers
+            .get(&SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .ok_or_else(|| AppError::Unauthorized(t("Missing webhook signature")))?;
+        super::verify(
+            &self.secret,
+            header,
+            body,
+            self.tolerance_secs,
+            OffsetDateTime::now_utc().unix_timestamp(),
+        )
+    }
+
+    fn parse_callback(&self, body: &[u8]) -> Result<GatewayCallback, AppError> {
+        serde_json::from_slice(body)
+            .map_err(|_| AppError::BadRequest(t("Invalid callback payload")))
+    }
+}
@@ -0,0 +1,148 @@
+//! Payment gateways that take deposits through a hosted checkout. A provider
+//! opens a checkout session for a deposit and later calls back with the
+//! outcome; callbacks are handled by `workflows::gateway_callback`.
+
+pub mod fake;
+
+use std::sync::Arc;
+
+use axum::http::HeaderMap;
+use core_i18n::t;
+use core_web::error::AppError;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::internal::config::payment_gateway::PaymentGatewayConfig;
+
+/// What the provider needs to open a checkout for a deposit.
+#[derive(Debug, Clone)]
+pub struct CheckoutRequest {
+    pub deposit_id: i64,
+    pub amount: Decimal,
+    pub description: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckoutSession {
+    pub session_id: String,
+    /// Where the user is sent to pay
+    pub checkout_url: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GatewayPaymentStatus {
+    Paid,
+    Failed,
+    Pending,
+}
+
+/// A verified callback, in provider-neutral terms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GatewayCallback {
+    pub event_id: String,
+    pub session_id: String,
+    pub status: GatewayPaymentStatus,
+    pub amount: Decimal,
+}
+
+#[async_trait::async_trait]
+pub trait PaymentGateway: Send + Sync {
+    /// Stored on the deposit and used in the callback path
+    fn code(&self) -> &'static str;
+
+    async fn create_checkout(&self, request: &CheckoutRequest) -> Result<CheckoutSession, AppError>;
+
+    /// Checks the callback was signed by the provider. Runs on the raw body,
+    /// before anything is parsed.
+    fn verify_signature(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), AppError>;
+
+    fn parse_callback(&self, body: &[u8]) -> Result<GatewayCallback, AppError>;
+}
+
+/// The provider named in `[payment_gateway]`, if gateway deposits are on.
+pub fn from_config(config: &PaymentGatewayConfig) -> Option<Arc<dyn PaymentGateway>> {
+    match config.provider.as_str() {
+        fake::CODE => Some(Arc::new(fake::FakeGateway::new(config))),
+        _ => None,
+    }
+}
+
+/// Signature header value for `body` sent at `timestamp`:
+/// `t=<unix seconds>,v1=<hex hmac of "<t>.<body>">`.
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    format!("t={timestamp},v1={}", signature_hex(secret, timestamp, body))
+}
+
+/// Verifies a header made by [`sign`]. Signatures older or newer than
+/// `tolerance_secs` are refused so a captured callback can't be replayed
+/// later.
+pub fn verify(
+    secret: &str,
+    header: &str,
+    body: &[u8],
+    tolerance_secs: u64,
+    now: i64,
+) -> Result<(), AppError> {
+    let invalid = || AppError::Unauthorized(t("Invalid webhook signature"));
+
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+            Some(("v1", value)) => signatures.push(value),
+            _ => {}
+        }
+    }
+    let timestamp = timestamp.ok_or_else(invalid)?;
+    if now.abs_diff(timestamp) > tolerance_secs {
+        return Err(invalid());
+    }
+
+    // verify_slice compares in constant time
+    let verified = signatures.iter().any(|signature| {
+        hex::decode(signature).is_ok_and(|bytes| {
+            signature_mac(secret, timestamp, body)
+                .verify_slice(&bytes)
+                .is_ok()
+        })
+    });
+    if verified {
+        Ok(())
+    } else {
+        Err(invalid())
+    }
+}
+
+fn signature_hex(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    hex::encode(signature_mac(secret, timestamp, body).finalize().into_bytes())
+}
+
+fn signature_mac(secret: &str, timestamp: i64, body: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(format!("{timestamp}.").as_bytes());
+    mac.update(body);
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign, verify};
+
+    #[test]
+    fn verify_rejects_tampered_and_stale_callbacks() {
+        let secret = "test-secret-0123456789";
+        let body = br#"{"status":"paid"}"#;
+        let header = sign(secret, 1_700_000_000, body);
+
+        assert!(verify(secret, &header, body, 300, 1_700_000_100).is_ok());
+        assert!(verify(secret, &header, br#"{"status":"failed"}"#, 300, 1_700_000_100).is_err());
+        assert!(verify("another-secret-0123456", &header, body, 300, 1_700_000_100).is_err());
+        assert!(verify(secret, &header, body, 300, 1_700_001_000).is_err());
+        assert!(verify(secret, "v1=deadbeef", body, 300, 1_700_000_100).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+//! A provider that pays nothing out and settles every payout the same way,
+//! for development and tests. It still reports the exchanges a real
+//! provider would make, so `http_client_log` can be checked.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::{
+    HttpExchange, PayoutAccepted, PayoutProvider, PayoutRequest, PayoutStatus, ProviderCall,
+};
+
+pub const CODE: &str = "mock";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MockOutcome {
+    #[default]
+    Paid,
+    Failed,
+    /// Never settles, for trying the manual path
+    Pending,
+}
+
+pub struct MockPayoutProvider {
+    outcome: MockOutcome,
+}
+
+impl MockPayoutProvider {
+    pub fn new(outcome: MockOutcome) -> Self {
+        Self { outcome }
+    }
+
+    fn status_for_outcome(&self) -> PayoutStatus {
+        match self.outcome {
+            MockOutcome::Paid => PayoutStatus::Paid,
+            MockOutcome::Failed => PayoutStatus::Failed("Declined by mock provider".to_string()),
+            MockOutcome::Pending => PayoutStatus::Pending,
+        }
+    }
+}
+
+fn exchange(
+    method: &str,
+    url: String,
+    request_body: Option<String>,
+    response: serde_json::Value,
+) -> HttpExchange {
+    HttpExchange {
+        method: method.to_string(),
+        url,
+        request_headers: Some(serde_json::json!({ "content-type": "application/json" })),
+        request_body,
+        response_status: Some(200),
+        response_headers: Some(serde_json::json!({ "content-type": "application/json" })),
+        response_body: Some(response.to_string()),
+        duration: Duration::ZERO,
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutProvider for MockPayoutProvider {
+    fn code(&self) -> &'static str {
+        CODE
+    }
+
+    /// Accepts every payout; the outcome shows up on the first status check.
+    async fn submit(&self, request: &PayoutRequest) -> ProviderCall<PayoutAccepted> {
+        let reference = format!("mock_{}", request.withdrawal_id);
+        let response = serde_json::json!({ "reference": reference, "status": "pending" });
+        ProviderCall {
+            exchanges: vec![exchange(
+                "POST",
+                "mock://payouts".to_string(),
+                serde_json::to_string(request).ok(),
+                response,
+            )],
+            result: Ok(PayoutAccepted {
+                reference,
+                status: PayoutStatus::Pending,
+            }),
+        }
+    }
+
+    async fn status(&self, reference: &str) -> ProviderCall<PayoutStatus> {
+        let status = self.status_for_outcome();
+        let response = serde_json::json!({
+            "reference": reference,
+            "status": match &status {
+                PayoutStatus::Pending => "pending",
+                PayoutStatus::Paid => "paid",
+                PayoutStatus::Failed(_) => "failed",
+            },
+        });
+        ProviderCall {
+            exchanges: vec![exchange("GET", format!("mock://payouts/{reference}"), None, response)],
+            result: Ok(status),
+        }
+    }
+}
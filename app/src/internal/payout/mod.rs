@@ -0,0 +1,78 @@
+//! Payout providers that send withdrawals out. A provider accepts a payout
+//! when a withdrawal is processed and reports its final status later; the
+//! withdrawal is settled by `workflows::payout`.
+
+pub mod mock;
+
+use std::{sync::Arc, time::Duration};
+
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use crate::internal::config::payout::PayoutConfig;
+
+/// Where and how much to pay out for a withdrawal.
+#[derive(Debug, Clone, Serialize)]
+pub struct PayoutRequest {
+    pub withdrawal_id: i64,
+    /// What the user receives, after the fee
+    pub amount: Decimal,
+    pub bank_code: Option<String>,
+    pub bank_account_name: Option<String>,
+    pub bank_account_number: Option<String>,
+    pub crypto_network: Option<String>,
+    pub crypto_wallet_address: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayoutStatus {
+    Pending,
+    Paid,
+    Failed(String),
+}
+
+/// The provider's answer to a submission.
+#[derive(Debug, Clone)]
+pub struct PayoutAccepted {
+    /// The provider's id for the payout, used to ask for its status
+    pub reference: String,
+    pub status: PayoutStatus,
+}
+
+/// One request a provider made and what came back, for `http_client_log`.
+#[derive(Debug, Clone)]
+pub struct HttpExchange {
+    pub method: String,
+    pub url: String,
+    pub request_headers: Option<serde_json::Value>,
+    pub request_body: Option<String>,
+    pub response_status: Option<i32>,
+    pub response_headers: Option<serde_json::Value>,
+    pub response_body: Option<String>,
+    pub duration: Duration,
+}
+
+/// A provider call's result together with every request it made, so the
+/// exchanges are logged whether or not the call succeeded.
+pub struct ProviderCall<T> {
+    pub result: anyhow::Result<T>,
+    pub exchanges: Vec<HttpExchange>,
+}
+
+#[async_trait::async_trait]
+pub trait PayoutProvider: Send + Sync {
+    /// Stored on the withdrawal as `payout_provider`
+    fn code(&self) -> &'static str;
+
+    async fn submit(&self, request: &PayoutRequest) -> ProviderCall<PayoutAccepted>;
+
+    async fn status(&self, reference: &str) -> ProviderCall<PayoutStatus>;
+}
+
+/// The provider named in `[payout]`, if payouts are automated.
+pub fn from_config(config: &PayoutConfig) -> Option<Arc<dyn PayoutProvider>> {
+    match config.provider.as_str() {
+        mock::CODE => Some(Arc::new(mock::MockPayoutProvider::new(config.mock_outcome))),
+        _ => None,
+    }
+}
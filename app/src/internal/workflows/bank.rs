@@ -1,14 +1,20 @@
 use core_db::common::sql::{DbConn, Op};
 use core_db::platform::attachments::types::AttachmentInput;
 use core_i18n::t;
-use core_web::error::AppError;
+use core_web::{error::AppError, extract::validation::transform_validation_errors};
 use generated::localized;
-use generated::models::{BankCol, BankModel, BankRecord, CountryCol, CountryModel};
+use generated::models::{
+    BankAccountNumberFormat, BankCol, BankModel, BankRecord, CountryCol, CountryModel,
+};
 use time::OffsetDateTime;
+use validator::ValidationErrors;
 
 use crate::{
     contracts::api::v1::admin::bank::AdminBankInput,
     internal::api::state::AppApiState,
+    validation::bank_account::{
+        normalize_bank_account_number, validate_bank_account_number, BankAccountNumberRules,
+    },
 };
 
 pub async fn detail(state: &AppApiState, id: i64) -> Result<BankRecord, AppError> {
@@ -18,6 +24,25 @@ pub async fn detail(state: &AppApiState, id: i64) -> Result<BankRecord, AppError
         .ok_or_else(|| AppError::NotFound(t("Bank not found")))
 }
 
+/// Normalizes `number` and rejects it under `field` unless it follows the
+/// bank's account number rules. Returns the number to store.
+pub fn ensure_account_number(
+    bank: &BankRecord,
+    field: &'static str,
+    number: &str,
+) -> Result<String, AppError> {
+    let number = normalize_bank_account_number(bank.account_number_format, number);
+    if let Err(error) = validate_bank_account_number(&BankAccountNumberRules::of(bank), &number) {
+        let mut errors = ValidationErrors::new();
+        errors.add(field, error);
+        return Err(AppError::Validation {
+            message: t("Validation failed"),
+            errors: transform_validation_errors(errors),
+        });
+    }
+    Ok(number)
+}
+
 pub async fn create(
     state: &AppApiState,
     req: AdminBankInput,
@@ -45,6 +70,13 @@ pub async fn create(
         .set(BankCol::NAME, req.name)?
         .set(BankCol::CODE, req.code)?
         .set(BankCol::STATUS, req.status)?
+        .set(
+            BankCol::ACCOUNT_NUMBER_FORMAT,
+            req.account_number_format.unwrap_or(BankAccountNumberFormat::Any),
+        )?
+        .set(BankCol::ACCOUNT_NUMBER_MIN_LENGTH, req.account_number_min_length)?
+        .set(BankCol::ACCOUNT_NUMBER_MAX_LENGTH, req.account_number_max_length)?
+        .set(BankCol::ACCOUNT_NUMBER_PATTERN, req.account_number_pattern)?
         .set(BankCol::SORT_ORDER, req.sort_order.unwrap_or(0))?
         .set(BankCol::CREATED_AT, now)?
         .set(BankCol::UPDATED_AT, now)?
@@ -92,6 +124,13 @@ pub async fn update(
         .assign(BankCol::NAME, req.name)?
         .assign(BankCol::CODE, req.code)?
         .assign(BankCol::STATUS, req.status)?
+        .assign(
+            BankCol::ACCOUNT_NUMBER_FORMAT,
+            req.account_number_format.unwrap_or(BankAccountNumberFormat::Any),
+        )?
+        .assign(BankCol::ACCOUNT_NUMBER_MIN_LENGTH, req.account_number_min_length)?
+        .assign(BankCol::ACCOUNT_NUMBER_MAX_LENGTH, req.account_number_max_length)?
+        .assign(BankCol::ACCOUNT_NUMBER_PATTERN, req.account_number_pattern)?
         .assign(BankCol::SORT_ORDER, req.sort_order.unwrap_or(0))?
         .assign(BankCol::UPDATED_AT, OffsetDateTime::now_utc())?
         .save()
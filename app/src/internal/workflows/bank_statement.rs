@@ -0,0 +1,454 @@
+use core_db::common::sql::{DbConn, Op, OrderDir};
+use core_i18n::{t, t_args};
+use core_web::error::AppError;
+use generated::models::{
+    BankModel, BankStatementImportCol, BankStatementImportModel, BankStatementImportRecord,
+    BankStatementLineCol, BankStatementLineModel, BankStatementLineRecord,
+    BankStatementLineStatus, CompanyBankAccountModel, DepositCol, DepositModel, DepositRecord,
+    DepositReviewAction, DepositStatus,
+};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{
+    contracts::api::v1::admin::deposit::AdminDepositReviewInput,
+    internal::{
+        api::state::AppApiState,
+        config::bank_statement::StatementFormat,
+        workflows::{deposit, financial_approval::ApprovalOutcome},
+    },
+};
+
+pub struct BankStatementDetail {
+    pub statement: BankStatementImportRecord,
+    pub lines: Vec<BankStatementLineRecord>,
+}
+
+/// A credit line read from the statement, before matching.
+#[derive(Debug)]
+struct StatementLine {
+    line_number: i32,
+    reference: String,
+    amount: Decimal,
+    account_number: Option<String>,
+    transaction_date: Option<String>,
+    raw: serde_json::Value,
+}
+
+struct LineMatch {
+    status: BankStatementLineStatus,
+    deposit_id: Option<i64>,
+    note: Option<String>,
+}
+
+pub async fn list(state: &AppApiState) -> Result<Vec<BankStatementImportRecord>, AppError> {
+    BankStatementImportModel::query(DbConn::pool(&state.db))
+        .order_by(BankStatementImportCol::CREATED_AT, OrderDir::Desc)
+        .limit(50)
+        .all()
+        .await
+        .map_err(AppError::from)
+}
+
+pub async fn detail(
+    state: &AppApiState,
+    statement_id: i64,
+) -> Result<BankStatementDetail, AppError> {
+    let statement = BankStatementImportModel::find(DbConn::pool(&state.db), statement_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Bank statement not found")))?;
+    let lines = BankStatementLineModel::query(DbConn::pool(&state.db))
+        .where_col(BankStatementLineCol::IMPORT_ID, Op::Eq, statement_id)
+        .order_by(BankStatementLineCol::LINE_NUMBER, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(BankStatementDetail { statement, lines })
+}
+
+/// Reads a statement CSV for a company bank account and matches each credit
+/// line to a pending deposit into that account by reference code and amount.
+/// Every credit line is kept, matched or not; matched lines wait for an
+/// admin to approve the deposit through [`approve_line`].
+pub async fn import(
+    state: &AppApiState,
+    admin_id: i64,
+    company_bank_account_id: i64,
+    file_name: Option<String>,
+    content: &[u8],
+) -> Result<BankStatementDetail, AppError> {
+    let account = CompanyBankAccountModel::find(DbConn::pool(&state.db), company_bank_account_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Company bank account not found")))?;
+    let bank = BankModel::find(DbConn::pool(&state.db), account.bank_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Bank not found")))?;
+    let format = state
+        .bank_statement_config
+        .format_for(bank.code.as_deref())
+        .ok_or_else(|| {
+            AppError::BadRequest(t("No statement format is configured for this bank"))
+        })?;
+
+    let text = std::str::from_utf8(content)
+        .map_err(|_| AppError::BadRequest(t("Statement must be a UTF-8 CSV file")))?;
+    let statement_lines = read_lines(text, format)?;
+
+    let mut pending = DepositModel::query(DbConn::pool(&state.db))
+        .where_col(
+            DepositCol::COMPANY_BANK_ACCOUNT_ID,
+            Op::Eq,
+            Some(company_bank_account_id),
+        )
+        .where_col(DepositCol::STATUS, Op::Eq, DepositStatus::Pending)
+        .order_by(DepositCol::CREATED_AT, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    let now = OffsetDateTime::now_utc();
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    let statement = BankStatementImportModel::create(conn.clone())
+        .set(BankStatementImportCol::COMPANY_BANK_ACCOUNT_ID, company_bank_account_id)?
+        .set(BankStatementImportCol::FILE_NAME, file_name)?
+        .set(BankStatementImportCol::LINE_COUNT, statement_lines.len() as i32)?
+        .set(BankStatementImportCol::MATCHED_COUNT, 0)?
+        .set(BankStatementImportCol::UPLOADED_BY, admin_id)?
+        .set(BankStatementImportCol::CREATED_AT, now)?
+        .set(BankStatementImportCol::UPDATED_AT, now)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    let mut matched = 0;
+    for line in statement_lines {
+        let found = match_line(&line, &account.account_number, &mut pending);
+        if found.status == BankStatementLineStatus::Matched {
+            matched += 1;
+        }
+        BankStatementLineModel::create(conn.clone())
+            .set(BankStatementLineCol::IMPORT_ID, statement.id)?
+            .set(BankStatementLineCol::LINE_NUMBER, line.line_number)?
+            .set(BankStatementLineCol::REFERENCE, line.reference)?
+            .set(BankStatementLineCol::AMOUNT, Some(line.amount))?
+            .set(BankStatementLineCol::ACCOUNT_NUMBER, line.account_number)?
+            .set(BankStatementLineCol::TRANSACTION_DATE, line.transaction_date)?
+            .set(BankStatementLineCol::RAW, line.raw)?
+            .set(BankStatementLineCol::STATUS, found.status)?
+            .set(BankStatementLineCol::DEPOSIT_ID, found.deposit_id)?
+            .set(BankStatementLineCol::NOTE, found.note)?
+            .set(BankStatementLineCol::CREATED_AT, now)?
+            .set(BankStatementLineCol::UPDATED_AT, now)?
+            .save()
+            .await
+            .map_err(AppError::from)?;
+    }
+
+    BankStatementImportModel::query(conn.clone())
+        .where_col(BankStatementImportCol::ID, Op::Eq, statement.id)
+        .patch()
+        .assign(BankStatementImportCol::MATCHED_COUNT, matched)
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    detail(state, statement.id).await
+}
+
+/// Approves the deposit a statement line was matched to, as a normal review
+/// by the admin. An approval that needs a second admin is only proposed and
+/// the line stays matched until then.
+pub async fn approve_line(
+    state: &AppApiState,
+    admin_id: i64,
+    line_id: i64,
+) -> Result<(BankStatementLineRecord, ApprovalOutcome<DepositRecord>), AppError> {
+    let line = find_line(state, line_id).await?;
+    let deposit_id = match (line.status, line.deposit_id) {
+        (BankStatementLineStatus::Matched, Some(deposit_id)) => deposit_id,
+        _ => {
+            return Err(AppError::BadRequest(t(
+                "Statement line is not matched to a pending deposit",
+            )))
+        }
+    };
+
+    let outcome = deposit::review_deposit(
+        state,
+        admin_id,
+        deposit_id,
+        AdminDepositReviewInput {
+            action: DepositReviewAction::Approve,
+            admin_remark: Some(format!(
+                "Matched to bank statement #{} line {}",
+                line.import_id, line.line_number
+            )),
+        },
+    )
+    .await?;
+
+    if matches!(outcome, ApprovalOutcome::Applied(_)) {
+        BankStatementLineModel::query(DbConn::pool(&state.db))
+            .where_col(BankStatementLineCol::ID, Op::Eq, line_id)
+            .where_col(
+                BankStatementLineCol::STATUS,
+                Op::Eq,
+                BankStatementLineStatus::Matched,
+            )
+            .patch()
+            .assign(BankStatementLineCol::STATUS, BankStatementLineStatus::Approved)
+            .map_err(AppError::from)?
+            .assign(BankStatementLineCol::UPDATED_AT, OffsetDateTime::now_utc())
+            .map_err(AppError::from)?
+            .save()
+            .await
+            .map_err(AppError::from)?;
+    }
+
+    Ok((find_line(state, line_id).await?, outcome))
+}
+
+async fn find_line(state: &AppApiState, line_id: i64) -> Result<BankStatementLineRecord, AppError> {
+    BankStatementLineModel::find(DbConn::pool(&state.db), line_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Statement line not found")))
+}
+
+/// Pairs a line with the pending deposit whose reference code appears in it.
+/// A deposit is taken out of `pending` once matched so two lines of the same
+/// statement never claim it.
+fn match_line(
+    line: &StatementLine,
+    account_number: &str,
+    pending: &mut Vec<DepositRecord>,
+) -> LineMatch {
+    let unmatched = |note: String| LineMatch {
+        status: BankStatementLineStatus::Unmatched,
+        deposit_id: None,
+        note: Some(note),
+    };
+
+    if let Some(line_account) = line.account_number.as_deref() {
+        if digits(line_account) != digits(account_number) {
+            return unmatched("Paid into a different account".to_string());
+        }
+    }
+
+    let reference = line.reference.to_ascii_uppercase();
+    let Some(index) = pending.iter().position(|deposit| {
+        deposit
+            .reference_code
+            .as_deref()
+            .is_some_and(|code| reference.contains(code))
+    }) else {
+        return unmatched("No pending deposit with this reference".to_string());
+    };
+
+    if pending[index].amount != line.amount {
+        return unmatched(format!(
+            "Amount differs from deposit #{} ({})",
+            pending[index].id,
+            pending[index].amount.normalize()
+        ));
+    }
+
+    let deposit = pending.remove(index);
+    LineMatch {
+        status: BankStatementLineStatus::Matched,
+        deposit_id: Some(deposit.id),
+        note: None,
+    }
+}
+
+fn digits(value: &str) -> String {
+    value.chars().filter(char::is_ascii_digit).collect()
+}
+
+/// The credit lines of a statement. Debits and rows without an amount are
+/// skipped; a missing mapped column fails the whole upload.
+fn read_lines(text: &str, format: &StatementFormat) -> Result<Vec<StatementLine>, AppError> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut records = parse_csv(text, format.delimiter)
+        .into_iter()
+        .skip(format.skip_lines);
+    let (_, header) = records
+        .next()
+        .ok_or_else(|| AppError::BadRequest(t("Statement is empty")))?;
+    let header: Vec<String> = header.iter().map(|name| name.trim().to_string()).collect();
+
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|column| column.eq_ignore_ascii_case(name.trim()))
+            .ok_or_else(|| {
+                AppError::BadRequest(t_args(
+                    "Statement is missing the :column column",
+                    &[("column", name)],
+                ))
+            })
+    };
+    let reference_at = column(&format.reference_column)?;
+    let amount_at = column(&format.amount_column)?;
+    let account_at = format.account_column.as_deref().map(column).transpose()?;
+    let date_at = format.date_column.as_deref().map(column).transpose()?;
+
+    let mut lines = Vec::new();
+    for (line_number, fields) in records {
+        let field = |at: usize| fields.get(at).map(|value| value.trim()).unwrap_or("");
+        let Some(amount) = parse_amount(field(amount_at)).filter(|a| *a > Decimal::ZERO) else {
+            continue;
+        };
+        let optional = |at: Option<usize>| {
+            at.map(field)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let raw = header
+            .iter()
+            .zip(fields.iter())
+            .map(|(name, value)| (name.clone(), serde_json::Value::String(value.clone())))
+            .collect();
+
+        lines.push(StatementLine {
+            line_number: line_number as i32,
+            reference: field(reference_at).to_string(),
+            amount,
+            account_number: optional(account_at),
+            transaction_date: optional(date_at),
+            raw: serde_json::Value::Object(raw),
+        });
+    }
+    Ok(lines)
+}
+
+/// Reads amounts as banks print them: currency symbols, thousands separators
+/// and a trailing `CR` are ignored, and `(12.00)` or a trailing `DR` is
+/// negative.
+fn parse_amount(value: &str) -> Option<Decimal> {
+    let upper = value.trim().to_ascii_uppercase();
+    let negative = upper.starts_with('(') || upper.starts_with('-') || upper.ends_with("DR");
+    let number: String = upper
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    if number.is_empty() {
+        return None;
+    }
+    let amount: Decimal = number.parse().ok()?;
+    Some(if negative { -amount } else { amount })
+}
+
+/// Splits CSV text into records, each with the 1-based line it starts on.
+/// Handles quoted fields with embedded delimiters, doubled quotes and line
+/// breaks. Blank lines are dropped.
+fn parse_csv(text: &str, delimiter: char) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            '\r' => {}
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                if fields.iter().any(|value| !value.trim().is_empty()) {
+                    records.push((record_line, std::mem::take(&mut fields)));
+                }
+                fields.clear();
+                line += 1;
+                record_line = line;
+            }
+            c if c == delimiter => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    if fields.iter().any(|value| !value.trim().is_empty()) {
+        records.push((record_line, fields));
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_amount, parse_csv, read_lines};
+    use crate::internal::config::bank_statement::StatementFormat;
+    use rust_decimal::Decimal;
+
+    fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_csv_handles_quotes_and_blank_lines() {
+        let records = parse_csv("a,b\r\n\r\n\"x, \"\"y\"\"\",\"multi\nline\"\n1,2", ',');
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1], (3, vec!["x, \"y\"".to_string(), "multi\nline".to_string()]));
+        assert_eq!(records[2], (5, vec!["1".to_string(), "2".to_string()]));
+    }
+
+    #[test]
+    fn parse_amount_reads_bank_formats() {
+        assert_eq!(parse_amount("RM 1,234.50"), Some(dec("1234.50")));
+        assert_eq!(parse_amount("100.00 CR"), Some(dec("100.00")));
+        assert_eq!(parse_amount("(25.00)"), Some(dec("-25.00")));
+        assert_eq!(parse_amount("25.00DR"), Some(dec("-25.00")));
+        assert_eq!(parse_amount(""), None);
+    }
+
+    #[test]
+    fn read_lines_skips_preamble_and_debits() {
+        let format = StatementFormat {
+            delimiter: ';',
+            skip_lines: 1,
+            reference_column: "Description".to_string(),
+            amount_column: "Credit".to_string(),
+            account_column: None,
+            date_column: Some("Date".to_string()),
+        };
+        let text = "Account 1234\nDate;Description;Credit\n01/02;TRF ABCD2345;100.00\n\
+                    02/02;ATM;\n03/02;FEE;(1.00)\n";
+
+        let Ok(lines) = read_lines(text, &format) else {
+            panic!("statement should parse");
+        };
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].line_number, 3);
+        assert_eq!(lines[0].reference, "TRF ABCD2345");
+        assert_eq!(lines[0].amount, dec("100.00"));
+        assert_eq!(lines[0].transaction_date.as_deref(), Some("01/02"));
+    }
+}
@@ -9,7 +9,7 @@ use time::OffsetDateTime;
 
 use crate::{
     contracts::api::v1::admin::company_bank_account::AdminCompanyBankAccountInput,
-    internal::api::state::AppApiState,
+    internal::{api::state::AppApiState, workflows::bank as bank_workflow},
 };
 
 pub async fn detail(
@@ -22,7 +22,13 @@ pub async fn detail(
         .ok_or_else(|| AppError::NotFound(t("Company bank account not found")))
 }
 
-async fn validate_bank(state: &AppApiState, bank_id: i64) -> Result<(), AppError> {
+/// Checks the bank is enabled and the account number follows its rules.
+/// Returns the normalized account number.
+async fn validate_bank(
+    state: &AppApiState,
+    bank_id: i64,
+    account_number: &str,
+) -> Result<String, AppError> {
     let bank = BankModel::query(DbConn::pool(&state.db))
         .where_col(BankCol::ID, Op::Eq, bank_id)
         .first()
@@ -34,7 +40,7 @@ async fn validate_bank(state: &AppApiState, bank_id: i64) -> Result<(), AppError
         return Err(AppError::BadRequest(t("Bank is not enabled")));
     }
 
-    Ok(())
+    bank_workflow::ensure_account_number(&bank, "account_number", account_number)
 }
 
 pub async fn create(
@@ -42,7 +48,7 @@ pub async fn create(
     req: AdminCompanyBankAccountInput,
 ) -> Result<CompanyBankAccountRecord, AppError> {
     let bank_id: i64 = req.bank_id.into();
-    validate_bank(state, bank_id).await?;
+    let account_number = validate_bank(state, bank_id, &req.account_number).await?;
 
     let now = OffsetDateTime::now_utc();
     let row = CompanyBankAccountModel::create(DbConn::pool(&state.db))
@@ -50,7 +56,7 @@ pub async fn create(
         .map_err(AppError::from)?
         .set(CompanyBankAccountCol::ACCOUNT_NAME, req.account_name)
         .map_err(AppError::from)?
-        .set(CompanyBankAccountCol::ACCOUNT_NUMBER, req.account_number)
+        .set(CompanyBankAccountCol::ACCOUNT_NUMBER, account_number)
         .map_err(AppError::from)?
         .set(CompanyBankAccountCol::STATUS, req.status)
         .map_err(AppError::from)?
@@ -73,7 +79,7 @@ pub async fn update(
     req: AdminCompanyBankAccountInput,
 ) -> Result<CompanyBankAccountRecord, AppError> {
     let bank_id: i64 = req.bank_id.into();
-    validate_bank(state, bank_id).await?;
+    let account_number = validate_bank(state, bank_id, &req.account_number).await?;
 
     let affected = CompanyBankAccountModel::query(DbConn::pool(&state.db))
         .where_col(CompanyBankAccountCol::ID, Op::Eq, id)
@@ -82,7 +88,7 @@ pub async fn update(
         .map_err(AppError::from)?
         .assign(CompanyBankAccountCol::ACCOUNT_NAME, req.account_name)
         .map_err(AppError::from)?
-        .assign(CompanyBankAccountCol::ACCOUNT_NUMBER, req.account_number)
+        .assign(CompanyBankAccountCol::ACCOUNT_NUMBER, account_number)
         .map_err(AppError::from)?
         .assign(CompanyBankAccountCol::STATUS, req.status)
         .map_err(AppError::from)?
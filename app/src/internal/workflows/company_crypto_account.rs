@@ -9,7 +9,7 @@ use time::OffsetDateTime;
 
 use crate::{
     contracts::api::v1::admin::company_crypto_account::AdminCompanyCryptoAccountInput,
-    internal::api::state::AppApiState,
+    internal::{api::state::AppApiState, workflows::crypto_network},
 };
 
 pub async fn detail(
@@ -22,7 +22,12 @@ pub async fn detail(
         .ok_or_else(|| AppError::NotFound(t("Company crypto account not found")))
 }
 
-async fn validate_crypto_network(state: &AppApiState, network_id: i64) -> Result<(), AppError> {
+/// Checks the network is enabled and the wallet address fits its format.
+async fn validate_crypto_network(
+    state: &AppApiState,
+    network_id: i64,
+    wallet_address: &str,
+) -> Result<(), AppError> {
     let network = CryptoNetworkModel::query(DbConn::pool(&state.db))
         .where_col(CryptoNetworkCol::ID, Op::Eq, network_id)
         .first()
@@ -34,7 +39,7 @@ async fn validate_crypto_network(state: &AppApiState, network_id: i64) -> Result
         return Err(AppError::BadRequest(t("Crypto network is not enabled")));
     }
 
-    Ok(())
+    crypto_network::ensure_wallet_address(&network, "wallet_address", wallet_address)
 }
 
 pub async fn create(
@@ -42,13 +47,13 @@ pub async fn create(
     req: AdminCompanyCryptoAccountInput,
 ) -> Result<CompanyCryptoAccountRecord, AppError> {
     let network_id: i64 = req.crypto_network_id.into();
-    validate_crypto_network(state, network_id).await?;
+    validate_crypto_network(state, network_id, &req.wallet_address).await?;
 
     let now = OffsetDateTime::now_utc();
     let row = CompanyCryptoAccountModel::create(DbConn::pool(&state.db))
         .set(CompanyCryptoAccountCol::CRYPTO_NETWORK_ID, network_id)
         .map_err(AppError::from)?
-        .set(CompanyCryptoAccountCol::WALLET_ADDRESS, req.wallet_address)
+        .set(CompanyCryptoAccountCol::WALLET_ADDRESS, req.wallet_address.trim().to_string())
         .map_err(AppError::from)?
         .set(CompanyCryptoAccountCol::CONVERSION_RATE, req.conversion_rate)
         .map_err(AppError::from)?
@@ -73,14 +78,14 @@ pub async fn update(
     req: AdminCompanyCryptoAccountInput,
 ) -> Result<CompanyCryptoAccountRecord, AppError> {
     let network_id: i64 = req.crypto_network_id.into();
-    validate_crypto_network(state, network_id).await?;
+    validate_crypto_network(state, network_id, &req.wallet_address).await?;
 
     let affected = CompanyCryptoAccountModel::query(DbConn::pool(&state.db))
         .where_col(CompanyCryptoAccountCol::ID, Op::Eq, id)
         .patch()
         .assign(CompanyCryptoAccountCol::CRYPTO_NETWORK_ID, network_id)
         .map_err(AppError::from)?
-        .assign(CompanyCryptoAccountCol::WALLET_ADDRESS, req.wallet_address)
+        .assign(CompanyCryptoAccountCol::WALLET_ADDRESS, req.wallet_address.trim().to_string())
         .map_err(AppError::from)?
         .assign(CompanyCryptoAccountCol::CONVERSION_RATE, req.conversion_rate)
         .map_err(AppError::from)?
@@ -0,0 +1,202 @@
+use core_db::common::sql::{generate_snowflake_i64, DbConn, Op, OrderDir};
+use core_i18n::{t, t_args};
+use core_web::error::AppError;
+use generated::models::{
+    CreditConversionRateCol, CreditConversionRateModel, CreditConversionRateRecord,
+    CreditConversionRateStatus, CreditTransactionType, CreditType,
+};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{
+    contracts::api::v1::admin::credit_conversion::AdminCreditConversionRateInput,
+    internal::{
+        api::state::AppApiState,
+        config::fee::FeeSchedule,
+        workflows::credit_ledger::{self, CreditEntry},
+    },
+};
+
+pub struct ConversionResult {
+    pub related_key: String,
+    pub from_credit_type: CreditType,
+    pub to_credit_type: CreditType,
+    pub amount: Decimal,
+    pub fee: Decimal,
+    pub rate: Decimal,
+    pub converted_amount: Decimal,
+}
+
+pub async fn list_rates(
+    state: &AppApiState,
+    enabled_only: bool,
+) -> Result<Vec<CreditConversionRateRecord>, AppError> {
+    let mut query = CreditConversionRateModel::query(DbConn::pool(&state.db));
+    if enabled_only {
+        query = query.where_col(
+            CreditConversionRateCol::STATUS,
+            Op::Eq,
+            CreditConversionRateStatus::Enabled,
+        );
+    }
+    query
+        .order_by(CreditConversionRateCol::FROM_CREDIT_TYPE, OrderDir::Asc)
+        .order_by(CreditConversionRateCol::TO_CREDIT_TYPE, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)
+}
+
+async fn find_rate(
+    state: &AppApiState,
+    from: CreditType,
+    to: CreditType,
+) -> Result<Option<CreditConversionRateRecord>, AppError> {
+    CreditConversionRateModel::query(DbConn::pool(&state.db))
+        .where_col(CreditConversionRateCol::FROM_CREDIT_TYPE, Op::Eq, from)
+        .where_col(CreditConversionRateCol::TO_CREDIT_TYPE, Op::Eq, to)
+        .first()
+        .await
+        .map_err(AppError::from)
+}
+
+/// Creates or replaces the rate for one conversion direction.
+pub async fn upsert_rate(
+    state: &AppApiState,
+    req: AdminCreditConversionRateInput,
+) -> Result<CreditConversionRateRecord, AppError> {
+    let now = OffsetDateTime::now_utc();
+
+    match find_rate(state, req.from_credit_type, req.to_credit_type).await? {
+        Some(existing) => {
+            CreditConversionRateModel::query(DbConn::pool(&state.db))
+                .where_col(CreditConversionRateCol::ID, Op::Eq, existing.id)
+                .patch()
+                .assign(CreditConversionRateCol::RATE, req.rate)
+                .map_err(AppError::from)?
+                .assign(CreditConversionRateCol::FEE_PERCENTAGE, req.fee_percentage)
+                .map_err(AppError::from)?
+                .assign(CreditConversionRateCol::FEE_FIXED, req.fee_fixed)
+                .map_err(AppError::from)?
+                .assign(CreditConversionRateCol::MIN_AMOUNT, req.min_amount)
+                .map_err(AppError::from)?
+                .assign(CreditConversionRateCol::STATUS, req.status)
+                .map_err(AppError::from)?
+                .assign(CreditConversionRateCol::UPDATED_AT, now)
+                .map_err(AppError::from)?
+                .save()
+                .await
+                .map_err(AppError::from)?;
+        }
+        None => {
+            CreditConversionRateModel::create(DbConn::pool(&state.db))
+                .set(CreditConversionRateCol::FROM_CREDIT_TYPE, req.from_credit_type)?
+                .set(CreditConversionRateCol::TO_CREDIT_TYPE, req.to_credit_type)?
+                .set(CreditConversionRateCol::RATE, req.rate)?
+                .set(CreditConversionRateCol::FEE_PERCENTAGE, req.fee_percentage)?
+                .set(CreditConversionRateCol::FEE_FIXED, req.fee_fixed)?
+                .set(CreditConversionRateCol::MIN_AMOUNT, req.min_amount)?
+                .set(CreditConversionRateCol::STATUS, req.status)?
+                .set(CreditConversionRateCol::CREATED_AT, now)?
+                .set(CreditConversionRateCol::UPDATED_AT, now)?
+                .save()
+                .await
+                .map_err(AppError::from)?;
+        }
+    }
+
+    find_rate(state, req.from_credit_type, req.to_credit_type)
+        .await?
+        .ok_or_else(|| AppError::NotFound(t("Conversion rate not found")))
+}
+
+/// Converts `amount` of `from` into `to` for one user. The fee is taken from
+/// the source amount and the remainder is multiplied by the direction's rate.
+pub async fn convert(
+    state: &AppApiState,
+    user_id: i64,
+    admin_id: Option<i64>,
+    from: CreditType,
+    to: CreditType,
+    amount: Decimal,
+    remark: Option<String>,
+) -> Result<ConversionResult, AppError> {
+    if from == to {
+        return Err(AppError::BadRequest(t("Source and target credit types must differ")));
+    }
+    if amount <= Decimal::ZERO {
+        return Err(AppError::BadRequest(t("Amount must be greater than zero.")));
+    }
+
+    let rate = find_rate(state, from, to)
+        .await?
+        .filter(|r| r.status == CreditConversionRateStatus::Enabled)
+        .ok_or_else(|| AppError::BadRequest(t("Conversion is not available for these credit types")))?;
+
+    if amount < rate.min_amount {
+        let min_amount = rate.min_amount.normalize().to_string();
+        return Err(AppError::BadRequest(t_args(
+            "Minimum conversion amount is :amount",
+            &[("amount", min_amount.as_str())],
+        )));
+    }
+
+    let schedule = FeeSchedule {
+        fee_percentage: rate.fee_percentage,
+        fee_fixed: rate.fee_fixed,
+        ..Default::default()
+    };
+    let (fee, net_amount) = schedule
+        .calculate(amount)
+        .ok_or_else(|| AppError::BadRequest(t("Conversion amount does not cover the fee")))?;
+    let converted_amount = (net_amount * rate.rate).round_dp(8);
+    if converted_amount <= Decimal::ZERO {
+        return Err(AppError::BadRequest(t("Conversion amount does not cover the fee")));
+    }
+
+    let related_key = generate_snowflake_i64().to_string();
+    let params = serde_json::json!({
+        "rate": rate.rate.normalize().to_string(),
+        "fee": fee.normalize().to_string(),
+    });
+
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    let legs = [
+        (from, -amount, CreditTransactionType::ConvertOut),
+        (to, converted_amount, CreditTransactionType::ConvertIn),
+    ];
+
+    // The debit leg goes first so an insufficient balance aborts before
+    // anything is credited.
+    for (credit_type, delta, transaction_type) in legs {
+        credit_ledger::post(
+            conn.clone(),
+            &state.balance_config,
+            CreditEntry {
+                admin_id,
+                related_key: Some(related_key.clone()),
+                params: Some(params.clone()),
+                remark: remark.clone(),
+                ..CreditEntry::new(user_id, credit_type, delta, transaction_type)
+            },
+        )
+        .await?;
+    }
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    Ok(ConversionResult {
+        related_key,
+        from_credit_type: from,
+        to_credit_type: to,
+        amount,
+        fee,
+        rate: rate.rate,
+        converted_amount,
+    })
+}
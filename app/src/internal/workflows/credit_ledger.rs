@@ -0,0 +1,208 @@
+use core_db::common::sql::{DbConn, Op};
+use core_i18n::{t, t_args};
+use core_web::error::AppError;
+use generated::models::{
+    CreditTransactionType, CreditType, UserCol, UserCreditTransactionCol,
+    UserCreditTransactionModel, UserCreditTransactionRecord, UserModel,
+};
+use rust_decimal::Decimal;
+
+use crate::internal::config::balance::BalanceConfig;
+
+/// One balance movement and the transaction row that records it.
+pub struct CreditEntry {
+    pub user_id: i64,
+    pub admin_id: Option<i64>,
+    pub credit_type: CreditType,
+    /// Signed change applied to the balance
+    pub amount: Decimal,
+    pub transaction_type: CreditTransactionType,
+    pub related_key: Option<String>,
+    pub params: Option<serde_json::Value>,
+    pub remark: Option<String>,
+    pub custom_description: bool,
+}
+
+impl CreditEntry {
+    pub fn new(
+        user_id: i64,
+        credit_type: CreditType,
+        amount: Decimal,
+        transaction_type: CreditTransactionType,
+    ) -> Self {
+        Self {
+            user_id,
+            admin_id: None,
+            credit_type,
+            amount,
+            transaction_type,
+            related_key: None,
+            params: None,
+            remark: None,
+            custom_description: false,
+        }
+    }
+}
+
+/// Applies `entry.amount` to the user's balance and records the transaction
+/// with its `balance_before`/`balance_after` snapshot. Must be called with a
+/// transaction-scoped connection: the increment locks the user row until the
+/// caller commits, so the balance read back afterwards is exact.
+///
+/// Deductions carry the credit type's balance floor in the UPDATE itself, so
+/// concurrent debits cannot take the balance past it.
+pub async fn post(
+    conn: DbConn<'_>,
+    balance: &BalanceConfig,
+    entry: CreditEntry,
+) -> Result<UserCreditTransactionRecord, AppError> {
+    let (balance_col, column) = match entry.credit_type {
+        CreditType::Credit1 => (UserCol::CREDIT_1, "credit_1"),
+        CreditType::Credit2 => (UserCol::CREDIT_2, "credit_2"),
+    };
+    let policy = balance.policy(entry.credit_type);
+    let is_deduction = entry.amount < Decimal::ZERO;
+
+    let mut query =
+        UserModel::query(conn.clone()).where_col(UserCol::ID, Op::Eq, entry.user_id);
+    if is_deduction {
+        // balance + amount >= floor
+        query = query.where_col(balance_col, Op::Ge, policy.floor() - entry.amount);
+    }
+    let updated = query
+        .patch()
+        .increment(balance_col, entry.amount)
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    if updated == 0 {
+        let exists = UserModel::query(conn.clone())
+            .where_col(UserCol::ID, Op::Eq, entry.user_id)
+            .count()
+            .await
+            .map_err(AppError::from)?
+            > 0;
+        return Err(if !exists || !is_deduction {
+            AppError::NotFound(t("User not found"))
+        } else if policy.overdraft_limit.is_zero() {
+            AppError::BadRequest(t("Insufficient balance"))
+        } else {
+            let limit = policy.overdraft_limit.normalize().to_string();
+            AppError::BadRequest(t_args(
+                "Insufficient balance. Overdraft limit is :amount",
+                &[("amount", limit.as_str())],
+            ))
+        });
+    }
+
+    let balance_after = conn
+        .fetch_scalar(
+            sqlx::query_scalar::<_, Decimal>(&format!(
+                "SELECT {column} FROM users WHERE id = $1"
+            ))
+            .bind(entry.user_id),
+        )
+        .await
+        .map_err(AppError::from)?;
+
+    record(conn, entry, balance_after).await
+}
+
+/// Records a transaction row without moving the stored balance, for entries
+/// that only bring the ledger in line with a balance that already changed.
+pub async fn record(
+    conn: DbConn<'_>,
+    entry: CreditEntry,
+    balance_after: Decimal,
+) -> Result<UserCreditTransactionRecord, AppError> {
+    UserCreditTransactionModel::create(conn)
+        .set(UserCreditTransactionCol::USER_ID, entry.user_id)?
+        .set(UserCreditTransactionCol::ADMIN_ID, entry.admin_id)?
+        .set(UserCreditTransactionCol::CREDIT_TYPE, entry.credit_type)?
+        .set(UserCreditTransactionCol::AMOUNT, entry.amount)?
+        .set(
+            UserCreditTransactionCol::BALANCE_BEFORE,
+            balance_after - entry.amount,
+        )?
+        .set(UserCreditTransactionCol::BALANCE_AFTER, balance_after)?
+        .set(UserCreditTransactionCol::TRANSACTION_TYPE, entry.transaction_type)?
+        .set(UserCreditTransactionCol::RELATED_KEY, entry.related_key)?
+        .set(UserCreditTransactionCol::PARAMS, entry.params)?
+        .set(UserCreditTransactionCol::REMARK, entry.remark)?
+        .set(UserCreditTransactionCol::CUSTOM_DESCRIPTION, entry.custom_description)?
+        .save()
+        .await
+        .map_err(AppError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{post, CreditEntry};
+    use crate::internal::config::balance::{BalanceConfig, BalancePolicy};
+    use crate::internal::workflows::test_support;
+    use core_db::common::sql::DbConn;
+    use core_web::error::AppError;
+    use generated::models::{CreditTransactionType, CreditType, UserCreditTransactionRecord};
+    use rust_decimal::Decimal;
+
+    async fn deduct(
+        db: &sqlx::PgPool,
+        balance: &BalanceConfig,
+        user_id: i64,
+        amount: Decimal,
+    ) -> Result<UserCreditTransactionRecord, AppError> {
+        let scope = DbConn::pool(db).begin_scope().await.map_err(AppError::from)?;
+        let txn = post(
+            scope.conn(),
+            balance,
+            CreditEntry::new(
+                user_id,
+                CreditType::Credit1,
+                -amount,
+                CreditTransactionType::AdminDeduct,
+            ),
+        )
+        .await?;
+        scope.commit().await.map_err(AppError::from)?;
+        Ok(txn)
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[ignore = "requires DATABASE_URL"]
+    async fn parallel_deductions_stop_at_zero() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig::default();
+        let user_id = test_support::seed_user(&db, Decimal::from(50)).await;
+
+        let (a, b) = tokio::join!(
+            deduct(&db, &balance, user_id, Decimal::from(30)),
+            deduct(&db, &balance, user_id, Decimal::from(30)),
+        );
+
+        assert_eq!(a.is_ok() as u8 + b.is_ok() as u8, 1);
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::from(20));
+        assert_eq!(test_support::transaction_count(&db, user_id).await, 1);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires DATABASE_URL"]
+    async fn deduction_may_use_overdraft_limit() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig {
+            credit_1: BalancePolicy {
+                overdraft_limit: Decimal::from(25),
+            },
+            ..Default::default()
+        };
+        let user_id = test_support::seed_user(&db, Decimal::from(10)).await;
+
+        let txn = deduct(&db, &balance, user_id, Decimal::from(30))
+            .await
+            .expect("within overdraft");
+        assert_eq!(txn.balance_after, Decimal::from(-20));
+        assert!(deduct(&db, &balance, user_id, Decimal::from(10)).await.is_err());
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::from(-20));
+    }
+}
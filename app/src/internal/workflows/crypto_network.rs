@@ -1,14 +1,18 @@
 use core_db::common::sql::{DbConn, Op};
 use core_db::platform::attachments::types::AttachmentInput;
 use core_i18n::t;
-use core_web::error::AppError;
+use core_web::{error::AppError, extract::validation::transform_validation_errors};
 use generated::localized;
-use generated::models::{CryptoNetworkCol, CryptoNetworkModel, CryptoNetworkRecord};
+use generated::models::{
+    CryptoAddressFormat, CryptoNetworkCol, CryptoNetworkModel, CryptoNetworkRecord,
+};
 use time::OffsetDateTime;
+use validator::ValidationErrors;
 
 use crate::{
     contracts::api::v1::admin::crypto_network::AdminCryptoNetworkInput,
     internal::api::state::AppApiState,
+    validation::crypto_address::validate_crypto_address,
 };
 
 pub async fn detail(state: &AppApiState, id: i64) -> Result<CryptoNetworkRecord, AppError> {
@@ -18,6 +22,24 @@ pub async fn detail(state: &AppApiState, id: i64) -> Result<CryptoNetworkRecord,
         .ok_or_else(|| AppError::NotFound(t("Crypto network not found")))
 }
 
+/// Rejects `address` under `field` unless it fits the network's address
+/// format.
+pub fn ensure_wallet_address(
+    network: &CryptoNetworkRecord,
+    field: &'static str,
+    address: &str,
+) -> Result<(), AppError> {
+    if let Err(error) = validate_crypto_address(network.address_format, address) {
+        let mut errors = ValidationErrors::new();
+        errors.add(field, error);
+        return Err(AppError::Validation {
+            message: t("Validation failed"),
+            errors: transform_validation_errors(errors),
+        });
+    }
+    Ok(())
+}
+
 pub async fn create(
     state: &AppApiState,
     req: AdminCryptoNetworkInput,
@@ -34,6 +56,10 @@ pub async fn create(
         .set(CryptoNetworkCol::NAME, req.name)?
         .set(CryptoNetworkCol::SYMBOL, req.symbol)?
         .set(CryptoNetworkCol::STATUS, req.status)?
+        .set(
+            CryptoNetworkCol::ADDRESS_FORMAT,
+            req.address_format.unwrap_or(CryptoAddressFormat::Unchecked),
+        )?
         .set(CryptoNetworkCol::SORT_ORDER, req.sort_order.unwrap_or(0))?
         .set(CryptoNetworkCol::CREATED_AT, now)?
         .set(CryptoNetworkCol::UPDATED_AT, now)?
@@ -76,6 +102,10 @@ pub async fn update(
         .assign(CryptoNetworkCol::NAME, req.name)?
         .assign(CryptoNetworkCol::SYMBOL, req.symbol)?
         .assign(CryptoNetworkCol::STATUS, req.status)?
+        .assign(
+            CryptoNetworkCol::ADDRESS_FORMAT,
+            req.address_format.unwrap_or(CryptoAddressFormat::Unchecked),
+        )?
         .assign(CryptoNetworkCol::SORT_ORDER, req.sort_order.unwrap_or(0))?
         .assign(CryptoNetworkCol::UPDATED_AT, OffsetDateTime::now_utc())?
         .save()
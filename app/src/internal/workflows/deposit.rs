@@ -1,19 +1,86 @@
-use core_db::common::sql::{DbConn, Op};
+use core_db::common::sql::{DbConn, Op, OrderDir};
 use core_i18n::t;
 use core_web::error::AppError;
 use generated::models::{
-    CreditTransactionType, CreditType, DepositCol, DepositModel, DepositRecord,
-    DepositReviewAction, DepositStatus, OwnerType, UserCol, UserCreditTransactionCol,
-    UserCreditTransactionModel, UserModel,
+    CreditTransactionType, DepositCol, DepositModel, DepositRecord, DepositReviewAction,
+    DepositStatus, DepositStatusHistoryCol, DepositStatusHistoryModel,
+    DepositStatusHistoryRecord, FinancialApprovalAction, OwnerType, ReferralCommissionSource,
+    UserCreditTransactionCol, UserCreditTransactionModel, UserCreditTransactionRecord,
+    UserLimitKind,
 };
 use time::OffsetDateTime;
 
 use crate::{
     contracts::api::v1::admin::deposit::AdminDepositReviewInput,
-    internal::api::state::AppApiState,
+    internal::{
+        api::state::AppApiState,
+        config::balance::BalanceConfig,
+        workflows::{
+            credit_ledger::{self, CreditEntry},
+            financial_approval::{self, ApprovalOutcome, Proposal},
+            limits,
+            referral_commission::{self, CommissionSource},
+            review_claim::{self, ClaimSubject},
+        },
+    },
 };
 
-pub async fn detail(
+pub struct DepositDetail {
+    pub deposit: DepositRecord,
+    /// Every status the deposit entered, oldest first
+    pub history: Vec<DepositStatusHistoryRecord>,
+}
+
+pub struct DepositReversal {
+    pub deposit: DepositRecord,
+    pub commissions_reversed: usize,
+    /// Other ledger entries keyed on the deposit that were left untouched
+    pub needs_reversal: Vec<UserCreditTransactionRecord>,
+}
+
+/// Entries a reversal already accounts for; anything else linked to the
+/// deposit is reported back for manual follow-up.
+const REVERSAL_HANDLED_TYPES: [CreditTransactionType; 4] = [
+    CreditTransactionType::TopUp,
+    CreditTransactionType::DepositReversal,
+    CreditTransactionType::ReferralCommission,
+    CreditTransactionType::ReferralCommissionReversal,
+];
+
+/// Letters and digits that survive being read out or retyped: no 0/O or 1/I.
+const REFERENCE_CODE_ALPHABET: [char; 32] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J', 'K', 'L', 'M', 'N', 'P', 'Q', 'R', 'S', 'T',
+    'U', 'V', 'W', 'X', 'Y', 'Z', '2', '3', '4', '5', '6', '7', '8', '9',
+];
+pub const REFERENCE_CODE_LEN: usize = 8;
+
+/// Whether `value` has the shape of a deposit reference code, ignoring case.
+pub fn is_reference_code(value: &str) -> bool {
+    value.chars().count() == REFERENCE_CODE_LEN
+        && value
+            .chars()
+            .all(|c| REFERENCE_CODE_ALPHABET.contains(&c.to_ascii_uppercase()))
+}
+
+/// A reference code no other deposit uses yet, for the user's transfer memo.
+/// The unique index still guards against a concurrent deposit drawing the
+/// same code.
+pub async fn generate_reference_code(conn: DbConn<'_>) -> Result<String, AppError> {
+    for _ in 0..10 {
+        let code = nanoid::nanoid!(REFERENCE_CODE_LEN, &REFERENCE_CODE_ALPHABET);
+        let taken = DepositModel::query(conn.clone())
+            .where_col(DepositCol::REFERENCE_CODE, Op::Eq, Some(code.clone()))
+            .count()
+            .await
+            .map_err(AppError::from)?;
+        if taken == 0 {
+            return Ok(code);
+        }
+    }
+    Err(AppError::BadRequest(t("Failed to generate unique ID")))
+}
+
+pub async fn find(
     state: &AppApiState,
     deposit_id: i64,
 ) -> Result<DepositRecord, AppError> {
@@ -23,13 +90,133 @@ pub async fn detail(
         .ok_or_else(|| AppError::NotFound(t("Deposit not found")))
 }
 
+/// The deposit with its status timeline.
+pub async fn detail(
+    state: &AppApiState,
+    deposit_id: i64,
+) -> Result<DepositDetail, AppError> {
+    let deposit = find(state, deposit_id).await?;
+    let history = DepositStatusHistoryModel::query(DbConn::pool(&state.db))
+        .where_col(DepositStatusHistoryCol::DEPOSIT_ID, Op::Eq, deposit_id)
+        .order_by(DepositStatusHistoryCol::CREATED_AT, OrderDir::Asc)
+        .order_by(DepositStatusHistoryCol::ID, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(DepositDetail { deposit, history })
+}
+
+/// Appends the status the deposit just entered to its timeline. Call it in
+/// the transaction that changes the status.
+pub async fn record_status(
+    conn: DbConn<'_>,
+    deposit_id: i64,
+    status: DepositStatus,
+    admin_id: Option<i64>,
+    remark: Option<String>,
+) -> Result<(), AppError> {
+    DepositStatusHistoryModel::create(conn)
+        .set(DepositStatusHistoryCol::DEPOSIT_ID, deposit_id)?
+        .set(DepositStatusHistoryCol::STATUS, status)?
+        .set(DepositStatusHistoryCol::ADMIN_ID, admin_id)?
+        .set(DepositStatusHistoryCol::REMARK, remark)?
+        .set(DepositStatusHistoryCol::CREATED_AT, OffsetDateTime::now_utc())?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    Ok(())
+}
+
+/// Reviews a pending deposit. An approval must fit the user's `[limits]`;
+/// above the `[approval.deposit]` threshold it is only proposed and a second
+/// admin confirms it later.
 pub async fn review_deposit(
     state: &AppApiState,
     admin_id: i64,
     deposit_id: i64,
     req: AdminDepositReviewInput,
-) -> Result<DepositRecord, AppError> {
-    let deposit = DepositModel::find(DbConn::pool(&state.db), deposit_id)
+) -> Result<ApprovalOutcome<DepositRecord>, AppError> {
+    let deposit = find(state, deposit_id).await?;
+    if deposit.status != DepositStatus::Pending {
+        return Err(AppError::BadRequest(t("Deposit is not pending")));
+    }
+    review_claim::ensure_not_claimed(deposit.claimed_by, deposit.claimed_until, admin_id)?;
+
+    if req.action == DepositReviewAction::Approve && deposit.owner_type == OwnerType::User {
+        limits::check(
+            DbConn::pool(&state.db),
+            state,
+            UserLimitKind::Deposit,
+            deposit.owner_id,
+            deposit.amount,
+            deposit.created_at,
+            Some(deposit.id),
+        )
+        .await?;
+    }
+
+    if req.action == DepositReviewAction::Approve
+        && state.approval_config.requires_second_admin(
+            FinancialApprovalAction::DepositApprove,
+            deposit.credit_type,
+            deposit.amount,
+        )
+    {
+        let approval = financial_approval::propose(
+            state,
+            admin_id,
+            Proposal {
+                action: FinancialApprovalAction::DepositApprove,
+                subject_id: deposit_id,
+                credit_type: deposit.credit_type,
+                amount: deposit.amount,
+                payload: serde_json::json!({
+                    "action": req.action,
+                    "admin_remark": req.admin_remark,
+                }),
+            },
+        )
+        .await?;
+        return Ok(ApprovalOutcome::Proposed(approval));
+    }
+
+    apply_review(&state.db, &state.balance_config, Some(admin_id), deposit_id, req).await?;
+
+    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+    review_claim::publish(state, ClaimSubject::Deposit, deposit_id).await?;
+
+    Ok(ApprovalOutcome::Applied(find(state, deposit_id).await?))
+}
+
+/// Moves a pending deposit to its reviewed status. The status update only
+/// matches while the deposit is still pending, so when two reviews race the
+/// loser changes nothing and gets a conflict instead of crediting twice.
+/// `admin_id` is `None` when a payment gateway settled the deposit; review
+/// claims don't hold those back.
+pub async fn apply_review(
+    db: &sqlx::PgPool,
+    balance: &BalanceConfig,
+    admin_id: Option<i64>,
+    deposit_id: i64,
+    req: AdminDepositReviewInput,
+) -> Result<(), AppError> {
+    let scope = DbConn::pool(db).begin_scope().await.map_err(AppError::from)?;
+    apply_review_in(scope.conn(), balance, admin_id, deposit_id, req).await?;
+    scope.commit().await.map_err(AppError::from)?;
+
+    Ok(())
+}
+
+/// [`apply_review`] inside a transaction the caller commits.
+pub async fn apply_review_in(
+    conn: DbConn<'_>,
+    balance: &BalanceConfig,
+    admin_id: Option<i64>,
+    deposit_id: i64,
+    req: AdminDepositReviewInput,
+) -> Result<(), AppError> {
+    let deposit = DepositModel::find(conn.clone(), deposit_id)
         .await
         .map_err(AppError::from)?
         .ok_or_else(|| AppError::NotFound(t("Deposit not found")))?;
@@ -37,94 +224,306 @@ pub async fn review_deposit(
     if deposit.status != DepositStatus::Pending {
         return Err(AppError::BadRequest(t("Deposit is not pending")));
     }
+    if let Some(admin_id) = admin_id {
+        review_claim::ensure_not_claimed(deposit.claimed_by, deposit.claimed_until, admin_id)?;
+    }
 
+    let status = match req.action {
+        DepositReviewAction::Approve => DepositStatus::Approved,
+        DepositReviewAction::Reject => DepositStatus::Rejected,
+    };
     let now = OffsetDateTime::now_utc();
 
-    match req.action {
-        DepositReviewAction::Approve => {
-            let scope = DbConn::pool(&state.db).begin_scope().await.map_err(AppError::from)?;
-            let conn = scope.conn();
-
-            // Update deposit status
-            DepositModel::query(conn.clone())
-                .where_col(DepositCol::ID, Op::Eq, deposit_id)
-                .patch()
-                .assign(DepositCol::STATUS, DepositStatus::Approved)
-                .map_err(AppError::from)?
-                .assign(DepositCol::ADMIN_ID, Some(admin_id))
-                .map_err(AppError::from)?
-                .assign(DepositCol::ADMIN_REMARK, req.admin_remark)
-                .map_err(AppError::from)?
-                .assign(DepositCol::REVIEWED_AT, Some(now))
-                .map_err(AppError::from)?
-                .save()
-                .await
-                .map_err(AppError::from)?;
-
-            // Credit the owner (for User owner_type)
-            if deposit.owner_type == OwnerType::User {
-                // Insert credit transaction
-                UserCreditTransactionModel::create(conn.clone())
-                    .set(UserCreditTransactionCol::USER_ID, deposit.owner_id)?
-                    .set(UserCreditTransactionCol::ADMIN_ID, Some(admin_id))?
-                    .set(UserCreditTransactionCol::CREDIT_TYPE, deposit.credit_type)?
-                    .set(UserCreditTransactionCol::AMOUNT, deposit.net_amount)?
-                    .set(
-                        UserCreditTransactionCol::TRANSACTION_TYPE,
-                        CreditTransactionType::TopUp,
-                    )?
-                    .set(
-                        UserCreditTransactionCol::RELATED_KEY,
-                        Some(deposit_id.to_string()),
-                    )?
-                    .set(
-                        UserCreditTransactionCol::REMARK,
-                        Some(format!("Deposit #{}", deposit_id)),
-                    )?
-                    .set(UserCreditTransactionCol::CUSTOM_DESCRIPTION, false)?
-                    .save()
-                    .await
-                    .map_err(AppError::from)?;
-
-                // Increment user balance atomically
-                let update = match deposit.credit_type {
-                    CreditType::Credit1 => UserModel::query(conn.clone())
-                        .where_col(UserCol::ID, Op::Eq, deposit.owner_id)
-                        .patch()
-                        .increment(UserCol::CREDIT_1, deposit.net_amount),
-                    CreditType::Credit2 => UserModel::query(conn.clone())
-                        .where_col(UserCol::ID, Op::Eq, deposit.owner_id)
-                        .patch()
-                        .increment(UserCol::CREDIT_2, deposit.net_amount),
-                };
-                update
-                    .map_err(AppError::from)?
-                    .save()
-                    .await
-                    .map_err(AppError::from)?;
-            }
-
-            scope.commit().await.map_err(AppError::from)?;
-        }
-        DepositReviewAction::Reject => {
-            DepositModel::query(DbConn::pool(&state.db))
-                .where_col(DepositCol::ID, Op::Eq, deposit_id)
-                .patch()
-                .assign(DepositCol::STATUS, DepositStatus::Rejected)
-                .map_err(AppError::from)?
-                .assign(DepositCol::ADMIN_ID, Some(admin_id))
-                .map_err(AppError::from)?
-                .assign(DepositCol::ADMIN_REMARK, req.admin_remark)
-                .map_err(AppError::from)?
-                .assign(DepositCol::REVIEWED_AT, Some(now))
-                .map_err(AppError::from)?
-                .save()
-                .await
-                .map_err(AppError::from)?;
+    let updated = DepositModel::query(conn.clone())
+        .where_col(DepositCol::ID, Op::Eq, deposit_id)
+        .where_col(DepositCol::STATUS, Op::Eq, DepositStatus::Pending)
+        .patch()
+        .assign(DepositCol::STATUS, status)
+        .map_err(AppError::from)?
+        .assign(DepositCol::ADMIN_ID, admin_id)
+        .map_err(AppError::from)?
+        .assign(DepositCol::ADMIN_REMARK, req.admin_remark.clone())
+        .map_err(AppError::from)?
+        .assign(DepositCol::REVIEWED_AT, Some(now))
+        .map_err(AppError::from)?
+        .assign(DepositCol::CLAIMED_BY, None::<i64>)
+        .map_err(AppError::from)?
+        .assign(DepositCol::CLAIMED_UNTIL, None::<OffsetDateTime>)
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    if updated == 0 {
+        return Err(AppError::Conflict(t(
+            "Deposit was already reviewed by another admin",
+        )));
+    }
+    record_status(conn.clone(), deposit_id, status, admin_id, req.admin_remark).await?;
+
+    // Credit the owner (for User owner_type) and pay their uplines
+    if status == DepositStatus::Approved && deposit.owner_type == OwnerType::User {
+        credit_ledger::post(
+            conn.clone(),
+            balance,
+            CreditEntry {
+                admin_id,
+                related_key: Some(deposit_id.to_string()),
+                remark: Some(format!("Deposit #{}", deposit_id)),
+                ..CreditEntry::new(
+                    deposit.owner_id,
+                    deposit.credit_type,
+                    deposit.net_amount,
+                    CreditTransactionType::TopUp,
+                )
+            },
+        )
+        .await?;
+
+        referral_commission::pay(
+            conn.clone(),
+            balance,
+            admin_id,
+            &CommissionSource::deposit(&deposit),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Undoes an approved deposit: debits the credited `net_amount` under the
+/// balance policy, claws back the referral commissions it paid and moves the
+/// deposit to `Reversed`. Runs as one transaction, so a debit the policy
+/// refuses leaves the deposit approved.
+pub async fn reverse_deposit(
+    state: &AppApiState,
+    admin_id: i64,
+    deposit_id: i64,
+    reason: String,
+) -> Result<DepositReversal, AppError> {
+    let deposit = find(state, deposit_id).await?;
+    if deposit.status != DepositStatus::Approved {
+        return Err(AppError::BadRequest(t("Only approved deposits can be reversed")));
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let related_key = deposit_id.to_string();
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    let updated = DepositModel::query(conn.clone())
+        .where_col(DepositCol::ID, Op::Eq, deposit_id)
+        .where_col(DepositCol::STATUS, Op::Eq, DepositStatus::Approved)
+        .patch()
+        .assign(DepositCol::STATUS, DepositStatus::Reversed)
+        .map_err(AppError::from)?
+        .assign(DepositCol::REVERSED_BY, Some(admin_id))
+        .map_err(AppError::from)?
+        .assign(DepositCol::REVERSAL_REASON, Some(reason.clone()))
+        .map_err(AppError::from)?
+        .assign(DepositCol::REVERSED_AT, Some(now))
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    if updated == 0 {
+        return Err(AppError::Conflict(t(
+            "Deposit was already reversed by another admin",
+        )));
+    }
+    record_status(
+        conn.clone(),
+        deposit_id,
+        DepositStatus::Reversed,
+        Some(admin_id),
+        Some(reason.clone()),
+    )
+    .await?;
+
+    let mut commissions_reversed = 0;
+    if deposit.owner_type == OwnerType::User {
+        credit_ledger::post(
+            conn.clone(),
+            &state.balance_config,
+            CreditEntry {
+                admin_id: Some(admin_id),
+                related_key: Some(related_key.clone()),
+                remark: Some(reason),
+                ..CreditEntry::new(
+                    deposit.owner_id,
+                    deposit.credit_type,
+                    -deposit.net_amount,
+                    CreditTransactionType::DepositReversal,
+                )
+            },
+        )
+        .await?;
+
+        commissions_reversed = referral_commission::reverse(
+            conn.clone(),
+            &state.balance_config,
+            Some(admin_id),
+            ReferralCommissionSource::Deposit,
+            deposit_id,
+        )
+        .await?;
+    }
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    let needs_reversal = UserCreditTransactionModel::query(DbConn::pool(&state.db))
+        .where_col(UserCreditTransactionCol::RELATED_KEY, Op::Eq, Some(related_key))
+        .all()
+        .await
+        .map_err(AppError::from)?
+        .into_iter()
+        .filter(|txn| !REVERSAL_HANDLED_TYPES.contains(&txn.transaction_type))
+        .collect();
+
+    Ok(DepositReversal {
+        deposit: find(state, deposit_id).await?,
+        commissions_reversed,
+        needs_reversal,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_review;
+    use crate::contracts::api::v1::admin::deposit::AdminDepositReviewInput;
+    use crate::internal::config::balance::BalanceConfig;
+    use crate::internal::workflows::test_support;
+    use core_db::common::sql::generate_snowflake_i64;
+    use generated::models::{
+        CreditType, DepositMethod, DepositReviewAction, DepositStatus, OwnerType,
+    };
+    use rust_decimal::Decimal;
+
+    async fn seed_pending_deposit(db: &sqlx::PgPool, user_id: i64, amount: Decimal) -> i64 {
+        let id = generate_snowflake_i64();
+        sqlx::query(
+            "INSERT INTO deposits
+                 (id, owner_type, owner_id, credit_type, deposit_method, status, amount, net_amount)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $7)",
+        )
+        .bind(id)
+        .bind(OwnerType::User)
+        .bind(user_id)
+        .bind(CreditType::Credit1)
+        .bind(DepositMethod::Manual)
+        .bind(DepositStatus::Pending)
+        .bind(amount)
+        .execute(db)
+        .await
+        .expect("seed deposit");
+        id
+    }
+
+    fn review(action: DepositReviewAction) -> AdminDepositReviewInput {
+        AdminDepositReviewInput {
+            action,
+            admin_remark: None,
         }
     }
 
-    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[ignore = "requires DATABASE_URL"]
+    async fn parallel_approvals_credit_once() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig::default();
+        let admin_a = test_support::seed_admin(&db).await;
+        let admin_b = test_support::seed_admin(&db).await;
+        let user_id = test_support::seed_user(&db, Decimal::ZERO).await;
+        let deposit_id = seed_pending_deposit(&db, user_id, Decimal::from(100)).await;
+
+        let (a, b) = tokio::join!(
+            apply_review(
+                &db,
+                &balance,
+                Some(admin_a),
+                deposit_id,
+                review(DepositReviewAction::Approve),
+            ),
+            apply_review(
+                &db,
+                &balance,
+                Some(admin_b),
+                deposit_id,
+                review(DepositReviewAction::Approve),
+            ),
+        );
+
+        assert_eq!(a.is_ok() as u8 + b.is_ok() as u8, 1);
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::from(100));
+        assert_eq!(test_support::transaction_count(&db, user_id).await, 1);
+    }
 
-    detail(state, deposit_id).await
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[ignore = "requires DATABASE_URL"]
+    async fn approve_racing_reject_applies_one_outcome() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig::default();
+        let admin_id = test_support::seed_admin(&db).await;
+        let user_id = test_support::seed_user(&db, Decimal::ZERO).await;
+        let deposit_id = seed_pending_deposit(&db, user_id, Decimal::from(50)).await;
+
+        let (approve, reject) = tokio::join!(
+            apply_review(
+                &db,
+                &balance,
+                Some(admin_id),
+                deposit_id,
+                review(DepositReviewAction::Approve),
+            ),
+            apply_review(
+                &db,
+                &balance,
+                Some(admin_id),
+                deposit_id,
+                review(DepositReviewAction::Reject),
+            ),
+        );
+
+        assert_eq!(approve.is_ok() as u8 + reject.is_ok() as u8, 1);
+        let expected = if approve.is_ok() {
+            Decimal::from(50)
+        } else {
+            Decimal::ZERO
+        };
+        assert_eq!(test_support::credit_1(&db, user_id).await, expected);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires DATABASE_URL"]
+    async fn review_appends_to_status_history() {
+        let db = test_support::pool().await;
+        let admin_id = test_support::seed_admin(&db).await;
+        let user_id = test_support::seed_user(&db, Decimal::ZERO).await;
+        let deposit_id = seed_pending_deposit(&db, user_id, Decimal::from(10)).await;
+
+        apply_review(
+            &db,
+            &BalanceConfig::default(),
+            Some(admin_id),
+            deposit_id,
+            review(DepositReviewAction::Reject),
+        )
+        .await
+        .expect("reject");
+
+        let history: Vec<(DepositStatus, Option<i64>)> = sqlx::query_as(
+            "SELECT status, admin_id FROM deposit_status_histories
+             WHERE deposit_id = $1 ORDER BY created_at, id",
+        )
+        .bind(deposit_id)
+        .fetch_all(&db)
+        .await
+        .expect("history");
+        assert_eq!(history, vec![(DepositStatus::Rejected, Some(admin_id))]);
+    }
 }
@@ -0,0 +1,338 @@
+use core_db::common::sql::{DbConn, Op};
+use core_web::error::AppError;
+use generated::models::{
+    CreditTransactionType, DepositStatus, FinancialApprovalAction, OwnerType, WithdrawalCol,
+    WithdrawalModel, WithdrawalStatus,
+};
+use time::{Duration, OffsetDateTime};
+
+use crate::internal::{
+    config::{balance::BalanceConfig, expiry::ExpiryConfig},
+    workflows::{
+        credit_ledger::{self, CreditEntry},
+        deposit, financial_approval, withdrawal,
+    },
+};
+
+/// What one run changed. Expired records are listed so the caller can
+/// broadcast their released review claims.
+#[derive(Debug, Default)]
+pub struct ExpirySummary {
+    pub deposits_expired: Vec<i64>,
+    pub withdrawals_expired: Vec<i64>,
+    pub withdrawals_flagged: usize,
+}
+
+impl ExpirySummary {
+    pub fn is_empty(&self) -> bool {
+        self.deposits_expired.is_empty()
+            && self.withdrawals_expired.is_empty()
+            && self.withdrawals_flagged == 0
+    }
+}
+
+/// Runs every enabled expiry check once.
+pub async fn run(
+    db: &sqlx::PgPool,
+    balance: &BalanceConfig,
+    config: &ExpiryConfig,
+) -> Result<ExpirySummary, AppError> {
+    let now = OffsetDateTime::now_utc();
+    let mut summary = ExpirySummary::default();
+
+    if let Some(cutoff) = cutoff(now, config.deposit_pending_hours)? {
+        summary.deposits_expired = expire_deposits(db, cutoff, config.deposit_pending_hours).await?;
+    }
+    if let Some(cutoff) = cutoff(now, config.withdrawal_pending_hours)? {
+        summary.withdrawals_expired =
+            expire_withdrawals(db, balance, cutoff, config.withdrawal_pending_hours).await?;
+    }
+    if let Some(cutoff) = cutoff(now, config.withdrawal_processing_sla_hours)? {
+        summary.withdrawals_flagged =
+            flag_processing_withdrawals(db, cutoff, config.withdrawal_processing_sla_hours)
+                .await?;
+    }
+
+    Ok(summary)
+}
+
+/// `hours` before `now`, or `None` when the check is off. An age too large
+/// to subtract is an error rather than a panic in the worker.
+fn cutoff(now: OffsetDateTime, hours: u64) -> Result<Option<OffsetDateTime>, AppError> {
+    if hours == 0 {
+        return Ok(None);
+    }
+    i64::try_from(hours)
+        .ok()
+        .and_then(|hours| hours.checked_mul(3600))
+        .and_then(|seconds| now.checked_sub(Duration::seconds(seconds)))
+        .map(Some)
+        .ok_or_else(|| {
+            AppError::Internal(anyhow::anyhow!("[expiry] {hours} hours is out of range"))
+        })
+}
+
+/// Pending deposits hold no funds, so they are expired in one statement,
+/// which also drops any review claim on them. Gateway deposits are left to
+/// their provider, whose callback may still report them paid after the
+/// cutoff.
+async fn expire_deposits(
+    db: &sqlx::PgPool,
+    cutoff: OffsetDateTime,
+    hours: u64,
+) -> Result<Vec<i64>, AppError> {
+    let scope = DbConn::pool(db).begin_scope().await.map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    let expired = conn
+        .fetch_all(
+            sqlx::query_scalar::<_, i64>(
+                "UPDATE deposits
+                 SET status = $1, claimed_by = NULL, claimed_until = NULL, updated_at = $2
+                 WHERE status = $3 AND created_at < $4 AND gateway_provider IS NULL
+                 RETURNING id",
+            )
+            .bind(DepositStatus::Expired)
+            .bind(OffsetDateTime::now_utc())
+            .bind(DepositStatus::Pending)
+            .bind(cutoff),
+        )
+        .await
+        .map_err(AppError::from)?;
+
+    let remark = format!("Expired after {hours} hours pending");
+    for deposit_id in &expired {
+        deposit::record_status(
+            conn.clone(),
+            *deposit_id,
+            DepositStatus::Expired,
+            None,
+            Some(remark.clone()),
+        )
+        .await?;
+        financial_approval::decline_open(
+            conn.clone(),
+            FinancialApprovalAction::DepositApprove,
+            *deposit_id,
+            remark.clone(),
+        )
+        .await?;
+    }
+
+    scope.commit().await.map_err(AppError::from)?;
+    Ok(expired)
+}
+
+/// Expires stale pending withdrawals one at a time, each in its own
+/// transaction, and releases the hold taken at submission. The status change
+/// is a compare-and-swap so a withdrawal an admin picked up in the meantime
+/// is left alone. Review claims and open proposals go with it.
+async fn expire_withdrawals(
+    db: &sqlx::PgPool,
+    balance: &BalanceConfig,
+    cutoff: OffsetDateTime,
+    hours: u64,
+) -> Result<Vec<i64>, AppError> {
+    let stale = WithdrawalModel::query(DbConn::pool(db))
+        .where_col(WithdrawalCol::STATUS, Op::Eq, WithdrawalStatus::Pending)
+        .where_col(WithdrawalCol::CREATED_AT, Op::Lt, cutoff)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    let remark = format!("Expired after {hours} hours pending");
+    let mut expired = Vec::new();
+    for record in stale {
+        let scope = DbConn::pool(db).begin_scope().await.map_err(AppError::from)?;
+        let conn = scope.conn();
+
+        let updated = WithdrawalModel::query(conn.clone())
+            .where_col(WithdrawalCol::ID, Op::Eq, record.id)
+            .where_col(WithdrawalCol::STATUS, Op::Eq, WithdrawalStatus::Pending)
+            .patch()
+            .assign(WithdrawalCol::STATUS, WithdrawalStatus::Expired)
+            .map_err(AppError::from)?
+            .assign(WithdrawalCol::CLAIMED_BY, None::<i64>)
+            .map_err(AppError::from)?
+            .assign(WithdrawalCol::CLAIMED_UNTIL, None::<OffsetDateTime>)
+            .map_err(AppError::from)?
+            .assign(WithdrawalCol::UPDATED_AT, OffsetDateTime::now_utc())
+            .map_err(AppError::from)?
+            .save()
+            .await
+            .map_err(AppError::from)?;
+        if updated == 0 {
+            continue;
+        }
+
+        withdrawal::record_status(
+            conn.clone(),
+            record.id,
+            WithdrawalStatus::Expired,
+            None,
+            Some(remark.clone()),
+        )
+        .await?;
+        financial_approval::decline_open(
+            conn.clone(),
+            FinancialApprovalAction::WithdrawalApprove,
+            record.id,
+            remark.clone(),
+        )
+        .await?;
+
+        // Withdrawals from before the balance hold have nothing to release
+        if record.owner_type == OwnerType::User
+            && withdrawal::balance_held(conn.clone(), record.id).await?
+        {
+            credit_ledger::post(
+                conn.clone(),
+                balance,
+                CreditEntry {
+                    related_key: Some(record.id.to_string()),
+                    remark: Some(format!("Withdrawal #{} expired", record.id)),
+                    ..CreditEntry::new(
+                        record.owner_id,
+                        record.credit_type,
+                        record.amount,
+                        CreditTransactionType::WithdrawHoldRelease,
+                    )
+                },
+            )
+            .await?;
+        }
+
+        scope.commit().await.map_err(AppError::from)?;
+        expired.push(record.id);
+    }
+
+    Ok(expired)
+}
+
+/// Flags withdrawals that have been processing past the SLA. The payout may
+/// already be underway, so nothing is released; each one is flagged once and
+/// noted on its timeline for admins to chase.
+async fn flag_processing_withdrawals(
+    db: &sqlx::PgPool,
+    cutoff: OffsetDateTime,
+    hours: u64,
+) -> Result<usize, AppError> {
+    let scope = DbConn::pool(db).begin_scope().await.map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    let now = OffsetDateTime::now_utc();
+    let flagged = conn
+        .fetch_all(
+            sqlx::query_scalar::<_, i64>(
+                "UPDATE withdrawals SET sla_breached_at = $1, updated_at = $1
+                 WHERE status = $2 AND sla_breached_at IS NULL AND reviewed_at < $3
+                 RETURNING id",
+            )
+            .bind(now)
+            .bind(WithdrawalStatus::Processing)
+            .bind(cutoff),
+        )
+        .await
+        .map_err(AppError::from)?;
+
+    for withdrawal_id in &flagged {
+        withdrawal::record_status(
+            conn.clone(),
+            *withdrawal_id,
+            WithdrawalStatus::Processing,
+            None,
+            Some(format!("Processing for more than {hours} hours")),
+        )
+        .await?;
+    }
+
+    scope.commit().await.map_err(AppError::from)?;
+    Ok(flagged.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cutoff, run};
+    use crate::internal::config::{balance::BalanceConfig, expiry::ExpiryConfig};
+    use crate::internal::workflows::test_support;
+    use core_db::common::sql::generate_snowflake_i64;
+    use generated::models::{
+        CreditType, FinancialApprovalAction, FinancialApprovalStatus, WithdrawalStatus,
+    };
+    use rust_decimal::Decimal;
+    use time::{Duration, OffsetDateTime};
+
+    #[test]
+    fn cutoff_is_an_error_when_out_of_range() {
+        let now = OffsetDateTime::now_utc();
+        assert!(matches!(cutoff(now, 0), Ok(None)));
+        assert!(cutoff(now, 2).is_ok_and(|at| at == Some(now - Duration::hours(2))));
+        assert!(cutoff(now, u64::MAX).is_err());
+        assert!(cutoff(now, i64::MAX as u64 / 3600).is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires DATABASE_URL"]
+    async fn expired_withdrawal_drops_its_claim_and_proposal() {
+        let db = test_support::pool().await;
+        let admin_id = test_support::seed_admin(&db).await;
+        let user_id = test_support::seed_user(&db, Decimal::ZERO).await;
+        let withdrawal_id = test_support::seed_withdrawal(
+            &db,
+            user_id,
+            Decimal::from(40),
+            WithdrawalStatus::Pending,
+        )
+        .await;
+        sqlx::query(
+            "UPDATE withdrawals
+             SET created_at = NOW() - INTERVAL '2 hours', claimed_by = $2,
+                 claimed_until = NOW() + INTERVAL '5 minutes'
+             WHERE id = $1",
+        )
+        .bind(withdrawal_id)
+        .bind(admin_id)
+        .execute(&db)
+        .await
+        .expect("age withdrawal");
+        let approval_id = generate_snowflake_i64();
+        sqlx::query(
+            "INSERT INTO financial_approvals
+                 (id, action, subject_id, credit_type, amount, proposed_by)
+             VALUES ($1, $2, $3, $4, 40, $5)",
+        )
+        .bind(approval_id)
+        .bind(FinancialApprovalAction::WithdrawalApprove)
+        .bind(withdrawal_id)
+        .bind(CreditType::Credit1)
+        .bind(admin_id)
+        .execute(&db)
+        .await
+        .expect("seed approval");
+
+        let config = ExpiryConfig {
+            withdrawal_pending_hours: 1,
+            ..ExpiryConfig::default()
+        };
+        let Ok(summary) = run(&db, &BalanceConfig::default(), &config).await else {
+            panic!("expiry run failed");
+        };
+
+        assert!(summary.withdrawals_expired.contains(&withdrawal_id));
+        let claimed_by: Option<i64> =
+            sqlx::query_scalar("SELECT claimed_by FROM withdrawals WHERE id = $1")
+                .bind(withdrawal_id)
+                .fetch_one(&db)
+                .await
+                .expect("read claim");
+        assert_eq!(claimed_by, None);
+        let approval: FinancialApprovalStatus =
+            sqlx::query_scalar("SELECT status FROM financial_approvals WHERE id = $1")
+                .bind(approval_id)
+                .fetch_one(&db)
+                .await
+                .expect("read approval");
+        assert_eq!(approval, FinancialApprovalStatus::Declined);
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::from(40));
+    }
+}
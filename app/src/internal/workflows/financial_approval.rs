@@ -0,0 +1,400 @@
+use core_db::common::sql::{DbConn, Op, OrderDir};
+use core_i18n::t;
+use core_web::{auth::AuthUser, error::AppError};
+use generated::{
+    guards::AdminGuard,
+    models::{
+        CreditType, DepositModel, FinancialApprovalAction, FinancialApprovalCol,
+        FinancialApprovalModel, FinancialApprovalRecord, FinancialApprovalStatus, OwnerType,
+        UserLimitKind, WithdrawalModel, WithdrawalReviewAction,
+    },
+    permissions::Permission,
+};
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use time::OffsetDateTime;
+
+use crate::contracts::api::v1::admin::withdrawal::AdminWithdrawalReviewInput;
+use crate::internal::{
+    api::state::AppApiState,
+    workflows::{
+        deposit, limits,
+        review_claim::{self, ClaimSubject},
+        user_credit, withdrawal,
+    },
+};
+
+/// Result of an action that may need a second admin.
+pub enum ApprovalOutcome<T> {
+    /// Below the threshold: the action ran straight away.
+    Applied(T),
+    /// Above the threshold: the action waits for another admin to confirm it.
+    Proposed(FinancialApprovalRecord),
+}
+
+/// A financial action held back for a second admin.
+pub struct Proposal {
+    pub action: FinancialApprovalAction,
+    pub subject_id: i64,
+    pub credit_type: CreditType,
+    pub amount: Decimal,
+    /// The original request, replayed on confirmation
+    pub payload: serde_json::Value,
+}
+
+/// The permission a checker needs to confirm or decline the action; the same
+/// one the maker needed to propose it.
+pub fn required_permission(action: FinancialApprovalAction) -> Permission {
+    match action {
+        FinancialApprovalAction::DepositApprove => Permission::DepositManage,
+        FinancialApprovalAction::WithdrawalApprove => Permission::WithdrawalManage,
+        FinancialApprovalAction::CreditAdjust => Permission::UserCreditManage,
+    }
+}
+
+pub async fn detail(
+    state: &AppApiState,
+    approval_id: i64,
+) -> Result<FinancialApprovalRecord, AppError> {
+    FinancialApprovalModel::find(DbConn::pool(&state.db), approval_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Approval not found")))
+}
+
+/// Pending proposals the admin is allowed to decide on, oldest first.
+pub async fn list_pending(
+    state: &AppApiState,
+    auth: &AuthUser<AdminGuard>,
+) -> Result<Vec<FinancialApprovalRecord>, AppError> {
+    let approvals = FinancialApprovalModel::query(DbConn::pool(&state.db))
+        .where_col(
+            FinancialApprovalCol::STATUS,
+            Op::Eq,
+            FinancialApprovalStatus::Pending,
+        )
+        .order_by(FinancialApprovalCol::CREATED_AT, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(approvals
+        .into_iter()
+        .filter(|approval| auth.user.has_permission(required_permission(approval.action)))
+        .collect())
+}
+
+/// Records the maker's action as a pending proposal. A deposit or withdrawal
+/// can only have one open proposal at a time.
+pub async fn propose(
+    state: &AppApiState,
+    admin_id: i64,
+    proposal: Proposal,
+) -> Result<FinancialApprovalRecord, AppError> {
+    if proposal.action != FinancialApprovalAction::CreditAdjust {
+        let open = FinancialApprovalModel::query(DbConn::pool(&state.db))
+            .where_col(FinancialApprovalCol::ACTION, Op::Eq, proposal.action)
+            .where_col(FinancialApprovalCol::SUBJECT_ID, Op::Eq, proposal.subject_id)
+            .where_col(
+                FinancialApprovalCol::STATUS,
+                Op::Eq,
+                FinancialApprovalStatus::Pending,
+            )
+            .count()
+            .await
+            .map_err(AppError::from)?;
+        if open > 0 {
+            return Err(AppError::Conflict(t(
+                "An approval is already waiting for confirmation",
+            )));
+        }
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let approval = FinancialApprovalModel::create(DbConn::pool(&state.db))
+        .set(FinancialApprovalCol::ACTION, proposal.action)?
+        .set(FinancialApprovalCol::SUBJECT_ID, proposal.subject_id)?
+        .set(FinancialApprovalCol::CREDIT_TYPE, proposal.credit_type)?
+        .set(FinancialApprovalCol::AMOUNT, proposal.amount)?
+        .set(FinancialApprovalCol::PAYLOAD, proposal.payload)?
+        .set(FinancialApprovalCol::STATUS, FinancialApprovalStatus::Pending)?
+        .set(FinancialApprovalCol::PROPOSED_BY, admin_id)?
+        .set(FinancialApprovalCol::CREATED_AT, now)?
+        .set(FinancialApprovalCol::UPDATED_AT, now)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    // The maker is done with the record; their claim would otherwise keep the
+    // checker from applying the action.
+    if let Some(subject) = claim_subject(approval.action) {
+        review_claim::release(state, admin_id, subject, approval.subject_id).await?;
+    }
+
+    Ok(approval)
+}
+
+/// Confirms a proposal and runs the held-back action as the checker, in the
+/// same transaction that closes the proposal, after checking the user's
+/// limits again. The proposer can never confirm their own proposal.
+pub async fn confirm(
+    state: &AppApiState,
+    auth: &AuthUser<AdminGuard>,
+    approval_id: i64,
+    remark: Option<String>,
+) -> Result<FinancialApprovalRecord, AppError> {
+    let approval = detail(state, approval_id).await?;
+    ensure_can_decide(auth, &approval)?;
+    if approval.proposed_by == auth.user.id {
+        return Err(AppError::Forbidden(t(
+            "You cannot confirm an action you proposed",
+        )));
+    }
+
+    let admin_id = auth.user.id;
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    recheck_limits(conn.clone(), state, &approval).await?;
+    close(conn.clone(), &approval, admin_id, FinancialApprovalStatus::Confirmed, remark).await?;
+
+    let processed = match approval.action {
+        FinancialApprovalAction::DepositApprove => {
+            deposit::apply_review_in(
+                conn.clone(),
+                &state.balance_config,
+                Some(admin_id),
+                approval.subject_id,
+                decode_payload(&approval)?,
+            )
+            .await?;
+            false
+        }
+        FinancialApprovalAction::WithdrawalApprove => {
+            let review: AdminWithdrawalReviewInput = decode_payload(&approval)?;
+            let processed = review.action == WithdrawalReviewAction::Process;
+            withdrawal::apply_review_in(
+                conn.clone(),
+                &state.balance_config,
+                Some(admin_id),
+                approval.subject_id,
+                review,
+            )
+            .await?;
+            processed
+        }
+        FinancialApprovalAction::CreditAdjust => {
+            user_credit::apply_adjustment(
+                conn.clone(),
+                &state.balance_config,
+                admin_id,
+                approval.subject_id,
+                approval.credit_type,
+                decode_payload(&approval)?,
+            )
+            .await?;
+            false
+        }
+    };
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    // A confirmed Process sends the payout
+    if processed {
+        withdrawal::submit_payout(state, approval.subject_id).await?;
+    }
+
+    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+    if let Some(subject) = claim_subject(approval.action) {
+        review_claim::publish(state, subject, approval.subject_id).await?;
+    }
+
+    detail(state, approval_id).await
+}
+
+/// Declines a proposal without running it. The proposer may withdraw their
+/// own proposal this way.
+pub async fn decline(
+    state: &AppApiState,
+    auth: &AuthUser<AdminGuard>,
+    approval_id: i64,
+    remark: Option<String>,
+) -> Result<FinancialApprovalRecord, AppError> {
+    let approval = detail(state, approval_id).await?;
+    ensure_can_decide(auth, &approval)?;
+
+    close(
+        DbConn::pool(&state.db),
+        &approval,
+        auth.user.id,
+        FinancialApprovalStatus::Declined,
+        remark,
+    )
+    .await?;
+
+    detail(state, approval_id).await
+}
+
+/// Declines any pending proposal on a deposit or withdrawal that left the
+/// review queue on its own, e.g. by expiring. No admin decided it, so the
+/// reviewer stays empty and `remark` says why.
+pub async fn decline_open(
+    conn: DbConn<'_>,
+    action: FinancialApprovalAction,
+    subject_id: i64,
+    remark: String,
+) -> Result<(), AppError> {
+    let now = OffsetDateTime::now_utc();
+    FinancialApprovalModel::query(conn)
+        .where_col(FinancialApprovalCol::ACTION, Op::Eq, action)
+        .where_col(FinancialApprovalCol::SUBJECT_ID, Op::Eq, subject_id)
+        .where_col(
+            FinancialApprovalCol::STATUS,
+            Op::Eq,
+            FinancialApprovalStatus::Pending,
+        )
+        .patch()
+        .assign(FinancialApprovalCol::STATUS, FinancialApprovalStatus::Declined)
+        .map_err(AppError::from)?
+        .assign(FinancialApprovalCol::REVIEW_REMARK, Some(remark))
+        .map_err(AppError::from)?
+        .assign(FinancialApprovalCol::REVIEWED_AT, Some(now))
+        .map_err(AppError::from)?
+        .assign(FinancialApprovalCol::UPDATED_AT, now)
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    Ok(())
+}
+
+/// The review queue the action's subject sits in, if any.
+/// Checks an approval against the user's `[limits]` again, holding the user
+/// row until the confirm commits. Other deposits or withdrawals may have been
+/// approved since the proposal, so the check made then no longer holds.
+async fn recheck_limits(
+    conn: DbConn<'_>,
+    state: &AppApiState,
+    approval: &FinancialApprovalRecord,
+) -> Result<(), AppError> {
+    let (kind, owner_type, owner_id, amount, created_at) = match approval.action {
+        FinancialApprovalAction::DepositApprove => {
+            let deposit = DepositModel::find(conn.clone(), approval.subject_id)
+                .await
+                .map_err(AppError::from)?
+                .ok_or_else(|| AppError::NotFound(t("Deposit not found")))?;
+            (
+                UserLimitKind::Deposit,
+                deposit.owner_type,
+                deposit.owner_id,
+                deposit.amount,
+                deposit.created_at,
+            )
+        }
+        FinancialApprovalAction::WithdrawalApprove => {
+            let withdrawal = WithdrawalModel::find(conn.clone(), approval.subject_id)
+                .await
+                .map_err(AppError::from)?
+                .ok_or_else(|| AppError::NotFound(t("Withdrawal not found")))?;
+            (
+                UserLimitKind::Withdrawal,
+                withdrawal.owner_type,
+                withdrawal.owner_id,
+                withdrawal.amount,
+                withdrawal.created_at,
+            )
+        }
+        FinancialApprovalAction::CreditAdjust => return Ok(()),
+    };
+    if owner_type != OwnerType::User {
+        return Ok(());
+    }
+
+    limits::check(
+        conn,
+        state,
+        kind,
+        owner_id,
+        amount,
+        created_at,
+        Some(approval.subject_id),
+    )
+    .await
+}
+
+fn claim_subject(action: FinancialApprovalAction) -> Option<ClaimSubject> {
+    match action {
+        FinancialApprovalAction::DepositApprove => Some(ClaimSubject::Deposit),
+        FinancialApprovalAction::WithdrawalApprove => Some(ClaimSubject::Withdrawal),
+        FinancialApprovalAction::CreditAdjust => None,
+    }
+}
+
+fn ensure_can_decide(
+    auth: &AuthUser<AdminGuard>,
+    approval: &FinancialApprovalRecord,
+) -> Result<(), AppError> {
+    if !auth.user.has_permission(required_permission(approval.action)) {
+        return Err(AppError::Forbidden(t(
+            "Missing permission for this approval",
+        )));
+    }
+    if approval.status != FinancialApprovalStatus::Pending {
+        return Err(AppError::BadRequest(t("Approval is no longer pending")));
+    }
+    Ok(())
+}
+
+/// Moves the proposal out of pending, as a compare-and-swap so two admins
+/// deciding at once cannot both succeed.
+async fn close(
+    conn: DbConn<'_>,
+    approval: &FinancialApprovalRecord,
+    admin_id: i64,
+    status: FinancialApprovalStatus,
+    remark: Option<String>,
+) -> Result<(), AppError> {
+    let now = OffsetDateTime::now_utc();
+    let updated = FinancialApprovalModel::query(conn)
+        .where_col(FinancialApprovalCol::ID, Op::Eq, approval.id)
+        .where_col(
+            FinancialApprovalCol::STATUS,
+            Op::Eq,
+            FinancialApprovalStatus::Pending,
+        )
+        .patch()
+        .assign(FinancialApprovalCol::STATUS, status)
+        .map_err(AppError::from)?
+        .assign(FinancialApprovalCol::REVIEWED_BY, Some(admin_id))
+        .map_err(AppError::from)?
+        .assign(FinancialApprovalCol::REVIEW_REMARK, remark)
+        .map_err(AppError::from)?
+        .assign(FinancialApprovalCol::REVIEWED_AT, Some(now))
+        .map_err(AppError::from)?
+        .assign(FinancialApprovalCol::UPDATED_AT, now)
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    if updated == 0 {
+        return Err(AppError::Conflict(t(
+            "Approval was already decided by another admin",
+        )));
+    }
+    Ok(())
+}
+
+fn decode_payload<T: DeserializeOwned>(
+    approval: &FinancialApprovalRecord,
+) -> Result<T, AppError> {
+    serde_json::from_value(approval.payload.clone()).map_err(|err| {
+        AppError::Internal(anyhow::anyhow!(
+            "invalid payload on financial approval {}: {err}",
+            approval.id
+        ))
+    })
+}
@@ -0,0 +1,326 @@
+use std::time::{Duration, Instant};
+
+use axum::http::{header, HeaderMap, HeaderValue};
+use core_db::common::sql::{DbConn, Op};
+use core_i18n::t;
+use core_web::error::AppError;
+use generated::models::{
+    DepositCol, DepositModel, DepositRecord, DepositReviewAction, DepositStatus, WebhookLogCol,
+    WebhookLogModel,
+};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{
+    contracts::api::v1::admin::deposit::AdminDepositReviewInput,
+    internal::{
+        api::state::AppApiState,
+        payment_gateway::{
+            fake::{self, FakeGateway},
+            GatewayCallback, GatewayPaymentStatus,
+        },
+        workflows::{
+            deposit,
+            review_claim::{self, ClaimSubject},
+        },
+    },
+};
+
+/// A callback as it arrived, kept raw for signature checks and the log.
+pub struct CallbackRequest<'a> {
+    pub url: String,
+    pub headers: &'a HeaderMap,
+    pub body: &'a [u8],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackOutcome {
+    Approved,
+    Rejected,
+    /// The deposit already has this outcome, e.g. a retried callback
+    Duplicate,
+    /// Accepted without touching the deposit, with the reason
+    Ignored(String),
+}
+
+impl CallbackOutcome {
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Approved => "approved".to_string(),
+            Self::Rejected => "rejected".to_string(),
+            Self::Duplicate => "duplicate".to_string(),
+            Self::Ignored(reason) => format!("ignored: {reason}"),
+        }
+    }
+}
+
+/// Settles a gateway deposit from a provider callback. Every callback is
+/// written to `webhook_log` along with the answer it got, including ones that
+/// fail the signature check.
+///
+/// A paid callback approves through [`deposit::apply_review`], the same path
+/// an admin review takes, without an admin and without the second-admin
+/// threshold: the provider has already taken the money. Repeats of a callback
+/// that was already applied are answered as successful so the provider stops
+/// retrying, and never credit twice.
+pub async fn handle(
+    state: &AppApiState,
+    provider: &str,
+    request: CallbackRequest<'_>,
+) -> Result<CallbackOutcome, AppError> {
+    let started = Instant::now();
+    let result = process(state, provider, &request).await;
+
+    let (status, response) = match &result {
+        Ok(outcome) => (200, outcome.describe()),
+        Err(err) => error_response(err),
+    };
+    log(state, &request, status, response, started.elapsed()).await;
+
+    result
+}
+
+/// Plays the fake provider's side of a checkout: signs a callback for the
+/// session with the configured secret and hands it to [`handle`].
+pub async fn fake_checkout(
+    state: &AppApiState,
+    callback_url: String,
+    session_id: &str,
+    status: GatewayPaymentStatus,
+) -> Result<CallbackOutcome, AppError> {
+    if state.payment_gateway_config.provider != fake::CODE {
+        return Err(AppError::NotFound(t("Payment gateway not found")));
+    }
+    let deposit = find_by_session(state, fake::CODE, session_id).await?;
+
+    let gateway = FakeGateway::new(&state.payment_gateway_config);
+    let (signature, body) = gateway.signed_callback(&GatewayCallback {
+        event_id: format!("evt_{}", nanoid::nanoid!(16)),
+        session_id: session_id.to_string(),
+        status,
+        amount: deposit.amount,
+    })?;
+    let mut headers = HeaderMap::new();
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    headers.insert(
+        fake::SIGNATURE_HEADER,
+        HeaderValue::from_str(&signature)
+            .map_err(|e| AppError::Internal(anyhow::anyhow!("Invalid signature header: {e}")))?,
+    );
+
+    handle(
+        state,
+        fake::CODE,
+        CallbackRequest {
+            url: callback_url,
+            headers: &headers,
+            body: &body,
+        },
+    )
+    .await
+}
+
+async fn process(
+    state: &AppApiState,
+    provider: &str,
+    request: &CallbackRequest<'_>,
+) -> Result<CallbackOutcome, AppError> {
+    let gateway = state
+        .payment_gateway
+        .as_ref()
+        .filter(|gateway| gateway.code() == provider)
+        .ok_or_else(|| AppError::NotFound(t("Payment gateway not found")))?;
+    gateway.verify_signature(request.headers, request.body)?;
+    let callback = gateway.parse_callback(request.body)?;
+
+    let deposit = find_by_session(state, provider, &callback.session_id).await?;
+    let action = match settlement(deposit.status, deposit.amount, &callback) {
+        Ok(action) => action,
+        Err(outcome) => return Ok(outcome),
+    };
+
+    let review = AdminDepositReviewInput {
+        action,
+        admin_remark: Some(format!("{provider} event {}", callback.event_id)),
+    };
+    match deposit::apply_review(&state.db, &state.balance_config, None, deposit.id, review).await {
+        Ok(()) => {}
+        // A copy of this callback delivered concurrently may have got there
+        // first; answer as the deposit now stands.
+        Err(err @ (AppError::Conflict(_) | AppError::BadRequest(_))) => {
+            let current = find_by_session(state, provider, &callback.session_id).await?;
+            return match settlement(current.status, current.amount, &callback) {
+                Err(outcome) => Ok(outcome),
+                Ok(_) => Err(err),
+            };
+        }
+        Err(err) => return Err(err),
+    }
+
+    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+    review_claim::publish(state, ClaimSubject::Deposit, deposit.id).await?;
+
+    Ok(match action {
+        DepositReviewAction::Approve => CallbackOutcome::Approved,
+        DepositReviewAction::Reject => CallbackOutcome::Rejected,
+    })
+}
+
+/// The review a callback calls for, or why it calls for none.
+fn settlement(
+    status: DepositStatus,
+    amount: Decimal,
+    callback: &GatewayCallback,
+) -> Result<DepositReviewAction, CallbackOutcome> {
+    let action = match callback.status {
+        GatewayPaymentStatus::Paid => DepositReviewAction::Approve,
+        GatewayPaymentStatus::Failed => DepositReviewAction::Reject,
+        GatewayPaymentStatus::Pending => {
+            return Err(CallbackOutcome::Ignored("payment still pending".to_string()))
+        }
+    };
+
+    match (status, action) {
+        (DepositStatus::Pending, _) => {}
+        (DepositStatus::Approved, DepositReviewAction::Approve)
+        | (DepositStatus::Rejected, DepositReviewAction::Reject) => {
+            return Err(CallbackOutcome::Duplicate)
+        }
+        (status, _) => {
+            return Err(CallbackOutcome::Ignored(format!(
+                "deposit is {}",
+                status.explained_label()
+            )))
+        }
+    }
+
+    // Left pending for an admin to look at rather than crediting a
+    // different amount than the user asked for.
+    if action == DepositReviewAction::Approve && callback.amount != amount {
+        return Err(CallbackOutcome::Ignored(format!(
+            "paid {} but the deposit is for {}",
+            callback.amount.normalize(),
+            amount.normalize()
+        )));
+    }
+
+    Ok(action)
+}
+
+async fn find_by_session(
+    state: &AppApiState,
+    provider: &str,
+    session_id: &str,
+) -> Result<DepositRecord, AppError> {
+    DepositModel::query(DbConn::pool(&state.db))
+        .where_col(DepositCol::GATEWAY_PROVIDER, Op::Eq, Some(provider.to_string()))
+        .where_col(DepositCol::GATEWAY_SESSION_ID, Op::Eq, Some(session_id.to_string()))
+        .first()
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Deposit not found")))
+}
+
+fn error_response(err: &AppError) -> (i32, String) {
+    match err {
+        AppError::BadRequest(message) => (400, message.clone()),
+        AppError::Unauthorized(message) => (401, message.clone()),
+        AppError::Forbidden(message) => (403, message.clone()),
+        AppError::NotFound(message) => (404, message.clone()),
+        AppError::Conflict(message) => (409, message.clone()),
+        _ => (500, "Internal server error".to_string()),
+    }
+}
+
+/// Best effort: a failed log write never changes the answer the provider gets.
+async fn log(
+    state: &AppApiState,
+    request: &CallbackRequest<'_>,
+    status: i32,
+    response: String,
+    elapsed: Duration,
+) {
+    let headers: serde_json::Map<String, serde_json::Value> = request
+        .headers
+        .iter()
+        .filter(|(name, _)| **name != header::AUTHORIZATION && **name != header::COOKIE)
+        .filter_map(|(name, value)| {
+            let value = value.to_str().ok()?;
+            Some((name.as_str().to_string(), serde_json::Value::from(value)))
+        })
+        .collect();
+
+    let create = WebhookLogModel::create(DbConn::pool(&state.db))
+        .set(WebhookLogCol::ID, uuid::Uuid::new_v4())
+        .and_then(|create| create.set(WebhookLogCol::REQUEST_URL, request.url.clone()))
+        .and_then(|create| create.set(WebhookLogCol::REQUEST_METHOD, "POST".to_string()))
+        .and_then(|create| {
+            create.set(WebhookLogCol::REQUEST_HEADERS, Some(serde_json::Value::Object(headers)))
+        })
+        .and_then(|create| {
+            create.set(
+                WebhookLogCol::REQUEST_BODY,
+                Some(String::from_utf8_lossy(request.body).into_owned()),
+            )
+        })
+        .and_then(|create| create.set(WebhookLogCol::RESPONSE_STATUS, Some(status)))
+        .and_then(|create| create.set(WebhookLogCol::RESPONSE_BODY, Some(response)))
+        .and_then(|create| {
+            create.set(WebhookLogCol::DURATION_MS, Some(elapsed.as_millis() as i32))
+        })
+        .and_then(|create| create.set(WebhookLogCol::CREATED_AT, OffsetDateTime::now_utc()));
+    if let Ok(create) = create {
+        let _ = create.save().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{settlement, CallbackOutcome};
+    use crate::internal::payment_gateway::{GatewayCallback, GatewayPaymentStatus};
+    use generated::models::{DepositReviewAction, DepositStatus};
+    use rust_decimal::Decimal;
+
+    fn callback(status: GatewayPaymentStatus, amount: i64) -> GatewayCallback {
+        GatewayCallback {
+            event_id: "evt_1".to_string(),
+            session_id: "sess_1".to_string(),
+            status,
+            amount: Decimal::from(amount),
+        }
+    }
+
+    #[test]
+    fn paid_callback_approves_a_pending_deposit_once() {
+        let paid = callback(GatewayPaymentStatus::Paid, 100);
+        assert_eq!(
+            settlement(DepositStatus::Pending, Decimal::from(100), &paid),
+            Ok(DepositReviewAction::Approve)
+        );
+        assert_eq!(
+            settlement(DepositStatus::Approved, Decimal::from(100), &paid),
+            Err(CallbackOutcome::Duplicate)
+        );
+    }
+
+    #[test]
+    fn callbacks_that_do_not_fit_the_deposit_are_ignored() {
+        let paid = callback(GatewayPaymentStatus::Paid, 100);
+        assert!(matches!(
+            settlement(DepositStatus::Pending, Decimal::from(90), &paid),
+            Err(CallbackOutcome::Ignored(_))
+        ));
+        assert!(matches!(
+            settlement(DepositStatus::Expired, Decimal::from(100), &paid),
+            Err(CallbackOutcome::Ignored(_))
+        ));
+        assert!(matches!(
+            settlement(
+                DepositStatus::Pending,
+                Decimal::from(100),
+                &callback(GatewayPaymentStatus::Pending, 100),
+            ),
+            Err(CallbackOutcome::Ignored(_))
+        ));
+    }
+}
@@ -0,0 +1,337 @@
+use core_db::common::sql::{DbConn, Op, OrderDir};
+use core_db::platform::attachments::types::AttachmentInput;
+use core_i18n::{t, t_args};
+use core_web::error::AppError;
+use generated::localized;
+use generated::models::{
+    AdminModel, AdminRecord, KycDocumentType, KycLevel, KycReviewAction, KycStatus,
+    KycSubmissionCol, KycSubmissionModel, KycSubmissionRecord, UserCol, UserModel, UserRecord,
+};
+use time::OffsetDateTime;
+
+use crate::{
+    contracts::api::v1::{admin::kyc::AdminKycReviewInput, user::kyc::UserKycSubmitInput},
+    internal::api::state::AppApiState,
+};
+
+/// A submission with the user it is for and the admin who reviewed it.
+pub struct KycSubmissionDetail {
+    pub submission: KycSubmissionRecord,
+    pub user: Option<UserRecord>,
+    pub admin: Option<AdminRecord>,
+}
+
+/// Attachment fields a submission's document images are stored under.
+pub const DOCUMENT_FIELDS: [&str; 4] = ["id_front", "id_back", "selfie", "proof_of_address"];
+
+/// Documents an approval to `level` needs. Passports have no back side.
+pub fn required_documents(level: KycLevel, document_type: KycDocumentType) -> Vec<&'static str> {
+    let mut fields = vec!["id_front"];
+    if document_type != KycDocumentType::Passport {
+        fields.push("id_back");
+    }
+    if level == KycLevel::Advanced {
+        fields.extend(["selfie", "proof_of_address"]);
+    }
+    fields
+}
+
+fn has_document(submission: &KycSubmissionRecord, field: &str) -> bool {
+    match field {
+        "id_front" => submission.id_front.is_some(),
+        "id_back" => submission.id_back.is_some(),
+        "selfie" => submission.selfie.is_some(),
+        "proof_of_address" => submission.proof_of_address.is_some(),
+        _ => false,
+    }
+}
+
+pub fn missing_documents(submission: &KycSubmissionRecord) -> Vec<String> {
+    required_documents(submission.level, submission.document_type)
+        .into_iter()
+        .filter(|field| !has_document(submission, field))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Maps an upload slot from the URL to its attachment field.
+pub fn document_field(raw: &str) -> Result<&'static str, AppError> {
+    DOCUMENT_FIELDS
+        .into_iter()
+        .find(|field| *field == raw)
+        .ok_or_else(|| AppError::BadRequest(t("Unknown KYC document")))
+}
+
+fn is_above(level: KycLevel, current: KycLevel) -> bool {
+    level as i16 > current as i16
+}
+
+pub async fn find(state: &AppApiState, id: i64) -> Result<KycSubmissionRecord, AppError> {
+    KycSubmissionModel::find(DbConn::pool(&state.db), id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("KYC submission not found")))
+}
+
+pub async fn detail(state: &AppApiState, id: i64) -> Result<KycSubmissionDetail, AppError> {
+    let submission = find(state, id).await?;
+    let user = UserModel::find(DbConn::pool(&state.db), submission.user_id)
+        .await
+        .map_err(AppError::from)?;
+    let admin = match submission.admin_id {
+        Some(admin_id) => AdminModel::find(DbConn::pool(&state.db), admin_id)
+            .await
+            .map_err(AppError::from)?,
+        None => None,
+    };
+
+    Ok(KycSubmissionDetail {
+        submission,
+        user,
+        admin,
+    })
+}
+
+/// Fetches a submission only when it belongs to the given user.
+pub async fn find_own(
+    state: &AppApiState,
+    user_id: i64,
+    id: i64,
+) -> Result<KycSubmissionRecord, AppError> {
+    KycSubmissionModel::query(DbConn::pool(&state.db))
+        .where_col(KycSubmissionCol::ID, Op::Eq, id)
+        .where_col(KycSubmissionCol::USER_ID, Op::Eq, user_id)
+        .first()
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("KYC submission not found")))
+}
+
+/// The user's current KYC level and their most recent submission.
+pub async fn status(
+    state: &AppApiState,
+    user_id: i64,
+) -> Result<(KycLevel, Option<KycSubmissionRecord>), AppError> {
+    let user = UserModel::find(DbConn::pool(&state.db), user_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("User not found")))?;
+    let latest = KycSubmissionModel::query(DbConn::pool(&state.db))
+        .where_col(KycSubmissionCol::USER_ID, Op::Eq, user_id)
+        .order_by(KycSubmissionCol::CREATED_AT, OrderDir::Desc)
+        .order_by(KycSubmissionCol::ID, OrderDir::Desc)
+        .first()
+        .await
+        .map_err(AppError::from)?;
+
+    Ok((user.kyc_level, latest))
+}
+
+/// Opens a pending submission for a level above the user's current one. The
+/// documents are uploaded to it afterwards with [`attach_document`].
+pub async fn submit(
+    state: &AppApiState,
+    user_id: i64,
+    req: UserKycSubmitInput,
+) -> Result<KycSubmissionRecord, AppError> {
+    let (current, latest) = status(state, user_id).await?;
+    if !is_above(req.level, current) {
+        return Err(AppError::BadRequest(t("You are already verified at this level")));
+    }
+    if latest.is_some_and(|submission| submission.status == KycStatus::Pending) {
+        return Err(AppError::BadRequest(t(
+            "You already have a KYC submission waiting for review",
+        )));
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let submission = KycSubmissionModel::create(DbConn::pool(&state.db))
+        .set(KycSubmissionCol::USER_ID, user_id)?
+        .set(KycSubmissionCol::LEVEL, req.level)?
+        .set(KycSubmissionCol::STATUS, KycStatus::Pending)?
+        .set(KycSubmissionCol::FULL_NAME, req.full_name.trim().to_string())?
+        .set(KycSubmissionCol::DOCUMENT_TYPE, req.document_type)?
+        .set(KycSubmissionCol::DOCUMENT_NUMBER, req.document_number.trim().to_string())?
+        .set(KycSubmissionCol::CREATED_AT, now)?
+        .set(KycSubmissionCol::UPDATED_AT, now)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+
+    find(state, submission.id).await
+}
+
+/// Stores an uploaded image as one of a pending submission's documents,
+/// replacing an earlier upload to the same field.
+pub async fn attach_document(
+    state: &AppApiState,
+    submission: &KycSubmissionRecord,
+    field: &'static str,
+    upload: AttachmentInput,
+) -> Result<KycSubmissionRecord, AppError> {
+    if submission.status != KycStatus::Pending {
+        return Err(AppError::BadRequest(t("KYC submission is not pending")));
+    }
+
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    // Touching the row first locks it while still pending, so a review
+    // cannot land between the check and the new document.
+    let updated = KycSubmissionModel::query(conn.clone())
+        .where_col(KycSubmissionCol::ID, Op::Eq, submission.id)
+        .where_col(KycSubmissionCol::STATUS, Op::Eq, KycStatus::Pending)
+        .patch()
+        .assign(KycSubmissionCol::UPDATED_AT, OffsetDateTime::now_utc())
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    if updated == 0 {
+        return Err(AppError::Conflict(t("KYC submission is not pending")));
+    }
+    localized::replace_single_attachment(
+        conn.clone(),
+        localized::KYC_SUBMISSION_OWNER_TYPE,
+        submission.id,
+        field,
+        &upload,
+    )
+    .await
+    .map_err(AppError::from)?;
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    find(state, submission.id).await
+}
+
+/// Approves or rejects a pending submission. An approval needs every
+/// document the level requires and raises the user's KYC level; a level the
+/// user already passed is never lowered. The status update only matches
+/// while the submission is pending, so a second reviewer gets a conflict.
+pub async fn review(
+    state: &AppApiState,
+    admin_id: i64,
+    id: i64,
+    req: AdminKycReviewInput,
+) -> Result<KycSubmissionDetail, AppError> {
+    let submission = find(state, id).await?;
+    if submission.status != KycStatus::Pending {
+        return Err(AppError::BadRequest(t("KYC submission is not pending")));
+    }
+
+    let (status, reject_reason) = match req.action {
+        KycReviewAction::Approve => {
+            let missing = missing_documents(&submission);
+            if !missing.is_empty() {
+                let missing = missing.join(", ");
+                return Err(AppError::BadRequest(t_args(
+                    "Missing KYC documents: :documents",
+                    &[("documents", missing.as_str())],
+                )));
+            }
+            (KycStatus::Approved, None)
+        }
+        KycReviewAction::Reject => (
+            KycStatus::Rejected,
+            req.reject_reason.map(|reason| reason.trim().to_string()),
+        ),
+    };
+    let now = OffsetDateTime::now_utc();
+
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    let updated = KycSubmissionModel::query(conn.clone())
+        .where_col(KycSubmissionCol::ID, Op::Eq, id)
+        .where_col(KycSubmissionCol::STATUS, Op::Eq, KycStatus::Pending)
+        .patch()
+        .assign(KycSubmissionCol::STATUS, status)
+        .map_err(AppError::from)?
+        .assign(KycSubmissionCol::REJECT_REASON, reject_reason)
+        .map_err(AppError::from)?
+        .assign(KycSubmissionCol::ADMIN_ID, Some(admin_id))
+        .map_err(AppError::from)?
+        .assign(KycSubmissionCol::REVIEWED_AT, Some(now))
+        .map_err(AppError::from)?
+        .assign(KycSubmissionCol::UPDATED_AT, now)
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    if updated == 0 {
+        return Err(AppError::Conflict(t(
+            "KYC submission was already reviewed by another admin",
+        )));
+    }
+
+    if status == KycStatus::Approved {
+        UserModel::query(conn.clone())
+            .where_col(UserCol::ID, Op::Eq, submission.user_id)
+            .where_col(UserCol::KYC_LEVEL, Op::Lt, submission.level)
+            .patch()
+            .assign(UserCol::KYC_LEVEL, submission.level)
+            .map_err(AppError::from)?
+            .assign(UserCol::UPDATED_AT, now)
+            .map_err(AppError::from)?
+            .save()
+            .await
+            .map_err(AppError::from)?;
+    }
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+
+    detail(state, id).await
+}
+
+/// Fails unless the user has reached `min_level`; `None` requires nothing.
+pub async fn require_level(
+    conn: DbConn<'_>,
+    user_id: i64,
+    min_level: Option<KycLevel>,
+) -> Result<(), AppError> {
+    let Some(min_level) = min_level else {
+        return Ok(());
+    };
+    let user = UserModel::find(conn, user_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("User not found")))?;
+    if is_above(min_level, user.kyc_level) {
+        let level = min_level.explained_label();
+        return Err(AppError::Forbidden(t_args(
+            "Complete :level identity verification before withdrawing",
+            &[("level", level.as_str())],
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use generated::models::{KycDocumentType, KycLevel};
+
+    use super::required_documents;
+
+    #[test]
+    fn required_documents_depend_on_level_and_document() {
+        assert_eq!(
+            required_documents(KycLevel::Basic, KycDocumentType::IdCard),
+            ["id_front", "id_back"]
+        );
+        assert_eq!(
+            required_documents(KycLevel::Advanced, KycDocumentType::Passport),
+            ["id_front", "selfie", "proof_of_address"]
+        );
+    }
+}
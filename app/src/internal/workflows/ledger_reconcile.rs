@@ -0,0 +1,253 @@
+use core_db::common::sql::{generate_snowflake_i64, DbConn, Op, OrderDir};
+use core_i18n::t;
+use core_web::error::AppError;
+use generated::models::{
+    CreditLedgerMismatchCol, CreditLedgerMismatchModel, CreditLedgerMismatchRecord,
+    CreditLedgerMismatchStatus, CreditTransactionType, CreditType,
+};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::internal::{
+    api::state::AppApiState,
+    workflows::credit_ledger::{self, CreditEntry},
+};
+
+/// A user balance column that disagrees with the sum of its transactions.
+#[derive(Debug, sqlx::FromRow)]
+pub struct LedgerDrift {
+    pub user_id: i64,
+    pub username: String,
+    pub credit_type: CreditType,
+    pub stored_balance: Decimal,
+    pub ledger_balance: Decimal,
+}
+
+impl LedgerDrift {
+    pub fn difference(&self) -> Decimal {
+        self.stored_balance - self.ledger_balance
+    }
+}
+
+pub struct ScanSummary {
+    pub drifts: Vec<LedgerDrift>,
+    pub resolved: u64,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct LockedBalance {
+    credit_1: Decimal,
+    credit_2: Decimal,
+}
+
+const DRIFT_SQL: &str = "
+    SELECT u.id AS user_id, u.username, c.credit_type, c.stored_balance,
+           COALESCE(l.ledger_balance, 0) AS ledger_balance
+    FROM users u
+    CROSS JOIN LATERAL (VALUES (1::SMALLINT, u.credit_1), (2::SMALLINT, u.credit_2))
+        AS c(credit_type, stored_balance)
+    LEFT JOIN (
+        SELECT user_id, credit_type, SUM(amount) AS ledger_balance
+        FROM user_credit_transactions
+        GROUP BY user_id, credit_type
+    ) l ON l.user_id = u.id AND l.credit_type = c.credit_type
+    WHERE c.stored_balance <> COALESCE(l.ledger_balance, 0)
+    ORDER BY u.id, c.credit_type";
+
+pub async fn find_drift(db: &sqlx::PgPool) -> anyhow::Result<Vec<LedgerDrift>> {
+    Ok(sqlx::query_as::<_, LedgerDrift>(DRIFT_SQL)
+        .fetch_all(db)
+        .await?)
+}
+
+/// Compares every balance against its ledger and records the result: each
+/// drift is upserted as an open mismatch, and open mismatches that no longer
+/// drift are marked resolved.
+pub async fn scan(db: &sqlx::PgPool) -> anyhow::Result<ScanSummary> {
+    let drifts = find_drift(db).await?;
+    let now = OffsetDateTime::now_utc();
+
+    for drift in &drifts {
+        sqlx::query(
+            "INSERT INTO credit_ledger_mismatches
+                 (id, user_id, credit_type, stored_balance, ledger_balance, difference,
+                  status, detected_at, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $8, $8)
+             ON CONFLICT (user_id, credit_type) WHERE status = 1
+             DO UPDATE SET stored_balance = EXCLUDED.stored_balance,
+                           ledger_balance = EXCLUDED.ledger_balance,
+                           difference = EXCLUDED.difference,
+                           updated_at = EXCLUDED.updated_at",
+        )
+        .bind(generate_snowflake_i64())
+        .bind(drift.user_id)
+        .bind(drift.credit_type)
+        .bind(drift.stored_balance)
+        .bind(drift.ledger_balance)
+        .bind(drift.difference())
+        .bind(CreditLedgerMismatchStatus::Open)
+        .bind(now)
+        .execute(db)
+        .await?;
+    }
+
+    let user_ids: Vec<i64> = drifts.iter().map(|d| d.user_id).collect();
+    let credit_types: Vec<i16> = drifts
+        .iter()
+        .map(|d| match d.credit_type {
+            CreditType::Credit1 => 1,
+            CreditType::Credit2 => 2,
+        })
+        .collect();
+
+    let resolved = sqlx::query(
+        "UPDATE credit_ledger_mismatches
+         SET status = $1, resolved_at = $2, updated_at = $2
+         WHERE status = $3
+           AND (user_id, credit_type) NOT IN (
+               SELECT * FROM UNNEST($4::BIGINT[], $5::SMALLINT[])
+           )",
+    )
+    .bind(CreditLedgerMismatchStatus::Resolved)
+    .bind(now)
+    .bind(CreditLedgerMismatchStatus::Open)
+    .bind(user_ids)
+    .bind(credit_types)
+    .execute(db)
+    .await?
+    .rows_affected();
+
+    Ok(ScanSummary { drifts, resolved })
+}
+
+pub async fn list_open(state: &AppApiState) -> Result<Vec<CreditLedgerMismatchRecord>, AppError> {
+    CreditLedgerMismatchModel::query(DbConn::pool(&state.db))
+        .where_col(
+            CreditLedgerMismatchCol::STATUS,
+            Op::Eq,
+            CreditLedgerMismatchStatus::Open,
+        )
+        .order_by(CreditLedgerMismatchCol::DETECTED_AT, OrderDir::Asc)
+        .order_by(CreditLedgerMismatchCol::ID, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)
+}
+
+/// Brings the ledger back in line with the stored balance by writing a
+/// `LedgerCorrection` transaction for the current difference. The stored
+/// balance is left untouched since it is what users and holds were based on.
+/// The drift is re-measured under the user row lock, so a mismatch that has
+/// since healed is marked resolved instead of corrected.
+pub async fn correct(
+    state: &AppApiState,
+    admin_id: i64,
+    mismatch_id: i64,
+) -> Result<CreditLedgerMismatchRecord, AppError> {
+    let mismatch = CreditLedgerMismatchModel::find(DbConn::pool(&state.db), mismatch_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Ledger mismatch not found")))?;
+
+    if mismatch.status != CreditLedgerMismatchStatus::Open {
+        return Err(AppError::BadRequest(t("Ledger mismatch is no longer open")));
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    let locked = conn
+        .fetch_all(
+            sqlx::query_as::<_, LockedBalance>(
+                "SELECT credit_1, credit_2 FROM users WHERE id = $1 FOR UPDATE",
+            )
+            .bind(mismatch.user_id),
+        )
+        .await
+        .map_err(AppError::from)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| AppError::NotFound(t("User not found")))?;
+
+    let stored_balance = match mismatch.credit_type {
+        CreditType::Credit1 => locked.credit_1,
+        CreditType::Credit2 => locked.credit_2,
+    };
+    let ledger_balance = conn
+        .fetch_scalar(
+            sqlx::query_scalar::<_, Decimal>(
+                "SELECT COALESCE(SUM(amount), 0) FROM user_credit_transactions
+                 WHERE user_id = $1 AND credit_type = $2",
+            )
+            .bind(mismatch.user_id)
+            .bind(mismatch.credit_type),
+        )
+        .await
+        .map_err(AppError::from)?;
+    let difference = stored_balance - ledger_balance;
+
+    let status = if difference.is_zero() {
+        CreditLedgerMismatchStatus::Resolved
+    } else {
+        credit_ledger::record(
+            conn.clone(),
+            CreditEntry {
+                admin_id: Some(admin_id),
+                related_key: Some(mismatch.id.to_string()),
+                params: Some(serde_json::json!({
+                    "stored_balance": stored_balance.normalize().to_string(),
+                    "ledger_balance": ledger_balance.normalize().to_string(),
+                })),
+                remark: Some(format!("Ledger reconciliation #{}", mismatch.id)),
+                ..CreditEntry::new(
+                    mismatch.user_id,
+                    mismatch.credit_type,
+                    difference,
+                    CreditTransactionType::LedgerCorrection,
+                )
+            },
+            stored_balance,
+        )
+        .await?;
+        CreditLedgerMismatchStatus::Corrected
+    };
+
+    // Only one admin closes the mismatch; the loser's correction rolls back.
+    let updated = CreditLedgerMismatchModel::query(conn.clone())
+        .where_col(CreditLedgerMismatchCol::ID, Op::Eq, mismatch.id)
+        .where_col(CreditLedgerMismatchCol::STATUS, Op::Eq, CreditLedgerMismatchStatus::Open)
+        .patch()
+        .assign(CreditLedgerMismatchCol::STATUS, status)
+        .map_err(AppError::from)?
+        .assign(CreditLedgerMismatchCol::ADMIN_ID, Some(admin_id))
+        .map_err(AppError::from)?
+        .assign(CreditLedgerMismatchCol::STORED_BALANCE, stored_balance)
+        .map_err(AppError::from)?
+        .assign(CreditLedgerMismatchCol::LEDGER_BALANCE, ledger_balance)
+        .map_err(AppError::from)?
+        .assign(CreditLedgerMismatchCol::DIFFERENCE, difference)
+        .map_err(AppError::from)?
+        .assign(CreditLedgerMismatchCol::RESOLVED_AT, Some(now))
+        .map_err(AppError::from)?
+        .assign(CreditLedgerMismatchCol::UPDATED_AT, now)
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    if updated == 0 {
+        return Err(AppError::Conflict(t(
+            "Ledger mismatch was already handled by another admin",
+        )));
+    }
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    CreditLedgerMismatchModel::find(DbConn::pool(&state.db), mismatch.id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Ledger mismatch not found")))
+}
@@ -0,0 +1,472 @@
+use core_db::common::sql::{DbConn, Op};
+use core_i18n::{t, t_args};
+use core_web::error::AppError;
+use generated::models::{
+    OwnerType, UserLimitCol, UserLimitKind, UserLimitModel, UserLimitRecord, UserModel,
+};
+use rust_decimal::Decimal;
+use time::{Duration, OffsetDateTime};
+
+use crate::internal::{
+    api::state::AppApiState, config::limits::TransactionLimits, workflows::period,
+};
+
+/// Count and total amount of a user's requests in one window.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PeriodUsage {
+    pub count: i64,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LimitUsage {
+    pub daily: PeriodUsage,
+    pub weekly: PeriodUsage,
+    pub monthly: PeriodUsage,
+}
+
+/// The limits that apply to a user, layer by layer, and what they have used
+/// of them so far.
+#[derive(Debug, Clone)]
+pub struct LimitStatus {
+    pub kind: UserLimitKind,
+    /// Global caps with the user's country and KYC level applied
+    pub inherited: TransactionLimits,
+    /// The user's own override; unset fields inherit
+    pub overrides: TransactionLimits,
+    pub effective: TransactionLimits,
+    pub usage: LimitUsage,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct UsageRow {
+    daily_count: i64,
+    daily_amount: Decimal,
+    weekly_count: i64,
+    weekly_amount: Decimal,
+    monthly_count: i64,
+    monthly_amount: Decimal,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Period {
+    Day,
+    Week,
+    Month,
+}
+
+impl Period {
+    /// The calendar window containing `at`, in the app timezone.
+    fn window(self, timezone: &str, at: OffsetDateTime) -> (OffsetDateTime, OffsetDateTime) {
+        match self {
+            Self::Day => {
+                let start = period::day_start(timezone, at);
+                (start, start + Duration::days(1))
+            }
+            Self::Week => {
+                let start = period::week_start(timezone, at);
+                (start, start + Duration::weeks(1))
+            }
+            Self::Month => {
+                let start = period::month_start(timezone, at);
+                (start, period::month_start(timezone, start + Duration::days(31)))
+            }
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            Self::Day => t("today"),
+            Self::Week => t("this week"),
+            Self::Month => t("this month"),
+        }
+    }
+}
+
+fn table(kind: UserLimitKind) -> &'static str {
+    match kind {
+        UserLimitKind::Deposit => "deposits",
+        UserLimitKind::Withdrawal => "withdrawals",
+    }
+}
+
+/// Statuses that use up a limit: still pending or paid out. Rejected,
+/// reversed, refunded and expired requests give their allowance back.
+fn counted_statuses(kind: UserLimitKind) -> &'static str {
+    match kind {
+        UserLimitKind::Deposit => "(1, 2)",
+        UserLimitKind::Withdrawal => "(1, 2, 3)",
+    }
+}
+
+/// 0 means unlimited at any layer.
+fn cap(value: Option<Decimal>) -> Option<Decimal> {
+    value.filter(|value| !value.is_zero())
+}
+
+fn count_cap(value: Option<u32>) -> Option<i64> {
+    value.filter(|value| *value > 0).map(i64::from)
+}
+
+fn to_limits(record: &UserLimitRecord) -> TransactionLimits {
+    let count = |value: Option<i32>| value.and_then(|value| u32::try_from(value).ok());
+    TransactionLimits {
+        min_amount: record.min_amount,
+        max_amount: record.max_amount,
+        daily_count: count(record.daily_count),
+        daily_amount: record.daily_amount,
+        weekly_count: count(record.weekly_count),
+        weekly_amount: record.weekly_amount,
+        monthly_count: count(record.monthly_count),
+        monthly_amount: record.monthly_amount,
+    }
+}
+
+fn to_db_count(value: Option<u32>) -> Option<i32> {
+    value.map(|value| i32::try_from(value).unwrap_or(i32::MAX))
+}
+
+async fn find_override(
+    conn: DbConn<'_>,
+    user_id: i64,
+    kind: UserLimitKind,
+) -> Result<Option<UserLimitRecord>, AppError> {
+    UserLimitModel::query(conn)
+        .where_col(UserLimitCol::USER_ID, Op::Eq, user_id)
+        .where_col(UserLimitCol::KIND, Op::Eq, kind)
+        .first()
+        .await
+        .map_err(AppError::from)
+}
+
+/// Resolves the user's limits: global, then their country and KYC level,
+/// then their own override.
+async fn resolve(
+    conn: DbConn<'_>,
+    state: &AppApiState,
+    user_id: i64,
+    kind: UserLimitKind,
+) -> Result<(TransactionLimits, TransactionLimits), AppError> {
+    let user = UserModel::find(conn.clone(), user_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("User not found")))?;
+    let inherited = state
+        .limits_config
+        .for_user(kind, user.country_iso2.as_deref(), user.kyc_level);
+    let overrides = find_override(conn, user_id, kind)
+        .await?
+        .map(|record| to_limits(&record))
+        .unwrap_or_default();
+    Ok((inherited, overrides))
+}
+
+/// Sums the user's counted requests in the day, week and month containing
+/// `at`. `exclude_id` leaves out the request being checked.
+async fn usage(
+    conn: DbConn<'_>,
+    timezone: &str,
+    kind: UserLimitKind,
+    user_id: i64,
+    at: OffsetDateTime,
+    exclude_id: Option<i64>,
+) -> Result<LimitUsage, AppError> {
+    let (day_from, day_to) = Period::Day.window(timezone, at);
+    let (week_from, week_to) = Period::Week.window(timezone, at);
+    let (month_from, month_to) = Period::Month.window(timezone, at);
+
+    let rows = conn
+        .fetch_all(
+            sqlx::query_as::<_, UsageRow>(&format!(
+                "SELECT
+                   COUNT(*) FILTER (WHERE created_at >= $3 AND created_at < $4) AS daily_count,
+                   COALESCE(SUM(amount) FILTER (WHERE created_at >= $3 AND created_at < $4), 0)
+                     AS daily_amount,
+                   COUNT(*) FILTER (WHERE created_at >= $5 AND created_at < $6) AS weekly_count,
+                   COALESCE(SUM(amount) FILTER (WHERE created_at >= $5 AND created_at < $6), 0)
+                     AS weekly_amount,
+                   COUNT(*) FILTER (WHERE created_at >= $7 AND created_at < $8) AS monthly_count,
+                   COALESCE(SUM(amount) FILTER (WHERE created_at >= $7 AND created_at < $8), 0)
+                     AS monthly_amount
+                 FROM {table}
+                 WHERE owner_type = $1 AND owner_id = $2 AND status IN {statuses} AND id <> $9
+                   AND created_at >= LEAST($3, $5, $7) AND created_at < GREATEST($4, $6, $8)",
+                table = table(kind),
+                statuses = counted_statuses(kind),
+            ))
+            .bind(OwnerType::User)
+            .bind(user_id)
+            .bind(day_from)
+            .bind(day_to)
+            .bind(week_from)
+            .bind(week_to)
+            .bind(month_from)
+            .bind(month_to)
+            .bind(exclude_id.unwrap_or(0)),
+        )
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(rows
+        .into_iter()
+        .next()
+        .map(|row| LimitUsage {
+            daily: PeriodUsage {
+                count: row.daily_count,
+                amount: row.daily_amount,
+            },
+            weekly: PeriodUsage {
+                count: row.weekly_count,
+                amount: row.weekly_amount,
+            },
+            monthly: PeriodUsage {
+                count: row.monthly_count,
+                amount: row.monthly_amount,
+            },
+        })
+        .unwrap_or_default())
+}
+
+/// Fails when a request of `amount` made at `at` would break one of the
+/// user's limits. New requests pass `now`; reviews pass the request's own
+/// `created_at` and id, so it is checked against the windows it was made in.
+///
+/// Locks the user row first, so inside a transaction concurrent requests
+/// from the same user are counted against each other.
+pub async fn check(
+    conn: DbConn<'_>,
+    state: &AppApiState,
+    kind: UserLimitKind,
+    user_id: i64,
+    amount: Decimal,
+    at: OffsetDateTime,
+    exclude_id: Option<i64>,
+) -> Result<(), AppError> {
+    conn.fetch_all(
+        sqlx::query_scalar::<_, i64>("SELECT id FROM users WHERE id = $1 FOR UPDATE")
+            .bind(user_id),
+    )
+    .await
+    .map_err(AppError::from)?;
+
+    let (inherited, overrides) = resolve(conn.clone(), state, user_id, kind).await?;
+    let limits = inherited.overlay(&overrides);
+
+    if let Some(min) = cap(limits.min_amount).filter(|min| amount < *min) {
+        let min = min.normalize().to_string();
+        let message = match kind {
+            UserLimitKind::Deposit => "Minimum deposit amount is :amount",
+            UserLimitKind::Withdrawal => "Minimum withdrawal amount is :amount",
+        };
+        return Err(AppError::BadRequest(t_args(message, &[("amount", min.as_str())])));
+    }
+    if let Some(max) = cap(limits.max_amount).filter(|max| amount > *max) {
+        let max = max.normalize().to_string();
+        let message = match kind {
+            UserLimitKind::Deposit => "Maximum deposit amount is :amount",
+            UserLimitKind::Withdrawal => "Maximum withdrawal amount is :amount",
+        };
+        return Err(AppError::BadRequest(t_args(message, &[("amount", max.as_str())])));
+    }
+
+    let periods = [
+        (Period::Day, limits.daily_count, limits.daily_amount),
+        (Period::Week, limits.weekly_count, limits.weekly_amount),
+        (Period::Month, limits.monthly_count, limits.monthly_amount),
+    ];
+    if periods
+        .iter()
+        .all(|(_, count, total)| count_cap(*count).is_none() && cap(*total).is_none())
+    {
+        return Ok(());
+    }
+
+    let usage = usage(conn, &state.app_timezone, kind, user_id, at, exclude_id).await?;
+    for (period, count, total) in periods {
+        let used = match period {
+            Period::Day => usage.daily,
+            Period::Week => usage.weekly,
+            Period::Month => usage.monthly,
+        };
+        if let Some(count) = count_cap(count).filter(|count| used.count >= *count) {
+            let count = count.to_string();
+            let message = match kind {
+                UserLimitKind::Deposit => {
+                    "Deposit limit reached. At most :count deposits are allowed :period"
+                }
+                UserLimitKind::Withdrawal => {
+                    "Withdrawal limit reached. At most :count withdrawals are allowed :period"
+                }
+            };
+            return Err(AppError::BadRequest(t_args(
+                message,
+                &[("count", count.as_str()), ("period", period.label().as_str())],
+            )));
+        }
+        if let Some(total) = cap(total).filter(|total| used.amount + amount > *total) {
+            let remaining = (total - used.amount)
+                .max(Decimal::ZERO)
+                .normalize()
+                .to_string();
+            let message = match kind {
+                UserLimitKind::Deposit => {
+                    "Deposit amount limit exceeded. Remaining :period: :amount"
+                }
+                UserLimitKind::Withdrawal => {
+                    "Withdrawal amount limit exceeded. Remaining :period: :amount"
+                }
+            };
+            return Err(AppError::BadRequest(t_args(
+                message,
+                &[("period", period.label().as_str()), ("amount", remaining.as_str())],
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// The user's limits of one kind and their usage in the current windows.
+pub async fn status(
+    state: &AppApiState,
+    user_id: i64,
+    kind: UserLimitKind,
+) -> Result<LimitStatus, AppError> {
+    let conn = DbConn::pool(&state.db);
+    let (inherited, overrides) = resolve(conn.clone(), state, user_id, kind).await?;
+    let usage = usage(
+        conn,
+        &state.app_timezone,
+        kind,
+        user_id,
+        OffsetDateTime::now_utc(),
+        None,
+    )
+    .await?;
+
+    Ok(LimitStatus {
+        kind,
+        inherited,
+        overrides,
+        effective: inherited.overlay(&overrides),
+        usage,
+    })
+}
+
+/// Replaces the user's override for one kind. An override with nothing set
+/// is removed, so the user follows the configured limits again.
+pub async fn set_overrides(
+    state: &AppApiState,
+    admin_id: i64,
+    user_id: i64,
+    kind: UserLimitKind,
+    overrides: TransactionLimits,
+) -> Result<(), AppError> {
+    UserModel::find(DbConn::pool(&state.db), user_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("User not found")))?;
+
+    let now = OffsetDateTime::now_utc();
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    let existing = find_override(conn.clone(), user_id, kind).await?;
+    match existing {
+        Some(record) if overrides == TransactionLimits::default() => {
+            UserLimitModel::query(conn.clone())
+                .where_col(UserLimitCol::ID, Op::Eq, record.id)
+                .delete()
+                .await
+                .map_err(AppError::from)?;
+        }
+        Some(record) => {
+            UserLimitModel::query(conn.clone())
+                .where_col(UserLimitCol::ID, Op::Eq, record.id)
+                .patch()
+                .assign(UserLimitCol::MIN_AMOUNT, overrides.min_amount)
+                .map_err(AppError::from)?
+                .assign(UserLimitCol::MAX_AMOUNT, overrides.max_amount)
+                .map_err(AppError::from)?
+                .assign(UserLimitCol::DAILY_COUNT, to_db_count(overrides.daily_count))
+                .map_err(AppError::from)?
+                .assign(UserLimitCol::DAILY_AMOUNT, overrides.daily_amount)
+                .map_err(AppError::from)?
+                .assign(UserLimitCol::WEEKLY_COUNT, to_db_count(overrides.weekly_count))
+                .map_err(AppError::from)?
+                .assign(UserLimitCol::WEEKLY_AMOUNT, overrides.weekly_amount)
+                .map_err(AppError::from)?
+                .assign(UserLimitCol::MONTHLY_COUNT, to_db_count(overrides.monthly_count))
+                .map_err(AppError::from)?
+                .assign(UserLimitCol::MONTHLY_AMOUNT, overrides.monthly_amount)
+                .map_err(AppError::from)?
+                .assign(UserLimitCol::UPDATED_BY, Some(admin_id))
+                .map_err(AppError::from)?
+                .assign(UserLimitCol::UPDATED_AT, now)
+                .map_err(AppError::from)?
+                .save()
+                .await
+                .map_err(AppError::from)?;
+        }
+        None if overrides == TransactionLimits::default() => {}
+        None => {
+            UserLimitModel::create(conn.clone())
+                .set(UserLimitCol::USER_ID, user_id)?
+                .set(UserLimitCol::KIND, kind)?
+                .set(UserLimitCol::MIN_AMOUNT, overrides.min_amount)?
+                .set(UserLimitCol::MAX_AMOUNT, overrides.max_amount)?
+                .set(UserLimitCol::DAILY_COUNT, to_db_count(overrides.daily_count))?
+                .set(UserLimitCol::DAILY_AMOUNT, overrides.daily_amount)?
+                .set(UserLimitCol::WEEKLY_COUNT, to_db_count(overrides.weekly_count))?
+                .set(UserLimitCol::WEEKLY_AMOUNT, overrides.weekly_amount)?
+                .set(UserLimitCol::MONTHLY_COUNT, to_db_count(overrides.monthly_count))?
+                .set(UserLimitCol::MONTHLY_AMOUNT, overrides.monthly_amount)?
+                .set(UserLimitCol::UPDATED_BY, Some(admin_id))?
+                .set(UserLimitCol::CREATED_AT, now)?
+                .set(UserLimitCol::UPDATED_AT, now)?
+                .save()
+                .await
+                .map_err(AppError::from)?;
+        }
+    }
+
+    scope.commit().await.map_err(AppError::from)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, OffsetDateTime, UtcOffset};
+
+    use super::Period;
+
+    fn at(date: (i32, Month, u8), hour: u8, offset_hours: i8) -> OffsetDateTime {
+        Date::from_calendar_date(date.0, date.1, date.2)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+            .assume_offset(UtcOffset::from_hms(offset_hours, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn windows_follow_the_app_timezone_calendar() {
+        // Sunday 2024-03-03 01:00 in +08:00
+        let now = at((2024, Month::March, 2), 17, 0);
+
+        let (from, to) = Period::Day.window("+08:00", now);
+        assert_eq!(from, at((2024, Month::March, 3), 0, 8));
+        assert_eq!(to, at((2024, Month::March, 4), 0, 8));
+
+        let (from, to) = Period::Week.window("+08:00", now);
+        assert_eq!(from, at((2024, Month::February, 26), 0, 8));
+        assert_eq!(to, at((2024, Month::March, 4), 0, 8));
+
+        // Leap day, still February in UTC but March in +08:00
+        let (from, to) = Period::Month.window("+08:00", at((2024, Month::February, 29), 16, 0));
+        assert_eq!(from, at((2024, Month::March, 1), 0, 8));
+        assert_eq!(to, at((2024, Month::April, 1), 0, 8));
+    }
+}
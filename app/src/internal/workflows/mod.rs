@@ -1,16 +1,35 @@
 pub mod admin;
 pub mod admin_auth;
 pub mod bank;
+pub mod bank_statement;
 pub mod company_bank_account;
 pub mod company_crypto_account;
 pub mod content_page;
 pub mod country;
+pub mod credit_conversion;
+pub mod credit_ledger;
 pub mod crypto_network;
 pub mod deposit;
+pub mod expiry;
+pub mod financial_approval;
+pub mod gateway_callback;
 pub mod introducer_change;
+pub mod kyc;
+pub mod ledger_reconcile;
+pub mod limits;
 pub mod notification;
+pub mod payout;
+pub mod period;
+pub mod referral_commission;
+pub mod review_claim;
+#[cfg(test)]
+pub mod test_support;
 pub mod user_auth;
 pub mod user_manage;
 pub mod user_credit;
+pub mod user_deposit;
 pub mod user_team;
+pub mod user_transfer;
+pub mod user_withdrawal;
 pub mod withdrawal;
+pub mod withdrawal_destination;
@@ -1,4 +1,4 @@
-use core_realtime::{RealtimeEvent, RealtimeTarget};
+use core_realtime::{RealtimeEvent, RealtimePublisher, RealtimeTarget};
 
 use crate::internal::api::state::AppApiState;
 
@@ -7,6 +7,7 @@ use crate::internal::api::state::AppApiState;
 pub struct NotificationCounts {
     pub deposit: i64,
     pub withdrawal: i64,
+    pub kyc: i64,
 }
 
 impl RealtimeEvent for NotificationCounts {
@@ -26,21 +27,30 @@ pub async fn get_pending_counts(db: &sqlx::PgPool) -> Result<NotificationCounts,
             .fetch_one(db)
             .await?;
 
+    let kyc: (i64,) =
+        sqlx::query_as("SELECT COUNT(*) FROM kyc_submissions WHERE status = 1")
+            .fetch_one(db)
+            .await?;
+
     Ok(NotificationCounts {
         deposit: deposit.0,
         withdrawal: withdrawal.0,
+        kyc: kyc.0,
     })
 }
 
 /// Query counts and broadcast to all admin channel subscribers.
 /// Errors are logged but not propagated — notification dispatch must not fail the request.
 pub async fn dispatch_admin_notification_counts(state: &AppApiState) {
-    match get_pending_counts(&state.db).await {
+    publish_pending_counts(&state.db, &state.realtime).await;
+}
+
+/// Same as [`dispatch_admin_notification_counts`], for callers without an
+/// `AppApiState` such as background jobs.
+pub async fn publish_pending_counts(db: &sqlx::PgPool, realtime: &RealtimePublisher) {
+    match get_pending_counts(db).await {
         Ok(counts) => {
-            let _ = state
-                .realtime
-                .publish(RealtimeTarget { room: None }, &counts)
-                .await;
+            let _ = realtime.publish(RealtimeTarget { room: None }, &counts).await;
         }
         Err(_) => {
             // Silent fail — notification dispatch must not break the request
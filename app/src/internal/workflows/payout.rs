@@ -0,0 +1,398 @@
+use core_db::common::sql::{DbConn, Op};
+use core_i18n::t;
+use core_web::error::AppError;
+use generated::models::{
+    BankModel, CryptoNetworkModel, HttpClientLogCol, HttpClientLogModel, WithdrawalCol,
+    WithdrawalMethod, WithdrawalModel, WithdrawalRecord, WithdrawalReviewAction,
+    WithdrawalStatus,
+};
+use time::OffsetDateTime;
+
+use crate::{
+    contracts::api::v1::admin::withdrawal::AdminWithdrawalReviewInput,
+    internal::{
+        config::balance::BalanceConfig,
+        payout::{HttpExchange, PayoutProvider, PayoutRequest, PayoutStatus},
+        workflows::withdrawal,
+    },
+};
+
+#[derive(Debug, Default)]
+pub struct PayoutPollSummary {
+    pub approved: usize,
+    pub rejected: usize,
+    pub pending: usize,
+}
+
+impl PayoutPollSummary {
+    pub fn settled(&self) -> usize {
+        self.approved + self.rejected
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Settlement {
+    Approved,
+    Rejected,
+    Unchanged,
+}
+
+/// Sends a processing withdrawal to the provider. The withdrawal is marked
+/// as submitted before the provider is called, so it is sent at most once
+/// even when two admins process it together. A submission that fails is
+/// noted on the timeline and never retried, since the provider may have
+/// taken it anyway; an admin finishes it by hand.
+pub async fn submit(
+    db: &sqlx::PgPool,
+    balance: &BalanceConfig,
+    provider: &dyn PayoutProvider,
+    withdrawal_id: i64,
+) -> Result<(), AppError> {
+    let now = OffsetDateTime::now_utc();
+    let reserved = sqlx::query(
+        "UPDATE withdrawals
+         SET withdrawal_method = $1, payout_provider = $2, payout_submitted_at = $3,
+             updated_at = $3
+         WHERE id = $4 AND status = $5 AND payout_submitted_at IS NULL",
+    )
+    .bind(WithdrawalMethod::Payout)
+    .bind(provider.code())
+    .bind(now)
+    .bind(withdrawal_id)
+    .bind(WithdrawalStatus::Processing)
+    .execute(db)
+    .await
+    .map_err(AppError::from)?
+    .rows_affected()
+        == 1;
+    if !reserved {
+        return Ok(());
+    }
+
+    let record = WithdrawalModel::find(DbConn::pool(db), withdrawal_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Withdrawal not found")))?;
+    let request = payout_request(db, &record).await?;
+    let call = provider.submit(&request).await;
+    log_exchanges(db, call.exchanges).await;
+
+    let accepted = match call.result {
+        Ok(accepted) => accepted,
+        Err(err) => {
+            // Marks the submission as answered, which hands it back to admins
+            WithdrawalModel::query(DbConn::pool(db))
+                .where_col(WithdrawalCol::ID, Op::Eq, withdrawal_id)
+                .patch()
+                .assign(WithdrawalCol::PAYOUT_CHECKED_AT, Some(OffsetDateTime::now_utc()))
+                .map_err(AppError::from)?
+                .save()
+                .await
+                .map_err(AppError::from)?;
+            withdrawal::record_status(
+                DbConn::pool(db),
+                withdrawal_id,
+                WithdrawalStatus::Processing,
+                None,
+                Some(format!("Payout submission to {} failed: {err}", provider.code())),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    WithdrawalModel::query(DbConn::pool(db))
+        .where_col(WithdrawalCol::ID, Op::Eq, withdrawal_id)
+        .patch()
+        .assign(WithdrawalCol::PAYOUT_REFERENCE, Some(accepted.reference.clone()))
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    withdrawal::record_status(
+        DbConn::pool(db),
+        withdrawal_id,
+        WithdrawalStatus::Processing,
+        None,
+        Some(format!("Payout submitted to {} as {}", provider.code(), accepted.reference)),
+    )
+    .await?;
+
+    settle(db, balance, provider, withdrawal_id, &accepted.reference, accepted.status).await?;
+    Ok(())
+}
+
+/// Asks the provider about every payout still processing and settles the
+/// ones it has finished.
+pub async fn poll(
+    db: &sqlx::PgPool,
+    balance: &BalanceConfig,
+    provider: &dyn PayoutProvider,
+) -> Result<PayoutPollSummary, AppError> {
+    let processing = WithdrawalModel::query(DbConn::pool(db))
+        .where_col(WithdrawalCol::STATUS, Op::Eq, WithdrawalStatus::Processing)
+        .where_col(
+            WithdrawalCol::PAYOUT_PROVIDER,
+            Op::Eq,
+            Some(provider.code().to_string()),
+        )
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    let mut summary = PayoutPollSummary::default();
+    for record in processing {
+        let Some(reference) = record.payout_reference else {
+            continue;
+        };
+        let call = provider.status(&reference).await;
+        log_exchanges(db, call.exchanges).await;
+        // An unreachable provider is asked again on the next poll.
+        let Ok(status) = call.result else {
+            summary.pending += 1;
+            continue;
+        };
+
+        match settle(db, balance, provider, record.id, &reference, status).await? {
+            Settlement::Approved => summary.approved += 1,
+            Settlement::Rejected => summary.rejected += 1,
+            Settlement::Unchanged => summary.pending += 1,
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Applies the provider's status through [`withdrawal::apply_review`]: a paid
+/// payout approves the withdrawal, a failed one rejects it and refunds the
+/// hold. A withdrawal an admin already settled by hand is left alone.
+async fn settle(
+    db: &sqlx::PgPool,
+    balance: &BalanceConfig,
+    provider: &dyn PayoutProvider,
+    withdrawal_id: i64,
+    reference: &str,
+    status: PayoutStatus,
+) -> Result<Settlement, AppError> {
+    let (action, remark) = match status {
+        PayoutStatus::Pending => {
+            WithdrawalModel::query(DbConn::pool(db))
+                .where_col(WithdrawalCol::ID, Op::Eq, withdrawal_id)
+                .patch()
+                .assign(WithdrawalCol::PAYOUT_CHECKED_AT, Some(OffsetDateTime::now_utc()))
+                .map_err(AppError::from)?
+                .save()
+                .await
+                .map_err(AppError::from)?;
+            return Ok(Settlement::Unchanged);
+        }
+        PayoutStatus::Paid => (
+            WithdrawalReviewAction::Approve,
+            format!("Paid out by {} ({reference})", provider.code()),
+        ),
+        PayoutStatus::Failed(reason) => (
+            WithdrawalReviewAction::Reject,
+            format!("Payout failed at {}: {reason}", provider.code()),
+        ),
+    };
+
+    let review = AdminWithdrawalReviewInput {
+        action,
+        admin_remark: Some(remark),
+    };
+    match withdrawal::apply_review(db, balance, None, withdrawal_id, review).await {
+        Ok(()) => {}
+        Err(AppError::Conflict(_) | AppError::BadRequest(_)) => return Ok(Settlement::Unchanged),
+        Err(err) => return Err(err),
+    }
+
+    Ok(match action {
+        WithdrawalReviewAction::Approve => Settlement::Approved,
+        _ => Settlement::Rejected,
+    })
+}
+
+async fn payout_request(
+    db: &sqlx::PgPool,
+    record: &WithdrawalRecord,
+) -> Result<PayoutRequest, AppError> {
+    let bank_code = match record.bank_id {
+        Some(bank_id) => BankModel::find(DbConn::pool(db), bank_id)
+            .await
+            .map_err(AppError::from)?
+            .and_then(|bank| bank.code),
+        None => None,
+    };
+    let crypto_network = match record.crypto_network_id {
+        Some(network_id) => CryptoNetworkModel::find(DbConn::pool(db), network_id)
+            .await
+            .map_err(AppError::from)?
+            .map(|network| network.symbol),
+        None => None,
+    };
+
+    Ok(PayoutRequest {
+        withdrawal_id: record.id,
+        amount: record.net_amount,
+        bank_code,
+        bank_account_name: record.bank_account_name.clone(),
+        bank_account_number: record.bank_account_number.clone(),
+        crypto_network,
+        crypto_wallet_address: record.crypto_wallet_address.clone(),
+    })
+}
+
+/// Best effort, like the audit log: a failed write never fails the payout.
+async fn log_exchanges(db: &sqlx::PgPool, exchanges: Vec<HttpExchange>) {
+    for exchange in exchanges {
+        let insert = HttpClientLogModel::create(DbConn::pool(db))
+            .set(HttpClientLogCol::ID, uuid::Uuid::new_v4())
+            .and_then(|create| create.set(HttpClientLogCol::REQUEST_URL, exchange.url))
+            .and_then(|create| create.set(HttpClientLogCol::REQUEST_METHOD, exchange.method))
+            .and_then(|create| {
+                create.set(HttpClientLogCol::REQUEST_HEADERS, exchange.request_headers)
+            })
+            .and_then(|create| create.set(HttpClientLogCol::REQUEST_BODY, exchange.request_body))
+            .and_then(|create| {
+                create.set(HttpClientLogCol::RESPONSE_STATUS, exchange.response_status)
+            })
+            .and_then(|create| {
+                create.set(HttpClientLogCol::RESPONSE_HEADERS, exchange.response_headers)
+            })
+            .and_then(|create| {
+                create.set(HttpClientLogCol::RESPONSE_BODY, exchange.response_body)
+            })
+            .and_then(|create| {
+                create.set(
+                    HttpClientLogCol::DURATION_MS,
+                    Some(exchange.duration.as_millis() as i32),
+                )
+            })
+            .and_then(|create| {
+                create.set(HttpClientLogCol::CREATED_AT, OffsetDateTime::now_utc())
+            });
+
+        if let Ok(insert) = insert {
+            let _ = insert.save().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{poll, submit};
+    use crate::contracts::api::v1::admin::withdrawal::AdminWithdrawalReviewInput;
+    use crate::internal::config::balance::BalanceConfig;
+    use crate::internal::payout::mock::{MockOutcome, MockPayoutProvider};
+    use crate::internal::workflows::{test_support, withdrawal};
+    use core_web::error::AppError;
+    use generated::models::{WithdrawalReviewAction, WithdrawalStatus};
+    use rust_decimal::Decimal;
+
+    async fn status(db: &sqlx::PgPool, withdrawal_id: i64) -> WithdrawalStatus {
+        sqlx::query_scalar("SELECT status FROM withdrawals WHERE id = $1")
+            .bind(withdrawal_id)
+            .fetch_one(db)
+            .await
+            .expect("read status")
+    }
+
+    #[tokio::test]
+    #[ignore = "requires DATABASE_URL"]
+    async fn paid_payout_approves_without_refund() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig::default();
+        let provider = MockPayoutProvider::new(MockOutcome::Paid);
+        let user_id = test_support::seed_user(&db, Decimal::ZERO).await;
+        let withdrawal_id = test_support::seed_withdrawal(
+            &db,
+            user_id,
+            Decimal::from(40),
+            WithdrawalStatus::Processing,
+        )
+        .await;
+
+        if let Err(err) = submit(&db, &balance, &provider, withdrawal_id).await {
+            panic!("submit failed: {err:?}");
+        }
+        // A second submit is a no-op rather than a second payout.
+        if let Err(err) = submit(&db, &balance, &provider, withdrawal_id).await {
+            panic!("resubmit failed: {err:?}");
+        }
+        let Ok(summary) = poll(&db, &balance, &provider).await else {
+            panic!("poll failed");
+        };
+
+        assert!(summary.approved >= 1);
+        assert_eq!(status(&db, withdrawal_id).await, WithdrawalStatus::Approved);
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::ZERO);
+        let logged: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM http_client_logs WHERE request_url = 'mock://payouts'
+                 AND request_body LIKE '%' || $1 || '%'",
+        )
+        .bind(withdrawal_id.to_string())
+        .fetch_one(&db)
+        .await
+        .expect("count logs");
+        assert_eq!(logged, 1);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires DATABASE_URL"]
+    async fn failed_payout_rejects_and_refunds() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig::default();
+        let provider = MockPayoutProvider::new(MockOutcome::Failed);
+        let user_id = test_support::seed_user(&db, Decimal::ZERO).await;
+        let withdrawal_id = test_support::seed_withdrawal(
+            &db,
+            user_id,
+            Decimal::from(40),
+            WithdrawalStatus::Processing,
+        )
+        .await;
+
+        if let Err(err) = submit(&db, &balance, &provider, withdrawal_id).await {
+            panic!("submit failed: {err:?}");
+        }
+        if poll(&db, &balance, &provider).await.is_err() {
+            panic!("poll failed");
+        }
+
+        assert_eq!(status(&db, withdrawal_id).await, WithdrawalStatus::Rejected);
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::from(40));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires DATABASE_URL"]
+    async fn admins_cannot_settle_a_payout_in_flight() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig::default();
+        let provider = MockPayoutProvider::new(MockOutcome::Pending);
+        let admin_id = test_support::seed_admin(&db).await;
+        let user_id = test_support::seed_user(&db, Decimal::ZERO).await;
+        let withdrawal_id = test_support::seed_withdrawal(
+            &db,
+            user_id,
+            Decimal::from(40),
+            WithdrawalStatus::Processing,
+        )
+        .await;
+
+        if let Err(err) = submit(&db, &balance, &provider, withdrawal_id).await {
+            panic!("submit failed: {err:?}");
+        }
+        for action in [WithdrawalReviewAction::Approve, WithdrawalReviewAction::Reject] {
+            let review = AdminWithdrawalReviewInput {
+                action,
+                admin_remark: None,
+            };
+            let result =
+                withdrawal::apply_review(&db, &balance, Some(admin_id), withdrawal_id, review)
+                    .await;
+            assert!(matches!(result, Err(AppError::Conflict(_))));
+        }
+        assert_eq!(status(&db, withdrawal_id).await, WithdrawalStatus::Processing);
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::ZERO);
+    }
+}
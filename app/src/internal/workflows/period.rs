@@ -0,0 +1,69 @@
+use time::{Duration, OffsetDateTime, Time, UtcOffset};
+
+/// Parses the app timezone (`APP_TIMEZONE`): a fixed offset such as
+/// "+08:00" or "-05", or "UTC". Region names like "Asia/Kuala_Lumpur" are not
+/// supported and give `None`.
+pub fn parse_offset(timezone: &str) -> Option<UtcOffset> {
+    let raw = timezone.trim();
+    if raw.eq_ignore_ascii_case("utc") || raw == "Z" {
+        return Some(UtcOffset::UTC);
+    }
+    let (sign, rest) = match raw.as_bytes().first() {
+        Some(b'+') => (1, &raw[1..]),
+        Some(b'-') => (-1, &raw[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = rest.split_once(':').unwrap_or((rest, "00"));
+    let digits = |v: &str| !v.is_empty() && v.len() <= 2 && v.bytes().all(|b| b.is_ascii_digit());
+    if !digits(hours) || !digits(minutes) {
+        return None;
+    }
+    let (hours, minutes) = (hours.parse::<i8>().ok()?, minutes.parse::<i8>().ok()?);
+    if minutes >= 60 {
+        return None;
+    }
+    UtcOffset::from_hms(sign * hours, sign * minutes, 0).ok()
+}
+
+/// The app timezone as an offset. `AppApiState::new` refuses to start with a
+/// timezone `parse_offset` rejects, so the UTC fallback is never reached.
+pub fn app_offset(timezone: &str) -> UtcOffset {
+    parse_offset(timezone).unwrap_or(UtcOffset::UTC)
+}
+
+/// Start of the current day in the app timezone.
+pub fn day_start(timezone: &str, now: OffsetDateTime) -> OffsetDateTime {
+    let local = now.to_offset(app_offset(timezone));
+    local.replace_time(Time::MIDNIGHT)
+}
+
+/// Start of the current week (Monday) in the app timezone.
+pub fn week_start(timezone: &str, now: OffsetDateTime) -> OffsetDateTime {
+    let day = day_start(timezone, now);
+    day - Duration::days(day.weekday().number_days_from_monday() as i64)
+}
+
+/// Start of the current month in the app timezone.
+pub fn month_start(timezone: &str, now: OffsetDateTime) -> OffsetDateTime {
+    let day = day_start(timezone, now);
+    day - Duration::days(day.day() as i64 - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_offset_accepts_only_fixed_offsets() {
+        let offset = |h, m| UtcOffset::from_hms(h, m, 0).ok();
+        assert_eq!(parse_offset("+08:00"), offset(8, 0));
+        assert_eq!(parse_offset(" -05:30 "), offset(-5, -30));
+        assert_eq!(parse_offset("+8"), offset(8, 0));
+        assert_eq!(parse_offset("UTC"), Some(UtcOffset::UTC));
+        assert_eq!(parse_offset("Asia/Kuala_Lumpur"), None);
+        assert_eq!(parse_offset("08:00"), None);
+        assert_eq!(parse_offset("+08:60"), None);
+        assert_eq!(parse_offset("++8"), None);
+        assert_eq!(parse_offset(""), None);
+    }
+}
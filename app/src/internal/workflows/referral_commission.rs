@@ -0,0 +1,316 @@
+use core_db::common::sql::{generate_snowflake_i64, DbConn, Op, OrderDir};
+use core_i18n::t;
+use core_web::error::AppError;
+use generated::models::{
+    CreditTransactionType, CreditType, DepositRecord, DepositStatus, OwnerType,
+    ReferralCommissionCol, ReferralCommissionModel, ReferralCommissionRateCol,
+    ReferralCommissionRateModel, ReferralCommissionRateRecord, ReferralCommissionRecord,
+    ReferralCommissionSource, ReferralCommissionStatus,
+};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::internal::{
+    api::state::AppApiState,
+    config::balance::BalanceConfig,
+    workflows::{
+        credit_ledger::{self, CreditEntry},
+        deposit,
+    },
+};
+
+/// Deepest upline level a rate can be configured for.
+pub const MAX_LEVELS: usize = 20;
+
+/// An event that pays commission to the uplines of `user_id`.
+pub struct CommissionSource {
+    pub source_type: ReferralCommissionSource,
+    pub source_id: i64,
+    pub user_id: i64,
+    pub credit_type: CreditType,
+    pub base_amount: Decimal,
+}
+
+impl CommissionSource {
+    /// Commission on an approved deposit is based on the amount credited.
+    pub fn deposit(deposit: &DepositRecord) -> Self {
+        Self {
+            source_type: ReferralCommissionSource::Deposit,
+            source_id: deposit.id,
+            user_id: deposit.owner_id,
+            credit_type: deposit.credit_type,
+            base_amount: deposit.net_amount,
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct Upline {
+    user_id: i64,
+    level: i32,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct ReversedCommission {
+    id: i64,
+    beneficiary_user_id: i64,
+    level: i32,
+    credit_type: CreditType,
+    amount: Decimal,
+}
+
+pub async fn list_rates(
+    state: &AppApiState,
+) -> Result<Vec<ReferralCommissionRateRecord>, AppError> {
+    ReferralCommissionRateModel::query(DbConn::pool(&state.db))
+        .order_by(ReferralCommissionRateCol::CREDIT_TYPE, OrderDir::Asc)
+        .order_by(ReferralCommissionRateCol::LEVEL, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)
+}
+
+/// Replaces every level rate for one credit type. `percentages[0]` is paid to
+/// the direct introducer, `percentages[1]` to their introducer, and so on; an
+/// empty list turns commission off for the credit type.
+pub async fn replace_rates(
+    state: &AppApiState,
+    credit_type: CreditType,
+    percentages: Vec<Decimal>,
+) -> Result<Vec<ReferralCommissionRateRecord>, AppError> {
+    let now = OffsetDateTime::now_utc();
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    ReferralCommissionRateModel::query(conn.clone())
+        .where_col(ReferralCommissionRateCol::CREDIT_TYPE, Op::Eq, credit_type)
+        .delete()
+        .await
+        .map_err(AppError::from)?;
+
+    for (index, percentage) in percentages.into_iter().enumerate() {
+        ReferralCommissionRateModel::create(conn.clone())
+            .set(ReferralCommissionRateCol::CREDIT_TYPE, credit_type)?
+            .set(ReferralCommissionRateCol::LEVEL, index as i32 + 1)?
+            .set(ReferralCommissionRateCol::PERCENTAGE, percentage)?
+            .set(ReferralCommissionRateCol::CREATED_AT, now)?
+            .set(ReferralCommissionRateCol::UPDATED_AT, now)?
+            .save()
+            .await
+            .map_err(AppError::from)?;
+    }
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    list_rates(state).await
+}
+
+pub async fn list_for_source(
+    state: &AppApiState,
+    source_type: ReferralCommissionSource,
+    source_id: i64,
+) -> Result<Vec<ReferralCommissionRecord>, AppError> {
+    ReferralCommissionModel::query(DbConn::pool(&state.db))
+        .where_col(ReferralCommissionCol::SOURCE_TYPE, Op::Eq, source_type)
+        .where_col(ReferralCommissionCol::SOURCE_ID, Op::Eq, source_id)
+        .order_by(ReferralCommissionCol::LEVEL, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)
+}
+
+/// Pays each configured level up the introducer chain of `source.user_id`.
+///
+/// Every level is claimed through the unique (source, level) index before it
+/// is credited, so running this again for the same source only pays levels
+/// that have not been paid yet. A level whose commission was reversed stays
+/// claimed and is not paid again. Call it inside the transaction that
+/// commits the source event.
+pub async fn pay(
+    conn: DbConn<'_>,
+    balance: &BalanceConfig,
+    admin_id: Option<i64>,
+    source: &CommissionSource,
+) -> Result<usize, AppError> {
+    let rates = ReferralCommissionRateModel::query(conn.clone())
+        .where_col(
+            ReferralCommissionRateCol::CREDIT_TYPE,
+            Op::Eq,
+            source.credit_type,
+        )
+        .where_col(ReferralCommissionRateCol::PERCENTAGE, Op::Gt, Decimal::ZERO)
+        .order_by(ReferralCommissionRateCol::LEVEL, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+    let Some(max_level) = rates.iter().map(|rate| rate.level).max() else {
+        return Ok(0);
+    };
+
+    let uplines = conn
+        .fetch_all(
+            sqlx::query_as::<_, Upline>(
+                "WITH RECURSIVE upline AS (
+                     SELECT introducer_user_id AS user_id, 1 AS level FROM users WHERE id = $1
+                     UNION ALL
+                     SELECT u.introducer_user_id, up.level + 1
+                     FROM users u
+                     JOIN upline up ON u.id = up.user_id
+                     WHERE up.level < $2
+                 )
+                 SELECT user_id, level FROM upline WHERE user_id IS NOT NULL ORDER BY level",
+            )
+            .bind(source.user_id)
+            .bind(max_level),
+        )
+        .await
+        .map_err(AppError::from)?;
+
+    let now = OffsetDateTime::now_utc();
+    let mut paid = 0;
+    for upline in uplines {
+        let Some(rate) = rates.iter().find(|rate| rate.level == upline.level) else {
+            continue;
+        };
+        let amount = (source.base_amount * rate.percentage).round_dp(8);
+        if amount <= Decimal::ZERO {
+            continue;
+        }
+
+        let claimed = conn
+            .fetch_all(
+                sqlx::query_scalar::<_, i64>(
+                    "INSERT INTO referral_commissions
+                         (id, source_type, source_id, source_user_id, beneficiary_user_id,
+                          level, credit_type, base_amount, percentage, amount, status,
+                          created_at, updated_at)
+                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $12)
+                     ON CONFLICT (source_type, source_id, level) DO NOTHING
+                     RETURNING id",
+                )
+                .bind(generate_snowflake_i64())
+                .bind(source.source_type)
+                .bind(source.source_id)
+                .bind(source.user_id)
+                .bind(upline.user_id)
+                .bind(upline.level)
+                .bind(source.credit_type)
+                .bind(source.base_amount)
+                .bind(rate.percentage)
+                .bind(amount)
+                .bind(ReferralCommissionStatus::Paid)
+                .bind(now),
+            )
+            .await
+            .map_err(AppError::from)?;
+        if claimed.is_empty() {
+            continue;
+        }
+
+        credit_ledger::post(
+            conn.clone(),
+            balance,
+            CreditEntry {
+                admin_id,
+                related_key: Some(source.source_id.to_string()),
+                params: Some(serde_json::json!({ "level": upline.level })),
+                remark: Some(format!("Level {} commission", upline.level)),
+                ..CreditEntry::new(
+                    upline.user_id,
+                    source.credit_type,
+                    amount,
+                    CreditTransactionType::ReferralCommission,
+                )
+            },
+        )
+        .await?;
+        paid += 1;
+    }
+
+    Ok(paid)
+}
+
+/// Claws back every paid commission of a source that is being refunded.
+/// Only rows still marked paid are reversed, so repeating a reversal is a
+/// no-op. The debit is held to the beneficiary's balance policy like any
+/// other deduction.
+pub async fn reverse(
+    conn: DbConn<'_>,
+    balance: &BalanceConfig,
+    admin_id: Option<i64>,
+    source_type: ReferralCommissionSource,
+    source_id: i64,
+) -> Result<usize, AppError> {
+    let now = OffsetDateTime::now_utc();
+    let reversed = conn
+        .fetch_all(
+            sqlx::query_as::<_, ReversedCommission>(
+                "UPDATE referral_commissions
+                 SET status = $1, reversed_at = $2, updated_at = $2
+                 WHERE source_type = $3 AND source_id = $4 AND status = $5
+                 RETURNING id, beneficiary_user_id, level, credit_type, amount",
+            )
+            .bind(ReferralCommissionStatus::Reversed)
+            .bind(now)
+            .bind(source_type)
+            .bind(source_id)
+            .bind(ReferralCommissionStatus::Paid),
+        )
+        .await
+        .map_err(AppError::from)?;
+
+    for commission in &reversed {
+        credit_ledger::post(
+            conn.clone(),
+            balance,
+            CreditEntry {
+                admin_id,
+                related_key: Some(source_id.to_string()),
+                params: Some(serde_json::json!({ "level": commission.level })),
+                remark: Some(format!("Commission #{} reversed", commission.id)),
+                ..CreditEntry::new(
+                    commission.beneficiary_user_id,
+                    commission.credit_type,
+                    -commission.amount,
+                    CreditTransactionType::ReferralCommissionReversal,
+                )
+            },
+        )
+        .await?;
+    }
+
+    Ok(reversed.len())
+}
+
+/// Pays any commission still owed for an approved deposit, e.g. after its
+/// approval failed part-way or rates were configured later.
+pub async fn rerun_for_deposit(
+    state: &AppApiState,
+    admin_id: i64,
+    deposit_id: i64,
+) -> Result<Vec<ReferralCommissionRecord>, AppError> {
+    let deposit = deposit::find(state, deposit_id).await?;
+    if deposit.status != DepositStatus::Approved || deposit.owner_type != OwnerType::User {
+        return Err(AppError::BadRequest(t(
+            "Commission only applies to approved user deposits",
+        )));
+    }
+
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    pay(
+        scope.conn(),
+        &state.balance_config,
+        Some(admin_id),
+        &CommissionSource::deposit(&deposit),
+    )
+    .await?;
+    scope.commit().await.map_err(AppError::from)?;
+
+    list_for_source(state, ReferralCommissionSource::Deposit, deposit_id).await
+}
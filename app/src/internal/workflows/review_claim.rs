@@ -0,0 +1,222 @@
+use core_i18n::{t, t_args};
+use core_realtime::{RealtimeEvent, RealtimePublisher, RealtimeTarget};
+use core_web::error::AppError;
+use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+
+use crate::internal::api::state::AppApiState;
+
+/// A record type in the admin review queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaimSubject {
+    Deposit,
+    Withdrawal,
+}
+
+impl ClaimSubject {
+    fn table(self) -> &'static str {
+        match self {
+            Self::Deposit => "deposits",
+            Self::Withdrawal => "withdrawals",
+        }
+    }
+
+    /// Statuses that still wait on an admin; only these can be claimed.
+    fn reviewable_statuses(self) -> &'static str {
+        match self {
+            Self::Deposit => "(1)",
+            Self::Withdrawal => "(1, 2)",
+        }
+    }
+
+    fn not_found(self) -> AppError {
+        match self {
+            Self::Deposit => AppError::NotFound(t("Deposit not found")),
+            Self::Withdrawal => AppError::NotFound(t("Withdrawal not found")),
+        }
+    }
+}
+
+/// Who currently holds a record in the review queue. All fields are empty
+/// when the record is unclaimed or the claim has expired.
+#[derive(Debug, Clone)]
+pub struct ReviewClaim {
+    pub subject: ClaimSubject,
+    pub id: i64,
+    pub claimed_by: Option<i64>,
+    pub claimer_username: Option<String>,
+    pub claimed_until: Option<OffsetDateTime>,
+}
+
+/// Claim change broadcast to the admin channel, so other consoles can show
+/// "being reviewed by X".
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReviewClaimChanged {
+    pub subject: ClaimSubject,
+    pub id: String,
+    pub claimed_by: Option<String>,
+    pub claimer_username: Option<String>,
+    pub claimed_until: Option<String>,
+}
+
+impl RealtimeEvent for ReviewClaimChanged {
+    const CHANNEL: &'static str = "admin";
+    const EVENT: &'static str = "review_claim";
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct ClaimRow {
+    claimed_by: Option<i64>,
+    claimed_until: Option<OffsetDateTime>,
+    claimer_username: Option<String>,
+}
+
+/// Fails when another admin holds a live claim on the record.
+pub fn ensure_not_claimed(
+    claimed_by: Option<i64>,
+    claimed_until: Option<OffsetDateTime>,
+    admin_id: i64,
+) -> Result<(), AppError> {
+    let held = claimed_until.is_some_and(|until| until > OffsetDateTime::now_utc());
+    if held && claimed_by.is_some_and(|holder| holder != admin_id) {
+        return Err(AppError::Conflict(t(
+            "This record is being reviewed by another admin",
+        )));
+    }
+    Ok(())
+}
+
+/// Locks a pending record to the admin for `[review_claim] ttl_secs`.
+/// Claiming again extends the admin's own claim; an expired claim can be
+/// taken over by anyone.
+pub async fn claim(
+    state: &AppApiState,
+    admin_id: i64,
+    subject: ClaimSubject,
+    id: i64,
+) -> Result<ReviewClaim, AppError> {
+    let now = OffsetDateTime::now_utc();
+    let until = now + Duration::seconds(state.review_claim_config.ttl_secs as i64);
+    let claimed = sqlx::query(&format!(
+        "UPDATE {table} SET claimed_by = $1, claimed_until = $2
+         WHERE id = $3 AND status IN {statuses}
+           AND (claimed_by IS NULL OR claimed_by = $1 OR claimed_until < $4)",
+        table = subject.table(),
+        statuses = subject.reviewable_statuses(),
+    ))
+    .bind(admin_id)
+    .bind(until)
+    .bind(id)
+    .bind(now)
+    .execute(&state.db)
+    .await
+    .map_err(AppError::from)?
+    .rows_affected()
+        == 1;
+
+    if !claimed {
+        let reviewable: Option<bool> = sqlx::query_scalar(&format!(
+            "SELECT status IN {statuses} FROM {table} WHERE id = $1",
+            table = subject.table(),
+            statuses = subject.reviewable_statuses(),
+        ))
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(AppError::from)?;
+        return match reviewable {
+            None => Err(subject.not_found()),
+            Some(false) => Err(AppError::BadRequest(t("This record is no longer pending review"))),
+            Some(true) => {
+                let current = current(&state.db, subject, id).await?;
+                Err(AppError::Conflict(t_args(
+                    "This record is being reviewed by :admin",
+                    &[("admin", current.claimer_username.as_deref().unwrap_or("-"))],
+                )))
+            }
+        };
+    }
+
+    publish(state, subject, id).await
+}
+
+/// Drops the admin's own claim. Releasing a claim that is not held is a no-op.
+pub async fn release(
+    state: &AppApiState,
+    admin_id: i64,
+    subject: ClaimSubject,
+    id: i64,
+) -> Result<ReviewClaim, AppError> {
+    sqlx::query(&format!(
+        "UPDATE {table} SET claimed_by = NULL, claimed_until = NULL
+         WHERE id = $1 AND claimed_by = $2",
+        table = subject.table(),
+    ))
+    .bind(id)
+    .bind(admin_id)
+    .execute(&state.db)
+    .await
+    .map_err(AppError::from)?;
+
+    publish(state, subject, id).await
+}
+
+/// Broadcasts the record's current claim so other consoles can show who is
+/// reviewing it. Call it after any change to the claim columns.
+pub async fn publish(
+    state: &AppApiState,
+    subject: ClaimSubject,
+    id: i64,
+) -> Result<ReviewClaim, AppError> {
+    publish_with(&state.db, &state.realtime, subject, id).await
+}
+
+/// [`publish`] for callers outside a request, such as jobs.
+pub async fn publish_with(
+    db: &sqlx::PgPool,
+    realtime: &RealtimePublisher,
+    subject: ClaimSubject,
+    id: i64,
+) -> Result<ReviewClaim, AppError> {
+    let claim = current(db, subject, id).await?;
+    let event = ReviewClaimChanged {
+        subject,
+        id: id.to_string(),
+        claimed_by: claim.claimed_by.map(|admin_id| admin_id.to_string()),
+        claimer_username: claim.claimer_username.clone(),
+        claimed_until: claim.claimed_until.and_then(|until| until.format(&Rfc3339).ok()),
+    };
+    let _ = realtime.publish(RealtimeTarget { room: None }, &event).await;
+    Ok(claim)
+}
+
+async fn current(
+    db: &sqlx::PgPool,
+    subject: ClaimSubject,
+    id: i64,
+) -> Result<ReviewClaim, AppError> {
+    let row = sqlx::query_as::<_, ClaimRow>(&format!(
+        "SELECT r.claimed_by, r.claimed_until, a.username AS claimer_username
+         FROM {table} r
+         LEFT JOIN admin a ON a.id = r.claimed_by
+         WHERE r.id = $1",
+        table = subject.table(),
+    ))
+    .bind(id)
+    .fetch_optional(db)
+    .await
+    .map_err(AppError::from)?
+    .ok_or_else(|| subject.not_found())?;
+
+    // An expired claim reads as released.
+    let live = row
+        .claimed_until
+        .filter(|until| *until > OffsetDateTime::now_utc());
+    Ok(ReviewClaim {
+        subject,
+        id,
+        claimed_by: live.and(row.claimed_by),
+        claimer_username: live.and(row.claimer_username),
+        claimed_until: live,
+    })
+}
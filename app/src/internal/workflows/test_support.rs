@@ -0,0 +1,106 @@
+//! Database fixtures for workflow tests. Tests using these need a migrated
+//! database in `DATABASE_URL`, so they are ignored by default and run with
+//! `cargo test -- --ignored`.
+
+use core_db::common::sql::generate_snowflake_i64;
+use generated::models::{
+    CreditTransactionType, CreditType, OwnerType, WithdrawalMethod, WithdrawalStatus,
+};
+use rust_decimal::Decimal;
+
+pub async fn pool() -> sqlx::PgPool {
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    sqlx::postgres::PgPoolOptions::new()
+        .max_connections(8)
+        .connect(&url)
+        .await
+        .expect("connect to DATABASE_URL")
+}
+
+pub async fn seed_admin(db: &sqlx::PgPool) -> i64 {
+    let id = generate_snowflake_i64();
+    sqlx::query(
+        "INSERT INTO admin (id, username, password, name, admin_type)
+         VALUES ($1, $2, 'test', 'Test Admin', 'admin')",
+    )
+    .bind(id)
+    .bind(format!("test_admin_{id}"))
+    .execute(db)
+    .await
+    .expect("seed admin");
+    id
+}
+
+pub async fn seed_user(db: &sqlx::PgPool, credit_1: Decimal) -> i64 {
+    let id = generate_snowflake_i64();
+    sqlx::query(
+        "INSERT INTO users (id, uuid, username, password, credit_1)
+         VALUES ($1, $2, $3, 'test', $4)",
+    )
+    .bind(id)
+    .bind(format!("test-{id}"))
+    .bind(format!("test_user_{id}"))
+    .bind(credit_1)
+    .execute(db)
+    .await
+    .expect("seed user");
+    id
+}
+
+/// Seeds a withdrawal whose amount has already been held from the balance,
+/// along with the hold's ledger entry.
+pub async fn seed_withdrawal(
+    db: &sqlx::PgPool,
+    user_id: i64,
+    amount: Decimal,
+    status: WithdrawalStatus,
+) -> i64 {
+    let id = generate_snowflake_i64();
+    sqlx::query(
+        "INSERT INTO withdrawals
+             (id, owner_type, owner_id, credit_type, withdrawal_method, status, amount, net_amount)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $7)",
+    )
+    .bind(id)
+    .bind(OwnerType::User)
+    .bind(user_id)
+    .bind(CreditType::Credit1)
+    .bind(WithdrawalMethod::Manual)
+    .bind(status)
+    .bind(amount)
+    .execute(db)
+    .await
+    .expect("seed withdrawal");
+    sqlx::query(
+        "INSERT INTO user_credit_transactions
+             (id, user_id, credit_type, amount, transaction_type, related_key,
+              balance_before, balance_after)
+         SELECT $1, id, $2, -$3, $4, $5, credit_1 + $3, credit_1 FROM users WHERE id = $6",
+    )
+    .bind(generate_snowflake_i64())
+    .bind(CreditType::Credit1)
+    .bind(amount)
+    .bind(CreditTransactionType::Withdraw)
+    .bind(id.to_string())
+    .bind(user_id)
+    .execute(db)
+    .await
+    .expect("seed withdrawal hold");
+    id
+}
+
+pub async fn credit_1(db: &sqlx::PgPool, user_id: i64) -> Decimal {
+    sqlx::query_scalar("SELECT credit_1 FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_one(db)
+        .await
+        .expect("read balance")
+}
+
+pub async fn transaction_count(db: &sqlx::PgPool, user_id: i64) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM user_credit_transactions WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(db)
+        .await
+        .expect("count transactions")
+}
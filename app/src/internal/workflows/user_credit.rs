@@ -2,21 +2,31 @@ use core_db::common::sql::{DbConn, Op};
 use core_i18n::t;
 use core_web::error::AppError;
 use generated::models::{
-    CreditTransactionType, CreditType, UserCol, UserCreditTransactionCol,
+    CreditTransactionType, CreditType, FinancialApprovalAction, UserCol,
     UserCreditTransactionModel, UserCreditTransactionRecord, UserModel,
 };
 use rust_decimal::Decimal;
 
 use crate::{
     contracts::api::v1::admin::user_credit::AdminCreditAdjustInput,
-    internal::api::state::AppApiState,
+    internal::{
+        api::state::AppApiState,
+        config::balance::BalanceConfig,
+        workflows::{
+            credit_ledger::{self, CreditEntry},
+            financial_approval::{self, ApprovalOutcome, Proposal},
+        },
+    },
 };
 
+/// Adds or deducts credit for a user. An adjustment whose size is above the
+/// `[approval.credit_adjust]` threshold is only proposed; a second admin
+/// confirms it later.
 pub async fn adjust_credit(
     state: &AppApiState,
     admin_id: i64,
     req: AdminCreditAdjustInput,
-) -> Result<UserCreditTransactionRecord, AppError> {
+) -> Result<ApprovalOutcome<UserCreditTransactionRecord>, AppError> {
     let username = req.username.to_ascii_lowercase();
     let amount = req.amount;
 
@@ -36,29 +46,80 @@ pub async fn adjust_credit(
         .map_err(AppError::from)?
         .ok_or_else(|| AppError::NotFound(t("User not found")))?;
 
+    if state.approval_config.requires_second_admin(
+        FinancialApprovalAction::CreditAdjust,
+        credit_type,
+        amount,
+    ) {
+        let payload = serde_json::json!({
+            "username": user.username,
+            "credit_type": req.credit_type,
+            "amount": req.amount,
+            "remark": req.remark,
+            "custom_description": req.custom_description,
+            "custom_description_text": req.custom_description_text.map(|text| {
+                serde_json::json!({ "en": text.en, "zh": text.zh })
+            }),
+        });
+        let approval = financial_approval::propose(
+            state,
+            admin_id,
+            Proposal {
+                action: FinancialApprovalAction::CreditAdjust,
+                subject_id: user.id,
+                credit_type,
+                amount,
+                payload,
+            },
+        )
+        .await?;
+        return Ok(ApprovalOutcome::Proposed(approval));
+    }
+
+    // Begin transaction scope — both operations share the same DB transaction
+    let scope = DbConn::pool(&state.db).begin_scope().await.map_err(AppError::from)?;
+    let txn = apply_adjustment(
+        scope.conn(),
+        &state.balance_config,
+        admin_id,
+        user.id,
+        credit_type,
+        req,
+    )
+    .await?;
+    scope.commit().await.map_err(AppError::from)?;
+
+    Ok(ApprovalOutcome::Applied(txn))
+}
+
+/// Posts a resolved adjustment inside a transaction the caller commits.
+pub async fn apply_adjustment(
+    conn: DbConn<'_>,
+    balance: &BalanceConfig,
+    admin_id: i64,
+    user_id: i64,
+    credit_type: CreditType,
+    req: AdminCreditAdjustInput,
+) -> Result<UserCreditTransactionRecord, AppError> {
+    let amount = req.amount;
     let transaction_type = if amount > Decimal::ZERO {
         CreditTransactionType::AdminAdd
     } else {
         CreditTransactionType::AdminDeduct
     };
 
-    // Begin transaction scope — both operations share the same DB transaction
-    let scope = DbConn::pool(&state.db).begin_scope().await.map_err(AppError::from)?;
-    let conn = scope.conn();
-
-    // Insert transaction record
-    let txn = UserCreditTransactionModel::create(conn.clone())
-        .set(UserCreditTransactionCol::USER_ID, user.id)?
-        .set(UserCreditTransactionCol::ADMIN_ID, Some(admin_id))?
-        .set(UserCreditTransactionCol::CREDIT_TYPE, credit_type)?
-        .set(UserCreditTransactionCol::AMOUNT, amount)?
-        .set(UserCreditTransactionCol::TRANSACTION_TYPE, transaction_type)?
-        .set(UserCreditTransactionCol::RELATED_KEY, None::<String>)?
-        .set(UserCreditTransactionCol::REMARK, req.remark)?
-        .set(UserCreditTransactionCol::CUSTOM_DESCRIPTION, req.custom_description)?
-        .save()
-        .await
-        .map_err(AppError::from)?;
+    // Balance update and transaction record, with the running balance snapshot
+    let txn = credit_ledger::post(
+        conn.clone(),
+        balance,
+        CreditEntry {
+            admin_id: Some(admin_id),
+            remark: req.remark,
+            custom_description: req.custom_description,
+            ..CreditEntry::new(user_id, credit_type, amount, transaction_type)
+        },
+    )
+    .await?;
 
     if let Some(custom_description_text) = req.custom_description_text {
         txn.upsert_custom_description_text(conn.clone(), Some(custom_description_text))
@@ -66,29 +127,8 @@ pub async fn adjust_credit(
             .map_err(AppError::from)?;
     }
 
-    // Atomic relative balance update
-    let update = match credit_type {
-        CreditType::Credit1 => UserModel::query(conn.clone())
-            .where_col(UserCol::ID, Op::Eq, user.id)
-            .patch()
-            .increment(UserCol::CREDIT_1, amount),
-        CreditType::Credit2 => UserModel::query(conn.clone())
-            .where_col(UserCol::ID, Op::Eq, user.id)
-            .patch()
-            .increment(UserCol::CREDIT_2, amount),
-    };
-    update
-        .map_err(AppError::from)?
-        .save()
-        .await
-        .map_err(AppError::from)?;
-
-    let txn = UserCreditTransactionModel::find(conn, txn.id)
+    UserCreditTransactionModel::find(conn, txn.id)
         .await
         .map_err(AppError::from)?
-        .ok_or_else(|| AppError::BadRequest(t("Failed to create credit transaction")))?;
-
-    scope.commit().await.map_err(AppError::from)?;
-
-    Ok(txn)
+        .ok_or_else(|| AppError::BadRequest(t("Failed to create credit transaction")))
 }
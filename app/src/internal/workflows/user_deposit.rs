@@ -0,0 +1,284 @@
+use core_db::common::sql::{DbConn, Op, OrderDir};
+use core_i18n::{t, t_args};
+use core_web::error::AppError;
+use generated::models::{
+    CompanyBankAccountCol, CompanyBankAccountModel, CompanyBankAccountRecord,
+    CompanyBankAccountStatus, CompanyCryptoAccountCol, CompanyCryptoAccountModel,
+    CompanyCryptoAccountRecord, CompanyCryptoAccountStatus, DepositCol, DepositMethod,
+    DepositModel, DepositRecord, DepositStatus, OwnerType, UserLimitKind,
+};
+use time::OffsetDateTime;
+
+use crate::{
+    contracts::api::v1::user::deposit::{UserDepositCreateInput, UserGatewayDepositInput},
+    internal::{
+        api::state::AppApiState,
+        payment_gateway::CheckoutRequest,
+        workflows::{deposit, limits},
+    },
+};
+
+pub struct DepositTargets {
+    pub bank_accounts: Vec<CompanyBankAccountRecord>,
+    pub crypto_accounts: Vec<CompanyCryptoAccountRecord>,
+}
+
+pub struct UserDepositPage {
+    pub data: Vec<DepositRecord>,
+    pub total: i64,
+    pub per_page: i64,
+    pub current_page: i64,
+    pub last_page: i64,
+}
+
+pub async fn list_targets(state: &AppApiState) -> Result<DepositTargets, AppError> {
+    let bank_accounts = CompanyBankAccountModel::query(DbConn::pool(&state.db))
+        .where_col(
+            CompanyBankAccountCol::STATUS,
+            Op::Eq,
+            CompanyBankAccountStatus::Enabled,
+        )
+        .order_by(CompanyBankAccountCol::SORT_ORDER, OrderDir::Asc)
+        .order_by(CompanyBankAccountCol::ID, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    let crypto_accounts = CompanyCryptoAccountModel::query(DbConn::pool(&state.db))
+        .where_col(
+            CompanyCryptoAccountCol::STATUS,
+            Op::Eq,
+            CompanyCryptoAccountStatus::Enabled,
+        )
+        .order_by(CompanyCryptoAccountCol::SORT_ORDER, OrderDir::Asc)
+        .order_by(CompanyCryptoAccountCol::ID, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(DepositTargets {
+        bank_accounts,
+        crypto_accounts,
+    })
+}
+
+/// Fetches a deposit only when it belongs to the given user.
+pub async fn detail(
+    state: &AppApiState,
+    user_id: i64,
+    deposit_id: i64,
+) -> Result<DepositRecord, AppError> {
+    DepositModel::query(DbConn::pool(&state.db))
+        .where_col(DepositCol::ID, Op::Eq, deposit_id)
+        .where_col(DepositCol::OWNER_TYPE, Op::Eq, OwnerType::User)
+        .where_col(DepositCol::OWNER_ID, Op::Eq, user_id)
+        .first()
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Deposit not found")))
+}
+
+/// `receipt_params` is written with the insert, so a deposit submitted with a
+/// receipt is never visible without it.
+pub async fn create(
+    state: &AppApiState,
+    user_id: i64,
+    req: UserDepositCreateInput,
+    receipt_params: Option<serde_json::Value>,
+) -> Result<DepositRecord, AppError> {
+    let mut conversion_rate = None;
+
+    if let Some(account_id) = req.company_bank_account_id {
+        let account_id: i64 = account_id.into();
+        let account = CompanyBankAccountModel::find(DbConn::pool(&state.db), account_id)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::NotFound(t("Company bank account not found")))?;
+        if account.status != CompanyBankAccountStatus::Enabled {
+            return Err(AppError::BadRequest(t("Deposit account is not available")));
+        }
+    }
+
+    if let Some(account_id) = req.company_crypto_account_id {
+        let account_id: i64 = account_id.into();
+        let account = CompanyCryptoAccountModel::find(DbConn::pool(&state.db), account_id)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::NotFound(t("Company crypto account not found")))?;
+        if account.status != CompanyCryptoAccountStatus::Enabled {
+            return Err(AppError::BadRequest(t("Deposit account is not available")));
+        }
+        conversion_rate = Some(account.conversion_rate);
+    }
+
+    let (fee, net_amount) = quote(state, req.amount)?;
+
+    let now = OffsetDateTime::now_utc();
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+    limits::check(conn.clone(), state, UserLimitKind::Deposit, user_id, req.amount, now, None)
+        .await?;
+    let reference_code = deposit::generate_reference_code(conn.clone()).await?;
+
+    let deposit = DepositModel::create(conn.clone())
+        .set(DepositCol::OWNER_TYPE, OwnerType::User)?
+        .set(DepositCol::OWNER_ID, user_id)?
+        .set(DepositCol::CREDIT_TYPE, req.credit_type)?
+        .set(DepositCol::DEPOSIT_METHOD, DepositMethod::Manual)?
+        .set(
+            DepositCol::COMPANY_BANK_ACCOUNT_ID,
+            req.company_bank_account_id.map(Into::<i64>::into),
+        )?
+        .set(
+            DepositCol::COMPANY_CRYPTO_ACCOUNT_ID,
+            req.company_crypto_account_id.map(Into::<i64>::into),
+        )?
+        .set(DepositCol::CONVERSION_RATE, conversion_rate)?
+        .set(DepositCol::STATUS, DepositStatus::Pending)?
+        .set(DepositCol::AMOUNT, req.amount)?
+        .set(DepositCol::FEE, fee)?
+        .set(DepositCol::NET_AMOUNT, net_amount)?
+        .set(DepositCol::REMARK, req.remark)?
+        .set(DepositCol::REFERENCE_CODE, Some(reference_code))?
+        .set(DepositCol::PARAMS, receipt_params)?
+        .set(DepositCol::CREATED_AT, now)?
+        .set(DepositCol::UPDATED_AT, now)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    deposit::record_status(conn.clone(), deposit.id, DepositStatus::Pending, None, None).await?;
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+
+    detail(state, user_id, deposit.id).await
+}
+
+/// Opens a checkout with the configured payment gateway. The deposit stays
+/// pending until the gateway calls back; see `workflows::gateway_callback`.
+pub async fn create_gateway(
+    state: &AppApiState,
+    user_id: i64,
+    req: UserGatewayDepositInput,
+) -> Result<DepositRecord, AppError> {
+    let gateway = state
+        .payment_gateway
+        .as_ref()
+        .ok_or_else(|| AppError::BadRequest(t("Online payment is not available")))?;
+    let (fee, net_amount) = quote(state, req.amount)?;
+
+    let now = OffsetDateTime::now_utc();
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+    limits::check(conn.clone(), state, UserLimitKind::Deposit, user_id, req.amount, now, None)
+        .await?;
+
+    let deposit = DepositModel::create(conn.clone())
+        .set(DepositCol::OWNER_TYPE, OwnerType::User)?
+        .set(DepositCol::OWNER_ID, user_id)?
+        .set(DepositCol::CREDIT_TYPE, req.credit_type)?
+        .set(DepositCol::DEPOSIT_METHOD, DepositMethod::Gateway)?
+        .set(DepositCol::STATUS, DepositStatus::Pending)?
+        .set(DepositCol::AMOUNT, req.amount)?
+        .set(DepositCol::FEE, fee)?
+        .set(DepositCol::NET_AMOUNT, net_amount)?
+        .set(DepositCol::REMARK, req.remark)?
+        .set(DepositCol::GATEWAY_PROVIDER, Some(gateway.code().to_string()))?
+        .set(DepositCol::CREATED_AT, now)?
+        .set(DepositCol::UPDATED_AT, now)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    // Nothing is committed if the provider refuses the checkout.
+    let session = gateway
+        .create_checkout(&CheckoutRequest {
+            deposit_id: deposit.id,
+            amount: deposit.amount,
+            description: format!("Deposit #{}", deposit.id),
+        })
+        .await?;
+
+    DepositModel::query(conn.clone())
+        .where_col(DepositCol::ID, Op::Eq, deposit.id)
+        .patch()
+        .assign(DepositCol::GATEWAY_SESSION_ID, Some(session.session_id))
+        .map_err(AppError::from)?
+        .assign(DepositCol::GATEWAY_CHECKOUT_URL, Some(session.checkout_url))
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    deposit::record_status(conn.clone(), deposit.id, DepositStatus::Pending, None, None).await?;
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+
+    detail(state, user_id, deposit.id).await
+}
+
+/// Fee and net amount for a new deposit, after the minimum amount check.
+fn quote(
+    state: &AppApiState,
+    amount: rust_decimal::Decimal,
+) -> Result<(rust_decimal::Decimal, rust_decimal::Decimal), AppError> {
+    let deposit_config = &state.deposit_config;
+    if amount < deposit_config.min_amount {
+        let min_amount = deposit_config.min_amount.normalize().to_string();
+        return Err(AppError::BadRequest(t_args(
+            "Minimum deposit amount is :amount",
+            &[("amount", min_amount.as_str())],
+        )));
+    }
+
+    deposit_config
+        .calculate_fee(amount)
+        .filter(|(_, net)| *net > rust_decimal::Decimal::ZERO)
+        .ok_or_else(|| AppError::BadRequest(t("Deposit amount does not cover the fee")))
+}
+
+pub async fn list(
+    state: &AppApiState,
+    user_id: i64,
+    page: Option<i64>,
+    per_page: Option<i64>,
+) -> Result<UserDepositPage, AppError> {
+    let current_page = page.unwrap_or(1).max(1);
+    let per_page = per_page
+        .unwrap_or(state.datatable_default_per_page)
+        .clamp(1, 100);
+
+    let total = DepositModel::query(DbConn::pool(&state.db))
+        .where_col(DepositCol::OWNER_TYPE, Op::Eq, OwnerType::User)
+        .where_col(DepositCol::OWNER_ID, Op::Eq, user_id)
+        .count()
+        .await
+        .map_err(AppError::from)?;
+
+    let data = DepositModel::query(DbConn::pool(&state.db))
+        .where_col(DepositCol::OWNER_TYPE, Op::Eq, OwnerType::User)
+        .where_col(DepositCol::OWNER_ID, Op::Eq, user_id)
+        .order_by(DepositCol::CREATED_AT, OrderDir::Desc)
+        .order_by(DepositCol::ID, OrderDir::Desc)
+        .offset((current_page - 1) * per_page)
+        .limit(per_page)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(UserDepositPage {
+        data,
+        total,
+        per_page,
+        current_page,
+        last_page: ((total + per_page - 1) / per_page).max(1),
+    })
+}
@@ -0,0 +1,192 @@
+use core_db::common::sql::{generate_snowflake_i64, DbConn};
+use core_i18n::{t, t_args};
+use core_web::error::AppError;
+use generated::models::{CreditTransactionType, CreditType, UserBanStatus, UserModel};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{
+    contracts::api::v1::user::transfer::UserTransferInput,
+    internal::{
+        api::state::AppApiState,
+        workflows::{
+            credit_ledger::{self, CreditEntry},
+            introducer_change::resolve_user_by_username,
+            period,
+        },
+    },
+};
+
+pub struct TransferResult {
+    pub related_key: String,
+    pub to_username: String,
+    pub credit_type: CreditType,
+    pub amount: Decimal,
+    pub balance: Decimal,
+    pub created_at: OffsetDateTime,
+}
+
+/// Whether `ancestor_id` appears anywhere in the introducer chain above `user_id`.
+async fn is_upline_of(
+    state: &AppApiState,
+    ancestor_id: i64,
+    user_id: i64,
+) -> Result<bool, AppError> {
+    let found = sqlx::query_scalar::<_, bool>(
+        "WITH RECURSIVE upline AS (
+             SELECT introducer_user_id AS id, 1 AS depth FROM users WHERE id = $1
+             UNION ALL
+             SELECT u.introducer_user_id, up.depth + 1
+             FROM users u
+             JOIN upline up ON u.id = up.id
+             WHERE up.depth < 10000
+         )
+         SELECT EXISTS(SELECT 1 FROM upline WHERE id = $2)",
+    )
+    .bind(user_id)
+    .bind(ancestor_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(AppError::from)?;
+    Ok(found)
+}
+
+pub async fn transfer(
+    state: &AppApiState,
+    from_user_id: i64,
+    req: UserTransferInput,
+) -> Result<TransferResult, AppError> {
+    let config = &state.transfer_config;
+
+    if !config.allows(req.credit_type) {
+        return Err(AppError::BadRequest(t("This credit type cannot be transferred")));
+    }
+    if req.amount < config.min_amount {
+        let min_amount = config.min_amount.normalize().to_string();
+        return Err(AppError::BadRequest(t_args(
+            "Minimum transfer amount is :amount",
+            &[("amount", min_amount.as_str())],
+        )));
+    }
+
+    let sender = UserModel::find(DbConn::pool(&state.db), from_user_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("User not found")))?;
+    let recipient = resolve_user_by_username(state, &req.to_username).await?;
+
+    if recipient.id == sender.id {
+        return Err(AppError::BadRequest(t("Cannot transfer to yourself")));
+    }
+    if recipient.ban == UserBanStatus::Yes {
+        return Err(AppError::BadRequest(t("Recipient cannot receive transfers")));
+    }
+    if config.restrict_to_network
+        && !is_upline_of(state, recipient.id, sender.id).await?
+        && !is_upline_of(state, sender.id, recipient.id).await?
+    {
+        return Err(AppError::BadRequest(t(
+            "You can only transfer to users in your own network",
+        )));
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let related_key = generate_snowflake_i64().to_string();
+
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+
+    // Lock both rows in id order so opposite-direction transfers between the
+    // same pair of users cannot deadlock.
+    let mut ids = [sender.id, recipient.id];
+    ids.sort_unstable();
+    let locked = conn
+        .fetch_all(
+            sqlx::query_scalar::<_, i64>(
+                "SELECT id FROM users WHERE id = ANY($1) ORDER BY id FOR UPDATE",
+            )
+            .bind(ids.to_vec()),
+        )
+        .await
+        .map_err(AppError::from)?;
+    if !locked.contains(&sender.id) {
+        return Err(AppError::NotFound(t("User not found")));
+    }
+
+    if !config.daily_cap.is_zero() {
+        // Runs after the sender row lock, so concurrent transfers see each other.
+        let sent_today = conn
+            .fetch_scalar(
+                sqlx::query_scalar::<_, Decimal>(
+                    "SELECT COALESCE(SUM(-amount), 0) FROM user_credit_transactions
+                     WHERE user_id = $1 AND credit_type = $2 AND transaction_type = $3
+                       AND created_at >= $4",
+                )
+                .bind(sender.id)
+                .bind(req.credit_type)
+                .bind(CreditTransactionType::TransferOut)
+                .bind(period::day_start(&state.app_timezone, now)),
+            )
+            .await
+            .map_err(AppError::from)?;
+
+        if sent_today + req.amount > config.daily_cap {
+            let remaining = (config.daily_cap - sent_today)
+                .max(Decimal::ZERO)
+                .normalize()
+                .to_string();
+            return Err(AppError::BadRequest(t_args(
+                "Daily transfer limit exceeded. Remaining today: :amount",
+                &[("amount", remaining.as_str())],
+            )));
+        }
+    }
+
+    let legs = [
+        (
+            sender.id,
+            -req.amount,
+            CreditTransactionType::TransferOut,
+            recipient.username.clone(),
+        ),
+        (
+            recipient.id,
+            req.amount,
+            CreditTransactionType::TransferIn,
+            sender.username.clone(),
+        ),
+    ];
+
+    // The sender's leg is checked against the balance policy by the ledger.
+    let mut sender_balance_after = Decimal::ZERO;
+    for (user_id, amount, transaction_type, counterparty) in legs {
+        let txn = credit_ledger::post(
+            conn.clone(),
+            &state.balance_config,
+            CreditEntry {
+                related_key: Some(related_key.clone()),
+                params: Some(serde_json::json!({ "username": counterparty })),
+                remark: req.remark.clone(),
+                ..CreditEntry::new(user_id, req.credit_type, amount, transaction_type)
+            },
+        )
+        .await?;
+        if user_id == sender.id {
+            sender_balance_after = txn.balance_after;
+        }
+    }
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    Ok(TransferResult {
+        related_key,
+        to_username: recipient.username,
+        credit_type: req.credit_type,
+        amount: req.amount,
+        balance: sender_balance_after,
+        created_at: now,
+    })
+}
@@ -0,0 +1,238 @@
+use core_db::common::sql::{DbConn, Op, OrderDir};
+use core_i18n::{t, t_args};
+use core_web::error::AppError;
+use generated::models::{
+    BankModel, BankStatus, CreditTransactionType, CryptoNetworkModel, CryptoNetworkStatus,
+    OwnerType, UserLimitKind, UserWithdrawalDestinationCol, UserWithdrawalDestinationModel,
+    WithdrawalCol, WithdrawalMethod, WithdrawalModel, WithdrawalRecord, WithdrawalStatus,
+};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{
+    contracts::api::v1::user::withdrawal::UserWithdrawalCreateInput,
+    internal::{
+        api::state::AppApiState,
+        workflows::{
+            bank as bank_workflow,
+            credit_ledger::{self, CreditEntry},
+            crypto_network, kyc, limits,
+            withdrawal::record_status,
+            withdrawal_destination,
+        },
+    },
+};
+
+pub struct UserWithdrawalPage {
+    pub data: Vec<WithdrawalRecord>,
+    pub total: i64,
+    pub per_page: i64,
+    pub current_page: i64,
+    pub last_page: i64,
+}
+
+/// Fetches a withdrawal only when it belongs to the given user.
+pub async fn detail(
+    state: &AppApiState,
+    user_id: i64,
+    withdrawal_id: i64,
+) -> Result<WithdrawalRecord, AppError> {
+    WithdrawalModel::query(DbConn::pool(&state.db))
+        .where_col(WithdrawalCol::ID, Op::Eq, withdrawal_id)
+        .where_col(WithdrawalCol::OWNER_TYPE, Op::Eq, OwnerType::User)
+        .where_col(WithdrawalCol::OWNER_ID, Op::Eq, user_id)
+        .first()
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Withdrawal not found")))
+}
+
+/// Creates a pending withdrawal and holds the full amount from the user's
+/// balance in the same transaction. Rejection or expiry releases the hold
+/// with a `WithdrawHoldRelease` entry; a refund only follows a payout. The
+/// saved destination's details are copied onto the withdrawal. Users below `[withdrawal] min_kyc_level` cannot
+/// withdraw.
+pub async fn create(
+    state: &AppApiState,
+    user_id: i64,
+    req: UserWithdrawalCreateInput,
+) -> Result<WithdrawalRecord, AppError> {
+    let fee_config = &state.withdrawal_config;
+    kyc::require_level(DbConn::pool(&state.db), user_id, fee_config.min_kyc_level).await?;
+    if req.amount < fee_config.min_amount {
+        let min_amount = fee_config.min_amount.normalize().to_string();
+        return Err(AppError::BadRequest(t_args(
+            "Minimum withdrawal amount is :amount",
+            &[("amount", min_amount.as_str())],
+        )));
+    }
+
+    let (fee, net_amount) = fee_config
+        .calculate_fee(req.amount)
+        .filter(|(_, net)| *net > Decimal::ZERO)
+        .ok_or_else(|| AppError::BadRequest(t("Withdrawal amount does not cover the fee")))?;
+
+    let destination =
+        withdrawal_destination::find_own(state, user_id, req.destination_id.into()).await?;
+    withdrawal_destination::ensure_usable(&destination, OffsetDateTime::now_utc())?;
+    let mut snapshot = serde_json::Map::new();
+
+    if let Some(id) = destination.bank_id {
+        let bank = BankModel::find(DbConn::pool(&state.db), id)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::NotFound(t("Bank not found")))?;
+        if bank.status != BankStatus::Enabled {
+            return Err(AppError::BadRequest(t("Bank is not enabled")));
+        }
+        // The bank's account number rules may have been tightened since saving
+        bank_workflow::ensure_account_number(
+            &bank,
+            "destination_id",
+            destination.bank_account_number.as_deref().unwrap_or_default(),
+        )?;
+        snapshot.insert("bank_name".to_string(), bank.name.clone().into());
+    }
+
+    if let Some(id) = destination.crypto_network_id {
+        let network = CryptoNetworkModel::find(DbConn::pool(&state.db), id)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::NotFound(t("Crypto network not found")))?;
+        if network.status != CryptoNetworkStatus::Enabled {
+            return Err(AppError::BadRequest(t("Crypto network is not enabled")));
+        }
+        // The network's address format may have been tightened since saving
+        crypto_network::ensure_wallet_address(
+            &network,
+            "destination_id",
+            destination.crypto_wallet_address.as_deref().unwrap_or_default(),
+        )?;
+        snapshot.insert("crypto_network_name".to_string(), network.name.clone().into());
+        snapshot.insert("crypto_network_symbol".to_string(), network.symbol.clone().into());
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let scope = DbConn::pool(&state.db)
+        .begin_scope()
+        .await
+        .map_err(AppError::from)?;
+    let conn = scope.conn();
+    limits::check(conn.clone(), state, UserLimitKind::Withdrawal, user_id, req.amount, now, None)
+        .await?;
+
+    // Checked again under a row lock, so a lock or delete that landed after
+    // the first read cannot be withdrawn to.
+    conn.fetch_all(
+        sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM user_withdrawal_destinations WHERE id = $1 FOR UPDATE",
+        )
+        .bind(destination.id),
+    )
+    .await
+    .map_err(AppError::from)?;
+    let destination = UserWithdrawalDestinationModel::query(conn.clone())
+        .where_col(UserWithdrawalDestinationCol::ID, Op::Eq, destination.id)
+        .where_col(UserWithdrawalDestinationCol::USER_ID, Op::Eq, user_id)
+        .first()
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Withdrawal destination not found")))?;
+    withdrawal_destination::ensure_usable(&destination, now)?;
+
+    let withdrawal = WithdrawalModel::create(conn.clone())
+        .set(WithdrawalCol::OWNER_TYPE, OwnerType::User)?
+        .set(WithdrawalCol::OWNER_ID, user_id)?
+        .set(WithdrawalCol::CREDIT_TYPE, req.credit_type)?
+        .set(WithdrawalCol::WITHDRAWAL_METHOD, WithdrawalMethod::Manual)?
+        .set(WithdrawalCol::BANK_ID, destination.bank_id)?
+        .set(WithdrawalCol::BANK_ACCOUNT_NAME, destination.bank_account_name.clone())?
+        .set(WithdrawalCol::BANK_ACCOUNT_NUMBER, destination.bank_account_number.clone())?
+        .set(WithdrawalCol::CRYPTO_NETWORK_ID, destination.crypto_network_id)?
+        .set(WithdrawalCol::CRYPTO_WALLET_ADDRESS, destination.crypto_wallet_address.clone())?
+        .set(WithdrawalCol::DESTINATION_ID, Some(destination.id))?
+        .set(WithdrawalCol::STATUS, WithdrawalStatus::Pending)?
+        .set(WithdrawalCol::AMOUNT, req.amount)?
+        .set(WithdrawalCol::FEE, fee)?
+        .set(WithdrawalCol::NET_AMOUNT, net_amount)?
+        .set(
+            WithdrawalCol::PARAMS,
+            Some(serde_json::json!({ "destination": snapshot })),
+        )?
+        .set(WithdrawalCol::REMARK, req.remark)?
+        .set(WithdrawalCol::CREATED_AT, now)?
+        .set(WithdrawalCol::UPDATED_AT, now)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    record_status(
+        conn.clone(),
+        withdrawal.id,
+        WithdrawalStatus::Pending,
+        None,
+        None,
+    )
+    .await?;
+
+    // Hold the balance; the ledger checks the balance policy in the UPDATE so
+    // concurrent requests cannot spend the same funds twice.
+    credit_ledger::post(
+        conn.clone(),
+        &state.balance_config,
+        CreditEntry {
+            related_key: Some(withdrawal.id.to_string()),
+            remark: Some(format!("Withdrawal #{}", withdrawal.id)),
+            ..CreditEntry::new(
+                user_id,
+                req.credit_type,
+                -req.amount,
+                CreditTransactionType::Withdraw,
+            )
+        },
+    )
+    .await?;
+
+    scope.commit().await.map_err(AppError::from)?;
+
+    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+
+    detail(state, user_id, withdrawal.id).await
+}
+
+pub async fn list(
+    state: &AppApiState,
+    user_id: i64,
+    page: Option<i64>,
+    per_page: Option<i64>,
+) -> Result<UserWithdrawalPage, AppError> {
+    let current_page = page.unwrap_or(1).max(1);
+    let per_page = per_page
+        .unwrap_or(state.datatable_default_per_page)
+        .clamp(1, 100);
+
+    let total = WithdrawalModel::query(DbConn::pool(&state.db))
+        .where_col(WithdrawalCol::OWNER_TYPE, Op::Eq, OwnerType::User)
+        .where_col(WithdrawalCol::OWNER_ID, Op::Eq, user_id)
+        .count()
+        .await
+        .map_err(AppError::from)?;
+
+    let data = WithdrawalModel::query(DbConn::pool(&state.db))
+        .where_col(WithdrawalCol::OWNER_TYPE, Op::Eq, OwnerType::User)
+        .where_col(WithdrawalCol::OWNER_ID, Op::Eq, user_id)
+        .order_by(WithdrawalCol::CREATED_AT, OrderDir::Desc)
+        .order_by(WithdrawalCol::ID, OrderDir::Desc)
+        .offset((current_page - 1) * per_page)
+        .limit(per_page)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(UserWithdrawalPage {
+        data,
+        total,
+        per_page,
+        current_page,
+        last_page: ((total + per_page - 1) / per_page).max(1),
+    })
+}
@@ -1,19 +1,34 @@
-use core_db::common::sql::{DbConn, Op};
+use core_db::common::sql::{DbConn, Op, OrderDir};
 use core_i18n::t;
 use core_web::error::AppError;
 use generated::models::{
-    CreditTransactionType, CreditType, OwnerType, UserCol, UserCreditTransactionModel, UserModel,
-    UserCreditTransactionCol, WithdrawalCol, WithdrawalModel, WithdrawalRecord,
-    WithdrawalReviewAction, WithdrawalStatus,
+    CreditTransactionType, FinancialApprovalAction, OwnerType, UserLimitKind, WithdrawalCol,
+    WithdrawalModel, WithdrawalRecord, WithdrawalReviewAction, WithdrawalStatus,
+    WithdrawalStatusHistoryCol, WithdrawalStatusHistoryModel, WithdrawalStatusHistoryRecord,
 };
 use time::OffsetDateTime;
 
 use crate::{
     contracts::api::v1::admin::withdrawal::AdminWithdrawalReviewInput,
-    internal::api::state::AppApiState,
+    internal::{
+        api::state::AppApiState,
+        config::balance::BalanceConfig,
+        workflows::{
+            credit_ledger::{self, CreditEntry},
+            financial_approval::{self, ApprovalOutcome, Proposal},
+            limits, payout,
+            review_claim::{self, ClaimSubject},
+        },
+    },
 };
 
-pub async fn detail(
+pub struct WithdrawalDetail {
+    pub withdrawal: WithdrawalRecord,
+    /// Every status the withdrawal entered, oldest first
+    pub history: Vec<WithdrawalStatusHistoryRecord>,
+}
+
+pub async fn find(
     state: &AppApiState,
     withdrawal_id: i64,
 ) -> Result<WithdrawalRecord, AppError> {
@@ -23,134 +38,493 @@ pub async fn detail(
         .ok_or_else(|| AppError::NotFound(t("Withdrawal not found")))
 }
 
+/// The withdrawal with its status timeline.
+pub async fn detail(
+    state: &AppApiState,
+    withdrawal_id: i64,
+) -> Result<WithdrawalDetail, AppError> {
+    let withdrawal = find(state, withdrawal_id).await?;
+    let history = WithdrawalStatusHistoryModel::query(DbConn::pool(&state.db))
+        .where_col(WithdrawalStatusHistoryCol::WITHDRAWAL_ID, Op::Eq, withdrawal_id)
+        .order_by(WithdrawalStatusHistoryCol::CREATED_AT, OrderDir::Asc)
+        .order_by(WithdrawalStatusHistoryCol::ID, OrderDir::Asc)
+        .all()
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(WithdrawalDetail {
+        withdrawal,
+        history,
+    })
+}
+
+/// Appends the status the withdrawal just entered to its timeline. Call it
+/// in the transaction that changes the status.
+pub async fn record_status(
+    conn: DbConn<'_>,
+    withdrawal_id: i64,
+    status: WithdrawalStatus,
+    admin_id: Option<i64>,
+    remark: Option<String>,
+) -> Result<(), AppError> {
+    WithdrawalStatusHistoryModel::create(conn)
+        .set(WithdrawalStatusHistoryCol::WITHDRAWAL_ID, withdrawal_id)?
+        .set(WithdrawalStatusHistoryCol::STATUS, status)?
+        .set(WithdrawalStatusHistoryCol::ADMIN_ID, admin_id)?
+        .set(WithdrawalStatusHistoryCol::REMARK, remark)?
+        .set(WithdrawalStatusHistoryCol::CREATED_AT, OffsetDateTime::now_utc())?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+    Ok(())
+}
+
+/// Reviews a withdrawal. The step that sends the money out must fit the
+/// user's `[limits]`, and above the `[approval.withdrawal]` threshold it is
+/// only proposed; a second admin confirms it later. That step is Approve,
+/// or Process when a payout provider is configured, in which case Process
+/// also submits the payout.
 pub async fn review_withdrawal(
     state: &AppApiState,
     admin_id: i64,
     withdrawal_id: i64,
     req: AdminWithdrawalReviewInput,
-) -> Result<WithdrawalRecord, AppError> {
-    let withdrawal = WithdrawalModel::find(DbConn::pool(&state.db), withdrawal_id)
+) -> Result<ApprovalOutcome<WithdrawalRecord>, AppError> {
+    let withdrawal = find(state, withdrawal_id).await?;
+    review_claim::ensure_not_claimed(withdrawal.claimed_by, withdrawal.claimed_until, admin_id)?;
+
+    let pays_out = match req.action {
+        WithdrawalReviewAction::Approve => true,
+        WithdrawalReviewAction::Process => state.payout.is_some(),
+        WithdrawalReviewAction::Reject | WithdrawalReviewAction::Refund => false,
+    };
+    if pays_out && withdrawal.owner_type == OwnerType::User {
+        limits::check(
+            DbConn::pool(&state.db),
+            state,
+            UserLimitKind::Withdrawal,
+            withdrawal.owner_id,
+            withdrawal.amount,
+            withdrawal.created_at,
+            Some(withdrawal.id),
+        )
+        .await?;
+    }
+    if pays_out
+        && state.approval_config.requires_second_admin(
+            FinancialApprovalAction::WithdrawalApprove,
+            withdrawal.credit_type,
+            withdrawal.amount,
+        )
+    {
+        if req.action == WithdrawalReviewAction::Process
+            && withdrawal.status != WithdrawalStatus::Pending
+        {
+            return Err(AppError::BadRequest(t("Withdrawal is not pending")));
+        }
+        if req.action == WithdrawalReviewAction::Approve
+            && withdrawal.status != WithdrawalStatus::Processing
+        {
+            return Err(AppError::BadRequest(t(
+                "Withdrawal must be in processing status to approve",
+            )));
+        }
+        let approval = financial_approval::propose(
+            state,
+            admin_id,
+            Proposal {
+                action: FinancialApprovalAction::WithdrawalApprove,
+                subject_id: withdrawal_id,
+                credit_type: withdrawal.credit_type,
+                amount: withdrawal.amount,
+                payload: serde_json::json!({
+                    "action": req.action,
+                    "admin_remark": req.admin_remark,
+                }),
+            },
+        )
+        .await?;
+        return Ok(ApprovalOutcome::Proposed(approval));
+    }
+
+    let action = req.action;
+    apply_review(&state.db, &state.balance_config, Some(admin_id), withdrawal_id, req).await?;
+    if action == WithdrawalReviewAction::Process {
+        submit_payout(state, withdrawal_id).await?;
+    }
+
+    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+    review_claim::publish(state, ClaimSubject::Withdrawal, withdrawal_id).await?;
+
+    Ok(ApprovalOutcome::Applied(find(state, withdrawal_id).await?))
+}
+
+/// Hands a withdrawal that was just processed to the payout provider, if one
+/// is configured.
+pub async fn submit_payout(state: &AppApiState, withdrawal_id: i64) -> Result<(), AppError> {
+    match &state.payout {
+        Some(provider) => {
+            payout::submit(&state.db, &state.balance_config, provider.as_ref(), withdrawal_id)
+                .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Applies a review action as a compare-and-swap on the status: the update
+/// only matches while the withdrawal is still in a status the action accepts,
+/// so when two reviews race the loser gets a conflict and nothing is refunded
+/// twice. `admin_id` is `None` when a payout provider settled the
+/// withdrawal; review claims don't hold those back.
+pub async fn apply_review(
+    db: &sqlx::PgPool,
+    balance: &BalanceConfig,
+    admin_id: Option<i64>,
+    withdrawal_id: i64,
+    req: AdminWithdrawalReviewInput,
+) -> Result<(), AppError> {
+    let scope = DbConn::pool(db).begin_scope().await.map_err(AppError::from)?;
+    apply_review_in(scope.conn(), balance, admin_id, withdrawal_id, req).await?;
+    scope.commit().await.map_err(AppError::from)?;
+
+    Ok(())
+}
+
+/// [`apply_review`] inside a transaction the caller commits.
+pub async fn apply_review_in(
+    conn: DbConn<'_>,
+    balance: &BalanceConfig,
+    admin_id: Option<i64>,
+    withdrawal_id: i64,
+    req: AdminWithdrawalReviewInput,
+) -> Result<(), AppError> {
+    let withdrawal = WithdrawalModel::find(conn.clone(), withdrawal_id)
         .await
         .map_err(AppError::from)?
         .ok_or_else(|| AppError::NotFound(t("Withdrawal not found")))?;
 
-    let now = OffsetDateTime::now_utc();
-
-    match req.action {
+    let (expected, status): (&[WithdrawalStatus], WithdrawalStatus) = match req.action {
         WithdrawalReviewAction::Process => {
-            if withdrawal.status != WithdrawalStatus::Pending {
-                return Err(AppError::BadRequest(t("Withdrawal is not pending")));
-            }
-
-            WithdrawalModel::query(DbConn::pool(&state.db))
-                .where_col(WithdrawalCol::ID, Op::Eq, withdrawal_id)
-                .patch()
-                .assign(WithdrawalCol::STATUS, WithdrawalStatus::Processing)
-                .map_err(AppError::from)?
-                .assign(WithdrawalCol::ADMIN_ID, Some(admin_id))
-                .map_err(AppError::from)?
-                .assign(WithdrawalCol::ADMIN_REMARK, req.admin_remark)
-                .map_err(AppError::from)?
-                .assign(WithdrawalCol::REVIEWED_AT, Some(now))
-                .map_err(AppError::from)?
-                .save()
-                .await
-                .map_err(AppError::from)?;
+            (&[WithdrawalStatus::Pending], WithdrawalStatus::Processing)
         }
+        // The balance was already held when the withdrawal was submitted,
+        // so approving only finalises the status.
         WithdrawalReviewAction::Approve => {
-            if withdrawal.status != WithdrawalStatus::Processing {
-                return Err(AppError::BadRequest(t("Withdrawal must be in processing status to approve")));
-            }
+            (&[WithdrawalStatus::Processing], WithdrawalStatus::Approved)
+        }
+        WithdrawalReviewAction::Reject => (
+            &[WithdrawalStatus::Pending, WithdrawalStatus::Processing],
+            WithdrawalStatus::Rejected,
+        ),
+        // Reverses a payout that already went out, e.g. a bounced transfer.
+        WithdrawalReviewAction::Refund => {
+            (&[WithdrawalStatus::Approved], WithdrawalStatus::Refunded)
+        }
+    };
 
-            let scope = DbConn::pool(&state.db).begin_scope().await.map_err(AppError::from)?;
-            let conn = scope.conn();
-
-            // Update withdrawal status
-            WithdrawalModel::query(conn.clone())
-                .where_col(WithdrawalCol::ID, Op::Eq, withdrawal_id)
-                .patch()
-                .assign(WithdrawalCol::STATUS, WithdrawalStatus::Approved)
-                .map_err(AppError::from)?
-                .assign(WithdrawalCol::ADMIN_ID, Some(admin_id))
-                .map_err(AppError::from)?
-                .assign(WithdrawalCol::ADMIN_REMARK, req.admin_remark)
-                .map_err(AppError::from)?
-                .assign(WithdrawalCol::REVIEWED_AT, Some(now))
-                .map_err(AppError::from)?
-                .save()
-                .await
-                .map_err(AppError::from)?;
-
-            // Deduct balance for User owner_type
-            if withdrawal.owner_type == OwnerType::User {
-                // Insert credit transaction for withdrawal (net_amount = amount after fees)
-                UserCreditTransactionModel::create(conn.clone())
-                    .set(UserCreditTransactionCol::USER_ID, withdrawal.owner_id)?
-                    .set(UserCreditTransactionCol::ADMIN_ID, Some(admin_id))?
-                    .set(UserCreditTransactionCol::CREDIT_TYPE, withdrawal.credit_type)?
-                    .set(UserCreditTransactionCol::AMOUNT, -withdrawal.net_amount)?
-                    .set(
-                        UserCreditTransactionCol::TRANSACTION_TYPE,
-                        CreditTransactionType::Withdraw,
-                    )?
-                    .set(
-                        UserCreditTransactionCol::RELATED_KEY,
-                        Some(withdrawal_id.to_string()),
-                    )?
-                    .set(
-                        UserCreditTransactionCol::REMARK,
-                        Some(format!("Withdrawal #{}", withdrawal_id)),
-                    )?
-                    .set(UserCreditTransactionCol::CUSTOM_DESCRIPTION, false)?
-                    .save()
-                    .await
-                    .map_err(AppError::from)?;
-
-                // Decrement user balance atomically
-                let update = match withdrawal.credit_type {
-                    CreditType::Credit1 => UserModel::query(conn.clone())
-                        .where_col(UserCol::ID, Op::Eq, withdrawal.owner_id)
-                        .patch()
-                        .increment(UserCol::CREDIT_1, -withdrawal.net_amount),
-                    CreditType::Credit2 => UserModel::query(conn.clone())
-                        .where_col(UserCol::ID, Op::Eq, withdrawal.owner_id)
-                        .patch()
-                        .increment(UserCol::CREDIT_2, -withdrawal.net_amount),
-                };
-                update
-                    .map_err(AppError::from)?
-                    .save()
-                    .await
-                    .map_err(AppError::from)?;
+    if !expected.contains(&withdrawal.status) {
+        return Err(AppError::BadRequest(match req.action {
+            WithdrawalReviewAction::Process => t("Withdrawal is not pending"),
+            WithdrawalReviewAction::Approve => {
+                t("Withdrawal must be in processing status to approve")
             }
+            WithdrawalReviewAction::Reject => {
+                t("Withdrawal cannot be rejected in current status")
+            }
+            WithdrawalReviewAction::Refund => t("Withdrawal must be approved to refund"),
+        }));
+    }
+    // A payout the provider holds is settled from its status, not by hand.
+    // It stays in flight from submission until the provider either accepts
+    // it (and the poll settles it) or the submission is known to have failed.
+    let payout_in_flight = withdrawal.status == WithdrawalStatus::Processing
+        && withdrawal.payout_submitted_at.is_some()
+        && (withdrawal.payout_reference.is_some() || withdrawal.payout_checked_at.is_none());
+    if admin_id.is_some() && payout_in_flight {
+        return Err(AppError::Conflict(t(
+            "Withdrawal is with the payout provider and settles from its status",
+        )));
+    }
+    if let Some(admin_id) = admin_id {
+        review_claim::ensure_not_claimed(
+            withdrawal.claimed_by,
+            withdrawal.claimed_until,
+            admin_id,
+        )?;
+    }
+
+    let refund_reason = req
+        .admin_remark
+        .as_deref()
+        .map(str::trim)
+        .filter(|reason| !reason.is_empty())
+        .map(str::to_string);
+    if status == WithdrawalStatus::Refunded && refund_reason.is_none() {
+        return Err(AppError::BadRequest(t(
+            "A reason is required to refund a withdrawal",
+        )));
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let patch = WithdrawalModel::query(conn.clone())
+        .where_col(WithdrawalCol::ID, Op::Eq, withdrawal_id)
+        .where_in(WithdrawalCol::STATUS, expected.iter().copied())
+        .patch()
+        .assign(WithdrawalCol::STATUS, status)
+        .map_err(AppError::from)?;
+    // A refund keeps the original review intact and records its own actor.
+    let patch = if status == WithdrawalStatus::Refunded {
+        patch
+            .assign(WithdrawalCol::REFUNDED_BY, admin_id)
+            .map_err(AppError::from)?
+            .assign(WithdrawalCol::REFUND_REASON, refund_reason.clone())
+            .map_err(AppError::from)?
+            .assign(WithdrawalCol::REFUNDED_AT, Some(now))
+            .map_err(AppError::from)?
+    } else {
+        patch
+            .assign(WithdrawalCol::ADMIN_ID, admin_id)
+            .map_err(AppError::from)?
+            .assign(WithdrawalCol::ADMIN_REMARK, req.admin_remark.clone())
+            .map_err(AppError::from)?
+            .assign(WithdrawalCol::REVIEWED_AT, Some(now))
+            .map_err(AppError::from)?
+    };
+    // Moving to processing keeps the reviewer's claim for the payout step;
+    // any other outcome takes the withdrawal out of the queue.
+    let patch = if status == WithdrawalStatus::Processing {
+        patch
+    } else {
+        patch
+            .assign(WithdrawalCol::CLAIMED_BY, None::<i64>)
+            .map_err(AppError::from)?
+            .assign(WithdrawalCol::CLAIMED_UNTIL, None::<OffsetDateTime>)
+            .map_err(AppError::from)?
+    };
+    let updated = patch.save().await.map_err(AppError::from)?;
+
+    if updated == 0 {
+        return Err(AppError::Conflict(t(
+            "Withdrawal was already reviewed by another admin",
+        )));
+    }
+    let remark = if status == WithdrawalStatus::Refunded {
+        refund_reason
+    } else {
+        req.admin_remark
+    };
+    record_status(conn.clone(), withdrawal_id, status, admin_id, remark).await?;
 
-            scope.commit().await.map_err(AppError::from)?;
+    // A rejection releases the full hold taken at submission; a refund
+    // returns what was paid out, the fee stays charged. Withdrawals from
+    // before the hold were never charged up front: approving one charges the
+    // net amount as it used to, and rejecting one has nothing to release.
+    let held = match status {
+        WithdrawalStatus::Rejected | WithdrawalStatus::Approved => {
+            balance_held(conn.clone(), withdrawal_id).await?
         }
-        WithdrawalReviewAction::Reject => {
-            if withdrawal.status != WithdrawalStatus::Pending
-                && withdrawal.status != WithdrawalStatus::Processing
-            {
-                return Err(AppError::BadRequest(t("Withdrawal cannot be rejected in current status")));
-            }
+        _ => false,
+    };
+    let entry = match status {
+        WithdrawalStatus::Rejected if held => Some((
+            withdrawal.amount,
+            CreditTransactionType::WithdrawHoldRelease,
+            format!("Withdrawal #{} rejected", withdrawal_id),
+        )),
+        WithdrawalStatus::Approved if !held => Some((
+            -withdrawal.net_amount,
+            CreditTransactionType::Withdraw,
+            format!("Withdrawal #{}", withdrawal_id),
+        )),
+        WithdrawalStatus::Refunded => Some((
+            withdrawal.net_amount,
+            CreditTransactionType::WithdrawRefund,
+            format!("Withdrawal #{} refunded", withdrawal_id),
+        )),
+        _ => None,
+    };
+    if let Some((amount, transaction_type, remark)) = entry {
+        if withdrawal.owner_type == OwnerType::User {
+            credit_ledger::post(
+                conn.clone(),
+                balance,
+                CreditEntry {
+                    admin_id,
+                    related_key: Some(withdrawal_id.to_string()),
+                    remark: Some(remark),
+                    ..CreditEntry::new(
+                        withdrawal.owner_id,
+                        withdrawal.credit_type,
+                        amount,
+                        transaction_type,
+                    )
+                },
+            )
+            .await?;
+        }
+    }
 
-            WithdrawalModel::query(DbConn::pool(&state.db))
-                .where_col(WithdrawalCol::ID, Op::Eq, withdrawal_id)
-                .patch()
-                .assign(WithdrawalCol::STATUS, WithdrawalStatus::Rejected)
-                .map_err(AppError::from)?
-                .assign(WithdrawalCol::ADMIN_ID, Some(admin_id))
-                .map_err(AppError::from)?
-                .assign(WithdrawalCol::ADMIN_REMARK, req.admin_remark)
-                .map_err(AppError::from)?
-                .assign(WithdrawalCol::REVIEWED_AT, Some(now))
-                .map_err(AppError::from)?
-                .save()
-                .await
-                .map_err(AppError::from)?;
+    Ok(())
+}
+
+/// Whether a pending or processing withdrawal's amount was held from the
+/// balance on submission. Withdrawals from before the hold were only charged
+/// on approval, so until then the hold is their only `Withdraw` entry.
+pub async fn balance_held(conn: DbConn<'_>, withdrawal_id: i64) -> Result<bool, AppError> {
+    conn.fetch_scalar(
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS (
+                 SELECT 1 FROM user_credit_transactions
+                 WHERE transaction_type = $1 AND related_key = $2
+             )",
+        )
+        .bind(CreditTransactionType::Withdraw)
+        .bind(withdrawal_id.to_string()),
+    )
+    .await
+    .map_err(AppError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_review;
+    use crate::contracts::api::v1::admin::withdrawal::AdminWithdrawalReviewInput;
+    use crate::internal::config::balance::BalanceConfig;
+    use crate::internal::workflows::test_support;
+    use generated::models::{WithdrawalReviewAction, WithdrawalStatus};
+    use rust_decimal::Decimal;
+
+    fn review(action: WithdrawalReviewAction) -> AdminWithdrawalReviewInput {
+        AdminWithdrawalReviewInput {
+            action,
+            admin_remark: None,
         }
     }
 
-    crate::internal::workflows::notification::dispatch_admin_notification_counts(state).await;
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[ignore = "requires DATABASE_URL"]
+    async fn parallel_rejections_refund_once() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig::default();
+        let admin_a = test_support::seed_admin(&db).await;
+        let admin_b = test_support::seed_admin(&db).await;
+        let user_id = test_support::seed_user(&db, Decimal::ZERO).await;
+        let withdrawal_id = test_support::seed_withdrawal(
+            &db,
+            user_id,
+            Decimal::from(40),
+            WithdrawalStatus::Pending,
+        )
+        .await;
+
+        let (a, b) = tokio::join!(
+            apply_review(
+                &db,
+                &balance,
+                Some(admin_a),
+                withdrawal_id,
+                review(WithdrawalReviewAction::Reject),
+            ),
+            apply_review(
+                &db,
+                &balance,
+                Some(admin_b),
+                withdrawal_id,
+                review(WithdrawalReviewAction::Reject),
+            ),
+        );
+
+        assert_eq!(a.is_ok() as u8 + b.is_ok() as u8, 1);
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::from(40));
+        // The seeded hold and a single release
+        assert_eq!(test_support::transaction_count(&db, user_id).await, 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[ignore = "requires DATABASE_URL"]
+    async fn approve_racing_reject_applies_one_outcome() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig::default();
+        let admin_id = test_support::seed_admin(&db).await;
+        let user_id = test_support::seed_user(&db, Decimal::ZERO).await;
+        let withdrawal_id = test_support::seed_withdrawal(
+            &db,
+            user_id,
+            Decimal::from(40),
+            WithdrawalStatus::Processing,
+        )
+        .await;
+
+        let (approve, reject) = tokio::join!(
+            apply_review(
+                &db,
+                &balance,
+                Some(admin_id),
+                withdrawal_id,
+                review(WithdrawalReviewAction::Approve),
+            ),
+            apply_review(
+                &db,
+                &balance,
+                Some(admin_id),
+                withdrawal_id,
+                review(WithdrawalReviewAction::Reject),
+            ),
+        );
+
+        assert_eq!(approve.is_ok() as u8 + reject.is_ok() as u8, 1);
+        let expected = if approve.is_ok() {
+            Decimal::ZERO
+        } else {
+            Decimal::from(40)
+        };
+        assert_eq!(test_support::credit_1(&db, user_id).await, expected);
+    }
 
-    detail(state, withdrawal_id).await
+    #[tokio::test]
+    #[ignore = "requires DATABASE_URL"]
+    async fn withdrawals_from_before_the_hold_are_charged_on_approval() {
+        let db = test_support::pool().await;
+        let balance = BalanceConfig::default();
+        let admin_id = test_support::seed_admin(&db).await;
+        let user_id = test_support::seed_user(&db, Decimal::from(100)).await;
+        let mut withdrawal_ids = Vec::new();
+        for _ in 0..2 {
+            let withdrawal_id = test_support::seed_withdrawal(
+                &db,
+                user_id,
+                Decimal::from(40),
+                WithdrawalStatus::Processing,
+            )
+            .await;
+            sqlx::query("DELETE FROM user_credit_transactions WHERE related_key = $1")
+                .bind(withdrawal_id.to_string())
+                .execute(&db)
+                .await
+                .expect("drop hold");
+            withdrawal_ids.push(withdrawal_id);
+        }
+
+        let rejected = apply_review(
+            &db,
+            &balance,
+            Some(admin_id),
+            withdrawal_ids[0],
+            review(WithdrawalReviewAction::Reject),
+        )
+        .await;
+        assert!(rejected.is_ok());
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::from(100));
+
+        let approved = apply_review(
+            &db,
+            &balance,
+            Some(admin_id),
+            withdrawal_ids[1],
+            review(WithdrawalReviewAction::Approve),
+        )
+        .await;
+        assert!(approved.is_ok());
+        assert_eq!(test_support::credit_1(&db, user_id).await, Decimal::from(60));
+    }
 }
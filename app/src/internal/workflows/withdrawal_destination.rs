@@ -0,0 +1,258 @@
+use core_db::common::sql::{generate_snowflake_i64, DbConn, Op, OrderDir};
+use core_i18n::t;
+use core_web::error::AppError;
+use generated::models::{
+    BankModel, BankRecord, BankStatus, CryptoNetworkModel, CryptoNetworkRecord, CryptoNetworkStatus,
+    UserWithdrawalDestinationCol, UserWithdrawalDestinationModel, UserWithdrawalDestinationRecord,
+    WithdrawalDestinationKind, WithdrawalDestinationStatus,
+};
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    contracts::api::v1::user::withdrawal_destination::UserWithdrawalDestinationInput,
+    internal::{
+        api::state::AppApiState,
+        workflows::{bank as bank_workflow, crypto_network},
+    },
+};
+
+/// Admin lookup of any user's destination.
+pub async fn detail(
+    state: &AppApiState,
+    id: i64,
+) -> Result<UserWithdrawalDestinationRecord, AppError> {
+    UserWithdrawalDestinationModel::find(DbConn::pool(&state.db), id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Withdrawal destination not found")))
+}
+
+/// Fetches a destination only when it belongs to the given user.
+pub async fn find_own(
+    state: &AppApiState,
+    user_id: i64,
+    id: i64,
+) -> Result<UserWithdrawalDestinationRecord, AppError> {
+    UserWithdrawalDestinationModel::query(DbConn::pool(&state.db))
+        .where_col(UserWithdrawalDestinationCol::ID, Op::Eq, id)
+        .where_col(UserWithdrawalDestinationCol::USER_ID, Op::Eq, user_id)
+        .first()
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Withdrawal destination not found")))
+}
+
+pub async fn list_own(
+    state: &AppApiState,
+    user_id: i64,
+) -> Result<Vec<UserWithdrawalDestinationRecord>, AppError> {
+    UserWithdrawalDestinationModel::query(DbConn::pool(&state.db))
+        .where_col(UserWithdrawalDestinationCol::USER_ID, Op::Eq, user_id)
+        .order_by(UserWithdrawalDestinationCol::CREATED_AT, OrderDir::Desc)
+        .order_by(UserWithdrawalDestinationCol::ID, OrderDir::Desc)
+        .all()
+        .await
+        .map_err(AppError::from)
+}
+
+async fn ensure_bank_enabled(state: &AppApiState, bank_id: i64) -> Result<BankRecord, AppError> {
+    let bank = BankModel::find(DbConn::pool(&state.db), bank_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Bank not found")))?;
+    if bank.status != BankStatus::Enabled {
+        return Err(AppError::BadRequest(t("Bank is not enabled")));
+    }
+    Ok(bank)
+}
+
+async fn ensure_network_enabled(
+    state: &AppApiState,
+    network_id: i64,
+) -> Result<CryptoNetworkRecord, AppError> {
+    let network = CryptoNetworkModel::find(DbConn::pool(&state.db), network_id)
+        .await
+        .map_err(AppError::from)?
+        .ok_or_else(|| AppError::NotFound(t("Crypto network not found")))?;
+    if network.status != CryptoNetworkStatus::Enabled {
+        return Err(AppError::BadRequest(t("Crypto network is not enabled")));
+    }
+    Ok(network)
+}
+
+/// Saves a bank account or crypto wallet for the user. It can be withdrawn
+/// to once `[withdrawal] destination_cooling_off_hours` have passed.
+pub async fn create(
+    state: &AppApiState,
+    user_id: i64,
+    req: UserWithdrawalDestinationInput,
+) -> Result<UserWithdrawalDestinationRecord, AppError> {
+    let trim = |v: Option<String>| v.map(|v| v.trim().to_string()).unwrap_or_default();
+    let mut bank_id = None;
+    let mut bank_account_name = None;
+    let mut bank_account_number = None;
+    let mut crypto_network_id = None;
+    let mut crypto_wallet_address = None;
+
+    let kind = match (req.bank_id, req.crypto_network_id) {
+        (Some(id), None) => {
+            let id: i64 = id.into();
+            let bank = ensure_bank_enabled(state, id).await?;
+            let number = bank_workflow::ensure_account_number(
+                &bank,
+                "bank_account_number",
+                &trim(req.bank_account_number),
+            )?;
+            bank_id = Some(id);
+            bank_account_name = Some(trim(req.bank_account_name));
+            bank_account_number = Some(number);
+            WithdrawalDestinationKind::Bank
+        }
+        (None, Some(id)) => {
+            let id: i64 = id.into();
+            let network = ensure_network_enabled(state, id).await?;
+            let address = trim(req.crypto_wallet_address);
+            crypto_network::ensure_wallet_address(&network, "crypto_wallet_address", &address)?;
+            crypto_network_id = Some(id);
+            crypto_wallet_address = Some(address);
+            WithdrawalDestinationKind::Crypto
+        }
+        _ => return Err(AppError::BadRequest(t("Select either a bank or a crypto network."))),
+    };
+
+    // The unique indexes on (user, bank, number) and (user, network, address)
+    // decide duplicates, so two concurrent saves cannot both get through
+    let now = OffsetDateTime::now_utc();
+    let cooling_off = Duration::hours(state.withdrawal_config.destination_cooling_off_hours as i64);
+    let inserted = DbConn::pool(&state.db)
+        .fetch_all(
+            sqlx::query_scalar::<_, i64>(
+                "INSERT INTO user_withdrawal_destinations
+                     (id, user_id, kind, bank_id, bank_account_name, bank_account_number,
+                      crypto_network_id, crypto_wallet_address, status, available_at,
+                      created_at, updated_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $11)
+                 ON CONFLICT DO NOTHING
+                 RETURNING id",
+            )
+            .bind(generate_snowflake_i64())
+            .bind(user_id)
+            .bind(kind)
+            .bind(bank_id)
+            .bind(bank_account_name)
+            .bind(bank_account_number)
+            .bind(crypto_network_id)
+            .bind(crypto_wallet_address)
+            .bind(WithdrawalDestinationStatus::Active)
+            .bind(now + cooling_off)
+            .bind(now),
+        )
+        .await
+        .map_err(AppError::from)?;
+    let Some(id) = inserted.first().copied() else {
+        return Err(AppError::BadRequest(t("This withdrawal destination is already saved")));
+    };
+
+    find_own(state, user_id, id).await
+}
+
+/// Removes one of the user's destinations. Locked destinations stay so that
+/// admins keep seeing them. Withdrawals made to it keep their copied details.
+pub async fn delete_own(state: &AppApiState, user_id: i64, id: i64) -> Result<(), AppError> {
+    let destination = find_own(state, user_id, id).await?;
+    if destination.status == WithdrawalDestinationStatus::Locked {
+        return Err(AppError::BadRequest(t("A locked withdrawal destination cannot be removed")));
+    }
+
+    UserWithdrawalDestinationModel::query(DbConn::pool(&state.db))
+        .where_col(UserWithdrawalDestinationCol::ID, Op::Eq, id)
+        .where_col(
+            UserWithdrawalDestinationCol::STATUS,
+            Op::Eq,
+            WithdrawalDestinationStatus::Active,
+        )
+        .delete()
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(())
+}
+
+/// Fails unless withdrawals can go to the destination at `now`: it must not
+/// be locked and its cooling-off period must be over.
+pub fn ensure_usable(
+    destination: &UserWithdrawalDestinationRecord,
+    now: OffsetDateTime,
+) -> Result<(), AppError> {
+    if destination.status == WithdrawalDestinationStatus::Locked {
+        return Err(AppError::BadRequest(t("This withdrawal destination is locked")));
+    }
+    if destination.available_at > now {
+        return Err(AppError::BadRequest(t(
+            "This withdrawal destination is still in its cooling-off period",
+        )));
+    }
+    Ok(())
+}
+
+/// Stops withdrawals to the destination until an admin unlocks it.
+pub async fn lock(
+    state: &AppApiState,
+    admin_id: i64,
+    id: i64,
+    reason: String,
+) -> Result<UserWithdrawalDestinationRecord, AppError> {
+    let destination = detail(state, id).await?;
+    if destination.status == WithdrawalDestinationStatus::Locked {
+        return Err(AppError::BadRequest(t("Withdrawal destination is already locked")));
+    }
+
+    let now = OffsetDateTime::now_utc();
+    UserWithdrawalDestinationModel::query(DbConn::pool(&state.db))
+        .where_col(UserWithdrawalDestinationCol::ID, Op::Eq, id)
+        .patch()
+        .assign(UserWithdrawalDestinationCol::STATUS, WithdrawalDestinationStatus::Locked)
+        .map_err(AppError::from)?
+        .assign(UserWithdrawalDestinationCol::LOCK_REASON, Some(reason.trim().to_string()))
+        .map_err(AppError::from)?
+        .assign(UserWithdrawalDestinationCol::LOCKED_BY, Some(admin_id))
+        .map_err(AppError::from)?
+        .assign(UserWithdrawalDestinationCol::LOCKED_AT, Some(now))
+        .map_err(AppError::from)?
+        .assign(UserWithdrawalDestinationCol::UPDATED_AT, now)
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    detail(state, id).await
+}
+
+pub async fn unlock(
+    state: &AppApiState,
+    id: i64,
+) -> Result<UserWithdrawalDestinationRecord, AppError> {
+    let destination = detail(state, id).await?;
+    if destination.status != WithdrawalDestinationStatus::Locked {
+        return Err(AppError::BadRequest(t("Withdrawal destination is not locked")));
+    }
+
+    UserWithdrawalDestinationModel::query(DbConn::pool(&state.db))
+        .where_col(UserWithdrawalDestinationCol::ID, Op::Eq, id)
+        .patch()
+        .assign(UserWithdrawalDestinationCol::STATUS, WithdrawalDestinationStatus::Active)
+        .map_err(AppError::from)?
+        .assign(UserWithdrawalDestinationCol::LOCK_REASON, None::<String>)
+        .map_err(AppError::from)?
+        .assign(UserWithdrawalDestinationCol::LOCKED_BY, None::<i64>)
+        .map_err(AppError::from)?
+        .assign(UserWithdrawalDestinationCol::LOCKED_AT, None::<OffsetDateTime>)
+        .map_err(AppError::from)?
+        .assign(UserWithdrawalDestinationCol::UPDATED_AT, OffsetDateTime::now_utc())
+        .map_err(AppError::from)?
+        .save()
+        .await
+        .map_err(AppError::from)?;
+
+    detail(state, id).await
+}
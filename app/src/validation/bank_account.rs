@@ -0,0 +1,179 @@
+use std::borrow::Cow;
+
+use generated::models::{BankAccountNumberFormat, BankRecord};
+use regex::Regex;
+use validator::ValidationError;
+
+fn err(code: &'static str, msg: &'static str) -> ValidationError {
+    ValidationError::new(code).with_message(Cow::Borrowed(msg))
+}
+
+/// The account number rules a bank sets.
+pub struct BankAccountNumberRules<'a> {
+    pub country_iso2: &'a str,
+    pub format: BankAccountNumberFormat,
+    pub min_length: Option<i32>,
+    pub max_length: Option<i32>,
+    pub pattern: Option<&'a str>,
+}
+
+impl<'a> BankAccountNumberRules<'a> {
+    pub fn of(bank: &'a BankRecord) -> Self {
+        Self {
+            country_iso2: &bank.country_iso2,
+            format: bank.account_number_format,
+            min_length: bank.account_number_min_length,
+            max_length: bank.account_number_max_length,
+            pattern: bank.account_number_pattern.as_deref(),
+        }
+    }
+}
+
+/// Drops whitespace and uppercases IBANs, so a number compares equal
+/// however it was typed.
+pub fn normalize_bank_account_number(format: BankAccountNumberFormat, value: &str) -> String {
+    let compact: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    if format == BankAccountNumberFormat::Iban {
+        compact.to_ascii_uppercase()
+    } else {
+        compact
+    }
+}
+
+/// Checks a normalized account number against the bank's length range,
+/// format and pattern, in that order.
+pub fn validate_bank_account_number(
+    rules: &BankAccountNumberRules<'_>,
+    value: &str,
+) -> Result<(), ValidationError> {
+    if value.is_empty() {
+        return Err(err("required", "This field is required."));
+    }
+    let length = value.chars().count() as i32;
+    if rules.min_length.is_some_and(|min| length < min)
+        || rules.max_length.is_some_and(|max| length > max)
+    {
+        return Err(err(
+            "length",
+            "The account number length is not valid for this bank.",
+        ));
+    }
+
+    match rules.format {
+        BankAccountNumberFormat::Any => {}
+        BankAccountNumberFormat::Digits => {
+            if !value.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(err("digits", "The account number may only contain digits."));
+            }
+        }
+        BankAccountNumberFormat::Iban => validate_iban(rules.country_iso2, value)?,
+    }
+
+    if let Some(pattern) = rules.pattern {
+        let matches = account_number_regex(pattern).is_ok_and(|re| re.is_match(value));
+        if !matches {
+            return Err(err(
+                "pattern",
+                "The account number format is not valid for this bank.",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// For bank settings: the pattern must compile.
+pub fn validate_account_number_pattern(pattern: &str) -> Result<(), ValidationError> {
+    account_number_regex(pattern)
+        .map(|_| ())
+        .map_err(|_| err("regex", "Enter a valid regular expression."))
+}
+
+/// Patterns match the whole number, not a part of it.
+fn account_number_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{pattern})$"))
+}
+
+/// Country code, two check digits and up to 30 letters or digits whose
+/// mod-97 remainder is 1 (ISO 13616). The country must be the bank's.
+fn validate_iban(country_iso2: &str, value: &str) -> Result<(), ValidationError> {
+    let bytes = value.as_bytes();
+    let well_formed = (15..=34).contains(&bytes.len())
+        && bytes.iter().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..4].iter().all(u8::is_ascii_digit);
+    if !well_formed || iban_remainder(value) != 1 {
+        return Err(err("iban", "Enter a valid IBAN."));
+    }
+    if !value.starts_with(&country_iso2.to_ascii_uppercase()) {
+        return Err(err("iban_country", "The IBAN is not from this bank's country."));
+    }
+    Ok(())
+}
+
+/// Moves the first four characters to the end and reads letters as 10-35.
+fn iban_remainder(value: &str) -> u32 {
+    let (head, tail) = value.split_at(4);
+    tail.chars().chain(head.chars()).fold(0, |acc, c| {
+        let digit = c.to_digit(36).unwrap_or(0);
+        if digit >= 10 {
+            (acc * 100 + digit) % 97
+        } else {
+            (acc * 10 + digit) % 97
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use generated::models::BankAccountNumberFormat;
+
+    use super::{
+        normalize_bank_account_number, validate_account_number_pattern,
+        validate_bank_account_number, BankAccountNumberRules,
+    };
+
+    fn rules(format: BankAccountNumberFormat) -> BankAccountNumberRules<'static> {
+        BankAccountNumberRules {
+            country_iso2: "GB",
+            format,
+            min_length: None,
+            max_length: None,
+            pattern: None,
+        }
+    }
+
+    #[test]
+    fn iban_needs_a_valid_checksum_from_the_banks_country() {
+        let iban = rules(BankAccountNumberFormat::Iban);
+        let number = normalize_bank_account_number(iban.format, "gb82 west 1234 5698 7654 32");
+        assert_eq!(number, "GB82WEST12345698765432");
+        assert!(validate_bank_account_number(&iban, &number).is_ok());
+
+        let error = validate_bank_account_number(&iban, "GB82WEST12345698765431").unwrap_err();
+        assert_eq!(error.code, "iban");
+        let error = validate_bank_account_number(&iban, "DE89370400440532013000").unwrap_err();
+        assert_eq!(error.code, "iban_country");
+    }
+
+    #[test]
+    fn length_digits_and_pattern_rules_apply() {
+        let digits = BankAccountNumberRules {
+            min_length: Some(10),
+            max_length: Some(12),
+            ..rules(BankAccountNumberFormat::Digits)
+        };
+        assert!(validate_bank_account_number(&digits, "1234567890").is_ok());
+        let error = validate_bank_account_number(&digits, "123456789").unwrap_err();
+        assert_eq!(error.code, "length");
+        let error = validate_bank_account_number(&digits, "12345-67890").unwrap_err();
+        assert_eq!(error.code, "digits");
+
+        let patterned = BankAccountNumberRules {
+            pattern: Some("8[0-9]{9}"),
+            ..rules(BankAccountNumberFormat::Any)
+        };
+        assert!(validate_bank_account_number(&patterned, "8123456789").is_ok());
+        assert!(validate_bank_account_number(&patterned, "81234567890").is_err());
+        assert!(validate_account_number_pattern("[0-9").is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use std::borrow::Cow;
+
+use generated::models::CryptoAddressFormat;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use validator::ValidationError;
+
+fn err(code: &'static str, msg: &'static str) -> ValidationError {
+    ValidationError::new(code).with_message(Cow::Borrowed(msg))
+}
+
+/// Checks that `value` is a well-formed address in the network's format.
+/// `Unchecked` networks accept anything.
+pub fn validate_crypto_address(
+    format: CryptoAddressFormat,
+    value: &str,
+) -> Result<(), ValidationError> {
+    let value = value.trim();
+    match format {
+        CryptoAddressFormat::Unchecked => Ok(()),
+        CryptoAddressFormat::Evm => validate_evm(value),
+        CryptoAddressFormat::Tron => match base58check(value) {
+            Some(payload) if payload.len() == 21 && payload[0] == 0x41 => Ok(()),
+            _ => Err(err("crypto_address", "Enter a valid TRON address.")),
+        },
+        CryptoAddressFormat::Bitcoin => {
+            let legacy = base58check(value)
+                .is_some_and(|payload| payload.len() == 21 && matches!(payload[0], 0x00 | 0x05));
+            if legacy || is_segwit(value) {
+                Ok(())
+            } else {
+                Err(err("crypto_address", "Enter a valid Bitcoin address."))
+            }
+        }
+    }
+}
+
+/// `0x` and 40 hex digits. Mixed-case addresses carry an EIP-55 checksum,
+/// which must match; all-lowercase or all-uppercase ones carry none.
+fn validate_evm(value: &str) -> Result<(), ValidationError> {
+    let hex = value
+        .strip_prefix("0x")
+        .filter(|hex| hex.len() == 40 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+        .ok_or_else(|| {
+            err(
+                "crypto_address",
+                "Enter a valid EVM address (0x followed by 40 hex characters).",
+            )
+        })?;
+
+    let mixed_case = hex.bytes().any(|b| b.is_ascii_lowercase())
+        && hex.bytes().any(|b| b.is_ascii_uppercase());
+    if mixed_case && !eip55_checksum_matches(hex) {
+        return Err(err(
+            "checksum",
+            "The address checksum does not match. Check the letter case.",
+        ));
+    }
+    Ok(())
+}
+
+/// A letter is uppercase exactly when the matching nibble of the
+/// Keccak-256 hash of the lowercase address is 8 or more.
+fn eip55_checksum_matches(hex: &str) -> bool {
+    let hash = Keccak256::digest(hex.to_ascii_lowercase().as_bytes());
+    hex.bytes().enumerate().all(|(i, b)| {
+        if !b.is_ascii_alphabetic() {
+            return true;
+        }
+        let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0f };
+        (nibble >= 8) == b.is_ascii_uppercase()
+    })
+}
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn base58_decode(value: &str) -> Option<Vec<u8>> {
+    // Little-endian digits of the number while decoding
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in value.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let leading_zeros = value.bytes().take_while(|&c| c == b'1').count();
+    bytes.resize(bytes.len() + leading_zeros, 0);
+    bytes.reverse();
+    Some(bytes)
+}
+
+/// The payload of a Base58Check string whose 4-byte double-SHA256 checksum
+/// matches.
+fn base58check(value: &str) -> Option<Vec<u8>> {
+    let mut payload = base58_decode(value)?;
+    if payload.len() < 5 {
+        return None;
+    }
+    let checksum = payload.split_off(payload.len() - 4);
+    let hash = Sha256::digest(Sha256::digest(&payload));
+    (hash[..4] == checksum[..]).then_some(payload)
+}
+
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATORS: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+    let mut chk = 1u32;
+    for value in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x01ff_ffff) << 5) ^ u32::from(*value);
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+/// A mainnet (`bc1`) segwit address: bech32 for witness version 0 and
+/// bech32m for later versions (BIP-173, BIP-350).
+fn is_segwit(value: &str) -> bool {
+    let lower = value.to_ascii_lowercase();
+    if value != lower && value != value.to_ascii_uppercase() {
+        return false;
+    }
+    let Some(data) = lower.strip_prefix("bc1") else {
+        return false;
+    };
+    if lower.len() > 90 || data.len() < 7 {
+        return false;
+    }
+    let Some(values) = data
+        .bytes()
+        .map(|c| BECH32_CHARSET.iter().position(|&x| x == c).map(|p| p as u8))
+        .collect::<Option<Vec<u8>>>()
+    else {
+        return false;
+    };
+
+    // "bc" expanded: the high bits of each character, 0, then the low bits
+    let mut checked = vec![3, 3, 0, 2, 3];
+    checked.extend_from_slice(&values);
+    let version = values[0];
+    let constant = if version == 0 { 1 } else { 0x2bc8_30a3 };
+    if version > 16 || bech32_polymod(&checked) != constant {
+        return false;
+    }
+
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    let mut program_len = 0usize;
+    for value in &values[1..values.len() - 6] {
+        acc = ((acc << 5) | u32::from(*value)) & 0xfff;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            program_len += 1;
+        }
+    }
+    if bits >= 5 || acc & ((1 << bits) - 1) != 0 {
+        return false;
+    }
+    (2..=40).contains(&program_len) && (version != 0 || program_len == 20 || program_len == 32)
+}
+
+#[cfg(test)]
+mod tests {
+    use generated::models::CryptoAddressFormat;
+
+    use super::validate_crypto_address;
+
+    #[test]
+    fn evm_addresses_need_a_matching_checksum_when_mixed_case() {
+        let evm = |value: &str| validate_crypto_address(CryptoAddressFormat::Evm, value);
+        assert!(evm("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_ok());
+        assert!(evm("0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359").is_ok());
+        assert!(evm("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").is_ok());
+
+        let error = evm("0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap_err();
+        assert_eq!(error.code, "checksum");
+        assert!(evm("0x5aAeb6053F3E94C9b9A0").is_err());
+    }
+
+    #[test]
+    fn tron_and_bitcoin_addresses_are_checked_per_network() {
+        let tron = |value: &str| validate_crypto_address(CryptoAddressFormat::Tron, value);
+        let bitcoin = |value: &str| validate_crypto_address(CryptoAddressFormat::Bitcoin, value);
+
+        assert!(tron("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t").is_ok());
+        assert!(bitcoin("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t").is_err());
+        for address in [
+            "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa",
+            "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+        ] {
+            assert!(bitcoin(address).is_ok(), "{address}");
+        }
+        assert!(tron("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").is_err());
+        assert!(bitcoin("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb").is_err());
+        assert!(bitcoin("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5").is_err());
+    }
+}
@@ -1,3 +1,5 @@
+pub mod bank_account;
+pub mod crypto_address;
 pub mod db;
 pub mod sync;
 pub mod username;
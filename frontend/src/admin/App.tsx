@@ -13,10 +13,12 @@ const CountriesPage = lazy(() => import("@admin/pages/other/CountriesPage"));
 const ManageUsersPage = lazy(() => import("@admin/pages/user/ManageUsersPage"));
 const UserHierarchyPage = lazy(() => import("@admin/pages/user/UserHierarchyPage"));
 const IntroducerChangesPage = lazy(() => import("@admin/pages/user/IntroducerChangesPage"));
+const KycSubmissionsPage = lazy(() => import("@admin/pages/user/KycSubmissionsPage"));
 const AdjustCreditsPage = lazy(() => import("@admin/pages/user/AdjustCreditsPage"));
 const AuditLogsPage = lazy(() => import("@admin/pages/other/AuditLogsPage"));
 const DepositsPage = lazy(() => import("@admin/pages/finance/DepositsPage"));
 const WithdrawalsPage = lazy(() => import("@admin/pages/finance/WithdrawalsPage"));
+const WithdrawalDestinationsPage = lazy(() => import("@admin/pages/finance/WithdrawalDestinationsPage"));
 const BanksPage = lazy(() => import("@admin/pages/finance/BanksPage"));
 const CryptoNetworksPage = lazy(() => import("@admin/pages/finance/CryptoNetworksPage"));
 const CompanyBankAccountsPage = lazy(() => import("@admin/pages/finance/CompanyBankAccountsPage"));
@@ -50,6 +52,7 @@ export default function App() {
             <Route path="/other/audit-logs" element={<AuditLogsPage />} />
             <Route path="/finance/deposits" element={<DepositsPage />} />
             <Route path="/finance/withdrawals" element={<WithdrawalsPage />} />
+            <Route path="/finance/withdrawal-destinations" element={<WithdrawalDestinationsPage />} />
             <Route path="/finance/banks" element={<BanksPage />} />
             <Route path="/finance/crypto-networks" element={<CryptoNetworksPage />} />
             <Route path="/finance/company-bank-accounts" element={<CompanyBankAccountsPage />} />
@@ -58,6 +61,7 @@ export default function App() {
             <Route path="/user/hierarchy" element={<UserHierarchyPage />} />
             <Route path="/user/adjust-credits" element={<AdjustCreditsPage />} />
             <Route path="/user/introducer-changes" element={<IntroducerChangesPage />} />
+            <Route path="/user/kyc-submissions" element={<KycSubmissionsPage />} />
             <Route path="/developer/http-client-logs" element={<HttpClientLogsPage />} />
             <Route path="/developer/webhook-logs" element={<WebhookLogsPage />} />
             <Route path="/developer/sql-profiler-requests" element={<SqlProfilerRequestsPage />} />
@@ -1,13 +1,20 @@
 import type {
   AdjustableCreditType,
+  BankAccountNumberFormat,
   BankStatus,
   CompanyBankAccountStatus,
   CompanyCryptoAccountStatus,
   CreditType,
+  CryptoAddressFormat,
   CryptoNetworkStatus,
   DepositMethod,
   DepositStatus,
+  KycDocumentType,
+  KycLevel,
+  KycStatus,
   UserBanStatus,
+  WithdrawalDestinationKind,
+  WithdrawalDestinationStatus,
   WithdrawalMethod,
   WithdrawalStatus,
 } from "@admin/types";
@@ -47,10 +54,12 @@ export const OWNER_TYPE_I18N: Record<string, string> = {
 
 export const DEPOSIT_METHOD_I18N: Record<DepositMethod, string> = {
   "1": "enum.deposit_method.manual",
+  "2": "enum.deposit_method.gateway",
 };
 
 export const WITHDRAWAL_METHOD_I18N: Record<WithdrawalMethod, string> = {
   "1": "enum.withdrawal_method.manual",
+  "2": "enum.withdrawal_method.payout",
 };
 
 export const BANK_STATUS_I18N: Record<BankStatus, string> = {
@@ -63,6 +72,19 @@ export const CRYPTO_NETWORK_STATUS_I18N: Record<CryptoNetworkStatus, string> = {
   "2": "enum.crypto_network_status.disabled",
 };
 
+export const BANK_ACCOUNT_NUMBER_FORMAT_I18N: Record<BankAccountNumberFormat, string> = {
+  "1": "enum.bank_account_number_format.any",
+  "2": "enum.bank_account_number_format.digits",
+  "3": "enum.bank_account_number_format.iban",
+};
+
+export const CRYPTO_ADDRESS_FORMAT_I18N: Record<CryptoAddressFormat, string> = {
+  "1": "enum.crypto_address_format.unchecked",
+  "2": "enum.crypto_address_format.evm",
+  "3": "enum.crypto_address_format.tron",
+  "4": "enum.crypto_address_format.bitcoin",
+};
+
 export const COMPANY_BANK_ACCOUNT_STATUS_I18N: Record<CompanyBankAccountStatus, string> = {
   "1": "enum.company_bank_account_status.enabled",
   "2": "enum.company_bank_account_status.disabled",
@@ -72,3 +94,31 @@ export const COMPANY_CRYPTO_ACCOUNT_STATUS_I18N: Record<CompanyCryptoAccountStat
   "1": "enum.company_crypto_account_status.enabled",
   "2": "enum.company_crypto_account_status.disabled",
 };
+
+export const KYC_LEVEL_I18N: Record<KycLevel, string> = {
+  "0": "enum.kyc_level.unverified",
+  "1": "enum.kyc_level.basic",
+  "2": "enum.kyc_level.advanced",
+};
+
+export const KYC_STATUS_I18N: Record<KycStatus, string> = {
+  "1": "enum.kyc_status.pending",
+  "2": "enum.kyc_status.approved",
+  "3": "enum.kyc_status.rejected",
+};
+
+export const KYC_DOCUMENT_TYPE_I18N: Record<KycDocumentType, string> = {
+  "1": "enum.kyc_document_type.id_card",
+  "2": "enum.kyc_document_type.passport",
+  "3": "enum.kyc_document_type.driver_license",
+};
+
+export const WITHDRAWAL_DESTINATION_KIND_I18N: Record<WithdrawalDestinationKind, string> = {
+  "1": "enum.withdrawal_destination_kind.bank",
+  "2": "enum.withdrawal_destination_kind.crypto",
+};
+
+export const WITHDRAWAL_DESTINATION_STATUS_I18N: Record<WithdrawalDestinationStatus, string> = {
+  "1": "enum.withdrawal_destination_status.active",
+  "2": "enum.withdrawal_destination_status.locked",
+};
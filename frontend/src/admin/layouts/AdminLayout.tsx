@@ -7,6 +7,7 @@ import { useAuthStore } from "@admin/stores/auth";
 import { adminLocalePersistence } from "@admin/locale";
 import { useRealtimeStore } from "@admin/stores/realtime";
 import { useNotificationStore } from "@admin/stores/notifications";
+import { useReviewClaimStore, type ReviewClaimEvent } from "@admin/stores/reviewClaims";
 import { api } from "@admin/api";
 
 const STORAGE_KEY = "admin-sidebar-collapsed";
@@ -62,6 +63,14 @@ export default function AdminLayout() {
     return unsub;
   }, [on]);
 
+  // Listen for review_claim events so queues show who is reviewing what
+  useEffect(() => {
+    const unsub = on("review_claim", (data) => {
+      useReviewClaimStore.getState().applyEvent(data as ReviewClaimEvent);
+    });
+    return unsub;
+  }, [on]);
+
   // Fetch initial notification counts on mount
   useEffect(() => {
     api.get("notifications/counts")
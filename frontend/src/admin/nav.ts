@@ -76,6 +76,12 @@ export const navigation: NavItem[] = [
         path: "/user/introducer-changes",
         permissions: [PERMISSION.USER_CHANGE_INTRODUCER],
       },
+      {
+        label: "KYC Submissions",
+        path: "/user/kyc-submissions",
+        permissions: [PERMISSION.KYC_READ, PERMISSION.KYC_MANAGE],
+        notificationKey: "kyc",
+      },
     ],
   },
   {
@@ -94,6 +100,11 @@ export const navigation: NavItem[] = [
         permissions: [PERMISSION.WITHDRAWAL_READ, PERMISSION.WITHDRAWAL_MANAGE],
         notificationKey: "withdrawal",
       },
+      {
+        label: "Withdrawal Destinations",
+        path: "/finance/withdrawal-destinations",
+        permissions: [PERMISSION.WITHDRAWAL_DESTINATION_READ, PERMISSION.WITHDRAWAL_DESTINATION_MANAGE],
+      },
       {
         label: "Banks",
         path: "/finance/banks",
@@ -3,6 +3,7 @@ import { Pencil, Plus, Trash2 } from "lucide-react";
 import { useTranslation } from "react-i18next";
 import type { BankDatatableRow } from "@admin/types";
 import { PERMISSION } from "@admin/types";
+import { BANK_ACCOUNT_NUMBER_FORMAT_I18N } from "@admin/constants/enums";
 import { api } from "@admin/api";
 import { useAuthStore } from "@admin/stores/auth";
 import { availableCountries } from "@shared/countryRuntime";
@@ -74,9 +75,28 @@ function BankForm({
           { value: "2", label: t("Disabled") },
         ],
       },
+      {
+        name: "account_number_format",
+        type: "select",
+        label: t("Account Number Format"),
+        required: true,
+        options: [
+          { value: "1", label: t(BANK_ACCOUNT_NUMBER_FORMAT_I18N["1"]) },
+          { value: "2", label: t(BANK_ACCOUNT_NUMBER_FORMAT_I18N["2"]) },
+          { value: "3", label: t(BANK_ACCOUNT_NUMBER_FORMAT_I18N["3"]) },
+        ],
+      },
+      { name: "account_number_min_length", type: "number", label: t("Account Number Min Length") },
+      { name: "account_number_max_length", type: "number", label: t("Account Number Max Length") },
+      {
+        name: "account_number_pattern",
+        type: "text",
+        label: t("Account Number Pattern"),
+        placeholder: t("Regular expression, e.g. [0-9]{10}"),
+      },
       { name: "sort_order", type: "number", label: t("Sort Order") },
     ],
-    defaults: (defaults ?? { status: "1", sort_order: 0 }) as Record<string, AutoFormDefaultValue>,
+    defaults: (defaults ?? { status: "1", account_number_format: "1", sort_order: 0 }) as Record<string, AutoFormDefaultValue>,
     onSuccess: () => {
       close();
       alertSuccess({ title: t("Success"), message: bankId ? t("Bank updated") : t("Bank created") });
@@ -124,6 +144,10 @@ export default function BanksPage() {
             name: row.name,
             code: row.code ?? "",
             status: String(row.status),
+            account_number_format: String(row.account_number_format),
+            account_number_min_length: row.account_number_min_length ?? "",
+            account_number_max_length: row.account_number_max_length ?? "",
+            account_number_pattern: row.account_number_pattern ?? "",
             sort_order: row.sort_order,
           } : undefined}
           onSaved={() => refreshRef.current?.()}
@@ -199,6 +223,12 @@ export default function BanksPage() {
             </span>
           ),
         },
+        {
+          key: "account_number_format",
+          label: t("Account Number Format"),
+          render: (row: BankDatatableRow) =>
+            t(BANK_ACCOUNT_NUMBER_FORMAT_I18N[row.account_number_format] ?? row.account_number_format),
+        },
         { key: "sort_order", label: t("Sort"), cellClassName: "tabular-nums" },
         {
           key: "updated_at",
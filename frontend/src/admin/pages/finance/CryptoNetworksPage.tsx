@@ -3,6 +3,7 @@ import { Pencil, Plus, Trash2 } from "lucide-react";
 import { useTranslation } from "react-i18next";
 import type { CryptoNetworkDatatableRow } from "@admin/types";
 import { PERMISSION } from "@admin/types";
+import { CRYPTO_ADDRESS_FORMAT_I18N } from "@admin/constants/enums";
 import { api } from "@admin/api";
 import { useAuthStore } from "@admin/stores/auth";
 import {
@@ -67,9 +68,21 @@ function CryptoNetworkForm({
           { value: "2", label: t("Disabled") },
         ],
       },
+      {
+        name: "address_format",
+        type: "select",
+        label: t("Address Format"),
+        required: true,
+        options: [
+          { value: "1", label: t(CRYPTO_ADDRESS_FORMAT_I18N["1"]) },
+          { value: "2", label: t(CRYPTO_ADDRESS_FORMAT_I18N["2"]) },
+          { value: "3", label: t(CRYPTO_ADDRESS_FORMAT_I18N["3"]) },
+          { value: "4", label: t(CRYPTO_ADDRESS_FORMAT_I18N["4"]) },
+        ],
+      },
       { name: "sort_order", type: "number", label: t("Sort Order") },
     ],
-    defaults: (defaults ?? { status: "1", sort_order: 0 }) as Record<string, AutoFormDefaultValue>,
+    defaults: (defaults ?? { status: "1", address_format: "1", sort_order: 0 }) as Record<string, AutoFormDefaultValue>,
     onSuccess: () => {
       close();
       alertSuccess({ title: t("Success"), message: networkId ? t("Crypto network updated") : t("Crypto network created") });
@@ -116,6 +129,7 @@ export default function CryptoNetworksPage() {
             name: row.name,
             symbol: row.symbol,
             status: String(row.status),
+            address_format: String(row.address_format),
             sort_order: row.sort_order,
           } : undefined}
           onSaved={() => refreshRef.current?.()}
@@ -190,6 +204,12 @@ export default function CryptoNetworksPage() {
             </span>
           ),
         },
+        {
+          key: "address_format",
+          label: t("Address Format"),
+          render: (row: CryptoNetworkDatatableRow) =>
+            t(CRYPTO_ADDRESS_FORMAT_I18N[row.address_format] ?? row.address_format),
+        },
         { key: "sort_order", label: t("Sort"), cellClassName: "tabular-nums" },
         {
           key: "updated_at",
@@ -1,9 +1,10 @@
 import { useRef } from "react";
 import { useTranslation } from "react-i18next";
-import type { DepositDatatableRow } from "@admin/types";
+import type { DepositDatatableRow, DepositOutput, DepositReversalOutput } from "@admin/types";
 import { CREDIT_TYPE_I18N, DEPOSIT_METHOD_I18N } from "@admin/constants/enums";
 import { PERMISSION } from "@admin/types";
 import { useAuthStore } from "@admin/stores/auth";
+import { claimedByOther, useReviewClaimStore } from "@admin/stores/reviewClaims";
 import {
   Button,
   DataTable,
@@ -26,12 +27,16 @@ const STATUS_COLORS: Record<string, string> = {
   "1": "bg-yellow-100 text-yellow-800", // Pending
   "2": "bg-green-100 text-green-800",   // Approved
   "3": "bg-red-100 text-red-800",       // Rejected
+  "4": "bg-purple-100 text-purple-800", // Reversed
+  "5": "bg-gray-100 text-gray-800",     // Expired
 };
 
 const STATUS_LABELS: Record<string, string> = {
   "1": "Pending",
   "2": "Approved",
   "3": "Rejected",
+  "4": "Reversed",
+  "5": "Expired",
 };
 
 function ReviewDepositForm({
@@ -70,9 +75,16 @@ function ReviewDepositForm({
         placeholder: t("Enter remark (optional)"),
       },
     ],
-    onSuccess: () => {
+    onSuccess: (data) => {
+      const reviewed = data as DepositOutput;
       close();
-      alertSuccess({ title: t("Success"), message: t("Deposit reviewed") });
+      alertSuccess({
+        title: t("Success"),
+        // An approval above the threshold leaves the status unchanged until confirmed
+        message: reviewed.status === "1"
+          ? t("Submitted for confirmation by a second admin")
+          : t("Deposit reviewed"),
+      });
       onReviewed();
     },
     onError: (error) => {
@@ -93,6 +105,61 @@ function ReviewDepositForm({
   return <form id={formId} onSubmit={submit}>{form}</form>;
 }
 
+function ReverseDepositForm({
+  depositId,
+  onReversed,
+  formId,
+  onBusyChange,
+}: {
+  depositId: string;
+  onReversed: () => void;
+  formId: string;
+  onBusyChange: (busy: boolean) => void;
+}) {
+  const { t } = useTranslation();
+  const close = useModalStore((s) => s.close);
+
+  const { submit, busy, form } = useAutoForm(api, {
+    url: `deposits/${depositId}/reverse`,
+    method: "post",
+    fields: [
+      {
+        name: "reason",
+        type: "textarea",
+        label: t("Reason"),
+        required: true,
+        placeholder: t("Enter reversal reason"),
+      },
+    ],
+    onSuccess: (data) => {
+      const result = data as DepositReversalOutput;
+      close();
+      const pending = result.needs_reversal.length;
+      alertSuccess({
+        title: t("Success"),
+        message: pending > 0
+          ? t("Deposit reversed. {{count}} linked ledger entries need manual review.", { count: pending })
+          : t("Deposit reversed"),
+      });
+      onReversed();
+    },
+    onError: (error) => {
+      alertError({
+        title: t("Error"),
+        message: normalizeErrorMessage(error, t("Failed to reverse deposit.")),
+      });
+    },
+  });
+
+  const prevBusy = useRef(false);
+  if (prevBusy.current !== busy) {
+    prevBusy.current = busy;
+    onBusyChange(busy);
+  }
+
+  return <form id={formId} onSubmit={submit}>{form}</form>;
+}
+
 function UploadReceiptForm({
   entityType,
   entityId,
@@ -148,6 +215,37 @@ export default function DepositsPage() {
   const refreshRef = useRef<(() => void) | null>(null);
   const account = useAuthStore((s) => s.account);
   const canManage = useAuthStore.hasPermission(PERMISSION.DEPOSIT_MANAGE, account);
+  // Re-render when another console claims or releases a deposit
+  useReviewClaimStore((s) => s.claims);
+
+  const claimOf = (row: DepositDatatableRow) =>
+    useReviewClaimStore.getState().getClaim("deposit", row.id, {
+      claimed_by: row.claimed_by,
+      claimer_username: row.claimer_username,
+      claimed_until: row.claimed_until,
+    });
+
+  const claimAndReview = (row: DepositDatatableRow, refresh: () => void) => {
+    api.post(`deposits/${row.id}/claim`)
+      .then(() => openReviewModal(row, refresh))
+      .catch((error) => {
+        alertError({
+          title: t("Error"),
+          message: normalizeErrorMessage(error, t("Failed to claim for review.")),
+        });
+      });
+  };
+
+  const releaseClaim = (row: DepositDatatableRow, refresh: () => void) => {
+    api.post(`deposits/${row.id}/release`)
+      .then(() => refresh())
+      .catch((error) => {
+        alertError({
+          title: t("Error"),
+          message: normalizeErrorMessage(error, t("Failed to release claim.")),
+        });
+      });
+  };
 
   const openReviewModal = (row: DepositDatatableRow, refresh: () => void) => {
     refreshRef.current = refresh;
@@ -189,6 +287,44 @@ export default function DepositsPage() {
     });
   };
 
+  const openReverseModal = (row: DepositDatatableRow, refresh: () => void) => {
+    refreshRef.current = refresh;
+    const formId = `deposit-reverse-${Date.now()}`;
+    let modalId = "";
+    const renderFooter = (busy: boolean) => (
+      <>
+        <Button type="button" onClick={() => useModalStore.getState().close()} variant="secondary" disabled={busy}>
+          {t("Cancel")}
+        </Button>
+        <Button type="submit" form={formId} variant="danger" busy={busy}>
+          {busy ? t("Submitting\u2026") : t("Reverse")}
+        </Button>
+      </>
+    );
+    modalId = useModalStore.getState().open({
+      title: t("Reverse Deposit #{{id}}", { id: row.id }),
+      size: "lg",
+      content: (
+        <div>
+          <div className="mb-4 grid grid-cols-2 gap-2 text-sm">
+            <div><span className="text-muted">{t("Amount")}:</span> {moneyFormat(parseFloat(row.amount))}</div>
+            <div><span className="text-muted">{t("Net Amount")}:</span> {moneyFormat(parseFloat(row.net_amount))}</div>
+          </div>
+          <ReverseDepositForm
+            depositId={row.id}
+            onReversed={() => refreshRef.current?.()}
+            formId={formId}
+            onBusyChange={(busy) => {
+              if (!modalId) return;
+              useModalStore.getState().update(modalId, { footer: renderFooter(busy) });
+            }}
+          />
+        </div>
+      ),
+      footer: renderFooter(false),
+    });
+  };
+
   const openUploadReceiptModal = (row: DepositDatatableRow, refresh: () => void) => {
     refreshRef.current = refresh;
     const formId = `deposit-receipt-${Date.now()}`;
@@ -233,6 +369,12 @@ export default function DepositsPage() {
           label: t("ID"),
           cellClassName: "tabular-nums text-muted",
         },
+        {
+          key: "reference_code",
+          label: t("Reference Code"),
+          cellClassName: "font-mono",
+          render: (row) => row.reference_code ?? "\u2014",
+        },
         {
           key: "owner_id",
           label: t("Owner"),
@@ -305,12 +447,32 @@ export default function DepositsPage() {
                 label: t("Actions"),
                 sortable: false,
                 render: (row: DepositDatatableRow, ctx: DataTableCellContext<DepositDatatableRow>) => {
+                  if (row.status === "2") {
+                    return (
+                      <Button size="xs" variant="danger" onClick={() => openReverseModal(row, ctx.refresh)}>
+                        {t("Reverse")}
+                      </Button>
+                    );
+                  }
                   if (row.status !== "1") return null; // Only Pending
+                  const reviewer = claimedByOther(claimOf(row), account?.id);
+                  if (reviewer) {
+                    return (
+                      <span className="text-xs text-muted">
+                        {t("Being reviewed by {{name}}", { name: reviewer })}
+                      </span>
+                    );
+                  }
                   return (
                     <div className="flex gap-1">
-                      <Button size="xs" variant="primary" onClick={() => openReviewModal(row, ctx.refresh)}>
+                      <Button size="xs" variant="primary" onClick={() => claimAndReview(row, ctx.refresh)}>
                         {t("Review")}
                       </Button>
+                      {claimOf(row).claimed_by === account?.id && (
+                        <Button size="xs" variant="secondary" onClick={() => releaseClaim(row, ctx.refresh)}>
+                          {t("Release")}
+                        </Button>
+                      )}
                       <Button size="xs" variant="secondary" onClick={() => openUploadReceiptModal(row, ctx.refresh)}>
                         {t("Receipt")}
                       </Button>
@@ -0,0 +1,223 @@
+import { useRef } from "react";
+import { useTranslation } from "react-i18next";
+import type { WithdrawalDestinationDatatableRow } from "@admin/types";
+import { PERMISSION } from "@admin/types";
+import {
+  WITHDRAWAL_DESTINATION_KIND_I18N,
+  WITHDRAWAL_DESTINATION_STATUS_I18N,
+} from "@admin/constants/enums";
+import { useAuthStore } from "@admin/stores/auth";
+import {
+  Button,
+  DataTable,
+  useAutoForm,
+  useModalStore,
+  alertConfirm,
+  alertSuccess,
+  alertError,
+  formatDateTime,
+} from "@shared/components";
+import type { DataTableCellContext } from "@shared/components/DataTable";
+import { api } from "@admin/api";
+
+function normalizeErrorMessage(error: unknown, fallback: string): string {
+  const maybe = error as { response?: { data?: { message?: string } } };
+  return maybe?.response?.data?.message ?? fallback;
+}
+
+const STATUS_COLORS: Record<string, string> = {
+  "1": "bg-green-100 text-green-800", // Active
+  "2": "bg-red-100 text-red-800",     // Locked
+};
+
+function LockDestinationForm({
+  destinationId,
+  onLocked,
+  formId,
+  onBusyChange,
+}: {
+  destinationId: string;
+  onLocked: () => void;
+  formId: string;
+  onBusyChange: (busy: boolean) => void;
+}) {
+  const { t } = useTranslation();
+  const close = useModalStore((s) => s.close);
+
+  const { submit, busy, form } = useAutoForm(api, {
+    url: `withdrawal_destinations/${destinationId}/lock`,
+    method: "post",
+    fields: [
+      {
+        name: "reason",
+        type: "textarea",
+        label: t("Reason"),
+        required: true,
+        placeholder: t("Why withdrawals to this destination are stopped"),
+      },
+    ],
+    onSuccess: () => {
+      close();
+      alertSuccess({ title: t("Success"), message: t("Withdrawal destination locked") });
+      onLocked();
+    },
+    onError: (error) => {
+      alertError({
+        title: t("Error"),
+        message: normalizeErrorMessage(error, t("Failed to lock withdrawal destination.")),
+      });
+    },
+  });
+
+  const prevBusy = useRef(false);
+  if (prevBusy.current !== busy) {
+    prevBusy.current = busy;
+    onBusyChange(busy);
+  }
+
+  return <form id={formId} onSubmit={submit}>{form}</form>;
+}
+
+export default function WithdrawalDestinationsPage() {
+  const { t } = useTranslation();
+  const refreshRef = useRef<(() => void) | null>(null);
+  const account = useAuthStore((s) => s.account);
+  const canManage = useAuthStore.hasPermission(PERMISSION.WITHDRAWAL_DESTINATION_MANAGE, account);
+
+  const openLockModal = (row: WithdrawalDestinationDatatableRow, refresh: () => void) => {
+    refreshRef.current = refresh;
+    const formId = `withdrawal-destination-lock-${Date.now()}`;
+    let modalId = "";
+    const renderFooter = (busy: boolean) => (
+      <>
+        <Button type="button" onClick={() => useModalStore.getState().close()} variant="secondary" disabled={busy}>
+          {t("Cancel")}
+        </Button>
+        <Button type="submit" form={formId} variant="danger" busy={busy}>
+          {busy ? t("Submitting…") : t("Lock")}
+        </Button>
+      </>
+    );
+    modalId = useModalStore.getState().open({
+      title: t("Lock Withdrawal Destination #{{id}}", { id: row.id }),
+      content: (
+        <LockDestinationForm
+          destinationId={row.id}
+          onLocked={() => refreshRef.current?.()}
+          formId={formId}
+          onBusyChange={(busy) => {
+            if (!modalId) return;
+            useModalStore.getState().update(modalId, { footer: renderFooter(busy) });
+          }}
+        />
+      ),
+      footer: renderFooter(false),
+    });
+  };
+
+  const handleUnlock = async (row: WithdrawalDestinationDatatableRow, refresh: () => void) => {
+    await alertConfirm({
+      title: t("Unlock Withdrawal Destination"),
+      message: t("Withdrawals to this destination will be allowed again. Continue?"),
+      confirmText: t("Unlock"),
+      callback: async (result) => {
+        if (!result.isConfirmed) return;
+        try {
+          await api.post(`withdrawal_destinations/${row.id}/unlock`);
+          alertSuccess({ title: t("Success"), message: t("Withdrawal destination unlocked") });
+          refresh();
+        } catch (error) {
+          alertError({ title: t("Error"), message: normalizeErrorMessage(error, t("Failed to unlock withdrawal destination.")) });
+        }
+      },
+    });
+  };
+
+  return (
+    <DataTable<WithdrawalDestinationDatatableRow>
+      url="datatable/withdrawal_destination/query"
+      title={t("Withdrawal Destinations")}
+      subtitle={t("Bank accounts and crypto wallets users saved for withdrawals")}
+      columns={[
+        ...(canManage
+          ? [{
+              key: "actions" as keyof WithdrawalDestinationDatatableRow,
+              label: t("Actions"),
+              sortable: false,
+              render: (row: WithdrawalDestinationDatatableRow, ctx: DataTableCellContext<WithdrawalDestinationDatatableRow>) =>
+                row.status === "2" ? (
+                  <Button size="xs" variant="secondary" onClick={() => handleUnlock(row, ctx.refresh)}>
+                    {t("Unlock")}
+                  </Button>
+                ) : (
+                  <Button size="xs" variant="danger" onClick={() => openLockModal(row, ctx.refresh)}>
+                    {t("Lock")}
+                  </Button>
+                ),
+            }]
+          : []),
+        {
+          key: "id",
+          label: t("ID"),
+          cellClassName: "tabular-nums text-muted",
+        },
+        {
+          key: "user_username",
+          label: t("User"),
+          render: (row) => row.user_username ?? row.user_id,
+        },
+        {
+          key: "kind",
+          label: t("Type"),
+          render: (row) => t(WITHDRAWAL_DESTINATION_KIND_I18N[row.kind] ?? row.kind),
+        },
+        {
+          key: "bank_name",
+          label: t("Bank / Network"),
+          sortable: false,
+          render: (row) => row.bank_name ?? row.crypto_network_name ?? "—",
+        },
+        {
+          key: "bank_account_name",
+          label: t("Account Name"),
+          render: (row) => row.bank_account_name ?? "—",
+        },
+        {
+          key: "bank_account_number",
+          label: t("Account / Address"),
+          cellClassName: "font-mono",
+          render: (row) => row.bank_account_number ?? row.crypto_wallet_address ?? "—",
+        },
+        {
+          key: "status",
+          label: t("Status"),
+          render: (row) => (
+            <span className={`inline-flex items-center rounded-full px-2 py-0.5 text-xs font-medium ${STATUS_COLORS[row.status] ?? "bg-gray-100 text-gray-800"}`}>
+              {row.status_label || t(WITHDRAWAL_DESTINATION_STATUS_I18N[row.status] ?? "Unknown")}
+            </span>
+          ),
+        },
+        {
+          key: "lock_reason",
+          label: t("Lock Reason"),
+          cellClassName: "text-muted",
+          render: (row) => row.lock_reason
+            ? `${row.lock_reason}${row.locked_by_username ? ` (${row.locked_by_username})` : ""}`
+            : "—",
+        },
+        {
+          key: "available_at",
+          label: t("Usable From"),
+          cellClassName: "tabular-nums text-muted",
+          render: (row) => formatDateTime(row.available_at),
+        },
+        {
+          key: "created_at",
+          label: t("Created At"),
+          cellClassName: "tabular-nums text-muted",
+          render: (row) => formatDateTime(row.created_at),
+        },
+      ]}
+    />
+  );
+}
@@ -1,9 +1,10 @@
 import { useRef } from "react";
 import { useTranslation } from "react-i18next";
-import type { WithdrawalDatatableRow } from "@admin/types";
+import type { WithdrawalDatatableRow, WithdrawalOutput } from "@admin/types";
 import { CREDIT_TYPE_I18N, WITHDRAWAL_METHOD_I18N } from "@admin/constants/enums";
 import { PERMISSION } from "@admin/types";
 import { useAuthStore } from "@admin/stores/auth";
+import { claimedByOther, useReviewClaimStore } from "@admin/stores/reviewClaims";
 import {
   Button,
   DataTable,
@@ -27,6 +28,8 @@ const STATUS_COLORS: Record<string, string> = {
   "2": "bg-blue-100 text-blue-800",     // Processing
   "3": "bg-green-100 text-green-800",   // Approved
   "4": "bg-red-100 text-red-800",       // Rejected
+  "5": "bg-purple-100 text-purple-800", // Refunded
+  "6": "bg-gray-100 text-gray-800",     // Expired
 };
 
 const STATUS_LABELS: Record<string, string> = {
@@ -34,6 +37,8 @@ const STATUS_LABELS: Record<string, string> = {
   "2": "Processing",
   "3": "Approved",
   "4": "Rejected",
+  "5": "Refunded",
+  "6": "Expired",
 };
 
 function ReviewWithdrawalForm({
@@ -62,7 +67,11 @@ function ReviewWithdrawalForm({
     // Processing: can Approve or Reject
     actionOptions.push({ value: "2", label: t("Approve") });
     actionOptions.push({ value: "3", label: t("Reject") });
+  } else if (currentStatus === "3") {
+    // Approved: can Refund (e.g. the payout bounced)
+    actionOptions.push({ value: "4", label: t("Refund") });
   }
+  const isRefund = currentStatus === "3";
 
   const { submit, busy, form } = useAutoForm(api, {
     url: `withdrawals/${withdrawalId}/review`,
@@ -79,13 +88,21 @@ function ReviewWithdrawalForm({
       {
         name: "admin_remark",
         type: "textarea",
-        label: t("Admin Remark"),
-        placeholder: t("Enter remark (optional)"),
+        label: isRefund ? t("Reason") : t("Admin Remark"),
+        required: isRefund,
+        placeholder: isRefund ? t("Enter refund reason") : t("Enter remark (optional)"),
       },
     ],
-    onSuccess: () => {
+    onSuccess: (data) => {
+      const reviewed = data as WithdrawalOutput;
       close();
-      alertSuccess({ title: t("Success"), message: t("Withdrawal reviewed") });
+      alertSuccess({
+        title: t("Success"),
+        // An approval above the threshold leaves the status unchanged until confirmed
+        message: currentStatus === "2" && reviewed.status === "2"
+          ? t("Submitted for confirmation by a second admin")
+          : t("Withdrawal reviewed"),
+      });
       onReviewed();
     },
     onError: (error) => {
@@ -158,6 +175,37 @@ export default function WithdrawalsPage() {
   const refreshRef = useRef<(() => void) | null>(null);
   const account = useAuthStore((s) => s.account);
   const canManage = useAuthStore.hasPermission(PERMISSION.WITHDRAWAL_MANAGE, account);
+  // Re-render when another console claims or releases a withdrawal
+  useReviewClaimStore((s) => s.claims);
+
+  const claimOf = (row: WithdrawalDatatableRow) =>
+    useReviewClaimStore.getState().getClaim("withdrawal", row.id, {
+      claimed_by: row.claimed_by,
+      claimer_username: row.claimer_username,
+      claimed_until: row.claimed_until,
+    });
+
+  const claimAndReview = (row: WithdrawalDatatableRow, refresh: () => void) => {
+    api.post(`withdrawals/${row.id}/claim`)
+      .then(() => openReviewModal(row, refresh))
+      .catch((error) => {
+        alertError({
+          title: t("Error"),
+          message: normalizeErrorMessage(error, t("Failed to claim for review.")),
+        });
+      });
+  };
+
+  const releaseClaim = (row: WithdrawalDatatableRow, refresh: () => void) => {
+    api.post(`withdrawals/${row.id}/release`)
+      .then(() => refresh())
+      .catch((error) => {
+        alertError({
+          title: t("Error"),
+          message: normalizeErrorMessage(error, t("Failed to release claim.")),
+        });
+      });
+  };
 
   const openReviewModal = (row: WithdrawalDatatableRow, refresh: () => void) => {
     refreshRef.current = refresh;
@@ -287,6 +335,11 @@ export default function WithdrawalsPage() {
           render: (row) => (
             <span className={`inline-flex items-center rounded-full px-2 py-0.5 text-xs font-medium ${STATUS_COLORS[row.status] ?? "bg-gray-100 text-gray-800"}`}>
               {row.status_label || t(STATUS_LABELS[row.status] ?? "Unknown")}
+              {row.status === "2" && row.sla_breached_at && (
+                <span className="ml-1 text-red-700" title={formatDateTime(row.sla_breached_at)}>
+                  {t("SLA breached")}
+                </span>
+              )}
             </span>
           ),
         },
@@ -315,13 +368,34 @@ export default function WithdrawalsPage() {
                 label: t("Actions"),
                 sortable: false,
                 render: (row: WithdrawalDatatableRow, ctx: DataTableCellContext<WithdrawalDatatableRow>) => {
-                  // Only show actions for Pending or Processing
+                  // Approved withdrawals can still be refunded
+                  if (row.status === "3") {
+                    return (
+                      <Button size="xs" variant="secondary" onClick={() => openReviewModal(row, ctx.refresh)}>
+                        {t("Refund")}
+                      </Button>
+                    );
+                  }
+                  // Only show review actions for Pending or Processing
                   if (row.status !== "1" && row.status !== "2") return null;
+                  const reviewer = claimedByOther(claimOf(row), account?.id);
+                  if (reviewer) {
+                    return (
+                      <span className="text-xs text-muted">
+                        {t("Being reviewed by {{name}}", { name: reviewer })}
+                      </span>
+                    );
+                  }
                   return (
                     <div className="flex gap-1">
-                      <Button size="xs" variant="primary" onClick={() => openReviewModal(row, ctx.refresh)}>
+                      <Button size="xs" variant="primary" onClick={() => claimAndReview(row, ctx.refresh)}>
                         {t("Review")}
                       </Button>
+                      {claimOf(row).claimed_by === account?.id && (
+                        <Button size="xs" variant="secondary" onClick={() => releaseClaim(row, ctx.refresh)}>
+                          {t("Release")}
+                        </Button>
+                      )}
                       <Button size="xs" variant="secondary" onClick={() => openUploadReceiptModal(row, ctx.refresh)}>
                         {t("Receipt")}
                       </Button>
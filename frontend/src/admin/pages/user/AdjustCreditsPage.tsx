@@ -1,7 +1,7 @@
 import { useEffect, useRef } from "react";
 import { useTranslation } from "react-i18next";
 import { Plus } from "lucide-react";
-import type { UserCreditTransactionDatatableRow } from "@admin/types";
+import type { AdminCreditAdjustOutput, UserCreditTransactionDatatableRow } from "@admin/types";
 import { ADJUSTABLE_CREDIT_TYPES } from "@admin/types";
 import { CREDIT_TYPE_I18N, ADJUSTABLE_CREDIT_TYPE_I18N } from "@admin/constants/enums";
 import {
@@ -45,9 +45,15 @@ function AdjustCreditForm({
         ? [{ name: "custom_description_text", type: "text" as const, label: t("Custom Description"), localized: true }]
         : []),
     ],
-    onSuccess: () => {
+    onSuccess: (data) => {
+      const result = data as AdminCreditAdjustOutput;
       close();
-      alertSuccess({ title: t("Success"), message: t("Credit adjusted") });
+      alertSuccess({
+        title: t("Success"),
+        message: result.approval
+          ? t("Submitted for confirmation by a second admin")
+          : t("Credit adjusted"),
+      });
       onCreated();
     },
     onError: (error) => {
@@ -150,6 +156,18 @@ export default function AdjustCreditsPage() {
             return <span className={color}>{row.amount}</span>;
           },
         },
+        {
+          key: "balance_before",
+          label: t("Balance Before"),
+          cellClassName: "tabular-nums text-muted",
+          render: (row) => row.balance_before,
+        },
+        {
+          key: "balance_after",
+          label: t("Balance After"),
+          cellClassName: "tabular-nums",
+          render: (row) => row.balance_after,
+        },
         {
           key: "transaction_type_explained",
           label: t("Description"),
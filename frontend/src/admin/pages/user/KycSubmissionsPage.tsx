@@ -0,0 +1,288 @@
+import { useEffect, useRef, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { Loader2 } from "lucide-react";
+import type { KycSubmissionDatatableRow, KycSubmissionOutput } from "@admin/types";
+import { PERMISSION } from "@admin/types";
+import {
+  KYC_DOCUMENT_TYPE_I18N,
+  KYC_LEVEL_I18N,
+  KYC_STATUS_I18N,
+} from "@admin/constants/enums";
+import { useAuthStore } from "@admin/stores/auth";
+import {
+  Button,
+  DataTable,
+  useAutoForm,
+  useModalStore,
+  alertSuccess,
+  alertError,
+  formatDateTime,
+} from "@shared/components";
+import type { DataTableCellContext } from "@shared/components/DataTable";
+import type { ApiResponse } from "@shared/types";
+import { api } from "@admin/api";
+
+function normalizeErrorMessage(error: unknown, fallback: string): string {
+  const maybe = error as { response?: { data?: { message?: string } } };
+  return maybe?.response?.data?.message ?? fallback;
+}
+
+const STATUS_COLORS: Record<string, string> = {
+  "1": "bg-yellow-100 text-yellow-800", // Pending
+  "2": "bg-green-100 text-green-800",   // Approved
+  "3": "bg-red-100 text-red-800",       // Rejected
+};
+
+const DOCUMENTS: { key: string; label: string; url: keyof KycSubmissionOutput }[] = [
+  { key: "id_front", label: "ID Front", url: "id_front_url" },
+  { key: "id_back", label: "ID Back", url: "id_back_url" },
+  { key: "selfie", label: "Selfie", url: "selfie_url" },
+  { key: "proof_of_address", label: "Proof of Address", url: "proof_of_address_url" },
+];
+
+function KycSubmissionDetail({ submissionId }: { submissionId: string }) {
+  const { t } = useTranslation();
+  const [submission, setSubmission] = useState<KycSubmissionOutput | null>(null);
+
+  useEffect(() => {
+    api.get<ApiResponse<KycSubmissionOutput>>(`kyc_submissions/${submissionId}`)
+      .then((res) => setSubmission(res.data.data))
+      .catch((error) => {
+        alertError({
+          title: t("Error"),
+          message: normalizeErrorMessage(error, t("Failed to load KYC submission.")),
+        });
+      });
+  }, [submissionId, t]);
+
+  if (!submission) {
+    return (
+      <div className="mb-4 flex justify-center py-6 text-muted">
+        <Loader2 className="h-5 w-5 animate-spin" />
+      </div>
+    );
+  }
+
+  return (
+    <div className="mb-4 space-y-3 text-sm">
+      <div className="grid grid-cols-2 gap-2">
+        <div><span className="text-muted">{t("User")}:</span> {submission.username ?? submission.user_id}</div>
+        <div>
+          <span className="text-muted">{t("Current Level")}:</span>{" "}
+          {submission.user_kyc_level ? t(KYC_LEVEL_I18N[submission.user_kyc_level]) : "—"}
+        </div>
+        <div><span className="text-muted">{t("Full Name")}:</span> {submission.full_name}</div>
+        <div><span className="text-muted">{t("Requested Level")}:</span> {t(KYC_LEVEL_I18N[submission.level])}</div>
+        <div>
+          <span className="text-muted">{t("Document Type")}:</span>{" "}
+          {t(KYC_DOCUMENT_TYPE_I18N[submission.document_type])}
+        </div>
+        <div><span className="text-muted">{t("Document Number")}:</span> {submission.document_number}</div>
+      </div>
+      <div className="grid grid-cols-2 gap-3">
+        {DOCUMENTS.map((doc) => {
+          const url = submission[doc.url] as string | null;
+          const missing = submission.missing_documents.includes(doc.key);
+          if (!url && !missing) return null;
+          return (
+            <div key={doc.key}>
+              <div className="mb-1 text-muted">{t(doc.label)}</div>
+              {url ? (
+                <a href={url} target="_blank" rel="noreferrer">
+                  <img src={url} alt={t(doc.label)} className="max-h-48 rounded border" />
+                </a>
+              ) : (
+                <span className="text-xs text-red-600">{t("Not uploaded")}</span>
+              )}
+            </div>
+          );
+        })}
+      </div>
+    </div>
+  );
+}
+
+function ReviewKycForm({
+  submissionId,
+  onReviewed,
+  formId,
+  onBusyChange,
+}: {
+  submissionId: string;
+  onReviewed: () => void;
+  formId: string;
+  onBusyChange: (busy: boolean) => void;
+}) {
+  const { t } = useTranslation();
+  const close = useModalStore((s) => s.close);
+
+  const { submit, busy, form } = useAutoForm(api, {
+    url: `kyc_submissions/${submissionId}/review`,
+    method: "post",
+    fields: [
+      {
+        name: "action",
+        type: "select",
+        label: t("Action"),
+        required: true,
+        placeholder: t("Select action"),
+        options: [
+          { value: "1", label: t("Approve") },
+          { value: "2", label: t("Reject") },
+        ],
+      },
+      {
+        name: "reject_reason",
+        type: "textarea",
+        label: t("Reason"),
+        placeholder: t("Required when rejecting"),
+      },
+    ],
+    onSuccess: () => {
+      close();
+      alertSuccess({ title: t("Success"), message: t("KYC submission reviewed") });
+      onReviewed();
+    },
+    onError: (error) => {
+      alertError({
+        title: t("Error"),
+        message: normalizeErrorMessage(error, t("Failed to review KYC submission.")),
+      });
+    },
+  });
+
+  const prevBusy = useRef(false);
+  if (prevBusy.current !== busy) {
+    prevBusy.current = busy;
+    onBusyChange(busy);
+  }
+
+  return <form id={formId} onSubmit={submit}>{form}</form>;
+}
+
+export default function KycSubmissionsPage() {
+  const { t } = useTranslation();
+  const refreshRef = useRef<(() => void) | null>(null);
+  const account = useAuthStore((s) => s.account);
+  const canManage = useAuthStore.hasPermission(PERMISSION.KYC_MANAGE, account);
+
+  const openDetailModal = (row: KycSubmissionDatatableRow, refresh: () => void) => {
+    refreshRef.current = refresh;
+    const reviewable = canManage && row.status === "1";
+    const formId = `kyc-review-${Date.now()}`;
+    let modalId = "";
+    const renderFooter = (busy: boolean) => (
+      <>
+        <Button type="button" onClick={() => useModalStore.getState().close()} variant="secondary" disabled={busy}>
+          {reviewable ? t("Cancel") : t("Close")}
+        </Button>
+        {reviewable && (
+          <Button type="submit" form={formId} variant="primary" busy={busy}>
+            {busy ? t("Submitting…") : t("Submit")}
+          </Button>
+        )}
+      </>
+    );
+    modalId = useModalStore.getState().open({
+      title: t("KYC Submission #{{id}}", { id: row.id }),
+      size: "lg",
+      content: (
+        <div>
+          <KycSubmissionDetail submissionId={row.id} />
+          {reviewable && (
+            <ReviewKycForm
+              submissionId={row.id}
+              onReviewed={() => refreshRef.current?.()}
+              formId={formId}
+              onBusyChange={(busy) => {
+                if (!modalId) return;
+                useModalStore.getState().update(modalId, { footer: renderFooter(busy) });
+              }}
+            />
+          )}
+        </div>
+      ),
+      footer: renderFooter(false),
+    });
+  };
+
+  return (
+    <DataTable<KycSubmissionDatatableRow>
+      url="datatable/kyc_submission/query"
+      title={t("KYC Submissions")}
+      subtitle={t("Review user identity verification requests")}
+      columns={[
+        {
+          key: "id",
+          label: t("ID"),
+          cellClassName: "tabular-nums text-muted",
+        },
+        {
+          key: "user_username",
+          label: t("User"),
+          render: (row) => row.user_username ?? row.user_id,
+        },
+        {
+          key: "full_name",
+          label: t("Full Name"),
+        },
+        {
+          key: "level",
+          label: t("Level"),
+          render: (row) => t(KYC_LEVEL_I18N[row.level] ?? row.level),
+        },
+        {
+          key: "document_type",
+          label: t("Document Type"),
+          render: (row) => t(KYC_DOCUMENT_TYPE_I18N[row.document_type] ?? row.document_type),
+        },
+        {
+          key: "document_number",
+          label: t("Document Number"),
+          cellClassName: "font-mono",
+        },
+        {
+          key: "status",
+          label: t("Status"),
+          render: (row) => (
+            <span className={`inline-flex items-center rounded-full px-2 py-0.5 text-xs font-medium ${STATUS_COLORS[row.status] ?? "bg-gray-100 text-gray-800"}`}>
+              {row.status_label || t(KYC_STATUS_I18N[row.status] ?? "Unknown")}
+            </span>
+          ),
+        },
+        {
+          key: "reject_reason",
+          label: t("Reason"),
+          cellClassName: "text-muted",
+          render: (row) => row.reject_reason ?? "—",
+        },
+        {
+          key: "admin_username",
+          label: t("Reviewed By"),
+          cellClassName: "text-muted",
+          render: (row) => row.admin_username ?? "—",
+        },
+        {
+          key: "created_at",
+          label: t("Created At"),
+          cellClassName: "tabular-nums text-muted",
+          render: (row) => formatDateTime(row.created_at),
+        },
+        {
+          key: "actions" as keyof KycSubmissionDatatableRow,
+          label: t("Actions"),
+          sortable: false,
+          render: (row: KycSubmissionDatatableRow, ctx: DataTableCellContext<KycSubmissionDatatableRow>) => (
+            <Button
+              size="xs"
+              variant={canManage && row.status === "1" ? "primary" : "secondary"}
+              onClick={() => openDetailModal(row, ctx.refresh)}
+            >
+              {canManage && row.status === "1" ? t("Review") : t("View")}
+            </Button>
+          ),
+        },
+      ]}
+    />
+  );
+}
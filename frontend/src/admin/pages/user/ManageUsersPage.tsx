@@ -1,8 +1,12 @@
 import { useEffect, useMemo, useRef, useState } from "react";
 import { useTranslation } from "react-i18next";
-import { Plus, Pencil, ShieldBan, ShieldCheck } from "lucide-react";
+import { Plus, Pencil, Gauge, ShieldBan, ShieldCheck } from "lucide-react";
 import type {
   AdminMeOutput,
+  AdminUserLimitsInput,
+  LimitValues,
+  UserLimitOutput,
+  UserLimitsOutput,
   UserDatatableRow,
   UserDatatableSummaryOutput,
   UserCreditTransactionDatatableRow,
@@ -11,7 +15,7 @@ import type {
   CreditType,
 } from "@admin/types";
 import { PERMISSION, USER_BAN_STATUS, CREDIT_TYPE } from "@admin/types";
-import { CREDIT_TYPE_I18N, BAN_STATUS_I18N } from "@admin/constants/enums";
+import { CREDIT_TYPE_I18N, BAN_STATUS_I18N, KYC_LEVEL_I18N } from "@admin/constants/enums";
 import type { ApiResponse } from "@shared/types";
 import {
   Button,
@@ -103,6 +107,18 @@ function CreditTransactionsModal({
             return <span className={color}>{row.amount}</span>;
           },
         },
+        {
+          key: "balance_before",
+          label: t("Balance Before"),
+          cellClassName: "tabular-nums text-muted",
+          render: (row) => row.balance_before,
+        },
+        {
+          key: "balance_after",
+          label: t("Balance After"),
+          cellClassName: "tabular-nums",
+          render: (row) => row.balance_after,
+        },
         {
           key: "transaction_type_explained",
           label: t("Description"),
@@ -292,6 +308,181 @@ function EditUserForm({
   );
 }
 
+const LIMIT_ROWS: ReadonlyArray<{
+  key: keyof LimitValues;
+  label: string;
+  usage?: { period: "daily" | "weekly" | "monthly"; field: "count" | "amount" };
+}> = [
+  { key: "min_amount", label: "Min per request" },
+  { key: "max_amount", label: "Max per request" },
+  { key: "daily_count", label: "Daily count", usage: { period: "daily", field: "count" } },
+  { key: "daily_amount", label: "Daily amount", usage: { period: "daily", field: "amount" } },
+  { key: "weekly_count", label: "Weekly count", usage: { period: "weekly", field: "count" } },
+  { key: "weekly_amount", label: "Weekly amount", usage: { period: "weekly", field: "amount" } },
+  { key: "monthly_count", label: "Monthly count", usage: { period: "monthly", field: "count" } },
+  {
+    key: "monthly_amount",
+    label: "Monthly amount",
+    usage: { period: "monthly", field: "amount" },
+  },
+];
+
+type LimitKindKey = "deposit" | "withdrawal";
+type LimitDraft = Record<LimitKindKey, Record<keyof LimitValues, string>>;
+
+function toDraft(limits: UserLimitOutput): Record<keyof LimitValues, string> {
+  const draft = {} as Record<keyof LimitValues, string>;
+  for (const row of LIMIT_ROWS) {
+    const value = limits.overrides[row.key];
+    draft[row.key] = value == null ? "" : String(value);
+  }
+  return draft;
+}
+
+function fromDraft(draft: Record<keyof LimitValues, string>): LimitValues {
+  const values = {} as Record<keyof LimitValues, string | number | null>;
+  for (const row of LIMIT_ROWS) {
+    const raw = draft[row.key].trim();
+    if (raw === "") {
+      values[row.key] = null;
+    } else {
+      values[row.key] = row.key.endsWith("_count") ? Number(raw) : raw;
+    }
+  }
+  return values as unknown as LimitValues;
+}
+
+function formatLimit(value: string | number | null): string {
+  if (value == null) return "\u2014";
+  return Number(value) === 0 ? "\u221E" : String(value);
+}
+
+function UserLimitsForm({
+  userId,
+  canManage,
+  formId,
+  onBusyChange,
+}: {
+  userId: string;
+  canManage: boolean;
+  formId: string;
+  onBusyChange: (busy: boolean) => void;
+}) {
+  const { t } = useTranslation();
+  const [limits, setLimits] = useState<UserLimitsOutput | null>(null);
+  const [draft, setDraft] = useState<LimitDraft | null>(null);
+  const [busy, setBusy] = useState(false);
+
+  const load = (data: UserLimitsOutput) => {
+    setLimits(data);
+    setDraft({ deposit: toDraft(data.deposit), withdrawal: toDraft(data.withdrawal) });
+  };
+
+  useEffect(() => {
+    void api
+      .get<ApiResponse<UserLimitsOutput>>(`users/${userId}/limits`)
+      .then((res) => load(res.data.data))
+      .catch((error) => {
+        alertError({
+          title: t("Error"),
+          message: normalizeErrorMessage(error, t("Failed to load limits.")),
+        });
+      });
+  }, [userId, t]);
+
+  useEffect(() => {
+    onBusyChange(busy);
+  }, [busy, onBusyChange]);
+
+  const submit = async (event: React.FormEvent) => {
+    event.preventDefault();
+    if (!draft || busy) return;
+    setBusy(true);
+    try {
+      const payload: AdminUserLimitsInput = {
+        deposit: fromDraft(draft.deposit),
+        withdrawal: fromDraft(draft.withdrawal),
+      };
+      const res = await api.put<ApiResponse<UserLimitsOutput>>(
+        `users/${userId}/limits`,
+        payload,
+      );
+      load(res.data.data);
+      alertSuccess({ title: t("Success"), message: t("User limits saved") });
+    } catch (error) {
+      alertError({
+        title: t("Error"),
+        message: normalizeErrorMessage(error, t("Failed to save limits.")),
+      });
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  if (!limits || !draft) {
+    return <p className="text-sm text-muted">{t("Loading\u2026")}</p>;
+  }
+
+  const kinds: ReadonlyArray<{ key: LimitKindKey; label: string }> = [
+    { key: "deposit", label: t("Deposit") },
+    { key: "withdrawal", label: t("Withdrawal") },
+  ];
+
+  return (
+    <form id={formId} onSubmit={submit} className="space-y-4">
+      <p className="text-xs text-muted">
+        {t("Leave an override empty to inherit the country or global limit. 0 means unlimited.")}
+      </p>
+      {kinds.map((kind) => {
+        const current = limits[kind.key];
+        return (
+          <div key={kind.key}>
+            <h4 className="mb-2 text-sm font-semibold">{kind.label}</h4>
+            <table className="w-full text-sm">
+              <thead>
+                <tr className="text-left text-xs text-muted">
+                  <th className="py-1">{t("Limit")}</th>
+                  <th className="py-1">{t("Used")}</th>
+                  <th className="py-1">{t("Effective")}</th>
+                  <th className="py-1">{t("Inherited")}</th>
+                  <th className="py-1">{t("Override")}</th>
+                </tr>
+              </thead>
+              <tbody>
+                {LIMIT_ROWS.map((row) => (
+                  <tr key={row.key} className="border-t border-border">
+                    <td className="py-1">{t(row.label)}</td>
+                    <td className="py-1 tabular-nums">
+                      {row.usage ? current[row.usage.period][row.usage.field] : "\u2014"}
+                    </td>
+                    <td className="py-1 tabular-nums">{formatLimit(current.effective[row.key])}</td>
+                    <td className="py-1 tabular-nums text-muted">
+                      {formatLimit(current.inherited[row.key])}
+                    </td>
+                    <td className="py-1">
+                      <TextInput
+                        value={draft[kind.key][row.key]}
+                        disabled={!canManage || busy}
+                        inputMode={row.key.endsWith("_count") ? "numeric" : "decimal"}
+                        onChange={(e) =>
+                          setDraft({
+                            ...draft,
+                            [kind.key]: { ...draft[kind.key], [row.key]: e.target.value },
+                          })
+                        }
+                      />
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        );
+      })}
+    </form>
+  );
+}
+
 function canManageUsers(account: AdminMeOutput | null): boolean {
   if (!account) return false;
   return useAuthStore.hasPermission(PERMISSION.USER_MANAGE, account);
@@ -492,6 +683,46 @@ export default function ManageUsersPage() {
     });
   };
 
+  const handleLimits = (user: UserDatatableRow) => {
+    const formId = `user-limits-form-${Date.now()}-${Math.random().toString(36).slice(2)}`;
+    let modalId = "";
+    const renderFooter = (busy: boolean) => (
+      <>
+        <Button
+          type="button"
+          onClick={() => useModalStore.getState().close()}
+          variant="secondary"
+          disabled={busy}
+        >
+          {t("Close")}
+        </Button>
+        {canManage && (
+          <Button type="submit" form={formId} variant="primary" busy={busy}>
+            {busy ? t("Saving\u2026") : t("Save")}
+          </Button>
+        )}
+      </>
+    );
+    modalId = useModalStore.getState().open({
+      title: `${user.username} \u2014 ${t("Limits")}`,
+      size: "xl",
+      content: (
+        <UserLimitsForm
+          userId={user.id}
+          canManage={canManage}
+          formId={formId}
+          onBusyChange={(busy) => {
+            if (!modalId) return;
+            useModalStore
+              .getState()
+              .update(modalId, { footer: renderFooter(busy) });
+          }}
+        />
+      ),
+      footer: renderFooter(false),
+    });
+  };
+
   const handleBan = async (
     user: UserDatatableRow,
     refresh: () => void,
@@ -614,6 +845,16 @@ export default function ManageUsersPage() {
                       >
                         <Pencil size={16} />
                       </Button>
+                      <Button
+                        onClick={() => handleLimits(user)}
+                        variant="plain"
+                        size="sm"
+                        iconOnly
+                        disabled={banning}
+                        title={t("Limits")}
+                      >
+                        <Gauge size={16} />
+                      </Button>
                       <Button
                         onClick={() => handleBan(user, ctx.refresh)}
                         variant="plain"
@@ -658,6 +899,11 @@ export default function ManageUsersPage() {
           label: t("Ban Status"),
           render: (user) => <BanBadge status={user.ban} />,
         },
+        {
+          key: "kyc_level",
+          label: t("KYC Level"),
+          render: (user) => t(KYC_LEVEL_I18N[user.kyc_level] ?? user.kyc_level),
+        },
         {
           key: "country_iso2",
           label: t("Country ISO2"),
@@ -0,0 +1,39 @@
+import { create } from "zustand";
+
+export type ReviewClaimSubject = "deposit" | "withdrawal";
+
+export interface ReviewClaim {
+  claimed_by: string | null;
+  claimer_username: string | null;
+  claimed_until: string | null;
+}
+
+/** Payload of the `review_claim` event on the admin channel. */
+export interface ReviewClaimEvent extends ReviewClaim {
+  subject: ReviewClaimSubject;
+  id: string;
+}
+
+interface ReviewClaimState {
+  /** Latest claim per `${subject}:${id}`, as broadcast since the page loaded. */
+  claims: Record<string, ReviewClaim>;
+  /** Claim for a record, preferring a broadcast over the row's loaded value. */
+  getClaim: (subject: ReviewClaimSubject, id: string, fallback: ReviewClaim) => ReviewClaim;
+  /** Record a claim change. Call this from the websocket handler. */
+  applyEvent: (event: ReviewClaimEvent) => void;
+}
+
+export const useReviewClaimStore = create<ReviewClaimState>()((set, get) => ({
+  claims: {},
+  getClaim: (subject, id, fallback) => get().claims[`${subject}:${id}`] ?? fallback,
+  applyEvent: ({ subject, id, ...claim }) =>
+    set((state) => ({ claims: { ...state.claims, [`${subject}:${id}`]: claim } })),
+}));
+
+/** Name of the other admin holding a live claim, or null when free or held by `adminId`. */
+export function claimedByOther(claim: ReviewClaim, adminId: string | undefined): string | null {
+  if (!claim.claimed_by || !claim.claimed_until) return null;
+  if (new Date(claim.claimed_until).getTime() <= Date.now()) return null;
+  if (claim.claimed_by === adminId) return null;
+  return claim.claimer_username ?? claim.claimed_by;
+}
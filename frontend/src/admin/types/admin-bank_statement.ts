@@ -0,0 +1,35 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { BankStatementLineStatus } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BankStatementDetailOutput = { statement: BankStatementOutput, lines: Array<{ id: string, line_number: number, reference: string, amount: string | null, account_number: string | null, transaction_date: string | null, raw: Record<string, string>, status: BankStatementLineStatus, 
+/**
+ * The pending deposit the line was matched to
+ */
+deposit_id: string | null, 
+/**
+ * Why the line did not match
+ */
+note: string | null, }>, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BankStatementLineOutput = { id: string, line_number: number, reference: string, amount: string | null, account_number: string | null, transaction_date: string | null, raw: Record<string, string>, status: BankStatementLineStatus, 
+/**
+ * The pending deposit the line was matched to
+ */
+deposit_id: string | null, 
+/**
+ * Why the line did not match
+ */
+note: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BankStatementOutput = { id: string, company_bank_account_id: string, file_name: string | null, line_count: number, matched_count: number, uploaded_by: string, created_at: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BankStatementsOutput = { statements: Array<{ id: string, company_bank_account_id: string, file_name: string | null, line_count: number, matched_count: number, uploaded_by: string, created_at: string, }>, };
@@ -0,0 +1,27 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { CreditConversionRateStatus, CreditType } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AdminCreditConversionRateInput = { from_credit_type: CreditType, to_credit_type: CreditType, 
+/**
+ * Target units received per source unit after fees
+ */
+rate: string, fee_percentage: string, fee_fixed: string, min_amount: string, status: CreditConversionRateStatus, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AdminCreditConvertInput = { username: string, from_credit_type: CreditType, to_credit_type: CreditType, amount: string, remark: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreditConversionOutput = { related_key: string, username: string, from_credit_type: CreditType, to_credit_type: CreditType, amount: string, fee: string, rate: string, converted_amount: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreditConversionRateOutput = { from_credit_type: CreditType, to_credit_type: CreditType, rate: string, fee_percentage: string, fee_fixed: string, min_amount: string, status: CreditConversionRateStatus, updated_at: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreditConversionRatesOutput = { rates: Array<{ from_credit_type: CreditType, to_credit_type: CreditType, rate: string, fee_percentage: string, fee_fixed: string, min_amount: string, status: CreditConversionRateStatus, updated_at: string, }>, };
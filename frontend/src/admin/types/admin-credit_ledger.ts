@@ -0,0 +1,23 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { CreditLedgerMismatchStatus, CreditType } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreditLedgerMismatchOutput = { id: string, user_id: string, admin_id: string | null, credit_type: CreditType, stored_balance: string, ledger_balance: string, 
+/**
+ * Stored balance minus ledger balance
+ */
+difference: string, status: CreditLedgerMismatchStatus, detected_at: string, resolved_at: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreditLedgerMismatchesOutput = { mismatches: Array<{ id: string, user_id: string, admin_id: string | null, credit_type: CreditType, stored_balance: string, ledger_balance: string, 
+/**
+ * Stored balance minus ledger balance
+ */
+difference: string, status: CreditLedgerMismatchStatus, detected_at: string, resolved_at: string | null, }>, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreditLedgerScanOutput = { open: number, resolved: number, };
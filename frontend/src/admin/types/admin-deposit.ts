@@ -1,6 +1,10 @@
 // Auto-generated by `cargo run -p app --bin export-types`.
 // Do not edit manually — run `make gen-types` to regenerate.
-import type { CreditType, DepositMethod, DepositReviewAction, DepositStatus, OwnerType } from "@admin/types/enums";
+import type { CreditTransactionType, CreditType, DepositMethod, DepositReviewAction, DepositStatus, OwnerType } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AdminDepositReverseInput = { reason: string, };
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
@@ -8,4 +12,24 @@ export type AdminDepositReviewInput = { action: DepositReviewAction, admin_remar
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type DepositOutput = { id: string, owner_type: OwnerType, owner_id: string, credit_type: CreditType, deposit_method: DepositMethod, company_bank_account_id: string | null, company_crypto_account_id: string | null, conversion_rate: string | null, status: DepositStatus, amount: string, fee: string, net_amount: string, related_key: string | null, remark: string | null, admin_remark: string | null, reviewed_at: string | null, created_at: string, };
+export type DepositDetailOutput = { deposit: DepositOutput, 
+/**
+ * Every status the deposit entered, oldest first
+ */
+history: Array<{ status: DepositStatus, admin_id: string | null, remark: string | null, created_at: string, }>, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DepositOutput = { id: string, owner_type: OwnerType, owner_id: string, credit_type: CreditType, deposit_method: DepositMethod, company_bank_account_id: string | null, company_crypto_account_id: string | null, conversion_rate: string | null, status: DepositStatus, amount: string, fee: string, net_amount: string, reference_code: string | null, gateway_provider: string | null, gateway_session_id: string | null, related_key: string | null, remark: string | null, admin_remark: string | null, reviewed_at: string | null, reversed_by: string | null, reversal_reason: string | null, reversed_at: string | null, created_at: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DepositReversalOutput = { deposit: DepositOutput, commissions_reversed: number, 
+/**
+ * Ledger entries linked to the deposit that still need manual reversal
+ */
+needs_reversal: Array<{ id: string, user_id: string, credit_type: CreditType, amount: string, transaction_type: CreditTransactionType, related_key: string | null, remark: string | null, created_at: string, }>, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DepositStatusHistoryOutput = { status: DepositStatus, admin_id: string | null, remark: string | null, created_at: string, };
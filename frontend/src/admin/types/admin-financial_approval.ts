@@ -0,0 +1,23 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { CreditType, FinancialApprovalAction, FinancialApprovalStatus } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AdminFinancialApprovalDecisionInput = { remark: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type FinancialApprovalOutput = { id: string, action: FinancialApprovalAction, 
+/**
+ * Deposit or withdrawal id, or the user id for a credit adjustment
+ */
+subject_id: string, credit_type: CreditType, amount: string, payload: Record<string, unknown>, status: FinancialApprovalStatus, proposed_by: string, reviewed_by: string | null, review_remark: string | null, reviewed_at: string | null, created_at: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type FinancialApprovalsOutput = { approvals: Array<{ id: string, action: FinancialApprovalAction, 
+/**
+ * Deposit or withdrawal id, or the user id for a credit adjustment
+ */
+subject_id: string, credit_type: CreditType, amount: string, payload: Record<string, unknown>, status: FinancialApprovalStatus, proposed_by: string, reviewed_by: string | null, review_remark: string | null, reviewed_at: string | null, created_at: string, }>, };
@@ -0,0 +1,19 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { KycDocumentType, KycLevel, KycReviewAction, KycStatus } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AdminKycReviewInput = { action: KycReviewAction, reject_reason: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type KycSubmissionOutput = { id: string, user_id: string, username: string | null, 
+/**
+ * The user's level now, before this submission is reviewed
+ */
+user_kyc_level: KycLevel | null, level: KycLevel, status: KycStatus, full_name: string, document_type: KycDocumentType, document_number: string, reject_reason: string | null, admin_id: string | null, admin_username: string | null, id_front_url: string | null, id_back_url: string | null, selfie_url: string | null, proof_of_address_url: string | null, 
+/**
+ * Documents the user has yet to upload; approval needs none missing
+ */
+missing_documents: Array<string>, reviewed_at: string | null, created_at: string, };
@@ -3,4 +3,4 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type AdminNotificationCountsOutput = { deposit: bigint, withdrawal: bigint, };
+export type AdminNotificationCountsOutput = { deposit: bigint, withdrawal: bigint, kyc: bigint, };
@@ -0,0 +1,27 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { CreditType, ReferralCommissionSource, ReferralCommissionStatus } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AdminReferralCommissionRatesInput = { credit_type: CreditType, 
+/**
+ * Commission per upline level, starting with the direct introducer
+ */
+percentages: string[], };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReferralCommissionOutput = { id: string, source_type: ReferralCommissionSource, source_id: string, beneficiary_user_id: string, level: number, credit_type: CreditType, base_amount: string, percentage: string, amount: string, status: ReferralCommissionStatus, reversed_at: string | null, created_at: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReferralCommissionRateOutput = { credit_type: CreditType, level: number, percentage: string, updated_at: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReferralCommissionRatesOutput = { rates: Array<{ credit_type: CreditType, level: number, percentage: string, updated_at: string, }>, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ReferralCommissionsOutput = { commissions: Array<{ id: string, source_type: ReferralCommissionSource, source_id: string, beneficiary_user_id: string, level: number, credit_type: CreditType, base_amount: string, percentage: string, amount: string, status: ReferralCommissionStatus, reversed_at: string | null, created_at: string, }>, };
@@ -0,0 +1,10 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Who is reviewing a pending deposit or withdrawal. Empty when nobody holds
+ * a live claim.
+ */
+export type ReviewClaimOutput = { id: string, claimed_by: string | null, claimer_username: string | null, claimed_until: string | null, };
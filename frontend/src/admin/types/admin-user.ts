@@ -1,6 +1,13 @@
 // Auto-generated by `cargo run -p app --bin export-types`.
 // Do not edit manually — run `make gen-types` to regenerate.
-import type { UserBanStatus } from "@admin/types/enums";
+import type { UserBanStatus, UserLimitKind } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Replaces the user's overrides; leave every field empty to drop one.
+ */
+export type AdminUserLimitsInput = { deposit: LimitValues, withdrawal: LimitValues, };
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
@@ -16,6 +23,18 @@ export type CreateUserInput = { username: string, email: string | null, name: st
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
+export type LimitPeriodUsage = { count: bigint, amount: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One set of deposit or withdrawal limits. `null` is not set at this layer;
+ * 0 is unlimited.
+ */
+export type LimitValues = { min_amount: string | null, max_amount: string | null, daily_count: number | null, daily_amount: string | null, weekly_count: number | null, weekly_amount: string | null, monthly_count: number | null, monthly_amount: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
 export type UpdateUserInput = { id: string, username: string | null, email: string | null, name: string | null, password: string | null, country_iso2: string | null, contact_number: string | null, };
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
@@ -28,4 +47,24 @@ export type UserBanOutput = { banned: boolean, };
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
+export type UserLimitOutput = { kind: UserLimitKind, 
+/**
+ * Global limits with the user's country and KYC level applied
+ */
+inherited: LimitValues, 
+/**
+ * The user's own override
+ */
+overrides: LimitValues, 
+/**
+ * What is enforced
+ */
+effective: LimitValues, daily: LimitPeriodUsage, weekly: LimitPeriodUsage, monthly: LimitPeriodUsage, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserLimitsOutput = { deposit: UserLimitOutput, withdrawal: UserLimitOutput, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
 export type UserManageOutput = { id: string, uuid: string, username: string, name: string | null, email: string | null, locale: string | null, country_iso2: string | null, contact_number: string | null, ban: UserBanStatus, created_at: string, updated_at: string, };
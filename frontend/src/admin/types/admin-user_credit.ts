@@ -17,4 +17,12 @@ custom_description_text: LocalizedInput | null, };
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
+/**
+ * Either the posted transaction, or the proposal when the adjustment is
+ * above the approval threshold and waits for a second admin.
+ */
+export type AdminCreditAdjustOutput = { transaction: UserCreditTransactionOutput | null, approval: FinancialApprovalOutput | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
 export type UserCreditTransactionOutput = { id: string, user_id: string, credit_type: CreditType, amount: string, transaction_type: CreditTransactionType, related_key: string | null, remark: string | null, created_at: string, };
@@ -8,4 +8,16 @@ export type AdminWithdrawalReviewInput = { action: WithdrawalReviewAction, admin
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type WithdrawalOutput = { id: string, owner_type: OwnerType, owner_id: string, credit_type: CreditType, withdrawal_method: WithdrawalMethod, bank_id: string | null, bank_account_name: string | null, bank_account_number: string | null, crypto_network_id: string | null, crypto_wallet_address: string | null, conversion_rate: string | null, status: WithdrawalStatus, amount: string, fee: string, net_amount: string, related_key: string | null, remark: string | null, admin_remark: string | null, reviewed_at: string | null, created_at: string, };
+export type WithdrawalDetailOutput = { withdrawal: WithdrawalOutput, 
+/**
+ * Every status the withdrawal entered, oldest first
+ */
+history: Array<{ status: WithdrawalStatus, admin_id: string | null, remark: string | null, created_at: string, }>, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WithdrawalOutput = { id: string, owner_type: OwnerType, owner_id: string, credit_type: CreditType, withdrawal_method: WithdrawalMethod, bank_id: string | null, bank_account_name: string | null, bank_account_number: string | null, crypto_network_id: string | null, crypto_wallet_address: string | null, destination_id: string | null, conversion_rate: string | null, status: WithdrawalStatus, amount: string, fee: string, net_amount: string, related_key: string | null, remark: string | null, admin_remark: string | null, reviewed_at: string | null, refunded_by: string | null, refund_reason: string | null, refunded_at: string | null, sla_breached_at: string | null, payout_provider: string | null, payout_reference: string | null, created_at: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WithdrawalStatusHistoryOutput = { status: WithdrawalStatus, admin_id: string | null, remark: string | null, created_at: string, };
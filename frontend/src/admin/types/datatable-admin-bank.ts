@@ -1,7 +1,7 @@
 // Auto-generated by `cargo run -p app --bin export-types`.
 // Do not edit manually — run `make gen-types` to regenerate.
-import type { BankStatus } from "@admin/types/enums";
+import type { BankAccountNumberFormat, BankStatus } from "@admin/types/enums";
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type BankDatatableRow = { id: string, country_iso2: string, name: string, code: string | null, status: BankStatus, status_label: string, sort_order: number, updated_at: string, };
+export type BankDatatableRow = { id: string, country_iso2: string, name: string, code: string | null, status: BankStatus, status_label: string, account_number_format: BankAccountNumberFormat, account_number_min_length: number | null, account_number_max_length: number | null, account_number_pattern: string | null, sort_order: number, updated_at: string, };
@@ -4,4 +4,4 @@ import type { CreditType, DepositMethod, DepositStatus, OwnerType } from "@admin
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type DepositDatatableRow = { id: string, owner_type: OwnerType, owner_id: string, admin_id: string | null, credit_type: CreditType, deposit_method: DepositMethod, company_bank_account_id: string | null, company_bank_account_name: string | null, company_crypto_account_id: string | null, company_crypto_network_name: string | null, conversion_rate: string | null, status: DepositStatus, status_label: string, amount: string, fee: string, net_amount: string, related_key: string | null, remark: string | null, admin_remark: string | null, owner_name: string | null, admin_username: string | null, reviewed_at: string | null, created_at: string, updated_at: string, };
+export type DepositDatatableRow = { id: string, owner_type: OwnerType, owner_id: string, admin_id: string | null, credit_type: CreditType, deposit_method: DepositMethod, company_bank_account_id: string | null, company_bank_account_name: string | null, company_crypto_account_id: string | null, company_crypto_network_name: string | null, conversion_rate: string | null, status: DepositStatus, status_label: string, amount: string, fee: string, net_amount: string, reference_code: string | null, related_key: string | null, remark: string | null, admin_remark: string | null, owner_name: string | null, admin_username: string | null, reviewed_at: string | null, reversed_by: string | null, reverser_username: string | null, reversal_reason: string | null, reversed_at: string | null, claimed_by: string | null, claimer_username: string | null, claimed_until: string | null, created_at: string, updated_at: string, };
@@ -0,0 +1,7 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { KycDocumentType, KycLevel, KycStatus } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type KycSubmissionDatatableRow = { id: string, user_id: string, user_username: string | null, level: KycLevel, status: KycStatus, status_label: string, full_name: string, document_type: KycDocumentType, document_number: string, reject_reason: string | null, admin_id: string | null, admin_username: string | null, reviewed_at: string | null, created_at: string, updated_at: string, };
@@ -1,10 +1,10 @@
 // Auto-generated by `cargo run -p app --bin export-types`.
 // Do not edit manually — run `make gen-types` to regenerate.
-import type { UserBanStatus } from "@admin/types/enums";
+import type { KycLevel, UserBanStatus } from "@admin/types/enums";
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type UserDatatableRow = { id: string, uuid: string, username: string, name: string | null, email: string | null, locale: string | null, country_iso2: string | null, contact_number: string | null, ban: UserBanStatus, credit_1: string, credit_2: string, introducer_user_id: string | null, created_at: string, updated_at: string, };
+export type UserDatatableRow = { id: string, uuid: string, username: string, name: string | null, email: string | null, locale: string | null, country_iso2: string | null, contact_number: string | null, ban: UserBanStatus, kyc_level: KycLevel, credit_1: string, credit_2: string, introducer_user_id: string | null, created_at: string, updated_at: string, };
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
@@ -4,7 +4,7 @@ import type { CreditTransactionType, CreditType } from "@admin/types/enums";
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type UserCreditTransactionDatatableRow = { id: string, user_id: string, admin_id: string | null, credit_type: CreditType, amount: string, transaction_type: CreditTransactionType, 
+export type UserCreditTransactionDatatableRow = { id: string, user_id: string, admin_id: string | null, credit_type: CreditType, amount: string, balance_before: string, balance_after: string, transaction_type: CreditTransactionType, 
 /**
  * Backend-computed human-readable label for the transaction type,
  * respecting current locale and interpolating dynamic `params`.
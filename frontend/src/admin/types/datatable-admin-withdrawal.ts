@@ -4,4 +4,4 @@ import type { CreditType, OwnerType, WithdrawalMethod, WithdrawalStatus } from "
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type WithdrawalDatatableRow = { id: string, owner_type: OwnerType, owner_id: string, admin_id: string | null, credit_type: CreditType, withdrawal_method: WithdrawalMethod, bank_id: string | null, bank_name: string | null, crypto_network_id: string | null, crypto_network_name: string | null, conversion_rate: string | null, status: WithdrawalStatus, status_label: string, amount: string, fee: string, net_amount: string, related_key: string | null, remark: string | null, admin_remark: string | null, owner_name: string | null, admin_username: string | null, reviewed_at: string | null, created_at: string, updated_at: string, };
+export type WithdrawalDatatableRow = { id: string, owner_type: OwnerType, owner_id: string, admin_id: string | null, credit_type: CreditType, withdrawal_method: WithdrawalMethod, bank_id: string | null, bank_name: string | null, crypto_network_id: string | null, crypto_network_name: string | null, conversion_rate: string | null, status: WithdrawalStatus, status_label: string, amount: string, fee: string, net_amount: string, related_key: string | null, remark: string | null, admin_remark: string | null, owner_name: string | null, admin_username: string | null, reviewed_at: string | null, refunded_by: string | null, refunder_username: string | null, refund_reason: string | null, refunded_at: string | null, sla_breached_at: string | null, claimed_by: string | null, claimer_username: string | null, claimed_until: string | null, created_at: string, updated_at: string, };
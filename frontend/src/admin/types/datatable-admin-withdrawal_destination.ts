@@ -0,0 +1,7 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { WithdrawalDestinationKind, WithdrawalDestinationStatus } from "@admin/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WithdrawalDestinationDatatableRow = { id: string, user_id: string, user_username: string | null, kind: WithdrawalDestinationKind, bank_id: string | null, bank_name: string | null, bank_account_name: string | null, bank_account_number: string | null, crypto_network_id: string | null, crypto_network_name: string | null, crypto_wallet_address: string | null, status: WithdrawalDestinationStatus, status_label: string, available_at: string, lock_reason: string | null, locked_by: string | null, locked_by_username: string | null, locked_at: string | null, created_at: string, updated_at: string, };
@@ -41,6 +41,34 @@ export const AUDIT_ACTIONS: ReadonlyArray<AuditAction> = [
 
 export type AuthClientType = "web" | "mobile";
 
+export type BankAccountNumberFormat = "1" | "2" | "3";
+
+export const BANK_ACCOUNT_NUMBER_FORMAT: Readonly<Record<string, BankAccountNumberFormat>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+};
+
+export const BANK_ACCOUNT_NUMBER_FORMATS: ReadonlyArray<BankAccountNumberFormat> = [
+  "1",
+  "2",
+  "3",
+];
+
+export type BankStatementLineStatus = "1" | "2" | "3";
+
+export const BANK_STATEMENT_LINE_STATUS: Readonly<Record<string, BankStatementLineStatus>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+};
+
+export const BANK_STATEMENT_LINE_STATUSES: ReadonlyArray<BankStatementLineStatus> = [
+  "1",
+  "2",
+  "3",
+];
+
 export type BankStatus = "1" | "2";
 
 export const BANK_STATUS: Readonly<Record<string, BankStatus>> = {
@@ -89,7 +117,33 @@ export const CONTENT_PAGE_SYSTEM_FLAGS: ReadonlyArray<ContentPageSystemFlag> = [
   "1",
 ];
 
-export type CreditTransactionType = "101" | "102" | "201" | "202" | "301" | "302" | "401";
+export type CreditConversionRateStatus = "1" | "2";
+
+export const CREDIT_CONVERSION_RATE_STATUS: Readonly<Record<string, CreditConversionRateStatus>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const CREDIT_CONVERSION_RATE_STATUSES: ReadonlyArray<CreditConversionRateStatus> = [
+  "1",
+  "2",
+];
+
+export type CreditLedgerMismatchStatus = "1" | "2" | "3";
+
+export const CREDIT_LEDGER_MISMATCH_STATUS: Readonly<Record<string, CreditLedgerMismatchStatus>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+};
+
+export const CREDIT_LEDGER_MISMATCH_STATUSES: ReadonlyArray<CreditLedgerMismatchStatus> = [
+  "1",
+  "2",
+  "3",
+];
+
+export type CreditTransactionType = "101" | "102" | "201" | "202" | "301" | "302" | "303" | "401" | "402" | "501" | "502" | "601" | "602" | "901";
 
 export const CREDIT_TRANSACTION_TYPE: Readonly<Record<string, CreditTransactionType>> = {
   _101: "101",
@@ -98,7 +152,14 @@ export const CREDIT_TRANSACTION_TYPE: Readonly<Record<string, CreditTransactionT
   _202: "202",
   _301: "301",
   _302: "302",
+  _303: "303",
   _401: "401",
+  _402: "402",
+  _501: "501",
+  _502: "502",
+  _601: "601",
+  _602: "602",
+  _901: "901",
 };
 
 export const CREDIT_TRANSACTION_TYPES: ReadonlyArray<CreditTransactionType> = [
@@ -108,7 +169,14 @@ export const CREDIT_TRANSACTION_TYPES: ReadonlyArray<CreditTransactionType> = [
   "202",
   "301",
   "302",
+  "303",
   "401",
+  "402",
+  "501",
+  "502",
+  "601",
+  "602",
+  "901",
 ];
 
 export type CreditType = "1" | "2";
@@ -123,6 +191,22 @@ export const CREDIT_TYPES: ReadonlyArray<CreditType> = [
   "2",
 ];
 
+export type CryptoAddressFormat = "1" | "2" | "3" | "4";
+
+export const CRYPTO_ADDRESS_FORMAT: Readonly<Record<string, CryptoAddressFormat>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+  _4: "4",
+};
+
+export const CRYPTO_ADDRESS_FORMATS: ReadonlyArray<CryptoAddressFormat> = [
+  "1",
+  "2",
+  "3",
+  "4",
+];
+
 export type CryptoNetworkStatus = "1" | "2";
 
 export const CRYPTO_NETWORK_STATUS: Readonly<Record<string, CryptoNetworkStatus>> = {
@@ -135,14 +219,16 @@ export const CRYPTO_NETWORK_STATUSES: ReadonlyArray<CryptoNetworkStatus> = [
   "2",
 ];
 
-export type DepositMethod = "1";
+export type DepositMethod = "1" | "2";
 
 export const DEPOSIT_METHOD: Readonly<Record<string, DepositMethod>> = {
   _1: "1",
+  _2: "2",
 };
 
 export const DEPOSIT_METHODS: ReadonlyArray<DepositMethod> = [
   "1",
+  "2",
 ];
 
 export type DepositReviewAction = "1" | "2";
@@ -157,18 +243,104 @@ export const DEPOSIT_REVIEW_ACTIONS: ReadonlyArray<DepositReviewAction> = [
   "2",
 ];
 
-export type DepositStatus = "1" | "2" | "3";
+export type DepositStatus = "1" | "2" | "3" | "4" | "5";
 
 export const DEPOSIT_STATUS: Readonly<Record<string, DepositStatus>> = {
   _1: "1",
   _2: "2",
   _3: "3",
+  _4: "4",
+  _5: "5",
 };
 
 export const DEPOSIT_STATUSES: ReadonlyArray<DepositStatus> = [
   "1",
   "2",
   "3",
+  "4",
+  "5",
+];
+
+export type FinancialApprovalAction = "1" | "2" | "3";
+
+export const FINANCIAL_APPROVAL_ACTION: Readonly<Record<string, FinancialApprovalAction>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+};
+
+export const FINANCIAL_APPROVAL_ACTIONS: ReadonlyArray<FinancialApprovalAction> = [
+  "1",
+  "2",
+  "3",
+];
+
+export type FinancialApprovalStatus = "1" | "2" | "3";
+
+export const FINANCIAL_APPROVAL_STATUS: Readonly<Record<string, FinancialApprovalStatus>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+};
+
+export const FINANCIAL_APPROVAL_STATUSES: ReadonlyArray<FinancialApprovalStatus> = [
+  "1",
+  "2",
+  "3",
+];
+
+export type KycDocumentType = "1" | "2" | "3";
+
+export const KYC_DOCUMENT_TYPE: Readonly<Record<string, KycDocumentType>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+};
+
+export const KYC_DOCUMENT_TYPES: ReadonlyArray<KycDocumentType> = [
+  "1",
+  "2",
+  "3",
+];
+
+export type KycLevel = "0" | "1" | "2";
+
+export const KYC_LEVEL: Readonly<Record<string, KycLevel>> = {
+  _0: "0",
+  _1: "1",
+  _2: "2",
+};
+
+export const KYC_LEVELS: ReadonlyArray<KycLevel> = [
+  "0",
+  "1",
+  "2",
+];
+
+export type KycReviewAction = "1" | "2";
+
+export const KYC_REVIEW_ACTION: Readonly<Record<string, KycReviewAction>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const KYC_REVIEW_ACTIONS: ReadonlyArray<KycReviewAction> = [
+  "1",
+  "2",
+];
+
+export type KycStatus = "1" | "2" | "3";
+
+export const KYC_STATUS: Readonly<Record<string, KycStatus>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+};
+
+export const KYC_STATUSES: ReadonlyArray<KycStatus> = [
+  "1",
+  "2",
+  "3",
 ];
 
 export type OwnerType = "1";
@@ -181,7 +353,7 @@ export const OWNER_TYPES: ReadonlyArray<OwnerType> = [
   "1",
 ];
 
-export type Permission = "admin.read" | "admin.manage" | "content_page.read" | "content_page.manage" | "country.read" | "country.manage" | "user.read" | "user.manage" | "user.hierarchy" | "user.change_introducer" | "user.credit_read" | "user.credit_manage" | "audit_log.read" | "deposit.read" | "deposit.manage" | "withdrawal.read" | "withdrawal.manage" | "bank.read" | "bank.manage" | "crypto_network.read" | "crypto_network.manage" | "company_bank_account.read" | "company_bank_account.manage" | "company_crypto_account.read" | "company_crypto_account.manage" | "export";
+export type Permission = "admin.read" | "admin.manage" | "content_page.read" | "content_page.manage" | "country.read" | "country.manage" | "user.read" | "user.manage" | "user.hierarchy" | "user.change_introducer" | "user.credit_read" | "user.credit_manage" | "kyc.read" | "kyc.manage" | "audit_log.read" | "deposit.read" | "deposit.manage" | "withdrawal.read" | "withdrawal.manage" | "withdrawal_destination.read" | "withdrawal_destination.manage" | "bank.read" | "bank.manage" | "crypto_network.read" | "crypto_network.manage" | "company_bank_account.read" | "company_bank_account.manage" | "company_crypto_account.read" | "company_crypto_account.manage" | "export";
 
 export interface PermissionMeta {
   key: Permission;
@@ -204,11 +376,15 @@ export const PERMISSION_META: ReadonlyArray<PermissionMeta> = [
   { key: "user.change_introducer", guard: "admin", label: "Change User Introducer", group: "user", description: "Change a user's introducer and view change logs." },
   { key: "user.credit_read", guard: "admin", label: "Read User Credits", group: "user", description: "View credit transactions and balances." },
   { key: "user.credit_manage", guard: "admin", label: "Manage User Credits", group: "user", description: "Adjust user credit balances." },
+  { key: "kyc.read", guard: "admin", label: "Read KYC Submissions", group: "user", description: "View KYC submissions and their documents." },
+  { key: "kyc.manage", guard: "admin", label: "Manage KYC Submissions", group: "user", description: "Approve or reject KYC submissions." },
   { key: "audit_log.read", guard: "admin", label: "Read Audit Logs", group: "audit_log", description: "View audit log datatable and inspect change details." },
   { key: "deposit.read", guard: "admin", label: "Read Deposits", group: "finance", description: "View deposit records." },
   { key: "deposit.manage", guard: "admin", label: "Manage Deposits", group: "finance", description: "Approve or reject deposit requests." },
   { key: "withdrawal.read", guard: "admin", label: "Read Withdrawals", group: "finance", description: "View withdrawal records." },
   { key: "withdrawal.manage", guard: "admin", label: "Manage Withdrawals", group: "finance", description: "Process, approve or reject withdrawal requests." },
+  { key: "withdrawal_destination.read", guard: "admin", label: "Read Withdrawal Destinations", group: "finance", description: "View users' saved bank accounts and crypto wallets." },
+  { key: "withdrawal_destination.manage", guard: "admin", label: "Manage Withdrawal Destinations", group: "finance", description: "Lock and unlock users' saved bank accounts and crypto wallets." },
   { key: "bank.read", guard: "admin", label: "Read Banks", group: "finance", description: "View bank list." },
   { key: "bank.manage", guard: "admin", label: "Manage Banks", group: "finance", description: "Create, update, delete banks." },
   { key: "crypto_network.read", guard: "admin", label: "Read Crypto Networks", group: "finance", description: "View crypto network list." },
@@ -233,11 +409,15 @@ export const PERMISSIONS: ReadonlyArray<Permission> = [
   "user.change_introducer",
   "user.credit_read",
   "user.credit_manage",
+  "kyc.read",
+  "kyc.manage",
   "audit_log.read",
   "deposit.read",
   "deposit.manage",
   "withdrawal.read",
   "withdrawal.manage",
+  "withdrawal_destination.read",
+  "withdrawal_destination.manage",
   "bank.read",
   "bank.manage",
   "crypto_network.read",
@@ -262,11 +442,15 @@ export const PERMISSION: Readonly<Record<string, Permission>> = {
   USER_CHANGE_INTRODUCER: "user.change_introducer",
   USER_CREDIT_READ: "user.credit_read",
   USER_CREDIT_MANAGE: "user.credit_manage",
+  KYC_READ: "kyc.read",
+  KYC_MANAGE: "kyc.manage",
   AUDIT_LOG_READ: "audit_log.read",
   DEPOSIT_READ: "deposit.read",
   DEPOSIT_MANAGE: "deposit.manage",
   WITHDRAWAL_READ: "withdrawal.read",
   WITHDRAWAL_MANAGE: "withdrawal.manage",
+  WITHDRAWAL_DESTINATION_READ: "withdrawal_destination.read",
+  WITHDRAWAL_DESTINATION_MANAGE: "withdrawal_destination.manage",
   BANK_READ: "bank.read",
   BANK_MANAGE: "bank.manage",
   CRYPTO_NETWORK_READ: "crypto_network.read",
@@ -291,11 +475,15 @@ export const PERMISSION_META_BY_KEY: Readonly<Record<Permission, PermissionMeta>
   "user.change_introducer": { key: "user.change_introducer", guard: "admin", label: "Change User Introducer", group: "user", description: "Change a user's introducer and view change logs." },
   "user.credit_read": { key: "user.credit_read", guard: "admin", label: "Read User Credits", group: "user", description: "View credit transactions and balances." },
   "user.credit_manage": { key: "user.credit_manage", guard: "admin", label: "Manage User Credits", group: "user", description: "Adjust user credit balances." },
+  "kyc.read": { key: "kyc.read", guard: "admin", label: "Read KYC Submissions", group: "user", description: "View KYC submissions and their documents." },
+  "kyc.manage": { key: "kyc.manage", guard: "admin", label: "Manage KYC Submissions", group: "user", description: "Approve or reject KYC submissions." },
   "audit_log.read": { key: "audit_log.read", guard: "admin", label: "Read Audit Logs", group: "audit_log", description: "View audit log datatable and inspect change details." },
   "deposit.read": { key: "deposit.read", guard: "admin", label: "Read Deposits", group: "finance", description: "View deposit records." },
   "deposit.manage": { key: "deposit.manage", guard: "admin", label: "Manage Deposits", group: "finance", description: "Approve or reject deposit requests." },
   "withdrawal.read": { key: "withdrawal.read", guard: "admin", label: "Read Withdrawals", group: "finance", description: "View withdrawal records." },
   "withdrawal.manage": { key: "withdrawal.manage", guard: "admin", label: "Manage Withdrawals", group: "finance", description: "Process, approve or reject withdrawal requests." },
+  "withdrawal_destination.read": { key: "withdrawal_destination.read", guard: "admin", label: "Read Withdrawal Destinations", group: "finance", description: "View users' saved bank accounts and crypto wallets." },
+  "withdrawal_destination.manage": { key: "withdrawal_destination.manage", guard: "admin", label: "Manage Withdrawal Destinations", group: "finance", description: "Lock and unlock users' saved bank accounts and crypto wallets." },
   "bank.read": { key: "bank.read", guard: "admin", label: "Read Banks", group: "finance", description: "View bank list." },
   "bank.manage": { key: "bank.manage", guard: "admin", label: "Manage Banks", group: "finance", description: "Create, update, delete banks." },
   "crypto_network.read": { key: "crypto_network.read", guard: "admin", label: "Read Crypto Networks", group: "finance", description: "View crypto network list." },
@@ -307,6 +495,28 @@ export const PERMISSION_META_BY_KEY: Readonly<Record<Permission, PermissionMeta>
   "export": { key: "export", guard: "admin", label: "Export Data", group: "datatable", description: "Export datatable records as CSV." },
 };
 
+export type ReferralCommissionSource = "1";
+
+export const REFERRAL_COMMISSION_SOURCE: Readonly<Record<string, ReferralCommissionSource>> = {
+  _1: "1",
+};
+
+export const REFERRAL_COMMISSION_SOURCES: ReadonlyArray<ReferralCommissionSource> = [
+  "1",
+];
+
+export type ReferralCommissionStatus = "1" | "2";
+
+export const REFERRAL_COMMISSION_STATUS: Readonly<Record<string, ReferralCommissionStatus>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const REFERRAL_COMMISSION_STATUSES: ReadonlyArray<ReferralCommissionStatus> = [
+  "1",
+  "2",
+];
+
 export type UserBanStatus = "0" | "1";
 
 export const USER_BAN_STATUS: Readonly<Record<string, UserBanStatus>> = {
@@ -319,37 +529,79 @@ export const USER_BAN_STATUSES: ReadonlyArray<UserBanStatus> = [
   "1",
 ];
 
-export type WithdrawalMethod = "1";
+export type UserLimitKind = "1" | "2";
+
+export const USER_LIMIT_KIND: Readonly<Record<string, UserLimitKind>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const USER_LIMIT_KINDS: ReadonlyArray<UserLimitKind> = [
+  "1",
+  "2",
+];
+
+export type WithdrawalDestinationKind = "1" | "2";
+
+export const WITHDRAWAL_DESTINATION_KIND: Readonly<Record<string, WithdrawalDestinationKind>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const WITHDRAWAL_DESTINATION_KINDS: ReadonlyArray<WithdrawalDestinationKind> = [
+  "1",
+  "2",
+];
+
+export type WithdrawalDestinationStatus = "1" | "2";
+
+export const WITHDRAWAL_DESTINATION_STATUS: Readonly<Record<string, WithdrawalDestinationStatus>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const WITHDRAWAL_DESTINATION_STATUSES: ReadonlyArray<WithdrawalDestinationStatus> = [
+  "1",
+  "2",
+];
+
+export type WithdrawalMethod = "1" | "2";
 
 export const WITHDRAWAL_METHOD: Readonly<Record<string, WithdrawalMethod>> = {
   _1: "1",
+  _2: "2",
 };
 
 export const WITHDRAWAL_METHODS: ReadonlyArray<WithdrawalMethod> = [
   "1",
+  "2",
 ];
 
-export type WithdrawalReviewAction = "1" | "2" | "3";
+export type WithdrawalReviewAction = "1" | "2" | "3" | "4";
 
 export const WITHDRAWAL_REVIEW_ACTION: Readonly<Record<string, WithdrawalReviewAction>> = {
   _1: "1",
   _2: "2",
   _3: "3",
+  _4: "4",
 };
 
 export const WITHDRAWAL_REVIEW_ACTIONS: ReadonlyArray<WithdrawalReviewAction> = [
   "1",
   "2",
   "3",
+  "4",
 ];
 
-export type WithdrawalStatus = "1" | "2" | "3" | "4";
+export type WithdrawalStatus = "1" | "2" | "3" | "4" | "5" | "6";
 
 export const WITHDRAWAL_STATUS: Readonly<Record<string, WithdrawalStatus>> = {
   _1: "1",
   _2: "2",
   _3: "3",
   _4: "4",
+  _5: "5",
+  _6: "6",
 };
 
 export const WITHDRAWAL_STATUSES: ReadonlyArray<WithdrawalStatus> = [
@@ -357,4 +609,6 @@ export const WITHDRAWAL_STATUSES: ReadonlyArray<WithdrawalStatus> = [
   "2",
   "3",
   "4",
+  "5",
+  "6",
 ];
@@ -2,20 +2,28 @@ export * from "@admin/types/enums";
 export * from "@admin/types/admin";
 export * from "@admin/types/admin-auth";
 export * from "@admin/types/admin-bank";
+export * from "@admin/types/admin-bank_statement";
 export * from "@admin/types/admin-company_bank_account";
 export * from "@admin/types/admin-company_crypto_account";
 export * from "@admin/types/admin-content_page";
 export * from "@admin/types/admin-country";
+export * from "@admin/types/admin-credit_conversion";
+export * from "@admin/types/admin-credit_ledger";
 export * from "@admin/types/admin-crypto_network";
 export * from "@admin/types/admin-deposit";
 export * from "@admin/types/admin-developer_logs";
+export * from "@admin/types/admin-financial_approval";
 export * from "@admin/types/admin-hierarchy";
 export * from "@admin/types/admin-introducer_change";
+export * from "@admin/types/admin-kyc";
 export * from "@admin/types/admin-notification";
+export * from "@admin/types/admin-referral_commission";
+export * from "@admin/types/admin-review_claim";
 export * from "@admin/types/admin-tiptap_upload";
 export * from "@admin/types/admin-user";
 export * from "@admin/types/admin-user_credit";
 export * from "@admin/types/admin-withdrawal";
+export * from "@admin/types/admin-withdrawal_destination";
 export * from "@admin/types/datatable-admin";
 export * from "@admin/types/datatable-admin-audit_log";
 export * from "@admin/types/datatable-admin-bank";
@@ -27,9 +35,11 @@ export * from "@admin/types/datatable-admin-crypto_network";
 export * from "@admin/types/datatable-admin-deposit";
 export * from "@admin/types/datatable-admin-http_client_log";
 export * from "@admin/types/datatable-admin-introducer_change";
+export * from "@admin/types/datatable-admin-kyc_submission";
 export * from "@admin/types/datatable-admin-sql_profiler_query";
 export * from "@admin/types/datatable-admin-sql_profiler_request";
 export * from "@admin/types/datatable-admin-user";
 export * from "@admin/types/datatable-admin-user_credit_transaction";
 export * from "@admin/types/datatable-admin-webhook_log";
 export * from "@admin/types/datatable-admin-withdrawal";
+export * from "@admin/types/datatable-admin-withdrawal_destination";
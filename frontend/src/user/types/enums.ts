@@ -2,3 +2,143 @@
 // Do not edit manually — run `make gen-types` to regenerate.
 
 export type AuthClientType = "web" | "mobile";
+
+export type CreditType = "1" | "2";
+
+export const CREDIT_TYPE: Readonly<Record<string, CreditType>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const CREDIT_TYPES: ReadonlyArray<CreditType> = [
+  "1",
+  "2",
+];
+
+export type DepositMethod = "1" | "2";
+
+export const DEPOSIT_METHOD: Readonly<Record<string, DepositMethod>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const DEPOSIT_METHODS: ReadonlyArray<DepositMethod> = [
+  "1",
+  "2",
+];
+
+export type DepositStatus = "1" | "2" | "3" | "4" | "5";
+
+export const DEPOSIT_STATUS: Readonly<Record<string, DepositStatus>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+  _4: "4",
+  _5: "5",
+};
+
+export const DEPOSIT_STATUSES: ReadonlyArray<DepositStatus> = [
+  "1",
+  "2",
+  "3",
+  "4",
+  "5",
+];
+
+export type KycDocumentType = "1" | "2" | "3";
+
+export const KYC_DOCUMENT_TYPE: Readonly<Record<string, KycDocumentType>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+};
+
+export const KYC_DOCUMENT_TYPES: ReadonlyArray<KycDocumentType> = [
+  "1",
+  "2",
+  "3",
+];
+
+export type KycLevel = "0" | "1" | "2";
+
+export const KYC_LEVEL: Readonly<Record<string, KycLevel>> = {
+  _0: "0",
+  _1: "1",
+  _2: "2",
+};
+
+export const KYC_LEVELS: ReadonlyArray<KycLevel> = [
+  "0",
+  "1",
+  "2",
+];
+
+export type KycStatus = "1" | "2" | "3";
+
+export const KYC_STATUS: Readonly<Record<string, KycStatus>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+};
+
+export const KYC_STATUSES: ReadonlyArray<KycStatus> = [
+  "1",
+  "2",
+  "3",
+];
+
+export type WithdrawalDestinationKind = "1" | "2";
+
+export const WITHDRAWAL_DESTINATION_KIND: Readonly<Record<string, WithdrawalDestinationKind>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const WITHDRAWAL_DESTINATION_KINDS: ReadonlyArray<WithdrawalDestinationKind> = [
+  "1",
+  "2",
+];
+
+export type WithdrawalDestinationStatus = "1" | "2";
+
+export const WITHDRAWAL_DESTINATION_STATUS: Readonly<Record<string, WithdrawalDestinationStatus>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const WITHDRAWAL_DESTINATION_STATUSES: ReadonlyArray<WithdrawalDestinationStatus> = [
+  "1",
+  "2",
+];
+
+export type WithdrawalMethod = "1" | "2";
+
+export const WITHDRAWAL_METHOD: Readonly<Record<string, WithdrawalMethod>> = {
+  _1: "1",
+  _2: "2",
+};
+
+export const WITHDRAWAL_METHODS: ReadonlyArray<WithdrawalMethod> = [
+  "1",
+  "2",
+];
+
+export type WithdrawalStatus = "1" | "2" | "3" | "4" | "5" | "6";
+
+export const WITHDRAWAL_STATUS: Readonly<Record<string, WithdrawalStatus>> = {
+  _1: "1",
+  _2: "2",
+  _3: "3",
+  _4: "4",
+  _5: "5",
+  _6: "6",
+};
+
+export const WITHDRAWAL_STATUSES: ReadonlyArray<WithdrawalStatus> = [
+  "1",
+  "2",
+  "3",
+  "4",
+  "5",
+  "6",
+];
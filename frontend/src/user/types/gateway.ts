@@ -0,0 +1,21 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What the user did on the fake provider's checkout page.
+ */
+export type FakeCheckoutInput = { 
+/**
+ * `false` simulates a declined payment
+ */
+paid: boolean, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type GatewayCallbackOutput = { 
+/**
+ * `approved`, `rejected`, `duplicate` or `ignored: <reason>`
+ */
+outcome: string, };
@@ -1,3 +1,10 @@
 export * from "@user/types/enums";
+export * from "@user/types/gateway";
 export * from "@user/types/user-auth";
+export * from "@user/types/user-conversion";
+export * from "@user/types/user-deposit";
+export * from "@user/types/user-kyc";
 export * from "@user/types/user-team";
+export * from "@user/types/user-transfer";
+export * from "@user/types/user-withdrawal";
+export * from "@user/types/user-withdrawal_destination";
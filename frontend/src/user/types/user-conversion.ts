@@ -0,0 +1,19 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { CreditType } from "@user/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserConversionInput = { from_credit_type: CreditType, to_credit_type: CreditType, amount: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserConversionOutput = { related_key: string, from_credit_type: CreditType, to_credit_type: CreditType, amount: string, fee: string, rate: string, converted_amount: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserConversionRate = { from_credit_type: CreditType, to_credit_type: CreditType, rate: string, fee_percentage: string, fee_fixed: string, min_amount: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserConversionRatesOutput = { rates: Array<{ from_credit_type: CreditType, to_credit_type: CreditType, rate: string, fee_percentage: string, fee_fixed: string, min_amount: string, }>, };
@@ -0,0 +1,51 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { CreditType, DepositMethod, DepositStatus } from "@user/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserDepositBankTarget = { id: string, bank_id: string, bank_name: string | null, bank_logo_url: string | null, account_name: string, account_number: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserDepositCreateInput = { credit_type: CreditType, amount: string, company_bank_account_id: string | null, company_crypto_account_id: string | null, remark: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserDepositCryptoTarget = { id: string, crypto_network_id: string, crypto_network_name: string | null, crypto_network_symbol: string | null, crypto_network_logo_url: string | null, wallet_address: string, conversion_rate: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserDepositListOutput = { data: Array<{ id: string, credit_type: CreditType, deposit_method: DepositMethod, company_bank_account_id: string | null, company_crypto_account_id: string | null, conversion_rate: string | null, status: DepositStatus, amount: string, fee: string, net_amount: string, 
+/**
+ * Put this in the transfer memo so the payment can be matched
+ */
+reference_code: string | null, 
+/**
+ * Payment page of a gateway deposit that is still pending
+ */
+checkout_url: string | null, receipt_url: string | null, remark: string | null, admin_remark: string | null, reviewed_at: string | null, reversal_reason: string | null, reversed_at: string | null, created_at: string, }>, total: number, per_page: number, current_page: number, last_page: number, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserDepositListQuery = { page: bigint | null, per_page: bigint | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserDepositOutput = { id: string, credit_type: CreditType, deposit_method: DepositMethod, company_bank_account_id: string | null, company_crypto_account_id: string | null, conversion_rate: string | null, status: DepositStatus, amount: string, fee: string, net_amount: string, 
+/**
+ * Put this in the transfer memo so the payment can be matched
+ */
+reference_code: string | null, 
+/**
+ * Payment page of a gateway deposit that is still pending
+ */
+checkout_url: string | null, receipt_url: string | null, remark: string | null, admin_remark: string | null, reviewed_at: string | null, reversal_reason: string | null, reversed_at: string | null, created_at: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserDepositTargetsOutput = { bank_accounts: Array<{ id: string, bank_id: string, bank_name: string | null, bank_logo_url: string | null, account_name: string, account_number: string, }>, crypto_accounts: Array<{ id: string, crypto_network_id: string, crypto_network_name: string | null, crypto_network_symbol: string | null, crypto_network_logo_url: string | null, wallet_address: string, conversion_rate: string, }>, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserGatewayDepositInput = { credit_type: CreditType, amount: string, remark: string | null, };
@@ -0,0 +1,23 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { KycDocumentType, KycLevel, KycStatus } from "@user/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserKycOutput = { kyc_level: KycLevel, 
+/**
+ * The latest submission, if any
+ */
+submission: UserKycSubmissionOutput | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserKycSubmissionOutput = { id: string, level: KycLevel, status: KycStatus, full_name: string, document_type: KycDocumentType, document_number: string, reject_reason: string | null, id_front_url: string | null, id_back_url: string | null, selfie_url: string | null, proof_of_address_url: string | null, 
+/**
+ * Documents still to upload before the submission can be approved
+ */
+missing_documents: Array<string>, reviewed_at: string | null, created_at: string, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserKycSubmitInput = { level: KycLevel, full_name: string, document_type: KycDocumentType, document_number: string, };
@@ -0,0 +1,11 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { CreditType } from "@user/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserTransferInput = { to_username: string, credit_type: CreditType, amount: string, remark: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserTransferOutput = { related_key: string, to_username: string, credit_type: CreditType, amount: string, balance: string, created_at: string, };
@@ -0,0 +1,23 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { CreditType, WithdrawalMethod, WithdrawalStatus } from "@user/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserWithdrawalCreateInput = { credit_type: CreditType, amount: string, 
+/**
+ * One of the user's saved withdrawal destinations
+ */
+destination_id: string, remark: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserWithdrawalListOutput = { data: Array<{ id: string, credit_type: CreditType, withdrawal_method: WithdrawalMethod, bank_id: string | null, bank_account_name: string | null, bank_account_number: string | null, crypto_network_id: string | null, crypto_wallet_address: string | null, destination_id: string | null, status: WithdrawalStatus, amount: string, fee: string, net_amount: string, remark: string | null, admin_remark: string | null, reviewed_at: string | null, refund_reason: string | null, refunded_at: string | null, created_at: string, }>, total: number, per_page: number, current_page: number, last_page: number, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserWithdrawalListQuery = { page: bigint | null, per_page: bigint | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserWithdrawalOutput = { id: string, credit_type: CreditType, withdrawal_method: WithdrawalMethod, bank_id: string | null, bank_account_name: string | null, bank_account_number: string | null, crypto_network_id: string | null, crypto_wallet_address: string | null, destination_id: string | null, status: WithdrawalStatus, amount: string, fee: string, net_amount: string, remark: string | null, admin_remark: string | null, reviewed_at: string | null, refund_reason: string | null, refunded_at: string | null, created_at: string, };
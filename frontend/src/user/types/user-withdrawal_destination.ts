@@ -0,0 +1,27 @@
+// Auto-generated by `cargo run -p app --bin export-types`.
+// Do not edit manually — run `make gen-types` to regenerate.
+import type { WithdrawalDestinationKind, WithdrawalDestinationStatus } from "@user/types/enums";
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A bank account (`bank_id` with the account fields) or a crypto wallet
+ * (`crypto_network_id` with the address), never both.
+ */
+export type UserWithdrawalDestinationInput = { bank_id: string | null, bank_account_name: string | null, bank_account_number: string | null, crypto_network_id: string | null, crypto_wallet_address: string | null, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserWithdrawalDestinationListOutput = { data: Array<{ id: string, kind: WithdrawalDestinationKind, bank_id: string | null, bank_name: string | null, bank_account_name: string | null, bank_account_number: string | null, crypto_network_id: string | null, crypto_network_name: string | null, crypto_wallet_address: string | null, status: WithdrawalDestinationStatus, 
+/**
+ * Withdrawals can use the destination from this time
+ */
+available_at: string, created_at: string, }>, };
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UserWithdrawalDestinationOutput = { id: string, kind: WithdrawalDestinationKind, bank_id: string | null, bank_name: string | null, bank_account_name: string | null, bank_account_number: string | null, crypto_network_id: string | null, crypto_network_name: string | null, crypto_wallet_address: string | null, status: WithdrawalDestinationStatus, 
+/**
+ * Withdrawals can use the destination from this time
+ */
+available_at: string, created_at: string, };
@@ -0,0 +1,15 @@
+CREATE TABLE credit_conversion_rates (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    from_credit_type SMALLINT NOT NULL,
+    to_credit_type SMALLINT NOT NULL,
+    rate NUMERIC(18,8) NOT NULL CHECK (rate > 0),
+    fee_percentage NUMERIC(18,8) NOT NULL DEFAULT 0 CHECK (fee_percentage >= 0 AND fee_percentage <= 1),
+    fee_fixed NUMERIC(18,8) NOT NULL DEFAULT 0 CHECK (fee_fixed >= 0),
+    min_amount NUMERIC(18,8) NOT NULL DEFAULT 0 CHECK (min_amount >= 0),
+    status SMALLINT NOT NULL DEFAULT 1,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT chk_credit_conversion_rates_direction CHECK (from_credit_type <> to_credit_type)
+);
+CREATE UNIQUE INDEX uq_credit_conversion_rates_direction ON credit_conversion_rates(from_credit_type, to_credit_type);
+CREATE INDEX idx_credit_conversion_rates_status ON credit_conversion_rates(status);
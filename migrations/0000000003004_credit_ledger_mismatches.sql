@@ -0,0 +1,17 @@
+CREATE TABLE credit_ledger_mismatches (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    user_id BIGINT NOT NULL REFERENCES users(id),
+    admin_id BIGINT REFERENCES admin(id),
+    credit_type SMALLINT NOT NULL,
+    stored_balance NUMERIC(18,8) NOT NULL,
+    ledger_balance NUMERIC(18,8) NOT NULL,
+    difference NUMERIC(18,8) NOT NULL,
+    status SMALLINT NOT NULL DEFAULT 1,
+    detected_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE UNIQUE INDEX uq_credit_ledger_mismatches_open ON credit_ledger_mismatches(user_id, credit_type) WHERE status = 1;
+CREATE INDEX idx_credit_ledger_mismatches_user_id ON credit_ledger_mismatches(user_id);
+CREATE INDEX idx_credit_ledger_mismatches_status ON credit_ledger_mismatches(status);
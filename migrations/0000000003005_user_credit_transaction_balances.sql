@@ -0,0 +1,21 @@
+ALTER TABLE user_credit_transactions
+    ADD COLUMN balance_before NUMERIC(18,8),
+    ADD COLUMN balance_after NUMERIC(18,8);
+
+-- Backfill existing rows with the running ledger total per user and credit type.
+UPDATE user_credit_transactions t
+SET balance_after = r.running_balance,
+    balance_before = r.running_balance - t.amount
+FROM (
+    SELECT id,
+           SUM(amount) OVER (
+               PARTITION BY user_id, credit_type
+               ORDER BY created_at, id
+           ) AS running_balance
+    FROM user_credit_transactions
+) r
+WHERE r.id = t.id;
+
+ALTER TABLE user_credit_transactions
+    ALTER COLUMN balance_before SET NOT NULL,
+    ALTER COLUMN balance_after SET NOT NULL;
@@ -0,0 +1,16 @@
+CREATE TABLE idempotency_keys (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    scope TEXT NOT NULL,
+    idempotency_key TEXT NOT NULL,
+    request_method TEXT NOT NULL,
+    request_path TEXT NOT NULL,
+    fingerprint TEXT NOT NULL,
+    status SMALLINT NOT NULL DEFAULT 1,
+    response_status SMALLINT,
+    response_content_type TEXT,
+    response_body BYTEA,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL
+);
+CREATE UNIQUE INDEX uq_idempotency_keys_scope_key ON idempotency_keys(scope, idempotency_key);
+CREATE INDEX idx_idempotency_keys_expires_at ON idempotency_keys(expires_at);
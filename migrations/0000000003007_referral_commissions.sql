@@ -0,0 +1,29 @@
+CREATE TABLE referral_commission_rates (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    credit_type SMALLINT NOT NULL,
+    level INT NOT NULL CHECK (level > 0),
+    percentage NUMERIC(18,8) NOT NULL CHECK (percentage >= 0 AND percentage <= 1),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE UNIQUE INDEX uq_referral_commission_rates_level ON referral_commission_rates(credit_type, level);
+
+CREATE TABLE referral_commissions (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    source_type SMALLINT NOT NULL,
+    source_id BIGINT NOT NULL,
+    source_user_id BIGINT NOT NULL REFERENCES users(id),
+    beneficiary_user_id BIGINT NOT NULL REFERENCES users(id),
+    level INT NOT NULL CHECK (level > 0),
+    credit_type SMALLINT NOT NULL,
+    base_amount NUMERIC(18,8) NOT NULL,
+    percentage NUMERIC(18,8) NOT NULL,
+    amount NUMERIC(18,8) NOT NULL CHECK (amount > 0),
+    status SMALLINT NOT NULL DEFAULT 1,
+    reversed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE UNIQUE INDEX uq_referral_commissions_source_level ON referral_commissions(source_type, source_id, level);
+CREATE INDEX idx_referral_commissions_beneficiary_user_id ON referral_commissions(beneficiary_user_id);
+CREATE INDEX idx_referral_commissions_source_user_id ON referral_commissions(source_user_id);
@@ -0,0 +1,4 @@
+ALTER TABLE withdrawals
+    ADD COLUMN refunded_by BIGINT REFERENCES admin(id),
+    ADD COLUMN refund_reason TEXT,
+    ADD COLUMN refunded_at TIMESTAMPTZ;
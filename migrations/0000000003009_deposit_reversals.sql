@@ -0,0 +1,4 @@
+ALTER TABLE deposits
+    ADD COLUMN reversed_by BIGINT REFERENCES admin(id),
+    ADD COLUMN reversal_reason TEXT,
+    ADD COLUMN reversed_at TIMESTAMPTZ;
@@ -0,0 +1,21 @@
+CREATE TABLE financial_approvals (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    action SMALLINT NOT NULL,
+    subject_id BIGINT NOT NULL,
+    credit_type SMALLINT NOT NULL,
+    amount NUMERIC(18,8) NOT NULL,
+    payload JSONB NOT NULL DEFAULT '{}'::jsonb,
+    status SMALLINT NOT NULL DEFAULT 1,
+    proposed_by BIGINT NOT NULL REFERENCES admin(id),
+    reviewed_by BIGINT REFERENCES admin(id),
+    review_remark TEXT,
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (status <> 2 OR reviewed_by <> proposed_by)
+);
+-- One open proposal per deposit/withdrawal; credit adjustments may stack.
+CREATE UNIQUE INDEX uq_financial_approvals_pending_subject
+    ON financial_approvals(action, subject_id)
+    WHERE status = 1 AND action IN (1, 2);
+CREATE INDEX idx_financial_approvals_status ON financial_approvals(status);
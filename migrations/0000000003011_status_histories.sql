@@ -0,0 +1,23 @@
+-- Deposits and withdrawals submitted before this migration start their
+-- timeline at their next status change.
+CREATE TABLE deposit_status_histories (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    deposit_id BIGINT NOT NULL REFERENCES deposits(id) ON DELETE CASCADE,
+    status SMALLINT NOT NULL,
+    admin_id BIGINT REFERENCES admin(id),
+    remark TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_deposit_status_histories_deposit_id
+    ON deposit_status_histories(deposit_id, created_at);
+
+CREATE TABLE withdrawal_status_histories (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    withdrawal_id BIGINT NOT NULL REFERENCES withdrawals(id) ON DELETE CASCADE,
+    status SMALLINT NOT NULL,
+    admin_id BIGINT REFERENCES admin(id),
+    remark TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_withdrawal_status_histories_withdrawal_id
+    ON withdrawal_status_histories(withdrawal_id, created_at);
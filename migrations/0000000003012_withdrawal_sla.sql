@@ -0,0 +1 @@
+ALTER TABLE withdrawals ADD COLUMN sla_breached_at TIMESTAMPTZ;
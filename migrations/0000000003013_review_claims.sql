@@ -0,0 +1,7 @@
+ALTER TABLE deposits
+    ADD COLUMN claimed_by BIGINT REFERENCES admin(id),
+    ADD COLUMN claimed_until TIMESTAMPTZ;
+
+ALTER TABLE withdrawals
+    ADD COLUMN claimed_by BIGINT REFERENCES admin(id),
+    ADD COLUMN claimed_until TIMESTAMPTZ;
@@ -0,0 +1,38 @@
+-- Deposits submitted before this migration have no reference code and can
+-- only be matched by hand.
+ALTER TABLE deposits ADD COLUMN reference_code VARCHAR(16);
+CREATE UNIQUE INDEX uq_deposits_reference_code
+    ON deposits(reference_code) WHERE reference_code IS NOT NULL;
+
+CREATE TABLE bank_statement_imports (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    company_bank_account_id BIGINT NOT NULL REFERENCES company_bank_accounts(id),
+    file_name TEXT,
+    line_count INT NOT NULL DEFAULT 0,
+    matched_count INT NOT NULL DEFAULT 0,
+    uploaded_by BIGINT NOT NULL REFERENCES admin(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_bank_statement_imports_created_at
+    ON bank_statement_imports(created_at DESC);
+
+CREATE TABLE bank_statement_lines (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    import_id BIGINT NOT NULL REFERENCES bank_statement_imports(id) ON DELETE CASCADE,
+    line_number INT NOT NULL,
+    reference TEXT NOT NULL DEFAULT '',
+    amount NUMERIC(18,8),
+    account_number TEXT,
+    transaction_date TEXT,
+    raw JSONB NOT NULL DEFAULT '{}'::jsonb,
+    status SMALLINT NOT NULL,
+    deposit_id BIGINT REFERENCES deposits(id),
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_bank_statement_lines_import_id
+    ON bank_statement_lines(import_id, line_number);
+CREATE INDEX idx_bank_statement_lines_unmatched
+    ON bank_statement_lines(created_at) WHERE status = 2;
@@ -0,0 +1,8 @@
+-- Gateway deposits are tied to the provider's checkout session; callbacks
+-- look the deposit up by provider and session id.
+ALTER TABLE deposits
+    ADD COLUMN gateway_provider VARCHAR(32),
+    ADD COLUMN gateway_session_id VARCHAR(128),
+    ADD COLUMN gateway_checkout_url TEXT;
+CREATE UNIQUE INDEX uq_deposits_gateway_session
+    ON deposits(gateway_provider, gateway_session_id) WHERE gateway_session_id IS NOT NULL;
@@ -0,0 +1,9 @@
+-- Withdrawals sent through a payout provider. `payout_reference` is the
+-- provider's id for the payout, used to poll its status.
+ALTER TABLE withdrawals
+    ADD COLUMN payout_provider VARCHAR(32),
+    ADD COLUMN payout_reference VARCHAR(128),
+    ADD COLUMN payout_submitted_at TIMESTAMPTZ,
+    ADD COLUMN payout_checked_at TIMESTAMPTZ;
+CREATE UNIQUE INDEX uq_withdrawals_payout_reference
+    ON withdrawals(payout_provider, payout_reference) WHERE payout_reference IS NOT NULL;
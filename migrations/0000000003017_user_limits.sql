@@ -0,0 +1,24 @@
+-- Per-user overrides of the `[limits]` config, one row per user and kind
+-- (1 = deposit, 2 = withdrawal). NULL inherits the country or global value;
+-- 0 lifts the limit for this user.
+CREATE TABLE user_limits (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    user_id BIGINT NOT NULL REFERENCES users(id),
+    kind SMALLINT NOT NULL,
+    min_amount NUMERIC(18,8) CHECK (min_amount >= 0),
+    max_amount NUMERIC(18,8) CHECK (max_amount >= 0),
+    daily_count INT CHECK (daily_count >= 0),
+    daily_amount NUMERIC(18,8) CHECK (daily_amount >= 0),
+    weekly_count INT CHECK (weekly_count >= 0),
+    weekly_amount NUMERIC(18,8) CHECK (weekly_amount >= 0),
+    monthly_count INT CHECK (monthly_count >= 0),
+    monthly_amount NUMERIC(18,8) CHECK (monthly_amount >= 0),
+    updated_by BIGINT REFERENCES admin(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE UNIQUE INDEX uq_user_limits_user_kind ON user_limits(user_id, kind);
+
+-- Usage windows sum a user's records by creation time.
+CREATE INDEX idx_deposits_owner_created_at ON deposits(owner_type, owner_id, created_at);
+CREATE INDEX idx_withdrawals_owner_created_at ON withdrawals(owner_type, owner_id, created_at);
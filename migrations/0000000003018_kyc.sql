@@ -0,0 +1,24 @@
+-- Verified identity level: 0 = unverified, 1 = basic, 2 = advanced.
+ALTER TABLE users ADD COLUMN kyc_level SMALLINT NOT NULL DEFAULT 0;
+
+-- A user's request to reach a KYC level. Documents are `image` attachments
+-- owned by the submission (`id_front`, `id_back`, `selfie`,
+-- `proof_of_address`). Status: 1 = pending, 2 = approved, 3 = rejected.
+CREATE TABLE kyc_submissions (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    user_id BIGINT NOT NULL REFERENCES users(id),
+    level SMALLINT NOT NULL CHECK (level > 0),
+    status SMALLINT NOT NULL DEFAULT 1,
+    full_name TEXT NOT NULL,
+    document_type SMALLINT NOT NULL,
+    document_number TEXT NOT NULL,
+    reject_reason TEXT,
+    admin_id BIGINT REFERENCES admin(id),
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_kyc_submissions_user_id ON kyc_submissions(user_id);
+CREATE INDEX idx_kyc_submissions_status ON kyc_submissions(status);
+-- At most one submission per user waiting for review
+CREATE UNIQUE INDEX uq_kyc_submissions_pending ON kyc_submissions(user_id) WHERE status = 1;
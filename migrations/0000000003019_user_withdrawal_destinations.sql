@@ -0,0 +1,39 @@
+-- A user's saved bank accounts (kind 1) and crypto wallets (kind 2).
+-- Withdrawals can use a destination from `available_at`, which is later
+-- than `created_at` while it cools off. Status: 1 = active, 2 = locked.
+CREATE TABLE user_withdrawal_destinations (
+    id BIGINT PRIMARY KEY CHECK (id > 0),
+    user_id BIGINT NOT NULL REFERENCES users(id),
+    kind SMALLINT NOT NULL,
+    bank_id BIGINT REFERENCES banks(id),
+    bank_account_name TEXT,
+    bank_account_number TEXT,
+    crypto_network_id BIGINT REFERENCES crypto_networks(id),
+    crypto_wallet_address TEXT,
+    status SMALLINT NOT NULL DEFAULT 1,
+    available_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    lock_reason TEXT,
+    locked_by BIGINT REFERENCES admin(id),
+    locked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (
+        (kind = 1 AND bank_id IS NOT NULL AND bank_account_number IS NOT NULL
+            AND crypto_network_id IS NULL)
+        OR (kind = 2 AND crypto_network_id IS NOT NULL AND crypto_wallet_address IS NOT NULL
+            AND bank_id IS NULL)
+    )
+);
+CREATE INDEX idx_user_withdrawal_destinations_user_id ON user_withdrawal_destinations(user_id);
+CREATE INDEX idx_user_withdrawal_destinations_status ON user_withdrawal_destinations(status);
+CREATE UNIQUE INDEX uq_user_withdrawal_destinations_bank
+    ON user_withdrawal_destinations(user_id, bank_id, bank_account_number) WHERE kind = 1;
+CREATE UNIQUE INDEX uq_user_withdrawal_destinations_crypto
+    ON user_withdrawal_destinations(user_id, crypto_network_id, crypto_wallet_address)
+    WHERE kind = 2;
+
+-- The saved destination a withdrawal was requested to. Its details are still
+-- copied onto the withdrawal, so removing the destination keeps the history.
+ALTER TABLE withdrawals
+    ADD COLUMN destination_id BIGINT
+        REFERENCES user_withdrawal_destinations(id) ON DELETE SET NULL;
@@ -0,0 +1,3 @@
+-- Address format wallet addresses on the network are validated against
+-- (1 = unchecked, 2 = EVM, 3 = TRON, 4 = Bitcoin).
+ALTER TABLE crypto_networks ADD COLUMN address_format SMALLINT NOT NULL DEFAULT 1;
@@ -0,0 +1,9 @@
+-- Rules account numbers at the bank must follow (format 1 = any, 2 = digits
+-- only, 3 = IBAN). The length range and the pattern are optional.
+ALTER TABLE banks
+    ADD COLUMN account_number_format SMALLINT NOT NULL DEFAULT 1,
+    ADD COLUMN account_number_min_length INT CHECK (account_number_min_length > 0),
+    ADD COLUMN account_number_max_length INT CHECK (account_number_max_length > 0),
+    ADD COLUMN account_number_pattern TEXT,
+    ADD CONSTRAINT chk_banks_account_number_length
+        CHECK (account_number_max_length >= account_number_min_length);